@@ -5,6 +5,7 @@ pub mod check;
 pub mod generate;
 pub mod import;
 pub mod solve;
+pub mod suggest;
 
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write, stdout};
@@ -0,0 +1,104 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/suggest_test.rs"]
+mod suggest_test;
+
+use super::*;
+use vrp_pragmatic::format::problem::Job as ApiJob;
+use vrp_pragmatic::format::solution::{
+    deserialize_solution, get_insertion_suggestions, serialize_insertion_suggestions,
+};
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const JOBS_ARG_NAME: &str = "jobs-file";
+const MATRIX_ARG_NAME: &str = "matrix";
+const LIMIT_ARG_NAME: &str = "limit";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_suggest_app() -> Command {
+    Command::new("suggest")
+        .about("Suggests cheapest insertion positions for new jobs in existing solution without re-optimizing it")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(
+            Arg::new(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short('p')
+                .long(PROBLEM_ARG_NAME)
+                .required(true),
+        )
+        .arg(Arg::new(SOLUTION_ARG_NAME).help("Sets solution file").short('s').long(SOLUTION_ARG_NAME).required(true))
+        .arg(
+            Arg::new(JOBS_ARG_NAME)
+                .help("Sets file which contains an array of new jobs")
+                .short('j')
+                .long(JOBS_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .required(false)
+                .num_args(1..),
+        )
+        .arg(
+            Arg::new(LIMIT_ARG_NAME)
+                .help("Specifies max amount of suggestions per job")
+                .short('l')
+                .long(LIMIT_ARG_NAME)
+                .default_value("3")
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for result output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_suggest(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let input_format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+    if input_format != "pragmatic" {
+        return Err(format!("unknown format: '{input_format}'").into());
+    }
+
+    let limit = parse_int_value::<usize>(matches, LIMIT_ARG_NAME, "limit")?.unwrap_or(3);
+
+    let problem_path = matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+    let problem = deserialize_problem(BufReader::new(open_file(problem_path, "problem")))
+        .map_err(|errs| format!("cannot read problem: '{errs}'"))?;
+
+    let solution_path = matches.get_one::<String>(SOLUTION_ARG_NAME).unwrap();
+    let solution = deserialize_solution(BufReader::new(open_file(solution_path, "solution")))
+        .map_err(|err| format!("cannot read solution: '{err}'"))?;
+
+    let jobs_path = matches.get_one::<String>(JOBS_ARG_NAME).unwrap();
+    let jobs: Vec<ApiJob> = serde_json::from_reader(BufReader::new(open_file(jobs_path, "jobs")))
+        .map_err(|err| format!("cannot read jobs: '{err}'"))?;
+
+    let matrices = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .map(|paths| {
+            paths
+                .map(|path| {
+                    deserialize_matrix(BufReader::new(open_file(path, "routing matrix")))
+                        .map_err(|errs| format!("cannot read matrix: '{errs}'"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    let suggestions = get_insertion_suggestions(problem, matrices, solution, jobs, limit)?;
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let mut writer = out_writer_func(out_result);
+
+    serialize_insertion_suggestions(suggestions.as_slice(), &mut writer)
+}
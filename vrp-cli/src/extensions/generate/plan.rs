@@ -38,6 +38,7 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            soft_times: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -73,11 +74,19 @@ pub(crate) fn generate_plan(
                 same_assignee_key: job_proto.same_assignee_key.clone(),
                 sync: job_proto.sync.clone(),
                 sequence: job_proto.sequence.clone(),
+                product: job_proto.product.clone(),
+                max_ride_time: job_proto.max_ride_time.clone(),
+                split: job_proto.split.clone(),
+                trailer_allowed: job_proto.trailer_allowed,
+                transfer: job_proto.transfer.clone(),
+                assignment_costs: job_proto.assignment_costs.clone(),
+                zone: job_proto.zone.clone(),
+                outsourcing_cost: job_proto.outsourcing_cost,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan { jobs, relations: None, clustering: None, outsourcing_cost: None })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
@@ -71,6 +71,7 @@ mod actual {
                 duration: job.duration as Float,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                soft_times: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
@@ -101,6 +102,14 @@ mod actual {
                 affinity: None,
                 same_assignee_key: None,
                 sync: None,
+                product: None,
+                max_ride_time: None,
+                split: None,
+                trailer_allowed: None,
+                transfer: None,
+                assignment_costs: None,
+                zone: None,
+                outsourcing_cost: None,
                 sequence: None,
             })
             .collect();
@@ -123,6 +132,8 @@ mod actual {
                         distance: TieredCost::Fixed(0.0002), 
                         time: TieredCost::Fixed(0.005),
                         calculation_mode: None,
+                        time_rates: None,
+                        price_list: None,
                     },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
@@ -134,10 +145,17 @@ mod actual {
                         breaks: None,
                         reloads: None,
                         recharges: None,
+                        overtime: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    hours_of_service: None,
+                    trailer_id: None,
+                    overnight: None,
+                    emissions: None,
+                    compartments: None,
+                    loading_policy: None,
                 }
             })
             .collect();
@@ -164,11 +182,12 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, clustering: None, outsourcing_cost: None },
             fleet: Fleet {
                 vehicles,
                 profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
                 resources: None,
+                trailers: None,
             },
             objectives: None,
         })
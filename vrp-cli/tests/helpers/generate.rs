@@ -15,6 +15,14 @@ pub fn create_empty_job() -> Job {
         affinity: None,
         same_assignee_key: None,
         sync: None,
+        product: None,
+        max_ride_time: None,
+        split: None,
+        trailer_allowed: None,
+        transfer: None,
+        assignment_costs: None,
+        zone: None,
+        outsourcing_cost: None,
         sequence: None,
     }
 }
@@ -24,11 +32,17 @@ pub fn create_empty_job_task() -> JobTask {
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        soft_times: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, outsourcing_cost: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -36,7 +50,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: TieredCost::Fixed(1.), time: TieredCost::Fixed(0.), calculation_mode: None },
+        costs: VehicleCosts { fixed: None, distance: TieredCost::Fixed(1.), time: TieredCost::Fixed(0.), calculation_mode: None, time_rates: None, price_list: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
@@ -47,10 +61,17 @@ pub fn create_test_vehicle_type() -> VehicleType {
             breaks: None,
             reloads: None,
             recharges: None,
+            overtime: None,
         }],
         capacity: vec![10],
         skills: None,
         limits: None,
+        hours_of_service: None,
+        trailer_id: None,
+        overnight: None,
+        emissions: None,
+        compartments: None,
+        loading_policy: None,
     }
 }
 
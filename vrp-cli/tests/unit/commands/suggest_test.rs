@@ -0,0 +1,50 @@
+use super::*;
+use crate::cli::{get_app, run_subcommand};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn create_jobs_file() -> tempfile::NamedTempFile {
+    let mut jobs_file = tempfile::NamedTempFile::new().unwrap();
+    let jobs = r#"[{
+        "id": "job6",
+        "deliveries": [{
+            "places": [{ "location": { "lat": 52.52599, "lng": 13.45413 }, "duration": 60 }],
+            "demand": [1]
+        }]
+    }]"#;
+    jobs_file.write_all(jobs.as_bytes()).unwrap();
+
+    jobs_file
+}
+
+#[test]
+fn can_run_suggest_command() {
+    let jobs_file = create_jobs_file();
+    let out_file = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "suggest",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--jobs-file",
+        jobs_file.path().to_str().unwrap(),
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--limit",
+        "2",
+        "--out-result",
+        out_file.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    let result: serde_json::Value = serde_json::from_reader(BufReader::new(out_file.reopen().unwrap())).unwrap();
+    assert_eq!(result[0]["jobId"], "job6");
+    assert_eq!(result[0]["insertions"].as_array().unwrap().len(), 2);
+}
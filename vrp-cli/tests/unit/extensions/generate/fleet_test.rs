@@ -10,6 +10,7 @@ fn can_generate_fleet_of_specific_size() {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
             resources: None,
+            trailers: None,
         },
         objectives: None,
     };
@@ -0,0 +1,149 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/capacity_resource_test.rs"]
+mod capacity_resource_test;
+
+use crate::construction::enablers::FixedDepartureTourState;
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::{Duration, TimeWindow, Timestamp};
+use crate::models::problem::Actor;
+use crate::models::solution::Route;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Specifies a time when a resource with limited capacity, e.g. charger or loading dock, is occupied
+/// by the tour activity.
+#[derive(Clone, Debug)]
+pub struct ResourceSession {
+    /// A resource id.
+    pub resource_id: usize,
+    /// An amount of sessions which can use the resource at the same time.
+    pub capacity: usize,
+    /// An index of the activity in the tour.
+    pub activity_idx: usize,
+    /// A time when the resource is occupied.
+    pub time: TimeWindow,
+}
+
+/// Keeps sessions of all tours grouped by resource id.
+pub type ResourceSessions = HashMap<usize, Vec<(Arc<Actor>, TimeWindow)>>;
+
+/// Groups sessions of all routes by resource id.
+pub fn group_resource_sessions<'a>(
+    route_sessions: impl Iterator<Item = (&'a Route, &'a [ResourceSession])>,
+) -> ResourceSessions {
+    route_sessions.fold(ResourceSessions::default(), |mut acc, (route, sessions)| {
+        sessions.iter().for_each(|session| {
+            acc.entry(session.resource_id).or_default().push((route.actor.clone(), session.time.clone()));
+        });
+
+        acc
+    })
+}
+
+/// Returns sessions of the given resource used by other actors.
+pub fn get_other_sessions<'a>(
+    sessions: &'a ResourceSessions,
+    resource_id: usize,
+    actor: &'a Arc<Actor>,
+) -> impl Iterator<Item = &'a TimeWindow> + 'a {
+    sessions
+        .get(&resource_id)
+        .into_iter()
+        .flat_map(|sessions| sessions.iter())
+        .filter(move |(other, _)| other != actor)
+        .map(|(_, tw)| tw)
+}
+
+/// Returns sessions which cannot be served by the resource: sessions are served in order of their start
+/// time while there is a free capacity and the resource is available according to the given function.
+pub fn get_overbooked_sessions<T>(
+    mut sessions: Vec<(ResourceSession, T)>,
+    is_available_fn: impl Fn(&ResourceSession) -> bool,
+) -> Vec<(ResourceSession, T)> {
+    sessions.sort_by(|(a, _), (b, _)| a.resource_id.cmp(&b.resource_id).then(a.time.start.total_cmp(&b.time.start)));
+
+    let mut active: Vec<Timestamp> = Vec::default();
+    let mut resource_id = None;
+
+    sessions
+        .into_iter()
+        .filter(|(session, _)| {
+            if resource_id != Some(session.resource_id) {
+                resource_id = Some(session.resource_id);
+                active.clear();
+            }
+
+            active.retain(|&end| end > session.time.start);
+            let is_overbooked = active.len() >= session.capacity || !is_available_fn(session);
+            if !is_overbooked {
+                active.push(session.time.end);
+            }
+
+            is_overbooked
+        })
+        .collect()
+}
+
+/// Prepares resource slacks of the route when sessions of other routes are not known: any delay of the
+/// route sessions is prevented. As shifting departure time moves the sessions, it is not allowed too.
+pub fn get_route_resource_slacks(route_ctx: &mut RouteContext, sessions: &[ResourceSession]) -> Vec<Duration> {
+    if !sessions.is_empty() {
+        route_ctx.state_mut().set_fixed_departure(true);
+    }
+
+    get_resource_slacks(route_ctx.route(), sessions, |_| Duration::default())
+}
+
+/// Returns for each activity the max delay which can be applied to the sessions at or after it.
+pub fn get_resource_slacks<F>(route: &Route, sessions: &[ResourceSession], slack_fn: F) -> Vec<Duration>
+where
+    F: Fn(&ResourceSession) -> Duration,
+{
+    let mut slacks = vec![Duration::MAX; route.tour.total()];
+
+    let (end_idx, slack) =
+        sessions.iter().rev().fold((route.tour.total(), Duration::MAX), |(end_idx, slack), session| {
+            slacks[(session.activity_idx + 1)..end_idx].fill(slack);
+
+            (session.activity_idx + 1, slack.min(slack_fn(session)))
+        });
+    slacks[..end_idx].fill(slack);
+
+    slacks
+}
+
+/// Returns max amount of sessions which overlap with the given one at the same time.
+pub fn get_max_overlaps<'a>(sessions: impl Iterator<Item = &'a TimeWindow>, session: &TimeWindow) -> usize {
+    let overlapping =
+        sessions.filter(|other| other.start < session.end && session.start < other.end).collect::<Vec<_>>();
+
+    overlapping
+        .iter()
+        .map(|other| other.start.max(session.start))
+        .map(|time| overlapping.iter().filter(|other| other.start <= time && time < other.end).count())
+        .max()
+        .unwrap_or_default()
+}
+
+/// Returns max delay of the given session which keeps amount of overlapping sessions below capacity.
+pub fn get_slack<'a>(
+    sessions: impl Iterator<Item = &'a TimeWindow>,
+    capacity: usize,
+    session: &TimeWindow,
+) -> Duration {
+    let sessions = sessions.collect::<Vec<_>>();
+
+    if get_max_overlaps(sessions.iter().copied(), session) >= capacity {
+        return Duration::default();
+    }
+
+    // NOTE when session is delayed, a new overlap can happen only at start of some other session
+    sessions
+        .iter()
+        .map(|other| other.start)
+        .filter(|&time| time >= session.end)
+        .filter(|&time| sessions.iter().filter(|other| other.start <= time && time < other.end).count() >= capacity)
+        .map(|time| time - session.end)
+        .min_by(|a, b| a.total_cmp(b))
+        .unwrap_or(Duration::MAX)
+}
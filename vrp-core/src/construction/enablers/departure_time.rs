@@ -3,11 +3,13 @@
 mod departure_time_test;
 
 use crate::construction::enablers::*;
-use crate::construction::heuristics::RouteContext;
+use crate::construction::heuristics::{RouteContext, RouteState};
 use crate::models::common::Timestamp;
 use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
 use rosomaxa::prelude::Float;
 
+custom_tour_state!(pub FixedDeparture typeof bool);
+
 /// Tries to move forward route's departure time.
 pub fn advance_departure_time(
     route_ctx: &mut RouteContext,
@@ -36,6 +38,10 @@ fn try_advance_departure_time(
     transport: &(dyn TransportCost),
     optimize_whole_tour: bool,
 ) -> Option<Timestamp> {
+    if is_fixed_departure(route_ctx) {
+        return None;
+    }
+
     let route = route_ctx.route();
 
     let first = route.tour.get(1)?;
@@ -71,6 +77,10 @@ fn try_advance_departure_time(
 }
 
 fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
+    if is_fixed_departure(route_ctx) {
+        return None;
+    }
+
     let first = route_ctx.route().tour.get(1)?;
     let start = route_ctx.route().tour.start()?;
 
@@ -90,3 +100,8 @@ fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
 
     if max_change > 0. { Some(start.schedule.departure - max_change) } else { None }
 }
+
+/// Checks whether departure time of the route should not be changed as some feature depends on it.
+fn is_fixed_departure(route_ctx: &RouteContext) -> bool {
+    route_ctx.state().get_fixed_departure().copied().unwrap_or(false)
+}
@@ -112,6 +112,10 @@ impl CombinedFeatureState {
 }
 
 impl FeatureState for CombinedFeatureState {
+    fn notify_failure(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], jobs: &[Job]) -> bool {
+        notify_failure_with_states(&self.states, solution_ctx, route_indices, jobs)
+    }
+
     fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
         accept_insertion_with_states(&self.states, solution_ctx, route_index, job)
     }
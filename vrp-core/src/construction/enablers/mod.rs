@@ -1,6 +1,9 @@
 //! This module contains feature extension functionality which can be used to work with the same aspects
 //! from different features.
 
+mod capacity_resource;
+pub use self::capacity_resource::*;
+
 mod conditional_job;
 pub use self::conditional_job::*;
 
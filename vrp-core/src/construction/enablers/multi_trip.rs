@@ -3,8 +3,8 @@
 
 use crate::construction::enablers::*;
 use crate::construction::heuristics::*;
-use crate::models::problem::Job;
-use crate::models::solution::Route;
+use crate::models::problem::{Job, Single};
+use crate::models::solution::{Activity, Route};
 use crate::models::*;
 use rosomaxa::prelude::*;
 use std::collections::HashSet;
@@ -199,3 +199,190 @@ impl FeatureState for MultiTripState {
         self.multi_trip.get_route_intervals().update_solution_intervals(solution_ctx);
     }
 }
+
+/// Specifies a counter which is accumulated within a marker interval and reset by marker activities,
+/// e.g. traveled distance or driving time, together with its limit.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct MarkerIntervalCounter {
+    /// Returns an accumulated counter at given activity index.
+    pub counter_fn: Arc<dyn Fn(&RouteContext, usize) -> Float + Send + Sync>,
+    /// Returns a counter of the leg between two activities, the first one is specified by its index.
+    pub leg_counter_fn: Arc<dyn Fn(&RouteContext, usize, &Activity, &Activity) -> Float + Send + Sync>,
+    /// Returns a counter limit for the route interval, the flag specifies whether the interval is the first one.
+    pub limit_fn: Arc<dyn Fn(&RouteContext, bool) -> Option<Float> + Send + Sync>,
+}
+
+impl MarkerIntervalCounter {
+    /// Creates route intervals which are split by marker jobs when the counter exceeds its limit.
+    #[allow(clippy::type_complexity)]
+    pub fn create_route_intervals(
+        &self,
+        is_marker_single_fn: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+        is_assignable_fn: Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>,
+        intervals_state: Arc<dyn RouteIntervalsState>,
+    ) -> RouteIntervals {
+        RouteIntervals::Multiple {
+            is_marker_single_fn: is_marker_single_fn.clone(),
+            is_new_interval_needed_fn: Arc::new({
+                let counter = self.clone();
+                move |route_ctx| {
+                    route_ctx.route().tour.end_idx().is_some_and(|end_idx| {
+                        let is_first_interval = !route_ctx
+                            .route()
+                            .tour
+                            .all_activities()
+                            .any(|a| a.job.as_ref().is_some_and(|single| (is_marker_single_fn)(single)));
+
+                        (counter.limit_fn)(route_ctx, is_first_interval)
+                            .is_some_and(|limit| (counter.counter_fn)(route_ctx, end_idx) > limit)
+                    })
+                }
+            }),
+            is_obsolete_interval_fn: Arc::new({
+                let counter = self.clone();
+                move |route_ctx, left, right| {
+                    let route = route_ctx.route();
+                    let end_idx = get_interval_end_idx(route_ctx, right.end);
+                    let leg_counter = route
+                        .tour
+                        .get(left.end)
+                        .zip(route.tour.get(right.start + 1))
+                        .map_or(Float::default(), |(from, to)| (counter.leg_counter_fn)(route_ctx, left.end, from, to));
+
+                    let new_counter = (counter.counter_fn)(route_ctx, left.end)
+                        + (counter.counter_fn)(route_ctx, end_idx)
+                        - (counter.counter_fn)(route_ctx, right.start + 1)
+                        + leg_counter;
+
+                    (counter.limit_fn)(route_ctx, left.start == 0).is_some_and(|limit| new_counter <= limit)
+                }
+            }),
+            is_assignable_fn,
+            intervals_state,
+        }
+    }
+
+    /// Checks whether the interval counter exceeds the limit when it is changed by given delta.
+    pub fn is_interval_violation(&self, route_ctx: &RouteContext, interval: (usize, usize), delta: Float) -> bool {
+        let (start_idx, end_idx) = interval;
+
+        (self.limit_fn)(route_ctx, start_idx == 0)
+            .is_some_and(|limit| (self.counter_fn)(route_ctx, end_idx) + delta > limit)
+    }
+
+    /// Checks whether insertion of a new marker activity leads to the limit violation in one of the two parts
+    /// of the interval split by it.
+    pub fn is_marker_insertion_violation(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        interval: (usize, usize),
+    ) -> bool {
+        // S ----- A ---- [X] ------ B ----- F
+        let (start_idx, end_idx) = interval;
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let counter_fn = |activity_idx: usize| (self.counter_fn)(route_ctx, activity_idx);
+
+        // check S->X
+        let is_begin_violates = (self.limit_fn)(route_ctx, start_idx == 0).is_some_and(|limit| {
+            counter_fn(activity_ctx.index) + (self.leg_counter_fn)(route_ctx, activity_ctx.index, prev, target) > limit
+        });
+
+        // check X->F
+        let is_end_violates = activity_ctx.next.is_some_and(|next| {
+            let new_counter = counter_fn(end_idx) - counter_fn(activity_ctx.index + 1)
+                + (self.leg_counter_fn)(route_ctx, activity_ctx.index, target, next);
+
+            (self.limit_fn)(route_ctx, false).is_some_and(|limit| new_counter > limit)
+        });
+
+        is_begin_violates || is_end_violates
+    }
+}
+
+/// Returns accumulated counters for each activity of the route, reset at the start of each marker
+/// interval, and their total sum over all intervals. A marker activity keeps the counter accumulated
+/// to reach it. The leg counter function takes the index of the leg's start activity.
+pub fn get_marker_interval_counters(
+    route_ctx: &RouteContext,
+    route_intervals: &RouteIntervals,
+    mut leg_counter_fn: impl FnMut(usize, &Activity, &Activity) -> Float,
+) -> (Vec<Float>, Float) {
+    let route = route_ctx.route();
+    let mut counters = vec![Float::default(); route.tour.total()];
+
+    let total =
+        route_intervals.resolve_marker_intervals(route_ctx).fold(Float::default(), |total, (start_idx, end_idx)| {
+            let end_idx = get_interval_end_idx(route_ctx, end_idx);
+
+            let interval_counter = route.tour.activities_slice(start_idx, end_idx).windows(2).enumerate().fold(
+                Float::default(),
+                |acc, (leg_idx, leg)| match leg {
+                    [prev, next] => {
+                        let activity_idx = start_idx + leg_idx;
+                        let counter = acc + leg_counter_fn(activity_idx, prev, next);
+                        counters[activity_idx + 1] = counter;
+
+                        counter
+                    }
+                    _ => acc,
+                },
+            );
+
+            total + interval_counter
+        });
+
+    (counters, total)
+}
+
+/// Returns start and end indices of the marker interval which contains given activity index. The end
+/// index points to the marker activity which closes the interval, if it is not the last one.
+pub fn get_marker_interval(
+    route_ctx: &RouteContext,
+    route_intervals: &RouteIntervals,
+    activity_idx: usize,
+) -> (usize, usize) {
+    route_intervals
+        .resolve_marker_intervals(route_ctx)
+        .find(|(_, end_idx)| activity_idx <= *end_idx)
+        .map(|(start_idx, end_idx)| (start_idx, get_interval_end_idx(route_ctx, end_idx)))
+        .expect("invalid markers state")
+}
+
+/// Moves marker jobs from ignored to required and locked ones: all marker jobs if no routes are
+/// specified or only the ones which can be assigned to the given routes. Returns true if any is moved.
+pub fn recover_marker_jobs(
+    solution_ctx: &mut SolutionContext,
+    route_indices: &[usize],
+    route_intervals: &RouteIntervals,
+) -> bool {
+    let jobs: HashSet<_> = if route_indices.is_empty() {
+        solution_ctx.ignored.iter().filter(|job| route_intervals.is_marker_job(job)).cloned().collect()
+    } else {
+        route_indices
+            .iter()
+            .filter_map(|&idx| solution_ctx.routes.get(idx))
+            .flat_map(|route_ctx| {
+                solution_ctx.ignored.iter().filter(|job| route_intervals.is_marker_assignable(route_ctx.route(), job))
+            })
+            .cloned()
+            .collect()
+    };
+
+    if jobs.is_empty() {
+        false
+    } else {
+        solution_ctx.ignored.retain(|job| !jobs.contains(job));
+        solution_ctx.locked.extend(jobs.iter().cloned());
+        solution_ctx.required.extend(jobs);
+
+        true
+    }
+}
+
+/// Returns end index of the marker interval extended to the marker activity which closes it, if any.
+pub fn get_interval_end_idx(route_ctx: &RouteContext, end_idx: usize) -> usize {
+    let last_idx = route_ctx.route().tour.total() - 1;
+    end_idx + if end_idx == last_idx { 0 } else { 1 }
+}
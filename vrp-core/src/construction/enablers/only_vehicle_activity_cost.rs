@@ -1,5 +1,5 @@
 use crate::models::common::{Cost, Timestamp};
-use crate::models::problem::{ActivityCost, SimpleActivityCost};
+use crate::models::problem::{ActivityCost, SimpleActivityCost, TimeCostKind, get_vehicle_time_cost};
 use crate::models::solution::Activity;
 use crate::models::solution::Route;
 
@@ -15,8 +15,12 @@ impl ActivityCost for OnlyVehicleActivityCost {
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
         let service = activity.place.duration;
+        let vehicle = actor.vehicle.as_ref();
 
-        waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
+        let service_start = arrival + waiting;
+
+        get_vehicle_time_cost(vehicle, TimeCostKind::Waiting, arrival, waiting, vehicle.costs.per_waiting_time)
+            + get_vehicle_time_cost(vehicle, TimeCostKind::Service, service_start, service, vehicle.costs.per_service_time)
     }
 
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
@@ -1,7 +1,8 @@
 use crate::construction::heuristics::{RouteContext, RouteState};
 use crate::models::OP_START_MSG;
-use crate::models::common::{Distance, Duration, Schedule, Timestamp};
+use crate::models::common::{Distance, Duration, Schedule, TimeWindow, Timestamp};
 use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
 use rosomaxa::prelude::Float;
 
 custom_activity_state!(pub(crate) LatestArrival typeof Timestamp);
@@ -35,6 +36,27 @@ pub fn update_route_departure(
     update_route_schedule(route_ctx, activity, transport);
 }
 
+/// Returns activity's time window as it is defined by the job: features which move waiting time
+/// to a later activity postpone the start of activity's time window, so the original one is restored
+/// from the job's place times using the route departure.
+pub fn get_original_time(route: &Route, activity: &Activity) -> TimeWindow {
+    let departure = route.tour.start().map_or(Timestamp::default(), |start| start.schedule.departure);
+
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.places.get(activity.place.idx))
+        .and_then(|place| {
+            place
+                .times
+                .iter()
+                .map(|time| time.to_time_window(departure))
+                .filter(|time| time.end == activity.place.time.end && time.start <= activity.place.time.start)
+                .max_by(|a, b| a.start.total_cmp(&b.start))
+        })
+        .unwrap_or_else(|| activity.place.time.clone())
+}
+
 fn update_schedules(route_ctx: &mut RouteContext, activity: &(dyn ActivityCost), transport: &(dyn TransportCost)) {
     let init = {
         let start = route_ctx.route().tour.start().unwrap();
@@ -158,10 +158,9 @@ where
                 // determine static deliveries loaded at the begin and static pickups brought to the end
                 let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
                     (acc, T::default()),
-                    |acc, activity| {
-                        self.get_demand(activity)
-                            .map(|demand| (acc.0 + demand.delivery.0, acc.1 + demand.pickup.0))
-                            .unwrap_or_else(|| acc)
+                    |(delivery, pickup), activity| match self.get_demand(activity) {
+                        Some(demand) => (delivery + demand.delivery.0.clone(), pickup + demand.pickup.0.clone()),
+                        None => (delivery, pickup),
                     },
                 );
 
@@ -173,18 +172,18 @@ where
                         let change = self.get_demand(activity).map(|demand| demand.change()).unwrap_or_default();
 
                         let current = current + change;
-                        let max = max.max_load(current);
+                        let max = max.max_load(current.clone());
 
-                        current_capacities[activity_idx] = current;
-                        max_past_capacities[activity_idx] = max;
+                        current_capacities[activity_idx] = current.clone();
+                        max_past_capacities[activity_idx] = max.clone();
 
                         (current, max)
                     },
                 );
 
-                let current_max = (start_idx..=end_idx).rev().fold(current, |max, activity_idx| {
-                    let max = max.max_load(current_capacities[activity_idx]);
-                    max_future_capacities[activity_idx] = max;
+                let current_max = (start_idx..=end_idx).rev().fold(current.clone(), |max, activity_idx| {
+                    let max = max.max_load(current_capacities[activity_idx].clone());
+                    max_future_capacities[activity_idx] = max.clone();
 
                     max
                 });
@@ -306,16 +305,16 @@ fn has_demand_violation<T: LoadOps>(
 
     // check how static delivery affects a past max load
     if demand.delivery.0.is_not_empty() {
-        let past: T = state.get_max_past_capacity_at(pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(past + demand.delivery.0)) {
+        let past: T = state.get_max_past_capacity_at(pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(past + demand.delivery.0.clone())) {
             return Some(stopped);
         }
     }
 
     // check how static pickup affect future max load
     if demand.pickup.0.is_not_empty() {
-        let future: T = state.get_max_future_capacity_at(pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(future + demand.pickup.0)) {
+        let future: T = state.get_max_future_capacity_at(pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(future + demand.pickup.0.clone())) {
             return Some(false);
         }
     }
@@ -323,12 +322,12 @@ fn has_demand_violation<T: LoadOps>(
     // check dynamic load change
     let change = demand.change();
     if change.is_not_empty() {
-        let future: T = state.get_max_future_capacity_at(pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(future + change)) {
+        let future: T = state.get_max_future_capacity_at(pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(future + change.clone())) {
             return Some(false);
         }
 
-        let current: T = state.get_current_capacity_at(pivot_idx).copied().unwrap_or_default();
+        let current: T = state.get_current_capacity_at(pivot_idx).cloned().unwrap_or_default();
         if !capacity.can_fit(&(current + change)) {
             return Some(false);
         }
@@ -0,0 +1,323 @@
+//! A feature to model vehicles with multiple compartments where each compartment can keep only one product type
+//! per trip.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/compartments_test.rs"]
+mod compartments_test;
+
+use super::*;
+use crate::construction::enablers::get_route_intervals;
+use crate::models::solution::{Activity, Route};
+use std::marker::PhantomData;
+
+custom_dimension!(pub JobProduct typeof String);
+
+/// Specifies a vehicle compartment.
+#[derive(Clone, Debug)]
+pub struct Compartment<T: LoadOps> {
+    /// A compartment id.
+    pub id: String,
+    /// A compartment capacity.
+    pub capacity: T,
+    /// Product types which can be loaded into the compartment. Empty list means any product type.
+    pub products: Vec<String>,
+}
+
+impl<T: LoadOps> Compartment<T> {
+    /// Returns true if given product type can be loaded into the compartment.
+    pub fn is_allowed(&self, product: &str) -> bool {
+        self.products.is_empty() || self.products.iter().any(|allowed| allowed == product)
+    }
+}
+
+/// A trait to get or set vehicle compartments.
+pub trait VehicleCompartmentsDimension {
+    /// Sets vehicle compartments.
+    fn set_vehicle_compartments<T: LoadOps>(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self;
+
+    /// Gets vehicle compartments.
+    fn get_vehicle_compartments<T: LoadOps>(&self) -> Option<&Vec<Compartment<T>>>;
+}
+
+/// Keeps compartments usage within a single trip: a part of the route which starts at tour start or reload.
+pub struct CompartmentTrip<T: LoadOps> {
+    /// An index of the first trip activity in the tour.
+    pub start_idx: usize,
+    /// Product types served on the trip.
+    pub products: Vec<String>,
+    /// An index of the product assigned to each vehicle compartment. It is None if assignment is not possible.
+    pub assignment: Option<Vec<Option<usize>>>,
+    /// Product loads after departure from each trip activity. Loads are ordered as products.
+    pub loads: Vec<Vec<T>>,
+}
+
+/// Returns compartments usage for each trip of the route or empty collection if vehicle has no compartments.
+pub fn get_compartment_trips<T: LoadOps>(
+    route: &Route,
+    is_reload_single: &dyn Fn(&Single) -> bool,
+) -> Vec<CompartmentTrip<T>> {
+    let Some(compartments) = route.actor.vehicle.dimens.get_vehicle_compartments::<T>() else {
+        return Vec::default();
+    };
+
+    get_trips(route, is_reload_single)
+        .into_iter()
+        .map(|(start_idx, end_idx)| {
+            let singles =
+                route.tour.activities_slice(start_idx, end_idx).iter().map(|activity| activity.job.as_deref());
+            let (products, loads) = get_product_loads::<T>(singles);
+            let peaks = get_peak_loads(products.len(), loads.as_slice());
+            let assignment = assign_compartments(compartments.as_slice(), products.as_slice(), peaks.as_slice());
+
+            CompartmentTrip {
+                start_idx,
+                products: products.into_iter().map(|product| product.to_string()).collect(),
+                assignment,
+                loads,
+            }
+        })
+        .collect()
+}
+
+/// Creates a feature which assigns product types of the jobs to vehicle compartments.
+/// Each compartment can keep only one product type per trip, trips are separated by reload activities.
+pub fn create_compartments_feature<T, R>(name: &str, code: ViolationCode, is_reload_single: R) -> GenericResult<Feature>
+where
+    T: LoadOps,
+    R: Fn(&Single) -> bool + Send + Sync + 'static,
+{
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(CompartmentsConstraint::<T> {
+            code,
+            is_reload_single: Arc::new(is_reload_single),
+            phantom: Default::default(),
+        })
+        .build()
+}
+
+struct CompartmentsConstraint<T: LoadOps> {
+    code: ViolationCode,
+    is_reload_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureConstraint for CompartmentsConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let product = job.dimens().get_job_product()?;
+                let compartments = route_ctx.route().actor.vehicle.dimens.get_vehicle_compartments::<T>()?;
+
+                if compartments.iter().any(|compartment| compartment.is_allowed(product)) {
+                    None
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                self.evaluate_activity(route_ctx.route(), activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if source.dimens().get_job_product() == candidate.dimens().get_job_product() {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+}
+
+impl<T: LoadOps> CompartmentsConstraint<T> {
+    fn evaluate_activity(&self, route: &Route, activity_ctx: &ActivityContext) -> Option<ConstraintViolation> {
+        let target = activity_ctx.target.job.as_ref()?;
+        target.dimens.get_job_product()?;
+        let compartments = route.actor.vehicle.dimens.get_vehicle_compartments::<T>()?;
+
+        let (start_idx, end_idx) = get_trips(route, self.is_reload_single.as_ref())
+            .into_iter()
+            .find(|(start_idx, end_idx)| *start_idx <= activity_ctx.index && activity_ctx.index <= *end_idx)?;
+
+        let singles = route
+            .tour
+            .activities_slice(start_idx, activity_ctx.index)
+            .iter()
+            .map(|activity| activity.job.as_deref())
+            .chain(std::iter::once(Some(target.as_ref())))
+            .chain(
+                route
+                    .tour
+                    .activities_slice(activity_ctx.index + 1, end_idx)
+                    .iter()
+                    .map(|activity| activity.job.as_deref()),
+            );
+
+        let (products, loads) = get_product_loads::<T>(singles);
+        let peaks = get_peak_loads(products.len(), loads.as_slice());
+
+        if assign_compartments(compartments.as_slice(), products.as_slice(), peaks.as_slice()).is_some() {
+            None
+        } else {
+            ConstraintViolation::skip(self.code)
+        }
+    }
+}
+
+/// Returns trips as activity index ranges: each trip starts at tour start or reload activity.
+fn get_trips(route: &Route, is_reload_single: &dyn Fn(&Single) -> bool) -> Vec<(usize, usize)> {
+    get_route_intervals(route, |activity: &Activity| {
+        activity.job.as_ref().is_some_and(|single| is_reload_single(single))
+    })
+}
+
+/// Returns product types and their loads after departure from each activity.
+fn get_product_loads<'a, T: LoadOps>(
+    singles: impl Iterator<Item = Option<&'a Single>> + Clone,
+) -> (Vec<&'a str>, Vec<Vec<T>>) {
+    let get_demand = |single: Option<&'a Single>| {
+        single.and_then(|single| {
+            let product = single.dimens.get_job_product()?;
+            let demand = single.dimens.get_job_demand::<T>()?;
+
+            Some((product.as_str(), demand))
+        })
+    };
+
+    let products = singles.clone().filter_map(get_demand).fold(Vec::<&str>::default(), |mut products, (product, _)| {
+        if !products.contains(&product) {
+            products.push(product);
+        }
+        products
+    });
+    let get_index = |product: &str| products.iter().position(|other| *other == product).unwrap();
+
+    // NOTE static deliveries are loaded at the trip start
+    let initial = singles.clone().filter_map(get_demand).fold(vec![T::default(); products.len()], |mut acc, item| {
+        let (product, demand) = item;
+        let idx = get_index(product);
+        acc[idx] = acc[idx].clone() + demand.delivery.0.clone();
+        acc
+    });
+
+    let loads = singles
+        .scan(initial, |current, single| {
+            if let Some((product, demand)) = get_demand(single) {
+                let idx = get_index(product);
+                current[idx] = current[idx].clone() - demand.delivery.0.clone() - demand.delivery.1.clone()
+                    + demand.pickup.0.clone()
+                    + demand.pickup.1.clone();
+            }
+
+            Some(current.clone())
+        })
+        .collect();
+
+    (products, loads)
+}
+
+/// Returns max load of each product within the trip.
+fn get_peak_loads<T: LoadOps>(size: usize, loads: &[Vec<T>]) -> Vec<T> {
+    loads.iter().fold(vec![T::default(); size], |peaks, current| {
+        peaks.into_iter().zip(current.iter()).map(|(peak, load)| peak.max_load(load.clone())).collect()
+    })
+}
+
+/// Tries to assign compartments to products, so that the total capacity of the assigned compartments
+/// fits the peak load of each product. Returns an index of the product assigned to each compartment.
+fn assign_compartments<T: LoadOps>(
+    compartments: &[Compartment<T>],
+    products: &[&str],
+    peaks: &[T],
+) -> Option<Vec<Option<usize>>> {
+    // NOTE keep total capacity of allowed compartments starting from given index to prune the search early
+    let remaining = (0..=compartments.len())
+        .map(|start_idx| {
+            products
+                .iter()
+                .map(|product| {
+                    compartments[start_idx..]
+                        .iter()
+                        .filter(|compartment| compartment.is_allowed(product))
+                        .fold(T::default(), |acc, compartment| acc + compartment.capacity.clone())
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut search = AssignmentSearch {
+        compartments,
+        products,
+        peaks,
+        remaining: remaining.as_slice(),
+        assigned: vec![T::default(); products.len()],
+        assignment: vec![None; compartments.len()],
+    };
+
+    if search.run(0) { Some(search.assignment) } else { None }
+}
+
+struct AssignmentSearch<'a, T: LoadOps> {
+    compartments: &'a [Compartment<T>],
+    products: &'a [&'a str],
+    peaks: &'a [T],
+    remaining: &'a [Vec<T>],
+    assigned: Vec<T>,
+    assignment: Vec<Option<usize>>,
+}
+
+impl<T: LoadOps> AssignmentSearch<'_, T> {
+    fn run(&mut self, compartment_idx: usize) -> bool {
+        if self.is_fit(|_, assigned| assigned) {
+            return true;
+        }
+
+        let remaining = &self.remaining[compartment_idx];
+        if compartment_idx == self.compartments.len() || !self.is_fit(|idx, assigned| assigned + remaining[idx].clone())
+        {
+            return false;
+        }
+
+        let compartment = &self.compartments[compartment_idx];
+        for product_idx in 0..self.products.len() {
+            if !compartment.is_allowed(self.products[product_idx])
+                || self.assigned[product_idx].can_fit(&self.peaks[product_idx])
+            {
+                continue;
+            }
+
+            self.assigned[product_idx] = self.assigned[product_idx].clone() + compartment.capacity.clone();
+            self.assignment[compartment_idx] = Some(product_idx);
+
+            if self.run(compartment_idx + 1) {
+                return true;
+            }
+
+            self.assigned[product_idx] = self.assigned[product_idx].clone() - compartment.capacity.clone();
+            self.assignment[compartment_idx] = None;
+        }
+
+        self.run(compartment_idx + 1)
+    }
+
+    fn is_fit(&self, capacity_fn: impl Fn(usize, T) -> T) -> bool {
+        self.assigned
+            .iter()
+            .zip(self.peaks.iter())
+            .enumerate()
+            .all(|(idx, (assigned, peak))| capacity_fn(idx, assigned.clone()).can_fit(peak))
+    }
+}
+
+struct VehicleCompartmentsDimenKey;
+impl VehicleCompartmentsDimension for Dimensions {
+    fn set_vehicle_compartments<T: LoadOps>(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self {
+        self.set_value::<VehicleCompartmentsDimenKey, _>(compartments);
+        self
+    }
+
+    fn get_vehicle_compartments<T: LoadOps>(&self) -> Option<&Vec<Compartment<T>>> {
+        self.get_value::<VehicleCompartmentsDimenKey, _>()
+    }
+}
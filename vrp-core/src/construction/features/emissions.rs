@@ -0,0 +1,140 @@
+//! A feature to estimate and minimize CO2 emissions produced by vehicles.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/emissions_test.rs"]
+mod emissions_test;
+
+use super::*;
+use crate::construction::features::capacity::CurrentCapacityActivityState;
+use crate::models::common::LoadOps;
+use crate::models::solution::Activity;
+use std::marker::PhantomData;
+
+custom_dimension!(pub VehicleEmissions typeof EmissionModel);
+
+/// Specifies an emission model of a vehicle. Fuel (or energy) consumption is measured in the same units
+/// within the model, emissions are measured in units of the emission factor.
+#[derive(Clone, Debug)]
+pub struct EmissionModel {
+    /// A consumption per distance unit of an empty vehicle.
+    pub consumption: Float,
+    /// A consumption per distance unit of a fully loaded vehicle. Consumption of a partially loaded vehicle
+    /// is interpolated linearly using the load ratio.
+    pub full_consumption: Float,
+    /// A consumption per time unit while vehicle is idling, e.g. waiting for the time window start.
+    pub idle_consumption: Float,
+    /// An amount of emissions produced per consumption unit.
+    pub emission_factor: Float,
+}
+
+impl EmissionModel {
+    /// Returns emissions produced while driving given distance with given load ratio.
+    pub fn get_driving_emissions(&self, distance: Distance, load_ratio: Float) -> Float {
+        let load_ratio = if load_ratio.is_finite() { load_ratio.clamp(0., 1.) } else { 0. };
+        let consumption = self.consumption + (self.full_consumption - self.consumption) * load_ratio;
+
+        distance * consumption * self.emission_factor
+    }
+
+    /// Returns emissions produced while idling given duration.
+    pub fn get_idle_emissions(&self, duration: Duration) -> Float {
+        duration.max(0.) * self.idle_consumption * self.emission_factor
+    }
+}
+
+/// Creates a feature which minimizes total emissions produced by the fleet. Emissions are estimated using
+/// the `VehicleEmissions` dimension of the vehicle and the current load tracked by the capacity feature.
+/// Vehicles without emission model are considered as emission free.
+/// Route emissions are not cached as they depend on the capacity state which is updated by
+/// another feature.
+pub fn create_minimize_emissions_feature<T: LoadOps>(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(EmissionsObjective::<T> { transport, phantom: PhantomData })
+        .build()
+}
+
+struct EmissionsObjective<T: LoadOps> {
+    transport: Arc<dyn TransportCost>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureObjective for EmissionsObjective<T> {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| get_route_emissions::<T>(route_ctx, self.transport.as_ref()))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let Some(model) = route_ctx.route().actor.vehicle.dimens.get_vehicle_emissions() else {
+                    return Cost::default();
+                };
+
+                // NOTE use the load after previous activity for all legs and ignore idling
+                let load_ratio = get_load_ratio::<T>(route_ctx, activity_ctx.index);
+                let get_emissions = |from: &Activity, to: &Activity| {
+                    let distance = self.transport.distance(
+                        route_ctx.route(),
+                        from.place.location,
+                        to.place.location,
+                        TravelTime::Departure(from.schedule.departure),
+                    );
+                    model.get_driving_emissions(distance, load_ratio)
+                };
+
+                let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+                let left = get_emissions(prev, target);
+                let right =
+                    next.map_or(Cost::default(), |next| get_emissions(target, next) - get_emissions(prev, next));
+
+                left + right
+            }
+        }
+    }
+}
+
+/// Returns emissions produced by the route: driving emissions depend on the load carried on each leg,
+/// idle emissions are produced while waiting for the time window start.
+fn get_route_emissions<T: LoadOps>(route_ctx: &RouteContext, transport: &dyn TransportCost) -> Float {
+    let route = route_ctx.route();
+    let Some(model) = route.actor.vehicle.dimens.get_vehicle_emissions() else {
+        return Float::default();
+    };
+
+    route.tour.legs().fold(Float::default(), |acc, (items, leg_idx)| match items {
+        [from, to] => {
+            let distance = transport.distance(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            );
+            let waiting = to.place.time.start - to.schedule.arrival;
+
+            acc + model.get_driving_emissions(distance, get_load_ratio::<T>(route_ctx, leg_idx))
+                + model.get_idle_emissions(waiting)
+        }
+        _ => acc,
+    })
+}
+
+/// Returns a ratio between the load carried after the activity at given index and vehicle capacity.
+fn get_load_ratio<T: LoadOps>(route_ctx: &RouteContext, activity_idx: usize) -> Float {
+    let capacity = route_ctx.route().actor.vehicle.dimens.get_vehicle_capacity::<T>();
+    let load = route_ctx.state().get_current_capacity_at::<T>(activity_idx);
+
+    match (load, capacity) {
+        (Some(load), Some(capacity)) => load.ratio(capacity),
+        _ => Float::default(),
+    }
+}
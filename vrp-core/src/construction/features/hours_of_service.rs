@@ -0,0 +1,238 @@
+//! A feature to model driver hours-of-service rules: a mandatory rest after accumulated driving time
+//! and a cap on total driving time within the tour.
+//!
+//! Rest activities are modeled as marker jobs which reset accumulated driving time, similar to
+//! recharge stations which reset traveled distance.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/hours_of_service_test.rs"]
+mod hours_of_service_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::solution::{Activity, Route};
+use std::sync::Arc;
+
+/// Specifies hours-of-service limits of an actor.
+#[derive(Clone, Debug)]
+pub struct HoursOfServiceLimits {
+    /// Max driving time allowed without a rest.
+    pub max_driving_time: Duration,
+    /// Max total driving time within the tour.
+    pub max_total_driving_time: Option<Duration>,
+}
+
+/// Provides a way to build the hours-of-service feature.
+#[allow(clippy::type_complexity)]
+pub struct HoursOfServiceFeatureBuilder {
+    name: String,
+    violation_code: Option<ViolationCode>,
+    transport: Option<Arc<dyn TransportCost>>,
+    belongs_to_route_fn: Option<Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>>,
+    is_rest_single_fn: Option<RestSingleFn>,
+    limits_fn: Option<HoursOfServiceLimitsFn>,
+}
+
+impl HoursOfServiceFeatureBuilder {
+    /// Creates a new instance of `HoursOfServiceFeatureBuilder`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            violation_code: None,
+            transport: None,
+            belongs_to_route_fn: None,
+            is_rest_single_fn: None,
+            limits_fn: None,
+        }
+    }
+
+    /// Sets constraint violation code which is used to report back the reason of job's unassignment.
+    pub fn set_violation_code(mut self, violation_code: ViolationCode) -> Self {
+        self.violation_code = Some(violation_code);
+        self
+    }
+
+    /// Sets transport costs to estimate driving time.
+    pub fn set_transport(mut self, transport: Arc<dyn TransportCost>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets a function which specifies whether a given single job can be considered as a rest job.
+    pub fn set_is_rest_single<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> bool + Send + Sync + 'static,
+    {
+        self.is_rest_single_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which specifies whether a given route can serve a given job. This function
+    /// should return false, if the job is not rest.
+    pub fn set_belongs_to_route<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Route, &Job) -> bool + Send + Sync + 'static,
+    {
+        self.belongs_to_route_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Specifies hours-of-service limits function. It should return a fixed value for the same
+    /// actor all the time.
+    pub fn set_limits<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<HoursOfServiceLimits> + Send + Sync + 'static,
+    {
+        self.limits_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Builds the hours-of-service feature if all dependencies are set.
+    pub fn build(&mut self) -> GenericResult<Feature> {
+        let is_marker_single_fn =
+            self.is_rest_single_fn.take().ok_or_else(|| GenericError::from("is_rest_single must be set"))?;
+        let is_assignable_fn =
+            self.belongs_to_route_fn.take().ok_or_else(|| GenericError::from("belongs_to_route must be set"))?;
+
+        let transport = self.transport.take().ok_or_else(|| GenericError::from("transport must be set"))?;
+        let limits_fn = self.limits_fn.take().ok_or_else(|| GenericError::from("limits must be set"))?;
+
+        let code = self.violation_code.unwrap_or_default();
+
+        let counter = MarkerIntervalCounter {
+            counter_fn: Arc::new(get_driving_time),
+            leg_counter_fn: Arc::new({
+                let transport = transport.clone();
+                move |route_ctx, _, from, to| get_leg_driving_time(transport.as_ref(), route_ctx.route(), from, to)
+            }),
+            limit_fn: Arc::new({
+                let limits_fn = limits_fn.clone();
+                move |route_ctx, _| (limits_fn)(route_ctx.route().actor.as_ref()).map(|limits| limits.max_driving_time)
+            }),
+        };
+
+        create_multi_trip_feature(
+            self.name.as_str(),
+            code,
+            MarkerInsertionPolicy::Any,
+            Arc::new(RestableMultiTrip {
+                route_intervals: counter.create_route_intervals(
+                    is_marker_single_fn.clone(),
+                    is_assignable_fn,
+                    Arc::new(RestIntervalsState),
+                ),
+                counter,
+                transport,
+                code,
+                limits_fn,
+                rest_single_fn: is_marker_single_fn,
+            }),
+        )
+    }
+}
+
+type HoursOfServiceLimitsFn = Arc<dyn Fn(&Actor) -> Option<HoursOfServiceLimits> + Send + Sync>;
+type RestSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+
+custom_route_intervals_state!(RestIntervals);
+custom_activity_state!(RestDrivingTime typeof Duration);
+custom_tour_state!(TotalDrivingTime typeof Duration);
+
+struct RestableMultiTrip {
+    route_intervals: RouteIntervals,
+    counter: MarkerIntervalCounter,
+    transport: Arc<dyn TransportCost>,
+    code: ViolationCode,
+    limits_fn: HoursOfServiceLimitsFn,
+    rest_single_fn: RestSingleFn,
+}
+
+impl MultiTrip for RestableMultiTrip {
+    fn get_route_intervals(&self) -> &RouteIntervals {
+        &self.route_intervals
+    }
+
+    fn get_constraint(&self) -> &dyn FeatureConstraint {
+        self
+    }
+
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        if (self.limits_fn)(route_ctx.route().actor.as_ref()).is_none() {
+            return;
+        }
+
+        let (driving_counters, total_driving_time) =
+            get_marker_interval_counters(route_ctx, &self.route_intervals, |_, prev, next| {
+                get_leg_driving_time(self.transport.as_ref(), route_ctx.route(), prev, next)
+            });
+
+        route_ctx.state_mut().set_rest_driving_time_states(driving_counters);
+        route_ctx.state_mut().set_total_driving_time(total_driving_time);
+    }
+
+    fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
+        recover_marker_jobs(solution_ctx, route_indices, &self.route_intervals)
+    }
+}
+
+impl FeatureConstraint for RestableMultiTrip {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => ConstraintViolation::success(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl RestableMultiTrip {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let limits = (self.limits_fn)(route_ctx.route().actor.as_ref())?;
+        let interval = get_marker_interval(route_ctx, &self.route_intervals, activity_ctx.index);
+
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let transport = self.transport.as_ref();
+
+        let prev_to_tar = get_leg_driving_time(transport, route, prev, target);
+        let (tar_to_next, prev_to_next) =
+            activity_ctx.next.map_or((Duration::default(), Duration::default()), |next| {
+                (
+                    get_leg_driving_time(transport, route, target, next),
+                    get_leg_driving_time(transport, route, prev, next),
+                )
+            });
+        let driving_delta = prev_to_tar + tar_to_next - prev_to_next;
+
+        let is_new_rest = target.job.as_ref().is_some_and(|single| (self.rest_single_fn)(single));
+
+        let is_interval_violation = if is_new_rest {
+            self.counter.is_marker_insertion_violation(route_ctx, activity_ctx, interval)
+        } else {
+            self.counter.is_interval_violation(route_ctx, interval, driving_delta)
+        };
+
+        let is_total_violation = limits.max_total_driving_time.is_some_and(|max_total_driving_time| {
+            let total_driving_time = route_ctx.state().get_total_driving_time().copied().unwrap_or_default();
+
+            total_driving_time + driving_delta > max_total_driving_time
+        });
+
+        if is_interval_violation || is_total_violation { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+fn get_driving_time(route_ctx: &RouteContext, activity_idx: usize) -> Duration {
+    route_ctx.state().get_rest_driving_time_at(activity_idx).copied().unwrap_or_default()
+}
+
+fn get_leg_driving_time(transport: &dyn TransportCost, route: &Route, from: &Activity, to: &Activity) -> Duration {
+    transport.duration(route, from.place.location, to.place.location, TravelTime::Departure(from.schedule.departure))
+}
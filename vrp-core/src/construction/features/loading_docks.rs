@@ -0,0 +1,543 @@
+//! A feature to model loading docks at depot: only a limited amount of vehicles can be loaded there
+//! at the same time, so departures from depot and reload returns have to be staggered.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/loading_docks_test.rs"]
+mod loading_docks_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::common::TimeWindow;
+use crate::models::solution::{Activity, Route};
+use std::collections::{HashMap, HashSet};
+
+/// Specifies a loading dock resource placed at depot location.
+#[derive(Clone, Debug)]
+pub struct LoadingDock {
+    /// A dock id.
+    pub id: String,
+    /// A depot location.
+    pub location: Location,
+    /// An amount of vehicles which can be loaded at the same time.
+    pub capacity: usize,
+    /// A fixed loading duration.
+    pub loading_time: Duration,
+    /// An extra loading duration per unit of load.
+    pub unit_loading_time: Duration,
+    /// Opening hours: loading has to be fully done within one of them.
+    pub times: Vec<TimeWindow>,
+}
+
+impl LoadingDock {
+    /// Returns loading duration for the given amount of load.
+    pub fn get_loading_duration(&self, load: Float) -> Duration {
+        self.loading_time + self.unit_loading_time * load
+    }
+
+    fn get_opening_slack(&self, session: &TimeWindow) -> Duration {
+        self.times
+            .iter()
+            .find(|tw| tw.start <= session.start && session.end <= tw.end)
+            .map_or(Duration::default(), |tw| tw.end - session.end)
+    }
+
+    fn is_open(&self, session: &TimeWindow) -> bool {
+        self.times.iter().any(|tw| tw.start <= session.start && session.end <= tw.end)
+    }
+}
+
+/// Specifies a loading session: a time when the dock is occupied by the vehicle.
+#[derive(Clone, Debug)]
+pub struct DockSession {
+    /// An index of the dock.
+    pub dock_idx: usize,
+    /// An index of the activity in the tour: it is either tour start or reload.
+    pub activity_idx: usize,
+    /// A time when the dock is occupied.
+    pub time: TimeWindow,
+}
+
+/// Keeps loading docks and provides a way to get loading sessions of the route. Vehicle is loaded at
+/// the dock when its tour starts or it has a reload at the dock location. At tour start, loading is
+/// finished at departure time. At reload, the dock is occupied during the whole reload activity which
+/// duration is extended by the loading duration.
+pub struct LoadingDocks {
+    docks: Vec<LoadingDock>,
+    location_index: HashMap<Location, usize>,
+    is_reload_single_fn: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+    job_load_fn: Arc<dyn Fn(&Single) -> Float + Send + Sync>,
+}
+
+impl LoadingDocks {
+    /// Creates a new instance of `LoadingDocks`. A load of the vehicle at the dock is a static delivery
+    /// demand of the jobs served till the next reload or tour end; it is converted to amount of load units
+    /// using given function.
+    pub fn new<T, F, R>(docks: Vec<LoadingDock>, is_reload_single: R, load_fn: F) -> Self
+    where
+        T: LoadOps,
+        F: Fn(&T) -> Float + Send + Sync + 'static,
+        R: Fn(&Single) -> bool + Send + Sync + 'static,
+    {
+        let location_index = docks.iter().enumerate().map(|(idx, dock)| (dock.location, idx)).collect();
+
+        Self {
+            docks,
+            location_index,
+            is_reload_single_fn: Arc::new(is_reload_single),
+            job_load_fn: Arc::new(move |single| {
+                single.dimens.get_job_demand::<T>().map_or(Float::default(), |demand| load_fn(&demand.delivery.0))
+            }),
+        }
+    }
+
+    /// Returns all loading docks.
+    pub fn docks(&self) -> &[LoadingDock] {
+        self.docks.as_slice()
+    }
+
+    /// Returns loading sessions of the route.
+    pub fn get_sessions(&self, route: &Route) -> Vec<DockSession> {
+        self.get_intervals(route)
+            .into_iter()
+            .filter_map(|interval| Some((interval.dock_idx?, interval)))
+            .filter_map(|(dock_idx, interval)| {
+                let activity = route.tour.get(interval.activity_idx)?;
+                let time = if interval.activity_idx == 0 {
+                    let duration = self.docks[dock_idx].get_loading_duration(interval.load);
+                    TimeWindow::new(activity.schedule.departure - duration, activity.schedule.departure)
+                } else {
+                    let start = activity.schedule.arrival.max(activity.place.time.start);
+                    TimeWindow::new(start, activity.schedule.departure)
+                };
+
+                Some(DockSession { dock_idx, activity_idx: interval.activity_idx, time })
+            })
+            .collect()
+    }
+
+    /// Returns intervals started at tour start or reload together with their load.
+    fn get_intervals(&self, route: &Route) -> Vec<DockInterval> {
+        route.tour.all_activities().enumerate().fold(Vec::default(), |mut intervals, (activity_idx, activity)| {
+            match activity.job.as_ref() {
+                Some(single) if self.is_reload(single) => {
+                    intervals.push(DockInterval {
+                        activity_idx,
+                        dock_idx: self.location_index.get(&activity.place.location).copied(),
+                        load: Float::default(),
+                    });
+                }
+                Some(single) => {
+                    if let Some(interval) = intervals.last_mut() {
+                        interval.load += (self.job_load_fn)(single);
+                    }
+                }
+                None if activity_idx == 0 => intervals.push(DockInterval {
+                    activity_idx,
+                    dock_idx: self.location_index.get(&activity.place.location).copied(),
+                    load: Float::default(),
+                }),
+                None => {}
+            }
+
+            intervals
+        })
+    }
+
+    /// Returns load of the jobs served after the given activity till the next reload or tour end.
+    fn get_load_after(&self, route: &Route, activity_idx: usize) -> Float {
+        route
+            .tour
+            .all_activities()
+            .skip(activity_idx + 1)
+            .filter_map(|activity| activity.job.as_ref())
+            .take_while(|single| !self.is_reload(single))
+            .map(|single| (self.job_load_fn)(single))
+            .sum()
+    }
+
+    fn get_dock_idx(&self, activity: &Activity) -> Option<usize> {
+        self.location_index.get(&activity.place.location).copied()
+    }
+
+    fn is_reload(&self, single: &Single) -> bool {
+        (self.is_reload_single_fn)(single)
+    }
+}
+
+/// Creates a feature which limits amount of vehicles loaded at the depot docks at the same time.
+pub fn create_loading_docks_feature(
+    name: &str,
+    code: ViolationCode,
+    docks: Arc<LoadingDocks>,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(LoadingDockConstraint {
+            code,
+            docks: docks.clone(),
+            transport: transport.clone(),
+            activity: activity.clone(),
+        })
+        .with_state(LoadingDockState { docks, transport, activity })
+        .build()
+}
+
+custom_solution_state!(LoadingSessions typeof ResourceSessions);
+custom_activity_state!(LoadingSlack typeof Duration);
+custom_tour_state!(DockIntervals typeof Vec<DockInterval>);
+
+/// Specifies an interval of the tour which starts at the tour start or reload.
+#[derive(Clone)]
+struct DockInterval {
+    activity_idx: usize,
+    dock_idx: Option<usize>,
+    load: Float,
+}
+
+struct LoadingDockConstraint {
+    code: ViolationCode,
+    docks: Arc<LoadingDocks>,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureConstraint for LoadingDockConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl LoadingDockConstraint {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let get_others = |dock_idx: usize| get_other_dock_sessions(solution_ctx, route, dock_idx);
+
+        let intervals_state;
+        let intervals = match route_ctx.state().get_dock_intervals() {
+            Some(intervals) => intervals,
+            None => {
+                intervals_state = self.docks.get_intervals(route);
+                &intervals_state
+            }
+        };
+        let interval = intervals.iter().rev().find(|interval| interval.activity_idx <= activity_ctx.index)?;
+
+        let is_reload = target.job.as_ref().is_some_and(|single| self.docks.is_reload(single));
+        let target_load = target
+            .job
+            .as_ref()
+            .filter(|_| !is_reload)
+            .map_or(Float::default(), |single| (self.docks.job_load_fn)(single));
+
+        // NOTE extra loading time delays departure from the tour start or reload which starts the interval
+        let shift = match interval.dock_idx {
+            Some(dock_idx) if interval.activity_idx == 0 => {
+                let dock = &self.docks.docks[dock_idx];
+                let duration = dock.get_loading_duration(interval.load + target_load);
+                let departure = route.tour.start()?.schedule.departure;
+                let Some(new_departure) = find_departure(route, dock, &get_others(dock_idx), duration, departure)
+                else {
+                    return ConstraintViolation::skip(self.code);
+                };
+
+                new_departure - departure
+            }
+            Some(dock_idx) => self.docks.docks[dock_idx].unit_loading_time * target_load,
+            None => Duration::default(),
+        };
+
+        let slack_idx = interval.activity_idx.max(1);
+        if shift > 0. && self.get_slack(route_ctx, slack_idx) < shift {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        if shift > 0. && interval.activity_idx < activity_ctx.index {
+            let first_idx = interval.activity_idx + 1;
+            let first = route.tour.get(first_idx)?;
+            let latest_arrival = route_ctx.state().get_latest_arrival_at(first_idx).copied();
+            if first.schedule.arrival + shift > latest_arrival.unwrap_or(first.place.time.end) {
+                return ConstraintViolation::skip(self.code);
+            }
+        }
+
+        let departure = prev.schedule.departure + shift;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        if arrival > target.place.time.end {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let target_dock_idx = if is_reload { self.docks.get_dock_idx(target) } else { None };
+        let loading = target_dock_idx.map_or(Duration::default(), |dock_idx| {
+            let load = self.docks.get_load_after(route, activity_ctx.index);
+            self.docks.docks[dock_idx].get_loading_duration(load)
+        });
+        let departure = self.activity.estimate_departure(route, target, arrival) + loading;
+
+        if let Some(dock_idx) = target_dock_idx {
+            let dock = &self.docks.docks[dock_idx];
+            let session = TimeWindow::new(arrival.max(target.place.time.start), departure);
+            let others = get_others(dock_idx);
+
+            if !dock.is_open(&session) || get_max_overlaps(others.iter(), &session) >= dock.capacity {
+                return ConstraintViolation::skip(self.code);
+            }
+        }
+
+        let next = next?;
+
+        let next_arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        if shift > 0. || loading > 0. {
+            let latest_arrival = route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied();
+            if next_arrival > latest_arrival.unwrap_or(next.place.time.end) {
+                return ConstraintViolation::skip(self.code);
+            }
+        }
+
+        // NOTE assume that delay is propagated to all loading sessions later in the tour
+        let delay = self.activity.estimate_departure(route, next, next_arrival) - next.schedule.departure;
+        if delay > self.get_slack(route_ctx, activity_ctx.index + 1) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        None
+    }
+
+    fn get_slack(&self, route_ctx: &RouteContext, activity_idx: usize) -> Duration {
+        route_ctx.state().get_loading_slack_at(activity_idx).copied().unwrap_or(Duration::MAX)
+    }
+}
+
+struct LoadingDockState {
+    docks: Arc<LoadingDocks>,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureState for LoadingDockState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.update_departure(solution_ctx, route_index);
+        self.update_loading_sessions(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let intervals = self.docks.get_intervals(route_ctx.route());
+
+        let is_changed = intervals.iter().fold(false, |is_changed, interval| {
+            let Some(dock_idx) = interval.dock_idx else { return is_changed };
+            let duration = self.docks.docks[dock_idx].get_loading_duration(interval.load);
+            let route = route_ctx.route_mut();
+            let actor = route.actor.clone();
+            let activity = route.tour.get_mut(interval.activity_idx).expect("invalid dock interval");
+
+            match activity.job.as_ref() {
+                Some(single) => {
+                    let base_duration =
+                        single.places.get(activity.place.idx).map_or(Duration::default(), |place| place.duration);
+                    let duration = base_duration + duration;
+
+                    if activity.place.duration != duration {
+                        activity.place.duration = duration;
+                        return true;
+                    }
+                }
+                None => {
+                    // NOTE vehicle cannot be loaded before its shift starts
+                    let earliest = get_earliest_departure(actor.as_ref(), activity);
+                    if activity.schedule.departure < earliest + duration {
+                        activity.schedule.departure = earliest + duration;
+                        return true;
+                    }
+                }
+            }
+
+            is_changed
+        });
+
+        if is_changed {
+            update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+        }
+
+        // NOTE this state is overridden by update_loading_sessions
+        let sessions = self.get_resource_sessions(route_ctx.route());
+        let slacks = get_route_resource_slacks(route_ctx, sessions.as_slice());
+        route_ctx.state_mut().set_loading_slack_states(slacks);
+
+        route_ctx.state_mut().set_dock_intervals(intervals);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_overbooked_sessions(solution_ctx);
+        self.update_loading_sessions(solution_ctx);
+    }
+}
+
+impl LoadingDockState {
+    /// Moves departure of the modified route to the earliest time when a dock is available.
+    fn update_departure(&self, solution_ctx: &mut SolutionContext, route_index: usize) {
+        let route = solution_ctx.routes[route_index].route();
+        let Some(session) = self.docks.get_sessions(route).into_iter().find(|session| session.activity_idx == 0) else {
+            return;
+        };
+
+        let dock = &self.docks.docks[session.dock_idx];
+        let others = get_other_dock_sessions(solution_ctx, route, session.dock_idx);
+        let departure = session.time.end;
+
+        let new_departure = find_departure(route, dock, &others, session.time.duration(), departure);
+
+        if let Some(new_departure) = new_departure.filter(|&new_departure| new_departure > departure) {
+            let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+            update_route_departure(route_ctx, self.activity.as_ref(), self.transport.as_ref(), new_departure);
+        }
+    }
+
+    /// Removes all jobs from routes with loading sessions which exceed docks capacity or are outside
+    /// opening hours. That can happen when some jobs are removed from the tour, so loading sessions at
+    /// reloads are moved in time.
+    fn remove_overbooked_sessions(&self, solution_ctx: &mut SolutionContext) {
+        let sessions = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                self.get_resource_sessions(route_ctx.route()).into_iter().map(move |session| (session, route_idx))
+            })
+            .collect::<Vec<_>>();
+
+        let overbooked =
+            get_overbooked_sessions(sessions, |session| self.docks.docks[session.resource_id].is_open(&session.time))
+                .into_iter()
+                .map(|(_, route_idx)| route_idx)
+                .collect::<HashSet<_>>();
+
+        overbooked.into_iter().for_each(|route_idx| {
+            let route_ctx = solution_ctx.routes.get_mut(route_idx).expect("invalid route index");
+            let jobs = route_ctx.route().tour.jobs().cloned().collect::<Vec<_>>();
+
+            jobs.into_iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(&job);
+
+                if job.as_single().is_some_and(|single| self.docks.is_reload(single)) {
+                    solution_ctx.ignored.push(job);
+                } else {
+                    solution_ctx.required.push(job);
+                }
+            });
+        });
+    }
+
+    fn update_loading_sessions(&self, solution_ctx: &mut SolutionContext) {
+        let route_sessions = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| self.get_resource_sessions(route_ctx.route()))
+            .collect::<Vec<_>>();
+        let sessions = group_resource_sessions(
+            solution_ctx.routes.iter().map(|route_ctx| route_ctx.route()).zip(route_sessions.iter().map(Vec::as_slice)),
+        );
+
+        solution_ctx.routes.iter_mut().zip(route_sessions).for_each(|(route_ctx, route_sessions)| {
+            let actor = &route_ctx.route().actor;
+            let slacks = get_resource_slacks(route_ctx.route(), route_sessions.as_slice(), |session| {
+                // NOTE loading session at the tour start is rescheduled explicitly, so it is not limited
+                if session.activity_idx == 0 {
+                    return Duration::MAX;
+                }
+
+                let dock = &self.docks.docks[session.resource_id];
+                let others = get_other_sessions(&sessions, session.resource_id, actor);
+
+                get_slack(others, dock.capacity, &session.time).min(dock.get_opening_slack(&session.time))
+            });
+
+            route_ctx.state_mut().set_loading_slack_states(slacks);
+        });
+
+        solution_ctx.state.set_loading_sessions(sessions);
+    }
+
+    fn get_resource_sessions(&self, route: &Route) -> Vec<ResourceSession> {
+        self.docks
+            .get_sessions(route)
+            .into_iter()
+            .map(|session| ResourceSession {
+                resource_id: session.dock_idx,
+                capacity: self.docks.docks[session.dock_idx].capacity,
+                activity_idx: session.activity_idx,
+                time: session.time,
+            })
+            .collect()
+    }
+}
+
+/// Returns the earliest departure time not earlier than the given one when a loading session of the given
+/// duration fits into dock opening hours and capacity.
+fn find_departure(
+    route: &Route,
+    dock: &LoadingDock,
+    others: &[TimeWindow],
+    duration: Duration,
+    departure: Timestamp,
+) -> Option<Timestamp> {
+    let start = route.tour.start()?;
+    let earliest = get_earliest_departure(route.actor.as_ref(), start) + duration;
+    let latest = route.actor.detail.start.as_ref().and_then(|start| start.time.latest).unwrap_or(Timestamp::MAX);
+    let lower = departure.max(earliest) - duration;
+
+    // NOTE a session can start either at the lower bound, or when other session ends or dock opens
+    let mut candidates = std::iter::once(lower)
+        .chain(others.iter().map(|other| other.end))
+        .chain(dock.times.iter().map(|tw| tw.start))
+        .filter(|&time| time >= lower)
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| a.total_cmp(b));
+
+    candidates
+        .into_iter()
+        .map(|time| TimeWindow::new(time, time + duration))
+        .take_while(|session| session.end <= latest)
+        .find(|session| dock.is_open(session) && get_max_overlaps(others.iter(), session) < dock.capacity)
+        .map(|session| session.end)
+}
+
+fn get_earliest_departure(actor: &Actor, start: &Activity) -> Timestamp {
+    actor.detail.start.as_ref().and_then(|start| start.time.earliest).unwrap_or(start.place.time.start)
+}
+
+fn get_other_dock_sessions(solution_ctx: &SolutionContext, route: &Route, dock_idx: usize) -> Vec<TimeWindow> {
+    solution_ctx
+        .state
+        .get_loading_sessions()
+        .map(|sessions| get_other_sessions(sessions, dock_idx, &route.actor).cloned().collect())
+        .unwrap_or_default()
+}
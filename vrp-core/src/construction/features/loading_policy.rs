@@ -0,0 +1,139 @@
+//! A feature to model a loading policy of the vehicle, such as last-in-first-out, for pickup and delivery jobs.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/loading_policy_test.rs"]
+mod loading_policy_test;
+
+use super::*;
+use crate::models::solution::Activity;
+use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
+
+/// Specifies the order in which goods can be unloaded from the vehicle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoadingPolicy {
+    /// Last in, first out: only goods which were loaded last are accessible.
+    Lifo,
+    /// First in, first out: only goods which were loaded first are accessible.
+    Fifo,
+}
+
+custom_dimension!(pub VehicleLoadingPolicy typeof LoadingPolicy);
+
+/// Creates a feature which enforces vehicle's loading policy for pickup and delivery jobs.
+/// Each pickup puts the job's goods into the vehicle, each delivery requires them to be accessible
+/// and the last delivery of the job unloads all its goods. Jobs with static demand are not affected.
+pub fn create_loading_policy_feature<T: LoadOps>(name: &str, code: ViolationCode) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(LoadingPolicyConstraint::<T> { code, phantom: Default::default() })
+        .build()
+}
+
+/// Returns a first pickup and delivery job which violates the loading policy in given sequence of activities.
+pub fn get_loading_policy_violation<'a, T: LoadOps>(
+    policy: LoadingPolicy,
+    activities: impl Iterator<Item = &'a Activity> + Clone,
+) -> Option<Job> {
+    // NOTE consider only jobs which are completely present in the sequence, so partially inserted multi job
+    //      does not block other jobs during insertion
+    let counts = activities.clone().filter_map(get_multi_job).fold(
+        HashMap::<Job, (usize, usize)>::default(),
+        |mut acc, (job, single)| {
+            let (total, deliveries) = acc.entry(job).or_default();
+            *total += 1;
+            *deliveries += usize::from(get_operation::<T>(single) == Some(false));
+            acc
+        },
+    );
+    let mut remaining = counts
+        .into_iter()
+        .filter(|(job, (total, _))| job.as_multi().is_some_and(|multi| multi.jobs.len() == *total))
+        .map(|(job, (_, deliveries))| (job, deliveries))
+        .collect::<HashMap<_, _>>();
+
+    let mut goods = VecDeque::<Job>::default();
+
+    activities.filter_map(get_multi_job).find_map(|(job, single)| {
+        let is_pickup = get_operation::<T>(single)?;
+        let deliveries = remaining.get_mut(&job)?;
+
+        if is_pickup {
+            goods.push_back(job);
+            return None;
+        }
+
+        let accessible = match policy {
+            LoadingPolicy::Lifo => goods.iter().rev().take_while(|other| **other == job).count(),
+            LoadingPolicy::Fifo => goods.iter().take_while(|other| **other == job).count(),
+        };
+        *deliveries -= 1;
+
+        if accessible == 0 {
+            return Some(job);
+        }
+
+        if *deliveries == 0 {
+            if accessible != goods.iter().filter(|other| **other == job).count() {
+                return Some(job);
+            }
+
+            match policy {
+                LoadingPolicy::Lifo => goods.truncate(goods.len() - accessible),
+                LoadingPolicy::Fifo => drop(goods.drain(..accessible)),
+            }
+        }
+
+        None
+    })
+}
+
+struct LoadingPolicyConstraint<T: LoadOps> {
+    code: ViolationCode,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureConstraint for LoadingPolicyConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                get_multi_job(activity_ctx.target)?;
+                let route = route_ctx.route();
+                let policy = route.actor.vehicle.dimens.get_vehicle_loading_policy()?;
+
+                let activities = route
+                    .tour
+                    .all_activities()
+                    .take(activity_ctx.index + 1)
+                    .chain(std::iter::once(activity_ctx.target))
+                    .chain(route.tour.all_activities().skip(activity_ctx.index + 1));
+
+                get_loading_policy_violation::<T>(*policy, activities)
+                    .and_then(|_| ConstraintViolation::skip(self.code))
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+/// Returns a multi job which activity belongs to.
+fn get_multi_job(activity: &Activity) -> Option<(Job, &Single)> {
+    let single = activity.job.as_ref()?;
+
+    Multi::roots(single).map(|multi| (Job::Multi(multi), single.as_ref()))
+}
+
+/// Returns true if the single is a pickup with dynamic demand, false if delivery.
+fn get_operation<T: LoadOps>(single: &Single) -> Option<bool> {
+    let demand = single.dimens.get_job_demand::<T>()?;
+
+    match (demand.pickup.1 != T::default(), demand.delivery.1 != T::default()) {
+        (true, false) => Some(true),
+        (false, true) => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,265 @@
+//! A feature to limit a time which goods or passengers of pickup and delivery jobs spend in the vehicle.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/max_ride_time_test.rs"]
+mod max_ride_time_test;
+
+use super::*;
+use crate::construction::enablers::{get_original_time, update_route_schedule};
+use crate::models::common::TimeWindow;
+use crate::models::solution::{Activity, Route};
+use std::collections::{HashMap, HashSet};
+
+/// Specifies a max ride time of the multi job.
+#[derive(Clone, Copy, Debug)]
+pub enum MaxRideTime {
+    /// An absolute max ride duration.
+    Duration(Duration),
+    /// A factor of a direct travel time between the first and the last job's places.
+    Factor(Float),
+}
+
+custom_dimension!(pub JobMaxRideTime typeof MaxRideTime);
+
+/// Creates a feature which limits ride time of multi jobs, such as pickup and delivery. A ride time is a time
+/// between departure from the first job's activity in the tour and service start at the last one.
+/// To keep ride time short, the vehicle waits before the first job's activity instead of waiting at
+/// the next activity while the job is on board.
+pub fn create_max_ride_time_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    let schedule = Arc::new(RideSchedule { transport, activity });
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(MaxRideTimeConstraint { code, schedule: schedule.clone() })
+        .with_state(MaxRideTimeState { schedule })
+        .build()
+}
+
+struct MaxRideTimeConstraint {
+    code: ViolationCode,
+    schedule: Arc<RideSchedule>,
+}
+
+impl FeatureConstraint for MaxRideTimeConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let route = route_ctx.route();
+                let activities = route
+                    .tour
+                    .all_activities()
+                    .take(activity_ctx.index + 1)
+                    .chain(std::iter::once(activity_ctx.target))
+                    .chain(route.tour.all_activities().skip(activity_ctx.index + 1))
+                    .collect::<Vec<_>>();
+
+                if !activities.iter().any(|activity| get_ride_job(activity).is_some()) {
+                    return None;
+                }
+
+                let visits = self.schedule.get_visits(route, activities.as_slice());
+
+                if self.schedule.is_valid(route, activities.as_slice(), visits.as_slice()) {
+                    None
+                } else {
+                    ConstraintViolation::skip(self.code)
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct MaxRideTimeState {
+    schedule: Arc<RideSchedule>,
+}
+
+impl FeatureState for MaxRideTimeState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+        if !activities.iter().any(|activity| get_ride_job(activity).is_some()) {
+            return;
+        }
+
+        // NOTE move waiting before the first activity of the job by postponing its time window start
+        let visits = self.schedule.get_visits(route, activities.as_slice());
+        let times = activities
+            .iter()
+            .zip(visits.iter())
+            .enumerate()
+            .filter(|(_, (activity, _))| get_ride_job(activity).is_some())
+            .map(|(idx, (activity, visit))| {
+                let time = get_original_time(route, activity);
+                let start = if visit.delay > 0. { visit.start } else { time.start };
+
+                (idx, TimeWindow::new(start, time.end))
+            })
+            .filter(|(idx, time)| activities[*idx].place.time != *time)
+            .collect::<Vec<_>>();
+
+        if times.is_empty() {
+            return;
+        }
+
+        times.into_iter().for_each(|(idx, time)| {
+            route_ctx.route_mut().tour.get_mut(idx).expect("invalid activity index").place.time = time;
+        });
+
+        update_route_schedule(route_ctx, self.schedule.activity.as_ref(), self.schedule.transport.as_ref());
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        // NOTE departure rescheduling does not mark routes as stale, so all routes are checked
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+    }
+}
+
+/// Keeps timing of the activity visit.
+struct Visit {
+    arrival: Timestamp,
+    start: Timestamp,
+    departure: Timestamp,
+    delay: Duration,
+}
+
+struct RideSchedule {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl RideSchedule {
+    /// Returns visits of the activities where the first activity of each job with max ride time is
+    /// delayed to absorb waiting time at the next activity.
+    fn get_visits(&self, route: &Route, activities: &[&Activity]) -> Vec<Visit> {
+        let Some(first) = activities.first() else { return Vec::default() };
+        let init = Visit {
+            arrival: first.schedule.arrival,
+            start: first.schedule.departure,
+            departure: first.schedule.departure,
+            delay: Duration::default(),
+        };
+
+        let mut visits = activities.windows(2).fold(vec![init], |mut visits, pair| {
+            let (prev, activity) = (pair[0], pair[1]);
+            let prev_departure = visits.last().map_or(Timestamp::default(), |visit| visit.departure);
+            let arrival = prev_departure
+                + self.transport.duration(
+                    route,
+                    prev.place.location,
+                    activity.place.location,
+                    TravelTime::Departure(prev_departure),
+                );
+
+            let time = if get_ride_job(activity).is_some() {
+                get_original_time(route, activity)
+            } else {
+                activity.place.time.clone()
+            };
+
+            let departure = if time == activity.place.time {
+                self.activity.estimate_departure(route, activity, arrival)
+            } else {
+                let mut original = activity.deep_copy();
+                original.place.time = time.clone();
+                self.activity.estimate_departure(route, &original, arrival)
+            };
+
+            visits.push(Visit { arrival, start: arrival.max(time.start), departure, delay: Duration::default() });
+            visits
+        });
+
+        let mut jobs = HashSet::<Job>::default();
+        let is_first = activities
+            .iter()
+            .map(|activity| get_ride_job(activity).is_some_and(|multi| jobs.insert(Job::Multi(multi))))
+            .collect::<Vec<_>>();
+
+        // NOTE process backward, so waiting can be moved through the chain of delayed activities
+        (1..activities.len().saturating_sub(1)).rev().for_each(|idx| {
+            if !is_first[idx] {
+                return;
+            }
+
+            let waiting = (visits[idx + 1].start - visits[idx + 1].arrival).max(0.);
+            let slack = (get_original_time(route, activities[idx]).end - visits[idx].start).max(0.);
+            let delay = waiting.min(slack);
+
+            if delay > 0. {
+                visits[idx].start += delay;
+                visits[idx].departure += delay;
+                visits[idx].delay = delay;
+                visits[idx + 1].arrival += delay;
+            }
+        });
+
+        visits
+    }
+
+    /// Checks whether all jobs with max ride time, completely present in the activities, are within their limits.
+    fn is_valid(&self, route: &Route, activities: &[&Activity], visits: &[Visit]) -> bool {
+        // NOTE keep indices of the first and the last job's activities together with their amount
+        let rides = activities
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, activity)| get_ride_job(activity).map(|multi| (idx, Job::Multi(multi))))
+            .fold(HashMap::<Job, (usize, usize, usize)>::default(), |mut acc, (idx, job)| {
+                acc.entry(job)
+                    .and_modify(|(_, last_idx, count)| {
+                        *last_idx = idx;
+                        *count += 1;
+                    })
+                    .or_insert((idx, idx, 1));
+                acc
+            });
+
+        rides.into_iter().all(|(job, (first_idx, last_idx, count))| {
+            let Some(multi) = job.as_multi() else { return true };
+            let Some(max_ride_time) = multi.dimens.get_job_max_ride_time() else { return true };
+
+            if multi.jobs.len() != count {
+                return true;
+            }
+
+            let (first, last) = (activities[first_idx], activities[last_idx]);
+            let departure = visits[first_idx].departure;
+            let ride_time = visits[last_idx].start - departure;
+
+            let limit = match max_ride_time {
+                MaxRideTime::Duration(duration) => *duration,
+                MaxRideTime::Factor(factor) => {
+                    let direct = self.transport.duration(
+                        route,
+                        first.place.location,
+                        last.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                    direct * factor
+                }
+            };
+
+            ride_time <= limit
+        })
+    }
+}
+
+/// Returns a multi job with max ride time which activity belongs to.
+fn get_ride_job(activity: &Activity) -> Option<Arc<Multi>> {
+    let single = activity.job.as_ref()?;
+
+    Multi::roots(single).filter(|multi| multi.dimens.get_job_max_ride_time().is_some())
+}
@@ -0,0 +1,195 @@
+//! A feature to minimize changes of the solution relative to the baseline (reference) solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_changes_test.rs"]
+mod minimize_changes_test;
+
+use super::*;
+use crate::construction::enablers::calculate_travel;
+use crate::models::solution::Route;
+use std::collections::{HashMap, HashSet};
+use std::ops::Add;
+
+/// Specifies job assignment in the baseline solution.
+#[derive(Clone, Debug)]
+pub struct BaselineAssignment {
+    /// An id of the vehicle which serves the job.
+    pub vehicle_id: String,
+    /// A position of job's first activity in the tour (tour start has position zero).
+    pub position: usize,
+    /// An arrival time at job's first activity.
+    pub arrival: Timestamp,
+}
+
+/// Specifies thresholds and penalties used to penalize deviations from the baseline solution.
+#[derive(Clone, Debug)]
+pub struct MinimizeChangesConfig {
+    /// Max amount of positions job can be moved within the tour without penalty.
+    pub max_position_shift: usize,
+    /// Max arrival time shift without penalty.
+    pub max_arrival_shift: Duration,
+    /// A penalty for a job served by another vehicle.
+    pub vehicle_penalty: Cost,
+    /// A penalty for a job moved by more than allowed amount of positions.
+    pub position_penalty: Cost,
+    /// A penalty for a job with arrival time shifted by more than allowed threshold.
+    pub arrival_penalty: Cost,
+}
+
+impl Default for MinimizeChangesConfig {
+    fn default() -> Self {
+        Self {
+            max_position_shift: 2,
+            max_arrival_shift: 1800.,
+            vehicle_penalty: 1.,
+            position_penalty: 1.,
+            arrival_penalty: 1.,
+        }
+    }
+}
+
+/// Keeps amount of jobs which deviate from the baseline solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SolutionChanges {
+    /// Amount of jobs served by another vehicle.
+    pub vehicle: usize,
+    /// Amount of jobs moved by more than allowed amount of positions.
+    pub position: usize,
+    /// Amount of jobs with arrival time shifted by more than allowed threshold.
+    pub arrival: usize,
+}
+
+impl Add for SolutionChanges {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            vehicle: self.vehicle + rhs.vehicle,
+            position: self.position + rhs.position,
+            arrival: self.arrival + rhs.arrival,
+        }
+    }
+}
+
+/// A baseline solution represented by job assignments which are accessed by job id.
+pub struct BaselinePlan {
+    assignments: HashMap<String, BaselineAssignment>,
+    config: MinimizeChangesConfig,
+}
+
+impl BaselinePlan {
+    /// Creates a new instance of `BaselinePlan`.
+    pub fn new(assignments: HashMap<String, BaselineAssignment>, config: MinimizeChangesConfig) -> Self {
+        Self { assignments, config }
+    }
+
+    /// Returns changes of the given routes relative to the baseline. Jobs which are not present
+    /// in the baseline are ignored.
+    pub fn get_changes<'a>(&self, routes: impl Iterator<Item = &'a Route>) -> SolutionChanges {
+        routes.map(|route| self.get_route_changes(route)).fold(SolutionChanges::default(), |acc, changes| acc + changes)
+    }
+
+    /// Returns a total penalty for the given changes.
+    pub fn get_penalty(&self, changes: &SolutionChanges) -> Cost {
+        changes.vehicle as Cost * self.config.vehicle_penalty
+            + changes.position as Cost * self.config.position_penalty
+            + changes.arrival as Cost * self.config.arrival_penalty
+    }
+
+    fn get_route_changes(&self, route: &Route) -> SolutionChanges {
+        let Some(vehicle_id) = route.actor.vehicle.dimens.get_vehicle_id() else { return SolutionChanges::default() };
+        let mut visited = HashSet::new();
+
+        route
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(|(position, activity)| activity.retrieve_job().map(|job| (position, activity, job)))
+            .filter(|(_, _, job)| visited.insert(job.clone()))
+            .filter_map(|(position, activity, job)| {
+                let assignment = self.get_assignment(&job)?;
+
+                Some(SolutionChanges {
+                    vehicle: usize::from(assignment.vehicle_id != *vehicle_id),
+                    position: usize::from(self.is_position_changed(assignment, position)),
+                    arrival: usize::from(self.is_arrival_changed(assignment, activity.schedule.arrival)),
+                })
+            })
+            .fold(SolutionChanges::default(), |acc, changes| acc + changes)
+    }
+
+    fn get_assignment(&self, job: &Job) -> Option<&BaselineAssignment> {
+        job.dimens().get_job_id().and_then(|job_id| self.assignments.get(job_id))
+    }
+
+    fn is_position_changed(&self, assignment: &BaselineAssignment, position: usize) -> bool {
+        assignment.position.abs_diff(position) > self.config.max_position_shift
+    }
+
+    fn is_arrival_changed(&self, assignment: &BaselineAssignment, arrival: Timestamp) -> bool {
+        (assignment.arrival - arrival).abs() > self.config.max_arrival_shift
+    }
+}
+
+/// Creates a feature which penalizes deviations from the baseline solution: job is served by another
+/// vehicle, moved by more than allowed amount of positions or its arrival time is shifted by more than
+/// allowed threshold.
+pub fn create_minimize_changes_feature(
+    name: &str,
+    baseline: Arc<BaselinePlan>,
+    transport: Arc<dyn TransportCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(MinimizeChangesObjective { baseline, transport }).build()
+}
+
+struct MinimizeChangesObjective {
+    baseline: Arc<BaselinePlan>,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for MinimizeChangesObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        let changes = self.baseline.get_changes(solution.solution.routes.iter().map(|route_ctx| route_ctx.route()));
+
+        self.baseline.get_penalty(&changes)
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let vehicle_id = route_ctx.route().actor.vehicle.dimens.get_vehicle_id();
+
+                self.baseline
+                    .get_assignment(job)
+                    .filter(|assignment| vehicle_id.is_some_and(|vehicle_id| *vehicle_id != assignment.vehicle_id))
+                    .map_or(Cost::default(), |_| self.baseline.config.vehicle_penalty)
+            }
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                // NOTE only the first activity of the job is estimated, impact on other jobs is ignored
+                let Some(job) = activity_ctx.target.retrieve_job() else { return Cost::default() };
+                if route_ctx.route().tour.contains(&job) {
+                    return Cost::default();
+                }
+
+                let Some(assignment) = self.baseline.get_assignment(&job) else { return Cost::default() };
+
+                let (_, (prev_to_tar_dur, _)) = calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+                let arrival = activity_ctx.prev.schedule.departure + prev_to_tar_dur;
+                let config = &self.baseline.config;
+
+                let position_penalty = if self.baseline.is_position_changed(assignment, activity_ctx.index + 1) {
+                    config.position_penalty
+                } else {
+                    Cost::default()
+                };
+                let arrival_penalty = if self.baseline.is_arrival_changed(assignment, arrival) {
+                    config.arrival_penalty
+                } else {
+                    Cost::default()
+                };
+
+                position_penalty + arrival_penalty
+            }
+        }
+    }
+}
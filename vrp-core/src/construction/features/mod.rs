@@ -14,9 +14,18 @@ mod capacity;
 pub(crate) use self::capacity::MaxVehicleLoadTourState;
 pub use self::capacity::{CapacityFeatureBuilder, JobDemandDimension, VehicleCapacityDimension};
 
+mod compartments;
+pub use self::compartments::{
+    Compartment, CompartmentTrip, JobProductDimension, VehicleCompartmentsDimension, create_compartments_feature,
+    get_compartment_trips,
+};
+
 mod compatibility;
 pub use self::compatibility::{JobCompatibilityDimension, create_compatibility_feature};
 
+mod emissions;
+pub use self::emissions::{EmissionModel, VehicleEmissionsDimension, create_minimize_emissions_feature};
+
 mod fast_service;
 pub use self::fast_service::FastServiceFeatureBuilder;
 
@@ -53,27 +62,64 @@ pub use self::sync::{
 mod hierarchical_areas;
 pub use self::hierarchical_areas::*;
 
+mod hours_of_service;
+pub use self::hours_of_service::{HoursOfServiceFeatureBuilder, HoursOfServiceLimits};
+
 mod known_edge;
 pub use self::known_edge::create_known_edge_feature;
 
+mod loading_docks;
+pub use self::loading_docks::{DockSession, LoadingDock, LoadingDocks, create_loading_docks_feature};
+
+mod loading_policy;
+pub use self::loading_policy::{
+    LoadingPolicy, VehicleLoadingPolicyDimension, create_loading_policy_feature, get_loading_policy_violation,
+};
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
+mod max_ride_time;
+pub use self::max_ride_time::{JobMaxRideTimeDimension, MaxRideTime, create_max_ride_time_feature};
+
+mod minimize_changes;
+pub use self::minimize_changes::*;
+
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
+mod outsourcing;
+pub use self::outsourcing::{
+    OutsourcingViolationCodeExtraProperty, create_outsourcing_feature, get_outsourced_job_cost,
+};
+
 mod reachable;
 pub use self::reachable::create_reachable_feature;
 
 mod recharge;
-pub use self::recharge::RechargeFeatureBuilder;
+pub use self::recharge::{BatteryModel, RechargeFeatureBuilder, VehicleBatteryDimension};
 
 mod reloads;
 pub use self::reloads::{ReloadFeatureFactory, ReloadIntervalsTourState, SharedResource, SharedResourceId};
 
+mod shift_gaps;
+pub use self::shift_gaps::{VehicleShiftGapsDimension, create_shift_gaps_feature};
+
 mod skills;
 pub use self::skills::{JobSkills, JobSkillsDimension, VehicleSkillsDimension, create_skills_feature};
 
+mod soft_time_windows;
+pub use self::soft_time_windows::{
+    JobSoftTimeWindowsDimension, SoftTimeWindow, create_soft_time_windows_feature, get_activity_service_start, get_activity_soft_time_window,
+    get_activity_time_window_deviation,
+};
+
+mod split_jobs;
+pub use self::split_jobs::{JobSplitKeyDimension, JobSplitMaxVisitsDimension, create_split_jobs_feature};
+
+mod time_usage;
+pub use self::time_usage::{create_minimize_makespan_feature, create_minimize_waiting_time_feature};
+
 mod total_value;
 pub use self::total_value::*;
 
@@ -86,6 +132,18 @@ pub use self::tour_limits::*;
 mod tour_order;
 pub use self::tour_order::*;
 
+mod trailers;
+pub use self::trailers::{
+    JobTrailerAllowedDimension, JobTrailerOperationDimension, TrailerOperation, VehicleTrailerCapacityDimension,
+    create_trailer_feature,
+};
+
+mod transfers;
+pub use self::transfers::{
+    JobTransferHandlingTimeDimension, JobTransferKeyDimension, JobTransferLegDimension, TransferLeg,
+    create_transfers_feature,
+};
+
 mod transport;
 pub use self::transport::*;
 
@@ -0,0 +1,146 @@
+//! A feature to model an option to outsource jobs instead of serving them by the fleet.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/outsourcing_test.rs"]
+mod outsourcing_test;
+
+use super::*;
+use crate::models::Extras;
+use crate::models::solution::{Activity, Route};
+use std::iter::once;
+
+custom_extra_property!(pub OutsourcingViolationCode typeof ViolationCode);
+
+/// Creates a feature which prevents serving a job by the fleet when its insertion costs more than
+/// outsourcing it. The outsourcing cost is specified by `JobOutsourcingCost` dimension of the job; such
+/// jobs are expected to be left unassigned and their outsourcing cost is included into the total cost
+/// when the same `code` is set as `OutsourcingViolationCode` extra property of the problem.
+pub fn create_outsourcing_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(OutsourcingConstraint { code, transport, activity })
+        .build()
+}
+
+struct OutsourcingConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureConstraint for OutsourcingConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let outsourcing_cost = get_outsourcing_cost(activity_ctx.target)?;
+                let insertion_cost = self.estimate_insertion_cost(route_ctx, activity_ctx);
+
+                if insertion_cost > outsourcing_cost { ConstraintViolation::skip(self.code) } else { None }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_outsourcing_cost = |job: &Job| job.dimens().get_job_outsourcing_cost().is_some();
+
+        if has_outsourcing_cost(&source) || has_outsourcing_cost(&candidate) { Err(self.code) } else { Ok(source) }
+    }
+}
+
+impl OutsourcingConstraint {
+    /// Estimates a cost of serving the whole job which the target activity belongs to: a detour needed to
+    /// serve all its activities, including the ones inserted before the target, and fixed costs.
+    fn estimate_insertion_cost(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let target = activity_ctx.target;
+        let Some(job) = target.retrieve_job() else { return Cost::default() };
+
+        // NOTE activities of multi job are inserted one by one, so previous ones are already in the tour
+        let target_idx = activity_ctx.index + 1;
+        let activities = route
+            .tour
+            .all_activities()
+            .take(target_idx)
+            .chain(once(target))
+            .chain(route.tour.all_activities().skip(target_idx))
+            .collect::<Vec<_>>();
+        let job_indices = activities
+            .iter()
+            .enumerate()
+            .filter(|(_, activity)| activity.has_same_job(&job))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        // NOTE only the part of the tour between the first and the last job activities is affected
+        let first = job_indices.first().copied().unwrap_or(target_idx).saturating_sub(1);
+        let last = (job_indices.last().copied().unwrap_or(target_idx) + 2).min(activities.len());
+        let with_job = &activities[first..last];
+        let without_job = with_job.iter().filter(|activity| !activity.has_same_job(&job)).copied().collect::<Vec<_>>();
+
+        let job_fixed_cost = job_indices
+            .iter()
+            .filter_map(|idx| activities[*idx].job.as_ref())
+            .map(|single| {
+                single.dimens.get_job_fixed_cost().copied().unwrap_or_default()
+                    + get_job_assignment_cost(single, &route.actor)
+            })
+            .sum::<Cost>();
+
+        // NOTE the job opens a new route, so it has to pay for the vehicle too
+        let is_empty_route = route.tour.jobs().all(|other| *other == job);
+        let route_fixed_cost =
+            if is_empty_route { route.actor.driver.costs.fixed + route.actor.vehicle.costs.fixed } else { 0. };
+
+        self.estimate_sequence_cost(route, with_job) - self.estimate_sequence_cost(route, without_job.as_slice())
+            + job_fixed_cost
+            + route_fixed_cost
+    }
+
+    /// Estimates transport and activity costs of visiting given activities one by one.
+    fn estimate_sequence_cost(&self, route: &Route, activities: &[&Activity]) -> Cost {
+        let Some(first) = activities.first() else { return Cost::default() };
+
+        activities
+            .windows(2)
+            .fold((Cost::default(), first.schedule.departure), |(cost, departure), pair| {
+                let (from, to) = (pair[0], pair[1]);
+                let travel_time = TravelTime::Departure(departure);
+                let arrival =
+                    departure + self.transport.duration(route, from.place.location, to.place.location, travel_time);
+                let cost = cost
+                    + self.transport.cost(route, from.place.location, to.place.location, travel_time)
+                    + self.activity.cost(route, to, arrival);
+
+                (cost, self.activity.estimate_departure(route, to, arrival))
+            })
+            .0
+    }
+}
+
+/// Returns an outsourcing cost of the unassigned job if it is outsourced: the job is not served by the fleet
+/// because it is cheaper to outsource it, which is reported by the outsourcing violation `code`.
+pub fn get_outsourced_job_cost(job: &Job, info: &UnassignmentInfo, code: ViolationCode) -> Option<Cost> {
+    let is_outsourced = match info {
+        UnassignmentInfo::Simple(actual) => *actual == code,
+        UnassignmentInfo::Detailed(details) => details.iter().any(|(_, actual)| *actual == code),
+        UnassignmentInfo::Unknown => false,
+    };
+
+    job.dimens().get_job_outsourcing_cost().copied().filter(|_| is_outsourced)
+}
+
+/// Returns an outsourcing cost of the job which the activity belongs to.
+fn get_outsourcing_cost(activity: &Activity) -> Option<Cost> {
+    let single = activity.job.as_ref()?;
+
+    match Multi::roots(single) {
+        Some(multi) => multi.dimens.get_job_outsourcing_cost().copied(),
+        None => single.dimens.get_job_outsourcing_cost().copied(),
+    }
+}
@@ -1,5 +1,7 @@
 //! An experimental feature which provides a way to insert recharge stations in the tour to recharge
-//! (refuel) vehicle.
+//! (refuel) vehicle. By default, the range is modeled as a fixed distance, but it is also possible to use
+//! a battery model of an electric vehicle specified via `VehicleBatteryDimension`. Optionally, recharge
+//! stations can have a limited amount of chargers shared between all vehicles.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/recharge_test.rs"]
@@ -7,10 +9,96 @@ mod recharge_test;
 
 use super::*;
 use crate::construction::enablers::*;
-use crate::models::solution::Route;
-use std::collections::HashSet;
+use crate::construction::features::capacity::CurrentCapacityActivityState;
+use crate::models::common::TimeWindow;
+use crate::models::solution::{Activity, Route};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use std::sync::Arc;
 
+/// Specifies a battery model of an electric vehicle. Charge levels and consumed energy are measured
+/// in the same units.
+#[derive(Clone, Debug)]
+pub struct BatteryModel {
+    /// A battery capacity.
+    pub capacity: Float,
+    /// A charge level at the start of the tour.
+    pub initial_charge: Float,
+    /// A minimum charge level which has to be kept all the time.
+    pub reserve: Float,
+    /// An energy consumed per distance unit by an empty vehicle.
+    pub consumption: Float,
+    /// An extra energy consumed per distance unit per payload unit.
+    pub load_consumption: Float,
+    /// A charger power curve as a list of (charge level, power) pairs sorted by charge level: the power is
+    /// used while charge level is below the given one. The power of the last pair is used above it. Power is
+    /// measured as energy per time unit.
+    pub charging_curve: Vec<(Float, Float)>,
+}
+
+impl BatteryModel {
+    /// Returns energy consumed to travel given distance with given payload.
+    pub fn get_consumption(&self, distance: Distance, payload: Float) -> Float {
+        distance * (self.consumption + self.load_consumption * payload)
+    }
+
+    /// Returns duration needed to charge battery from one level to another using the charging curve.
+    /// The duration is rounded up to whole time units.
+    pub fn get_charging_duration(&self, from: Float, to: Float) -> Duration {
+        let (duration, level) = self.charging_curve.iter().fold((Duration::default(), from), |acc, &(limit, power)| {
+            let (duration, level) = acc;
+            if level >= to || limit <= level {
+                return acc;
+            }
+
+            let next_level = limit.min(to);
+            (duration + (next_level - level) / power, next_level)
+        });
+
+        let duration = match self.charging_curve.last() {
+            Some(&(_, power)) if level < to => duration + (to - level) / power,
+            _ => duration,
+        };
+
+        // NOTE avoid precision issues as fractional durations are hardly useful in practice
+        (duration - 1E-9).max(0.).ceil()
+    }
+
+    /// Returns charge levels at arrival and departure for each activity in the tour. Each leg is specified
+    /// by energy consumed to reach the activity and a flag whether the activity is a recharge. At recharge,
+    /// the vehicle charges only energy needed to reach the next recharge or the end of the tour keeping
+    /// the reserve.
+    pub fn get_charge_levels(&self, legs: &[(Float, bool)]) -> Vec<(Float, Float)> {
+        let mut needed = vec![Float::default(); legs.len()];
+        legs.iter().enumerate().rev().fold(Float::default(), |acc, (idx, &(energy, is_recharge))| {
+            if is_recharge {
+                needed[idx] = acc;
+                energy
+            } else {
+                acc + energy
+            }
+        });
+
+        legs.iter()
+            .zip(needed)
+            .scan(self.initial_charge, |level, (&(energy, is_recharge), needed)| {
+                let arrival = *level - energy;
+                let departure =
+                    if is_recharge { arrival.max((needed + self.reserve).min(self.capacity)) } else { arrival };
+                *level = departure;
+
+                Some((arrival, departure))
+            })
+            .collect()
+    }
+
+    fn get_usable_energy(&self, is_first_interval: bool) -> Float {
+        (if is_first_interval { self.initial_charge } else { self.capacity }) - self.reserve
+    }
+}
+
+custom_dimension!(pub VehicleBattery typeof Arc<BatteryModel>);
+
 /// Provides a way to build the recharge/refuel feature.
 #[allow(clippy::type_complexity)]
 pub struct RechargeFeatureBuilder {
@@ -20,6 +108,12 @@ pub struct RechargeFeatureBuilder {
     belongs_to_route_fn: Option<Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>>,
     is_recharge_single_fn: Option<RechargeSingleFn>,
     distance_limit_fn: Option<RechargeDistanceLimitFn>,
+    initial_distance_fn: Option<RechargeDistanceLimitFn>,
+    activity: Option<Arc<dyn ActivityCost>>,
+    route_payload_fn: Option<RoutePayloadFn>,
+    job_payload_fn: Option<JobPayloadFn>,
+    charger_code: Option<ViolationCode>,
+    charger_capacity_fn: Option<ChargerCapacityFn>,
 }
 
 impl RechargeFeatureBuilder {
@@ -31,7 +125,13 @@ impl RechargeFeatureBuilder {
             is_recharge_single_fn: None,
             belongs_to_route_fn: None,
             distance_limit_fn: None,
+            initial_distance_fn: None,
             transport: None,
+            activity: None,
+            route_payload_fn: None,
+            job_payload_fn: None,
+            charger_code: None,
+            charger_capacity_fn: None,
         }
     }
 
@@ -47,6 +147,36 @@ impl RechargeFeatureBuilder {
         self
     }
 
+    /// Sets activity costs to update recharge durations when battery model is used.
+    pub fn set_activity(mut self, activity: Arc<dyn ActivityCost>) -> Self {
+        self.activity = Some(activity);
+        self
+    }
+
+    /// Sets a function which converts vehicle load into payload used to estimate energy consumption when
+    /// battery model is used. Without it, payload is considered as zero.
+    pub fn set_payload<T, F>(mut self, func: F) -> Self
+    where
+        T: LoadOps,
+        F: Fn(&T) -> Float + Send + Sync + 'static,
+    {
+        let func = Arc::new(func);
+
+        self.route_payload_fn = Some(Arc::new({
+            let func = func.clone();
+            move |route_ctx, activity_idx| {
+                route_ctx.state().get_current_capacity_at::<T>(activity_idx).map_or(Float::default(), |load| func(load))
+            }
+        }));
+        self.job_payload_fn = Some(Arc::new(move |single| {
+            single.dimens.get_job_demand::<T>().map_or(Float::default(), |demand| {
+                func(&demand.pickup.0) + func(&demand.pickup.1) + func(&demand.delivery.0) + func(&demand.delivery.1)
+            })
+        }));
+
+        self
+    }
+
     /// Sets a function which specifies whether a given single job can be considered as a recharge job.
     pub fn set_is_recharge_single<F>(mut self, func: F) -> Self
     where
@@ -76,6 +206,33 @@ impl RechargeFeatureBuilder {
         self
     }
 
+    /// Specifies a distance which is already traveled since the last recharge at the start of the tour.
+    /// It reduces the distance limit of the first interval. Ignored when battery model is used.
+    pub fn set_initial_distance<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<Distance> + Send + Sync + 'static,
+    {
+        self.initial_distance_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets charger constraint violation code which is used to report back the reason of job's unassignment.
+    pub fn set_charger_code(mut self, code: ViolationCode) -> Self {
+        self.charger_code = Some(code);
+        self
+    }
+
+    /// Sets a function which returns amount of parallel chargers and the shared resource id for
+    /// a given recharge activity. When set, charging sessions of all vehicles at the same resource
+    /// cannot overlap in time beyond amount of chargers.
+    pub fn set_charger_capacity<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Activity) -> Option<(usize, SharedResourceId)> + Send + Sync + 'static,
+    {
+        self.charger_capacity_fn = Some(Arc::new(func));
+        self
+    }
+
     /// Builds the recharge feature if all dependencies are set.
     pub fn build(&mut self) -> GenericResult<Feature> {
         let is_marker_single_fn =
@@ -89,90 +246,127 @@ impl RechargeFeatureBuilder {
 
         let code = self.violation_code.unwrap_or_default();
 
-        create_multi_trip_feature(
+        let limits = RechargeLimits {
+            distance_limit_fn,
+            initial_distance_fn: self.initial_distance_fn.take(),
+            route_payload_fn: self.route_payload_fn.take(),
+            job_payload_fn: self.job_payload_fn.take(),
+        };
+
+        let counter = MarkerIntervalCounter {
+            counter_fn: Arc::new(get_counter),
+            leg_counter_fn: Arc::new({
+                let limits = limits.clone();
+                let transport = transport.clone();
+                move |route_ctx, from_idx, from, to| {
+                    let distance = get_leg_distance(transport.as_ref(), route_ctx.route(), from, to);
+                    limits.get_leg_counter(route_ctx, from_idx, distance)
+                }
+            }),
+            limit_fn: Arc::new({
+                let limits = limits.clone();
+                move |route_ctx, is_first_interval| {
+                    limits.get_limit(route_ctx.route().actor.as_ref(), is_first_interval)
+                }
+            }),
+        };
+
+        let charger_counter = counter.clone();
+        let recharge = create_multi_trip_feature(
             self.name.as_str(),
             code,
             MarkerInsertionPolicy::Any,
             Arc::new(RechargeableMultiTrip {
-                route_intervals: RouteIntervals::Multiple {
-                    is_marker_single_fn: is_marker_single_fn.clone(),
-                    is_new_interval_needed_fn: Arc::new({
-                        let distance_limit_fn = distance_limit_fn.clone();
-                        move |route_ctx| {
-                            route_ctx
-                                .route()
-                                .tour
-                                .end_idx()
-                                .map(|end_idx| {
-                                    let current: Distance = route_ctx
-                                        .state()
-                                        .get_recharge_distance_at(end_idx)
-                                        .copied()
-                                        .unwrap_or_default();
-
-                                    (distance_limit_fn)(route_ctx.route().actor.as_ref())
-                                        .is_some_and(|threshold| current > threshold)
-                                })
-                                .unwrap_or(false)
-                        }
-                    }),
-                    is_obsolete_interval_fn: Arc::new({
-                        let distance_limit_fn = distance_limit_fn.clone();
-                        let transport = transport.clone();
-                        let get_counter = move |route_ctx: &RouteContext, activity_idx: usize| {
-                            route_ctx
-                                .state()
-                                .get_recharge_distance_at(activity_idx)
-                                .copied()
-                                .unwrap_or(Distance::default())
-                        };
-                        let get_distance = move |route: &Route, from_idx: usize, to_idx: usize| {
-                            route.tour.get(from_idx).zip(route.tour.get(to_idx)).map_or(
-                                Distance::default(),
-                                |(from, to)| {
-                                    transport.distance(
-                                        route,
-                                        from.place.location,
-                                        to.place.location,
-                                        TravelTime::Departure(from.schedule.departure),
-                                    )
-                                },
-                            )
-                        };
-                        move |route_ctx, left, right| {
-                            let end_idx = get_end_idx(route_ctx, right.end);
-
-                            let new_distance = get_counter(route_ctx, left.end) + get_counter(route_ctx, end_idx)
-                                - get_counter(route_ctx, right.start + 1)
-                                + get_distance(route_ctx.route(), left.end, right.start + 1);
-
-                            (distance_limit_fn)(route_ctx.route().actor.as_ref())
-                                .is_some_and(|threshold| new_distance <= threshold)
-                        }
-                    }),
+                route_intervals: counter.create_route_intervals(
+                    is_marker_single_fn.clone(),
                     is_assignable_fn,
-                    intervals_state: Arc::new(RechargeIntervalsState),
-                },
-                transport,
+                    Arc::new(RechargeIntervalsState),
+                ),
+                counter,
+                transport: transport.clone(),
+                activity: self.activity.take(),
                 code,
-                distance_limit_fn,
+                limits,
                 recharge_single_fn: is_marker_single_fn.clone(),
             }),
-        )
+        )?;
+
+        let Some(capacity_fn) = self.charger_capacity_fn.take() else { return Ok(recharge) };
+
+        let charger = FeatureBuilder::default()
+            .with_name(self.name.as_str())
+            .with_constraint(ChargerConstraint {
+                code: self.charger_code.unwrap_or_default(),
+                capacity_fn: capacity_fn.clone(),
+                transport,
+            })
+            .with_state(ChargerState { capacity_fn, counter: charger_counter, recharge_single_fn: is_marker_single_fn })
+            .build()?;
+
+        FeatureCombinator::default().use_name(self.name.as_str()).add_features(&[recharge, charger]).combine()
     }
 }
 
 type RechargeDistanceLimitFn = Arc<dyn Fn(&Actor) -> Option<Distance> + Send + Sync>;
 type RechargeSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+type RoutePayloadFn = Arc<dyn Fn(&RouteContext, usize) -> Float + Send + Sync>;
+type JobPayloadFn = Arc<dyn Fn(&Single) -> Float + Send + Sync>;
 
 custom_route_intervals_state!(RechargeIntervals);
 custom_activity_state!(RechargeDistance typeof Distance);
+custom_activity_state!(RechargeEnergy typeof Float);
+custom_activity_state!(ChargeLevel typeof (Float, Float));
+
+/// Keeps functions to get a limit of the recharge interval and its counter. Interval counter is either
+/// a distance or energy consumption when battery model is used.
+#[derive(Clone)]
+struct RechargeLimits {
+    distance_limit_fn: RechargeDistanceLimitFn,
+    initial_distance_fn: Option<RechargeDistanceLimitFn>,
+    route_payload_fn: Option<RoutePayloadFn>,
+    job_payload_fn: Option<JobPayloadFn>,
+}
+
+impl RechargeLimits {
+    fn get_limit(&self, actor: &Actor, is_first_interval: bool) -> Option<Float> {
+        match actor.vehicle.dimens.get_vehicle_battery() {
+            Some(battery) => Some(battery.get_usable_energy(is_first_interval)),
+            None => (self.distance_limit_fn)(actor).map(|limit| {
+                let initial_distance = self
+                    .initial_distance_fn
+                    .as_ref()
+                    .filter(|_| is_first_interval)
+                    .and_then(|initial_distance_fn| (initial_distance_fn)(actor))
+                    .unwrap_or_default();
+
+                (limit - initial_distance).max(Distance::default())
+            }),
+        }
+    }
+
+    fn get_leg_counter(&self, route_ctx: &RouteContext, from_idx: usize, distance: Distance) -> Float {
+        match route_ctx.route().actor.vehicle.dimens.get_vehicle_battery() {
+            Some(battery) => battery.get_consumption(distance, self.get_route_payload(route_ctx, from_idx)),
+            None => distance,
+        }
+    }
+
+    fn get_route_payload(&self, route_ctx: &RouteContext, activity_idx: usize) -> Float {
+        self.route_payload_fn.as_ref().map_or(Float::default(), |payload_fn| (payload_fn)(route_ctx, activity_idx))
+    }
+
+    fn get_job_payload(&self, single: &Single) -> Float {
+        self.job_payload_fn.as_ref().map_or(Float::default(), |payload_fn| (payload_fn)(single))
+    }
+}
 
 struct RechargeableMultiTrip {
     route_intervals: RouteIntervals,
+    counter: MarkerIntervalCounter,
     transport: Arc<dyn TransportCost>,
+    activity: Option<Arc<dyn ActivityCost>>,
     code: ViolationCode,
-    distance_limit_fn: RechargeDistanceLimitFn,
+    limits: RechargeLimits,
     recharge_single_fn: RechargeSingleFn,
 }
 
@@ -186,81 +380,41 @@ impl MultiTrip for RechargeableMultiTrip {
     }
 
     fn recalculate_states(&self, route_ctx: &mut RouteContext) {
-        if (self.distance_limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+        let battery = route_ctx.route().actor.vehicle.dimens.get_vehicle_battery().cloned();
+        if battery.is_none() && (self.limits.distance_limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
             return;
         }
 
-        let last_idx = route_ctx.route().tour.total() - 1;
-        let marker_intervals = self.route_intervals.resolve_marker_intervals(route_ctx).collect::<Vec<_>>();
-        let mut distance_counters = vec![Distance::default(); route_ctx.route().tour.total()];
+        let get_distance =
+            |from: &Activity, to: &Activity| get_leg_distance(self.transport.as_ref(), route_ctx.route(), from, to);
 
-        marker_intervals.into_iter().for_each(|(start_idx, end_idx)| {
-            let route = route_ctx.route();
+        let (distance_counters, _) =
+            get_marker_interval_counters(route_ctx, &self.route_intervals, |_, prev, next| get_distance(prev, next));
 
-            let end_idx = if end_idx != last_idx { end_idx + 1 } else { end_idx };
+        let energy_counters = battery.as_ref().map(|battery| {
+            let mut leg_energies = vec![Float::default(); route_ctx.route().tour.total()];
+            let (energy_counters, _) =
+                get_marker_interval_counters(route_ctx, &self.route_intervals, |activity_idx, prev, next| {
+                    let payload = self.limits.get_route_payload(route_ctx, activity_idx);
+                    let energy = battery.get_consumption(get_distance(prev, next), payload);
+                    leg_energies[activity_idx + 1] = energy;
 
-            let _ = route
-                .tour
-                .activities_slice(start_idx, end_idx)
-                .windows(2)
-                .enumerate()
-                .filter_map(|(leg_idx, leg)| match leg {
-                    [prev, next] => Some((start_idx + leg_idx, prev, next)),
-                    _ => None,
-                })
-                .fold(Distance::default(), |acc, (activity_idx, prev, next)| {
-                    let distance = self.transport.distance(
-                        route,
-                        prev.place.location,
-                        next.place.location,
-                        TravelTime::Departure(prev.schedule.departure),
-                    );
-                    let counter = acc + distance;
-                    let next_idx = activity_idx + 1;
-
-                    distance_counters[next_idx] = counter;
-
-                    counter
+                    energy
                 });
+
+            (energy_counters, leg_energies)
         });
 
         route_ctx.state_mut().set_recharge_distance_states(distance_counters);
+
+        if let Some((battery, (energy_counters, leg_energies))) = battery.zip(energy_counters) {
+            route_ctx.state_mut().set_recharge_energy_states(energy_counters);
+            self.update_recharge_durations(route_ctx, battery.as_ref(), leg_energies);
+        }
     }
 
     fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
-        let routes = &mut solution_ctx.routes;
-
-        let jobs: HashSet<_> = if route_indices.is_empty() {
-            solution_ctx
-                .ignored
-                .iter()
-                .filter(|job| job.as_single().is_some_and(|single| (self.recharge_single_fn)(single)))
-                .cloned()
-                .collect()
-        } else {
-            routes
-                .iter()
-                .enumerate()
-                .filter(|(idx, _)| route_indices.contains(idx))
-                .flat_map(|(_, route_ctx)| {
-                    solution_ctx
-                        .ignored
-                        .iter()
-                        .filter(|job| self.route_intervals.is_marker_assignable(route_ctx.route(), job))
-                })
-                .cloned()
-                .collect()
-        };
-
-        if jobs.is_empty() {
-            false
-        } else {
-            solution_ctx.ignored.retain(|job| !jobs.contains(job));
-            solution_ctx.locked.extend(jobs.iter().cloned());
-            solution_ctx.required.extend(jobs);
-
-            true
-        }
+        recover_marker_jobs(solution_ctx, route_indices, &self.route_intervals)
     }
 }
 
@@ -287,55 +441,462 @@ impl RechargeableMultiTrip {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ConstraintViolation> {
-        let threshold = (self.distance_limit_fn)(route_ctx.route().actor.as_ref())?;
+        let actor = route_ctx.route().actor.as_ref();
+        let interval = get_marker_interval(route_ctx, &self.route_intervals, activity_ctx.index);
 
-        let interval_distance = self
-            .route_intervals
-            .resolve_marker_intervals(route_ctx)
-            .find(|(_, end_idx)| activity_ctx.index <= *end_idx)
-            .map(|(_, end_idx)| get_end_idx(route_ctx, end_idx))
-            .map(|end_idx| self.get_distance(route_ctx, end_idx))
-            .expect("invalid markers state");
+        self.limits.get_limit(actor, interval.0 == 0)?;
 
         let is_new_recharge = activity_ctx.target.job.as_ref().is_some_and(|single| (self.recharge_single_fn)(single));
 
         let is_violation = if is_new_recharge {
-            let ((prev_to_tar_distance, tar_to_next_distance), _) =
-                calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+            self.counter.is_marker_insertion_violation(route_ctx, activity_ctx, interval)
+        } else {
+            let (distance_delta, _) = calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
 
-            // S ----- A ---- [X] ------ B ----- F
+            match actor.vehicle.dimens.get_vehicle_battery() {
+                Some(battery) => self.is_energy_violation(route_ctx, activity_ctx, battery, distance_delta),
+                None => self.counter.is_interval_violation(route_ctx, interval, distance_delta),
+            }
+        };
 
-            let current_distance = self.get_distance(route_ctx, activity_ctx.index);
-            // check S->X
-            let is_begin_violates = (current_distance + prev_to_tar_distance) > threshold;
-            // check X->F
-            let is_end_violates = if activity_ctx.next.is_some() {
-                let next_distance = self.get_distance(route_ctx, activity_ctx.index + 1);
-                let new_interval_distance = interval_distance - next_distance;
+        let is_violation = is_violation
+            || actor.vehicle.dimens.get_vehicle_battery().is_some_and(|battery| {
+                self.is_charging_time_violation(route_ctx, activity_ctx, battery, interval, is_new_recharge)
+            });
 
-                (new_interval_distance + tar_to_next_distance) > threshold
-            } else {
-                false
-            };
+        if is_violation { ConstraintViolation::skip(self.code) } else { None }
+    }
+
+    /// Checks whether insertion of a non recharge activity leads to energy violation. As job's payload
+    /// changes vehicle load on legs which are not known here, its impact is estimated conservatively as
+    /// if the payload is carried during all legs of each interval.
+    fn is_energy_violation(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        battery: &BatteryModel,
+        distance_delta: Distance,
+    ) -> bool {
+        let actor = route_ctx.route().actor.as_ref();
+        let (energy_delta, payload_energy_rate) =
+            self.get_energy_delta(route_ctx, activity_ctx, battery, distance_delta);
+
+        self.route_intervals.resolve_marker_intervals(route_ctx).any(|(start_idx, end_idx)| {
+            let is_current = activity_ctx.index >= start_idx && activity_ctx.index <= end_idx;
+            let end_idx = get_interval_end_idx(route_ctx, end_idx);
+
+            let energy = get_counter(route_ctx, end_idx)
+                + payload_energy_rate * get_distance(route_ctx, end_idx)
+                + if is_current { energy_delta } else { Float::default() };
+
+            self.limits.get_limit(actor, start_idx == 0).is_some_and(|threshold| energy > threshold)
+        })
+    }
 
-            is_begin_violates || is_end_violates
+    /// Returns energy consumption delta of the activity insertion and energy consumed per distance unit
+    /// to carry job's payload.
+    fn get_energy_delta(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        battery: &BatteryModel,
+        distance_delta: Distance,
+    ) -> (Float, Float) {
+        let job_payload = activity_ctx
+            .target
+            .job
+            .as_ref()
+            .map_or(Float::default(), |single| self.limits.get_job_payload(single.as_ref()));
+        let payload_energy_rate = battery.load_consumption * job_payload;
+
+        let ((prev_to_tar_distance, tar_to_next_distance), _) =
+            calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+        let new_distance = prev_to_tar_distance + tar_to_next_distance;
+        let route_payload = self.limits.get_route_payload(route_ctx, activity_ctx.index);
+        let energy_delta = battery.get_consumption(new_distance, route_payload + job_payload)
+            - battery.get_consumption(new_distance - distance_delta, route_payload);
+
+        (energy_delta, payload_energy_rate)
+    }
+
+    /// Checks whether extra charging time caused by the insertion pushes activities after it beyond their
+    /// latest arrival. Charging durations are updated only when route state is recalculated, so they are
+    /// estimated here using charge levels of the current tour: a new recharge charges energy needed till
+    /// the end of its interval, any other activity extends charging at the recharge which starts its interval.
+    ///
+    /// NOTE a new recharge reduces charging at the previous one, this is ignored to keep estimation simple.
+    fn is_charging_time_violation(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        battery: &BatteryModel,
+        interval: (usize, usize),
+        is_new_recharge: bool,
+    ) -> bool {
+        let Some(activity) = self.activity.as_ref() else { return false };
+        let (start_idx, end_idx) = interval;
+        let Some(&(start_arrival, start_departure)) = route_ctx.state().get_charge_level_at(start_idx) else {
+            return false;
+        };
+
+        let is_first_interval = start_idx == 0;
+        let interval_energy = get_counter(route_ctx, end_idx);
+        let get_charge_level = |needed: Float| (needed + battery.reserve).min(battery.capacity);
+
+        let (prev_delay, target_duration) = if is_new_recharge {
+            let ((prev_to_tar_distance, tar_to_next_distance), _) =
+                calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+            let payload = self.limits.get_route_payload(route_ctx, activity_ctx.index);
+            let energy_before =
+                get_counter(route_ctx, activity_ctx.index) + battery.get_consumption(prev_to_tar_distance, payload);
+            let energy_after = battery.get_consumption(tar_to_next_distance, payload)
+                + activity_ctx
+                    .next
+                    .map_or(Float::default(), |_| interval_energy - get_counter(route_ctx, activity_ctx.index + 1));
+
+            let start_departure =
+                if is_first_interval { start_departure } else { start_arrival.max(get_charge_level(energy_before)) };
+            let arrival = start_departure - energy_before;
+            let departure = arrival.max(get_charge_level(energy_after));
+
+            (Duration::default(), battery.get_charging_duration(arrival, departure))
         } else {
+            if is_first_interval {
+                return false;
+            }
+
             let (distance_delta, _) = calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
+            let (energy_delta, payload_energy_rate) =
+                self.get_energy_delta(route_ctx, activity_ctx, battery, distance_delta);
+            let needed = interval_energy + energy_delta + payload_energy_rate * get_distance(route_ctx, end_idx);
+            let departure = start_arrival.max(get_charge_level(needed));
+
+            let delay = battery.get_charging_duration(start_arrival, departure)
+                - battery.get_charging_duration(start_arrival, start_departure);
 
-            (interval_distance + distance_delta) > threshold
+            (delay.max(Duration::default()), Duration::default())
         };
 
-        if is_violation { ConstraintViolation::skip(self.code) } else { None }
+        if prev_delay + target_duration <= Duration::default() {
+            return false;
+        }
+
+        let route = route_ctx.route();
+        let get_latest_arrival = |activity_idx: usize, activity: &Activity| {
+            route_ctx.state().get_latest_arrival_at(activity_idx).copied().unwrap_or(activity.place.time.end)
+        };
+
+        // NOTE activities between the recharge and the insertion point are delayed as well
+        let is_delayed_before = start_idx < activity_ctx.index
+            && route.tour.get(start_idx + 1).is_some_and(|activity| {
+                activity.schedule.arrival + prev_delay > get_latest_arrival(start_idx + 1, activity)
+            });
+        if is_delayed_before {
+            return true;
+        }
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let departure = prev.schedule.departure + prev_delay;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        if arrival > target.place.time.end {
+            return true;
+        }
+
+        let departure = activity.estimate_departure(route, target, arrival) + target_duration;
+
+        activity_ctx.next.is_some_and(|next| {
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            arrival > get_latest_arrival(activity_ctx.index + 1, next)
+        })
+    }
+
+    /// Updates durations of recharge activities according to energy charged there.
+    fn update_recharge_durations(
+        &self,
+        route_ctx: &mut RouteContext,
+        battery: &BatteryModel,
+        leg_energies: Vec<Float>,
+    ) {
+        let Some(activity) = self.activity.as_ref() else { return };
+
+        let legs = route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .zip(leg_energies)
+            .map(|(activity, energy)| {
+                (energy, activity.job.as_ref().is_some_and(|single| (self.recharge_single_fn)(single)))
+            })
+            .collect::<Vec<_>>();
+        let levels = battery.get_charge_levels(legs.as_slice());
+        route_ctx.state_mut().set_charge_level_states(levels.clone());
+
+        let mut is_changed = false;
+        route_ctx.route_mut().tour.all_activities_mut().zip(legs.iter().zip(levels)).for_each(
+            |(activity, (&(_, is_recharge), (arrival, departure)))| {
+                let Some(single) = activity.job.as_ref().filter(|_| is_recharge) else { return };
+
+                let base_duration =
+                    single.places.get(activity.place.idx).map_or(Duration::default(), |place| place.duration);
+                let duration = base_duration + battery.get_charging_duration(arrival, departure);
+
+                if activity.place.duration != duration {
+                    activity.place.duration = duration;
+                    is_changed = true;
+                }
+            },
+        );
+
+        if is_changed {
+            update_route_schedule(route_ctx, activity.as_ref(), self.transport.as_ref());
+        }
     }
 }
 
-impl RechargeableMultiTrip {
-    fn get_distance(&self, route_ctx: &RouteContext, activity_idx: usize) -> Distance {
-        route_ctx.state().get_recharge_distance_at(activity_idx).copied().unwrap_or(Distance::default())
+/// Returns interval counter: energy consumption if battery model is used or distance otherwise.
+fn get_counter(route_ctx: &RouteContext, activity_idx: usize) -> Float {
+    let state = route_ctx.state();
+
+    if route_ctx.route().actor.vehicle.dimens.get_vehicle_battery().is_some() {
+        state.get_recharge_energy_at(activity_idx).copied().unwrap_or_default()
+    } else {
+        state.get_recharge_distance_at(activity_idx).copied().unwrap_or_default()
+    }
+}
+
+fn get_distance(route_ctx: &RouteContext, activity_idx: usize) -> Distance {
+    route_ctx.state().get_recharge_distance_at(activity_idx).copied().unwrap_or(Distance::default())
+}
+
+fn get_leg_distance(transport: &dyn TransportCost, route: &Route, from: &Activity, to: &Activity) -> Distance {
+    transport.distance(route, from.place.location, to.place.location, TravelTime::Departure(from.schedule.departure))
+}
+
+// charger resource implementation
+
+type ChargerCapacityFn = Arc<dyn Fn(&Activity) -> Option<(usize, SharedResourceId)> + Send + Sync>;
+
+custom_solution_state!(ChargingSessions typeof ResourceSessions);
+custom_activity_state!(ChargingSlack typeof Duration);
+
+struct ChargerConstraint {
+    code: ViolationCode,
+    capacity_fn: ChargerCapacityFn,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureConstraint for ChargerConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl ChargerConstraint {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let target = activity_ctx.target;
+        // NOTE travel info includes waiting and service time at the next activity
+        let (_, (prev_to_tar_duration, tar_to_next_duration)) =
+            calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+        let departure = activity_ctx.prev.schedule.departure + prev_to_tar_duration;
+        let start = departure - target.place.duration;
+
+        // NOTE charging duration can be changed later by battery model, so session is an estimation
+        let is_overbooked = (self.capacity_fn)(target).is_some_and(|(capacity, resource_id)| {
+            let session = TimeWindow::new(start, departure);
+            let actor = &route_ctx.route().actor;
+
+            solution_ctx.state.get_charging_sessions().is_some_and(|sessions| {
+                get_max_overlaps(get_other_sessions(sessions, resource_id, actor), &session) >= capacity
+            })
+        });
+
+        // NOTE assume that delay is propagated to all charging sessions later in the tour
+        let is_delayed = activity_ctx.next.is_some_and(|next| {
+            let delay = departure + tar_to_next_duration - next.schedule.departure;
+            route_ctx.state().get_charging_slack_at(activity_ctx.index + 1).is_some_and(|&slack| delay > slack)
+        });
+
+        if is_overbooked || is_delayed { ConstraintViolation::skip(self.code) } else { None }
+    }
+}
+
+struct ChargerState {
+    capacity_fn: ChargerCapacityFn,
+    counter: MarkerIntervalCounter,
+    recharge_single_fn: RechargeSingleFn,
+}
+
+impl FeatureState for ChargerState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.update_charging_sessions(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        // NOTE this state is overridden by update_charging_sessions
+        let sessions = self.get_sessions(route_ctx.route());
+        let slacks = get_route_resource_slacks(route_ctx, sessions.as_slice());
+        route_ctx.state_mut().set_charging_slack_states(slacks);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_overbooked_sessions(solution_ctx);
+        self.update_charging_sessions(solution_ctx);
     }
 }
 
-fn get_end_idx(route_ctx: &RouteContext, end_idx: usize) -> usize {
-    let last_idx = route_ctx.route().tour.total() - 1;
-    end_idx + if end_idx == last_idx { 0 } else { 1 }
+impl ChargerState {
+    /// Removes recharges with charging sessions which exceed chargers capacity. That can happen when some jobs
+    /// are removed from the tour, so charging sessions are moved in time. Only the first overbooked recharge
+    /// of each route is handled at once: the solution state is accepted again when jobs are removed.
+    fn remove_overbooked_sessions(&self, solution_ctx: &mut SolutionContext) {
+        let sessions = solution_ctx
+            .routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_idx, route_ctx)| {
+                self.get_sessions(route_ctx.route()).into_iter().map(move |session| (session, route_idx))
+            })
+            .collect::<Vec<_>>();
+
+        let overbooked = get_overbooked_sessions(sessions, |_| true).into_iter().fold(
+            HashMap::<usize, usize>::default(),
+            |mut acc, (session, route_idx)| {
+                let activity_idx = session.activity_idx;
+                acc.entry(route_idx).and_modify(|idx| *idx = (*idx).min(activity_idx)).or_insert(activity_idx);
+                acc
+            },
+        );
+
+        overbooked.into_iter().for_each(|(route_idx, activity_idx)| {
+            let route_ctx = solution_ctx.routes.get_mut(route_idx).expect("invalid route index");
+            let jobs = self.get_overbooked_jobs(route_ctx, activity_idx);
+
+            jobs.into_iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(&job);
+
+                if job.as_single().is_some_and(|single| (self.recharge_single_fn)(single)) {
+                    solution_ctx.ignored.push(job);
+                } else {
+                    solution_ctx.required.push(job);
+                }
+            });
+        });
+    }
+
+    /// Returns jobs to be removed in order to release the overbooked recharge at given activity index. The recharge
+    /// is removed alone when the tour can be served without it, then together with the trip which relies on it.
+    /// If the merged recharge interval still exceeds the limit, all jobs of the route are returned.
+    fn get_overbooked_jobs(&self, route_ctx: &RouteContext, recharge_idx: usize) -> Vec<Job> {
+        let tour = &route_ctx.route().tour;
+        let last_idx = tour.total() - 1;
+        let is_recharge = |activity_idx: usize| {
+            tour.get(activity_idx)
+                .and_then(|activity| activity.job.as_ref())
+                .is_some_and(|single| (self.recharge_single_fn)(single))
+        };
+
+        let start_idx = (0..recharge_idx).rev().find(|&idx| is_recharge(idx)).unwrap_or(0);
+        let next_recharge_idx = (recharge_idx + 1..=last_idx).find(|&idx| is_recharge(idx));
+        let end_idx = next_recharge_idx.unwrap_or(last_idx);
+        let trip_end_idx = next_recharge_idx.unwrap_or(last_idx + 1);
+
+        [recharge_idx..recharge_idx + 1, recharge_idx..trip_end_idx]
+            .into_iter()
+            .find(|removed| !self.is_merged_interval_violation(route_ctx, (start_idx, end_idx), removed))
+            .map(|removed| {
+                removed
+                    .filter_map(|activity_idx| tour.get(activity_idx).and_then(|activity| activity.retrieve_job()))
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect()
+            })
+            .unwrap_or_else(|| tour.jobs().cloned().collect())
+    }
+
+    /// Checks whether the interval exceeds the recharge limit when activities in the given range are removed.
+    fn is_merged_interval_violation(
+        &self,
+        route_ctx: &RouteContext,
+        interval: (usize, usize),
+        removed: &Range<usize>,
+    ) -> bool {
+        let (start_idx, end_idx) = interval;
+        let activities = (start_idx..=end_idx)
+            .filter_map(|activity_idx| {
+                route_ctx.route().tour.get(activity_idx).map(|activity| (activity_idx, activity))
+            })
+            // NOTE the route end is kept even when it is in the removed range
+            .filter(|(activity_idx, activity)| activity.job.is_none() || !removed.contains(activity_idx))
+            .collect::<Vec<_>>();
+
+        let counter = activities
+            .windows(2)
+            .map(|pair| (self.counter.leg_counter_fn)(route_ctx, pair[0].0, pair[0].1, pair[1].1))
+            .sum::<Float>();
+
+        (self.counter.limit_fn)(route_ctx, start_idx == 0).is_some_and(|limit| counter > limit)
+    }
+
+    fn update_charging_sessions(&self, solution_ctx: &mut SolutionContext) {
+        let route_sessions =
+            solution_ctx.routes.iter().map(|route_ctx| self.get_sessions(route_ctx.route())).collect::<Vec<_>>();
+        let sessions = group_resource_sessions(
+            solution_ctx.routes.iter().map(|route_ctx| route_ctx.route()).zip(route_sessions.iter().map(Vec::as_slice)),
+        );
+
+        solution_ctx.routes.iter_mut().zip(route_sessions).for_each(|(route_ctx, route_sessions)| {
+            let actor = &route_ctx.route().actor;
+            let slacks = get_resource_slacks(route_ctx.route(), route_sessions.as_slice(), |session| {
+                let others = get_other_sessions(&sessions, session.resource_id, actor);
+                get_slack(others, session.capacity, &session.time)
+            });
+
+            route_ctx.state_mut().set_charging_slack_states(slacks);
+        });
+
+        solution_ctx.state.set_charging_sessions(sessions);
+    }
+
+    fn get_sessions(&self, route: &Route) -> Vec<ResourceSession> {
+        route
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(|(activity_idx, activity)| {
+                (self.capacity_fn)(activity).map(|(capacity, resource_id)| {
+                    let start = activity.schedule.arrival.max(activity.place.time.start);
+                    let time = TimeWindow::new(start, activity.schedule.departure);
+                    ResourceSession { resource_id, capacity, activity_idx, time }
+                })
+            })
+            .collect()
+    }
 }
@@ -21,7 +21,7 @@ use std::ops::{Add, Range, RangeInclusive, Sub};
 use std::sync::Arc;
 
 /// Represents a shared unique resource which is used to model reload with capacity constraint.
-pub trait SharedResource: LoadOps + Add + Sub + PartialOrd + Copy + Sized + Send + Sync + Default + 'static {}
+pub trait SharedResource: LoadOps + Add + Sub + PartialOrd + Clone + Sized + Send + Sync + Default + 'static {}
 
 /// Represents a shared resource id.
 pub type SharedResourceId = usize;
@@ -211,19 +211,15 @@ impl<T: LoadOps> ReloadFeatureFactory<T> {
                 let fold_demand = |range: Range<usize>, demand_fn: fn(&Demand<T>) -> T| {
                     route_ctx.route().tour.activities_slice(range.start, range.end).iter().fold(
                         T::default(),
-                        |acc, activity| {
-                            activity
-                                .job
-                                .as_ref()
-                                .and_then(|job| job.dimens.get_job_demand())
-                                .map(|demand| acc + demand_fn(demand))
-                                .unwrap_or_else(|| acc)
+                        |acc, activity| match activity.job.as_ref().and_then(|job| job.dimens.get_job_demand()) {
+                            Some(demand) => acc + demand_fn(demand),
+                            None => acc,
                         },
                     )
                 };
 
-                let left_pickup = fold_demand(left.clone(), |demand| demand.pickup.0);
-                let right_delivery = fold_demand(right.clone(), |demand| demand.delivery.0);
+                let left_pickup = fold_demand(left.clone(), |demand| demand.pickup.0.clone());
+                let right_delivery = fold_demand(right.clone(), |demand| demand.delivery.0.clone());
 
                 // static delivery moved to left
                 let new_max_load_left =
@@ -241,7 +237,7 @@ impl<T: LoadOps> ReloadFeatureFactory<T> {
                 has_enough_vehicle_capacity
                     && shared_resource_threshold_fn.as_ref().is_none_or(|shared_resource_threshold_fn| {
                         // total static delivery at left
-                        let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0);
+                        let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0.clone());
 
                         (shared_resource_threshold_fn)(route_ctx, left.start, &left_delivery)
                     })
@@ -315,7 +311,7 @@ impl<T: SharedResource> SharedResourceConstraint<T> {
                 route_ctx
                     .state()
                     .get_activity_state::<SharedResourceStateKey, Option<T>>(start_idx)
-                    .and_then(|resource_available| *resource_available)
+                    .and_then(|resource_available| resource_available.clone())
                     .and_then(|resource_available| {
                         let resource_demand = activity_ctx
                             .target
@@ -376,7 +372,7 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
 
                     if let Some((resource_demand, id)) = resource_demand_with_id {
                         let entry = acc.entry(id).or_default();
-                        *entry = *entry + resource_demand;
+                        *entry = entry.clone() + resource_demand;
                     }
 
                     acc
@@ -393,7 +389,7 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
                 let activity_idx = get_activity_by_idx(route_ctx.route(), start_idx);
                 let resource_available =
                     (self.resource_capacity_fn)(activity_idx).and_then(|(total_capacity, resource_id)| {
-                        total_demand.get(&resource_id).map(|total_demand| total_capacity - *total_demand)
+                        total_demand.get(&resource_id).map(|total_demand| total_capacity - total_demand.clone())
                     });
 
                 if let Some(resource_available) = resource_available {
@@ -0,0 +1,84 @@
+//! A feature to forbid serving jobs while the vehicle is off duty between its consecutive shifts.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/shift_gaps_test.rs"]
+mod shift_gaps_test;
+
+use super::*;
+use crate::models::common::TimeWindow;
+use std::iter::once;
+
+custom_dimension!(pub VehicleShiftGaps typeof Vec<TimeWindow>);
+
+/// Creates a feature which forbids serving job activities within vehicle's shift gaps, e.g. when
+/// the vehicle stays overnight between shifts of a multi-day tour. Activities of jobs accepted by
+/// `is_gap_single` are allowed there. Activities after the inserted one are rescheduled, so they
+/// cannot be pushed into a gap either.
+pub fn create_shift_gaps_feature<F>(
+    name: &str,
+    code: ViolationCode,
+    is_gap_single: F,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature>
+where
+    F: Fn(&Single) -> bool + Send + Sync + 'static,
+{
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ShiftGapsConstraint { code, is_gap_single, transport, activity })
+        .build()
+}
+
+struct ShiftGapsConstraint<F: Fn(&Single) -> bool + Send + Sync> {
+    code: ViolationCode,
+    is_gap_single: F,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl<F: Fn(&Single) -> bool + Send + Sync> FeatureConstraint for ShiftGapsConstraint<F> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let route = route_ctx.route();
+                let gaps = route.actor.vehicle.dimens.get_vehicle_shift_gaps()?;
+
+                let (mut prev, mut prev_departure) = (activity_ctx.prev, activity_ctx.prev.schedule.departure);
+                let next_activities = route.tour.all_activities().skip(activity_ctx.index + 1);
+
+                for (idx, activity) in once(activity_ctx.target).chain(next_activities).enumerate() {
+                    let arrival = prev_departure
+                        + self.transport.duration(
+                            route,
+                            prev.place.location,
+                            activity.place.location,
+                            TravelTime::Departure(prev_departure),
+                        );
+                    let departure = self.activity.estimate_departure(route, activity, arrival);
+                    let start = arrival.max(activity.place.time.start);
+
+                    let is_off_duty = activity.job.as_ref().is_some_and(|single| !(self.is_gap_single)(single))
+                        && gaps.iter().any(|gap| start < gap.end && departure > gap.start);
+                    if is_off_duty {
+                        return ConstraintViolation::skip(self.code);
+                    }
+
+                    // NOTE the rest of the tour is not affected when the activity is not postponed
+                    if idx > 0 && departure <= activity.schedule.departure {
+                        break;
+                    }
+
+                    (prev, prev_departure) = (activity, departure);
+                }
+
+                None
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
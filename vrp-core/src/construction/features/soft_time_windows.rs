@@ -0,0 +1,157 @@
+//! A feature to penalize serving jobs outside of their preferred time windows.
+//!
+//! Place time windows are expected to be already widened by the allowed earliness and lateness,
+//! so the transport feature treats them as hard. This feature recovers the original (preferred)
+//! window from the widened one and penalizes a deviation of the service start from it.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/soft_time_windows_test.rs"]
+mod soft_time_windows_test;
+
+use super::*;
+use crate::models::solution::Activity;
+
+/// Specifies soft time window tolerance of a job place.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SoftTimeWindow {
+    /// Max allowed earliness. Place time windows should already include it.
+    pub max_earliness: Duration,
+    /// Max allowed lateness. Place time windows should already include it.
+    pub max_lateness: Duration,
+    /// Cost per time unit of earliness.
+    pub earliness_cost: Cost,
+    /// Cost per time unit of lateness.
+    pub lateness_cost: Cost,
+}
+
+impl SoftTimeWindow {
+    /// Returns earliness and lateness of the service started at given time within widened time window.
+    pub fn get_deviation(&self, time: &TimeWindow, service_start: Timestamp) -> (Duration, Duration) {
+        let earliness = (time.start + self.max_earliness - service_start).max(0.);
+        let lateness = (service_start - (time.end - self.max_lateness)).max(0.);
+
+        (earliness, lateness)
+    }
+
+    /// Returns penalty of the service started at given time within widened time window.
+    pub fn get_penalty(&self, time: &TimeWindow, service_start: Timestamp) -> Cost {
+        let (earliness, lateness) = self.get_deviation(time, service_start);
+
+        earliness * self.earliness_cost + lateness * self.lateness_cost
+    }
+}
+
+custom_dimension!(pub JobSoftTimeWindows typeof Vec<Option<SoftTimeWindow>>);
+
+/// Returns soft time window of the place used by the activity, if it is specified.
+pub fn get_activity_soft_time_window(activity: &Activity) -> Option<&SoftTimeWindow> {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_job_soft_time_windows())
+        .and_then(|windows| windows.get(activity.place.idx))
+        .and_then(|window| window.as_ref())
+}
+
+/// Returns a time when the service of the activity starts if the vehicle arrives at given time: after
+/// commuting to the place, the service starts immediately when arrival is within widened time window.
+/// The same service start is used by the route schedule, penalty estimation and the solution report.
+pub fn get_activity_service_start(activity: &Activity, arrival: Timestamp) -> Timestamp {
+    let commuting = activity.commute.as_ref().map_or(Duration::default(), |commute| commute.forward.duration);
+
+    (arrival + commuting).max(activity.place.time.start)
+}
+
+/// Returns earliness and lateness of the activity served at given time. Both are zero if activity has
+/// no soft time window.
+pub fn get_activity_time_window_deviation(activity: &Activity, service_start: Timestamp) -> (Duration, Duration) {
+    get_activity_soft_time_window(activity)
+        .map(|soft| soft.get_deviation(&activity.place.time, service_start))
+        .unwrap_or_default()
+}
+
+/// Creates a feature which minimizes penalty of serving jobs outside of their preferred time windows.
+pub fn create_soft_time_windows_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(SoftTimeWindowsObjective { transport, activity }).build()
+}
+
+struct SoftTimeWindowsObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureObjective for SoftTimeWindowsObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .map(|activity| get_activity_penalty(activity, activity.schedule.arrival))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        let MoveContext::Activity { route_ctx, activity_ctx, .. } = move_ctx else {
+            return Cost::default();
+        };
+
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let target_penalty = get_activity_penalty(target, arrival);
+
+        let Some(next) = activity_ctx.next else {
+            return target_penalty;
+        };
+
+        let departure = self.activity.estimate_departure(route, target, arrival);
+        let next_arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE propagate schedule shift through the rest of the tour till it is absorbed by waiting time
+        let (penalty_delta, _) = route
+            .tour
+            .all_activities()
+            .skip(activity_ctx.index + 1)
+            .try_fold((Cost::default(), next_arrival - next.schedule.arrival), |(delta, shift), activity| {
+                if shift == 0. {
+                    return Err((delta, shift));
+                }
+
+                let old_start = get_activity_service_start(activity, activity.schedule.arrival);
+                let new_start = get_activity_service_start(activity, activity.schedule.arrival + shift);
+
+                let delta = delta + get_activity_penalty(activity, activity.schedule.arrival + shift)
+                    - get_activity_penalty(activity, activity.schedule.arrival);
+
+                Ok((delta, new_start - old_start))
+            })
+            .unwrap_or_else(|result| result);
+
+        target_penalty + penalty_delta
+    }
+}
+
+fn get_activity_penalty(activity: &Activity, arrival: Timestamp) -> Cost {
+    get_activity_soft_time_window(activity)
+        .map(|soft| soft.get_penalty(&activity.place.time, get_activity_service_start(activity, arrival)))
+        .unwrap_or_default()
+}
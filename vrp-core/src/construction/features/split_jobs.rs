@@ -0,0 +1,195 @@
+//! A feature to model jobs which demand can be served by multiple visits, potentially by different vehicles.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/split_jobs_test.rs"]
+mod split_jobs_test;
+
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::models::solution::Activity;
+use std::collections::{HashMap, HashSet};
+
+custom_dimension!(pub JobSplitKey typeof String);
+custom_dimension!(pub JobSplitMaxVisits typeof usize);
+custom_solution_state!(SplitJobVisits typeof HashMap<String, usize>);
+
+/// Creates a feature which keeps parts of split jobs consistent. Each part is a separate job with
+/// the same split key and its own share of the original demand, so the parts can be served by
+/// different tours. Consecutive parts of the same job in the tour are considered as a single visit
+/// which takes the service duration once, so the solver decides how much of the demand is served by
+/// each visit. An amount of visits is limited by `JobSplitMaxVisits` dimension, if it is set.
+///
+/// When some part cannot be assigned, all other parts of the same job are unassigned too, so the
+/// demand is either served completely or not served at all.
+pub fn create_split_jobs_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(SplitJobsConstraint { code })
+        .with_state(SplitJobsState { code, transport, activity })
+        .build()
+}
+
+struct SplitJobsConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for SplitJobsConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, activity_ctx, .. } => {
+                let prev_key = get_activity_split_key(activity_ctx.prev);
+                let target_key = get_activity_split_key(activity_ctx.target);
+                let next_key = activity_ctx.next.and_then(get_activity_split_key);
+
+                // NOTE insertion either starts a new visit of the target job or breaks a visit of another job
+                let new_visit = target_key.filter(|key| prev_key != Some(key) && next_key != Some(key));
+                let broken_visit = prev_key.filter(|key| next_key == Some(key) && target_key != Some(key));
+
+                let is_violation = new_visit
+                    .map(|key| (key, activity_ctx.target))
+                    .into_iter()
+                    .chain(broken_visit.map(|key| (key, activity_ctx.prev)))
+                    .any(|(key, activity)| {
+                        let visits = solution_ctx
+                            .state
+                            .get_split_job_visits()
+                            .and_then(|visits| visits.get(key))
+                            .copied()
+                            .unwrap_or_default();
+
+                        activity
+                            .job
+                            .as_ref()
+                            .and_then(|single| single.dimens.get_job_split_max_visits())
+                            .is_some_and(|max_visits| visits >= *max_visits)
+                    });
+
+                if is_violation { ConstraintViolation::skip(self.code) } else { None }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct SplitJobsState {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureState for SplitJobsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        // NOTE any insertion can break a visit of split job, so durations and visits are always updated
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.update_visits(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let mut is_changed = false;
+        let mut prev_key = None;
+
+        route_ctx.route_mut().tour.all_activities_mut().for_each(|activity| {
+            let key = get_activity_split_key(activity).cloned();
+
+            if let Some(single) = activity.job.as_ref().filter(|_| key.is_some()) {
+                let duration = if key == prev_key {
+                    Duration::default()
+                } else {
+                    single.places.get(activity.place.idx).map_or(Duration::default(), |place| place.duration)
+                };
+
+                if activity.place.duration != duration {
+                    activity.place.duration = duration;
+                    is_changed = true;
+                }
+            }
+
+            prev_key = key;
+        });
+
+        if is_changed {
+            update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.unassign_incomplete_jobs(solution_ctx);
+        // NOTE removed parts can change the first part of the visit which takes the service duration
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+        self.update_visits(solution_ctx);
+    }
+}
+
+impl SplitJobsState {
+    fn unassign_incomplete_jobs(&self, solution_ctx: &mut SolutionContext) {
+        // NOTE required parts can still be assigned, so only jobs with unassigned parts are affected
+        let pending = solution_ctx.required.iter().filter_map(get_split_key).collect::<HashSet<_>>();
+        let failed = solution_ctx
+            .unassigned
+            .keys()
+            .filter_map(get_split_key)
+            .filter(|key| !pending.contains(key))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        if failed.is_empty() {
+            return;
+        }
+
+        let mut removed = Vec::default();
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let jobs = route_ctx
+                .route()
+                .tour
+                .jobs()
+                .filter(|job| get_split_key(job).is_some_and(|key| failed.contains(key)))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            jobs.into_iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(&job);
+                removed.push(job);
+            });
+        });
+
+        solution_ctx.unassigned.extend(removed.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+
+    /// Counts visits of each split job: a visit is a sequence of consecutive parts of the job in the tour.
+    fn update_visits(&self, solution_ctx: &mut SolutionContext) {
+        let visits = solution_ctx.routes.iter().fold(HashMap::<String, usize>::default(), |mut acc, route_ctx| {
+            let mut prev_key = None;
+            route_ctx.route().tour.all_activities().for_each(|activity| {
+                let key = get_activity_split_key(activity);
+                if let Some(key) = key.filter(|key| prev_key != Some(*key)) {
+                    *acc.entry(key.clone()).or_default() += 1;
+                }
+
+                prev_key = key;
+            });
+
+            acc
+        });
+
+        solution_ctx.state.set_split_job_visits(visits);
+    }
+}
+
+fn get_split_key(job: &Job) -> Option<&String> {
+    job.dimens().get_job_split_key()
+}
+
+fn get_activity_split_key(activity: &Activity) -> Option<&String> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_split_key())
+}
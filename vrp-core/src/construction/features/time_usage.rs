@@ -0,0 +1,139 @@
+//! Provides the way to control how time of the fleet is used.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/time_usage_test.rs"]
+mod time_usage_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+
+/// Creates a feature to minimize makespan: the latest end time among all tours.
+pub fn create_minimize_makespan_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(MakespanObjective { transport, activity }).build()
+}
+
+/// Creates a feature to minimize total waiting time, e.g. time spent by vehicles waiting for
+/// the start of time windows.
+pub fn create_minimize_waiting_time_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(WaitingTimeObjective { transport, activity }).build()
+}
+
+struct MakespanObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureObjective for MakespanObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        get_makespan(&solution.solution)
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                let route = route_ctx.route();
+                let route_end = get_route_end(route);
+                let (_, target_departure) =
+                    get_target_schedule(route, activity_ctx, self.transport.as_ref(), self.activity.as_ref());
+
+                // NOTE the shift of the next activity is propagated to the route end as is: a possible
+                // reduction of waiting time later in the tour is ignored
+                let new_route_end = match activity_ctx.next {
+                    Some(next) => {
+                        let next_arrival =
+                            get_arrival(route, activity_ctx.target, next, target_departure, self.transport.as_ref());
+                        route_end + (next_arrival - next.schedule.arrival).max(0.)
+                    }
+                    None => route_end.max(target_departure),
+                };
+
+                (new_route_end - get_makespan(solution_ctx)).max(0.)
+            }
+        }
+    }
+}
+
+struct WaitingTimeObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl FeatureObjective for WaitingTimeObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities().skip(1))
+            .map(|activity| get_waiting_time(activity, activity.schedule.arrival))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let route = route_ctx.route();
+                let (target_arrival, target_departure) =
+                    get_target_schedule(route, activity_ctx, self.transport.as_ref(), self.activity.as_ref());
+
+                // NOTE only waiting at the target and the next activity is considered
+                let next_delta = activity_ctx.next.map_or(Cost::default(), |next| {
+                    let next_arrival =
+                        get_arrival(route, activity_ctx.target, next, target_departure, self.transport.as_ref());
+                    get_waiting_time(next, next_arrival) - get_waiting_time(next, next.schedule.arrival)
+                });
+
+                get_waiting_time(activity_ctx.target, target_arrival) + next_delta
+            }
+        }
+    }
+}
+
+/// Returns the latest end time among all routes.
+fn get_makespan(solution_ctx: &SolutionContext) -> Float {
+    solution_ctx.routes.iter().map(|route_ctx| get_route_end(route_ctx.route())).fold(Float::default(), Float::max)
+}
+
+/// Returns the end time of the route: departure from its last activity.
+fn get_route_end(route: &Route) -> Float {
+    route.tour.end().map_or(Float::default(), |end| end.schedule.departure)
+}
+
+/// Returns waiting time at the activity if vehicle arrives at given time.
+fn get_waiting_time(activity: &Activity, arrival: Timestamp) -> Duration {
+    (activity.place.time.start - arrival).max(0.)
+}
+
+fn get_arrival(
+    route: &Route,
+    from: &Activity,
+    to: &Activity,
+    departure: Timestamp,
+    transport: &dyn TransportCost,
+) -> Timestamp {
+    departure + transport.duration(route, from.place.location, to.place.location, TravelTime::Departure(departure))
+}
+
+/// Returns arrival and departure times of the target activity.
+fn get_target_schedule(
+    route: &Route,
+    activity_ctx: &ActivityContext,
+    transport: &dyn TransportCost,
+    activity: &dyn ActivityCost,
+) -> (Timestamp, Timestamp) {
+    let arrival =
+        get_arrival(route, activity_ctx.prev, activity_ctx.target, activity_ctx.prev.schedule.departure, transport);
+    let departure = activity.estimate_departure(route, activity_ctx.target, arrival);
+
+    (arrival, departure)
+}
@@ -0,0 +1,317 @@
+//! A feature to model a vehicle which pulls a detachable trailer. The trailer can be parked at specific
+//! locations, so the vehicle alone can serve jobs which are not accessible with the trailer.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/trailers_test.rs"]
+mod trailers_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::solution::{Activity, Route};
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+/// Specifies an operation with the trailer done by the activity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TrailerOperation {
+    /// The trailer is detached from the vehicle and parked.
+    Decouple,
+    /// The parked trailer is attached back to the vehicle.
+    Couple,
+}
+
+custom_dimension!(pub JobTrailerOperation typeof TrailerOperation);
+custom_dimension!(pub JobTrailerAllowed typeof bool);
+custom_dimension!(pub VehicleTrailerCapacity typeof T: LoadOps);
+
+/// Creates a feature which keeps trailer operations consistent. The vehicle capacity is expected to include
+/// the trailer's capacity which is specified separately via `VehicleTrailerCapacityDimension`.
+/// A trailer job is a multi job with decouple and couple activities at the same place. It is required only
+/// when some job, which is not allowed to be served with the trailer, cannot be assigned. Such job can be
+/// served only while the trailer is parked and the load carried at that time cannot exceed the vehicle's
+/// own capacity.
+pub fn create_trailer_feature<T, F>(name: &str, code: ViolationCode, belongs_to_route_fn: F) -> GenericResult<Feature>
+where
+    T: LoadOps,
+    F: Fn(&Route, &Job) -> bool + Send + Sync + 'static,
+{
+    let belongs_to_route_fn: BelongsToRouteFn = Arc::new(belongs_to_route_fn);
+    let context_transition = Box::new(ConcreteJobContextTransition {
+        remove_required: |_, _, job| is_trailer_job(job),
+        promote_required: |_, _, _| false,
+        remove_locked: |_, _, _| false,
+        promote_locked: |_, _, _| false,
+    });
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TrailerConstraint::<T> {
+            code,
+            belongs_to_route_fn: belongs_to_route_fn.clone(),
+            phantom: Default::default(),
+        })
+        .with_state(TrailerState { context_transition, belongs_to_route_fn })
+        .build()
+}
+
+type BelongsToRouteFn = Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>;
+
+struct TrailerConstraint<T: LoadOps> {
+    code: ViolationCode,
+    belongs_to_route_fn: BelongsToRouteFn,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> TrailerConstraint<T> {
+    fn evaluate_route(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
+        if !is_trailer_job(job) {
+            return None;
+        }
+
+        let route = route_ctx.route();
+        let has_trailer = route.actor.vehicle.dimens.get_vehicle_trailer_capacity::<T>().is_some();
+
+        if has_trailer && (self.belongs_to_route_fn)(route, job) { None } else { ConstraintViolation::fail(self.code) }
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let trailer_capacity = route.actor.vehicle.dimens.get_vehicle_trailer_capacity::<T>()?;
+        let capacity = route.actor.vehicle.dimens.get_vehicle_capacity::<T>()?;
+        let target = activity_ctx.target;
+
+        let has_operations = route.tour.all_activities().any(|activity| get_operation(activity).is_some());
+        if get_operation(target).is_none() && !has_operations {
+            // NOTE the trailer is attached all the time, so such job cannot be inserted at any position
+            return if is_forbidden_activity(target) { ConstraintViolation::fail(self.code) } else { None };
+        }
+
+        let activities = route
+            .tour
+            .all_activities()
+            .take(activity_ctx.index + 1)
+            .chain(std::iter::once(target))
+            .chain(route.tour.all_activities().skip(activity_ctx.index + 1))
+            .collect::<Vec<_>>();
+
+        if is_valid_trailer_usage(capacity, trailer_capacity, activities.as_slice()) {
+            None
+        } else {
+            ConstraintViolation::skip(self.code)
+        }
+    }
+}
+
+impl<T: LoadOps> FeatureConstraint for TrailerConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if is_trailer_job(&source) || is_trailer_job(&candidate) { Err(self.code) } else { Ok(source) }
+    }
+}
+
+struct TrailerState {
+    context_transition: Box<dyn JobContextTransition>,
+    belongs_to_route_fn: BelongsToRouteFn,
+}
+
+impl FeatureState for TrailerState {
+    fn notify_failure(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], jobs: &[Job]) -> bool {
+        if !jobs.iter().any(is_forbidden_job) {
+            return false;
+        }
+
+        // NOTE prefer trailer jobs of given routes, but fallback to all of them as jobs can be assigned to new routes
+        let candidates = solution_ctx.ignored.iter().filter(|job| is_trailer_job(job)).collect::<Vec<_>>();
+        let mut promoted = candidates
+            .iter()
+            .filter(|job| {
+                route_indices.iter().any(|&idx| (self.belongs_to_route_fn)(solution_ctx.routes[idx].route(), job))
+            })
+            .map(|&job| job.clone())
+            .collect::<HashSet<_>>();
+
+        if promoted.is_empty() {
+            promoted = candidates.into_iter().cloned().collect();
+        }
+
+        if promoted.is_empty() {
+            return false;
+        }
+
+        solution_ctx.ignored.retain(|job| !promoted.contains(job));
+        solution_ctx.required.extend(promoted);
+
+        true
+    }
+
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        process_conditional_jobs(solution_ctx, None, self.context_transition.as_ref());
+        remove_invalid_jobs(solution_ctx);
+    }
+}
+
+/// Removes trailer jobs without any job served while the trailer is parked and jobs which cannot be
+/// served with the trailer, but it is attached. The latter might happen when the trailer job is removed by ruin.
+fn remove_invalid_jobs(solution_ctx: &mut SolutionContext) {
+    let (trailer_jobs, forbidden_jobs) = solution_ctx.routes.iter().fold(
+        (HashSet::<Job>::default(), HashSet::<Job>::default()),
+        |(mut trailer_jobs, mut forbidden_jobs), route_ctx| {
+            let mut is_decoupled = false;
+            let mut is_empty = true;
+
+            route_ctx.route().tour.all_activities().for_each(|activity| match get_operation(activity) {
+                Some(TrailerOperation::Decouple) => {
+                    is_decoupled = true;
+                    is_empty = true;
+                }
+                Some(TrailerOperation::Couple) => {
+                    if is_empty {
+                        trailer_jobs.extend(activity.retrieve_job());
+                    }
+                    is_decoupled = false;
+                }
+                None if is_decoupled => is_empty &= activity.job.is_none(),
+                None if is_forbidden_activity(activity) => {
+                    forbidden_jobs.extend(activity.retrieve_job());
+                }
+                None => {}
+            });
+
+            (trailer_jobs, forbidden_jobs)
+        },
+    );
+
+    if trailer_jobs.is_empty() && forbidden_jobs.is_empty() {
+        return;
+    }
+
+    solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+        let jobs = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter(|job| trailer_jobs.contains(job) || forbidden_jobs.contains(job))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        jobs.iter().for_each(|job| {
+            route_ctx.route_mut().tour.remove(job);
+        });
+    });
+
+    solution_ctx.ignored.extend(trailer_jobs);
+    solution_ctx.required.extend(forbidden_jobs);
+}
+
+/// Checks whether trailer operations are consistent in given sequence of activities: the trailer is parked
+/// before it is attached back, jobs which are not allowed with the trailer are served only while it is parked,
+/// and the vehicle alone can carry the load at that time. The load is not checked while the trailer is
+/// parked till the end of the sequence as it happens only when the trailer job is partially inserted.
+fn is_valid_trailer_usage<T: LoadOps>(capacity: &T, trailer_capacity: &T, activities: &[&Activity]) -> bool {
+    let loads = get_loads::<T>(activities);
+    let vehicle_capacity = capacity.clone() - trailer_capacity.clone();
+    let mut decouple_idx = None;
+
+    activities.iter().enumerate().all(|(idx, activity)| match get_operation(activity) {
+        Some(TrailerOperation::Decouple) => decouple_idx.replace(idx).is_none(),
+        Some(TrailerOperation::Couple) => decouple_idx.take().is_some_and(|start_idx| {
+            let segment = &activities[start_idx..=idx];
+            let parked = get_parked_load(trailer_capacity, &loads[start_idx], segment);
+
+            loads[start_idx..=idx].iter().all(|load| vehicle_capacity.can_fit(&(load.clone() - parked.clone())))
+        }),
+        None => decouple_idx.is_some() || !is_forbidden_activity(activity),
+    })
+}
+
+/// Returns the load after each activity in the sequence. Static deliveries are loaded at the start.
+fn get_loads<T: LoadOps>(activities: &[&Activity]) -> Vec<T> {
+    let start = activities
+        .iter()
+        .filter_map(|activity| get_demand::<T>(activity))
+        .fold(T::default(), |acc, demand| acc + demand.delivery.0.clone());
+
+    activities
+        .iter()
+        .scan(start, |load, activity| {
+            if let Some(demand) = get_demand::<T>(activity) {
+                *load = load.clone() - demand.delivery.0.clone() - demand.delivery.1.clone()
+                    + demand.pickup.0.clone()
+                    + demand.pickup.1.clone();
+            }
+
+            Some(load.clone())
+        })
+        .collect()
+}
+
+/// Returns the load which is left in the parked trailer: everything which is not delivered while the trailer
+/// is parked, but not more than the trailer's capacity.
+fn get_parked_load<T: LoadOps>(trailer_capacity: &T, load: &T, segment: &[&Activity]) -> T {
+    let picked = segment
+        .iter()
+        .filter(|activity| get_demand::<T>(activity).is_some_and(|demand| demand.pickup.1.is_not_empty()))
+        .filter_map(|activity| get_multi_job(activity))
+        .collect::<HashSet<_>>();
+
+    // NOTE goods of pickup and delivery job picked up while the trailer is parked are not in the trailer
+    let delivered = segment.iter().fold(T::default(), |acc, activity| {
+        let Some(demand) = get_demand::<T>(activity) else { return acc };
+        let is_picked = get_multi_job(activity).is_some_and(|job| picked.contains(&job));
+
+        let acc = acc + demand.delivery.0.clone();
+        if is_picked { acc } else { acc + demand.delivery.1.clone() }
+    });
+
+    let rest = load.clone() - delivered;
+    let extra = (rest.clone() - trailer_capacity.clone()).max_load(T::default());
+
+    rest - extra
+}
+
+fn get_multi_job(activity: &Activity) -> Option<Job> {
+    activity.job.as_ref().and_then(|single| Multi::roots(single)).map(Job::Multi)
+}
+
+fn get_demand<T: LoadOps>(activity: &Activity) -> Option<&Demand<T>> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_demand())
+}
+
+fn get_operation(activity: &Activity) -> Option<TrailerOperation> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_trailer_operation()).copied()
+}
+
+fn is_trailer_job(job: &Job) -> bool {
+    match job {
+        Job::Single(single) => single.dimens.get_job_trailer_operation().is_some(),
+        Job::Multi(multi) => multi.jobs.iter().any(|single| single.dimens.get_job_trailer_operation().is_some()),
+    }
+}
+
+fn is_forbidden(dimens: &Dimensions) -> bool {
+    dimens.get_job_trailer_allowed().is_some_and(|is_allowed| !is_allowed)
+}
+
+fn is_forbidden_job(job: &Job) -> bool {
+    is_forbidden(job.dimens())
+}
+
+fn is_forbidden_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().is_some_and(|single| {
+        is_forbidden(&single.dimens) || Multi::roots(single).is_some_and(|multi| is_forbidden(&multi.dimens))
+    })
+}
@@ -0,0 +1,349 @@
+//! A feature to model jobs which goods are transferred between vehicles at a hub (cross-docking).
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/transfers_test.rs"]
+mod transfers_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::common::TimeWindow;
+use crate::models::problem::Multi;
+use crate::models::solution::{Activity, Route};
+use std::collections::{HashMap, HashSet};
+
+/// Specifies a leg of the transfer job.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransferLeg {
+    /// A leg which picks goods up and drops them at the hub.
+    Inbound,
+    /// A leg which collects goods at the hub and delivers them.
+    Outbound,
+}
+
+custom_dimension!(pub JobTransferKey typeof String);
+custom_dimension!(pub JobTransferHandlingTime typeof Duration);
+custom_dimension!(pub JobTransferLeg typeof TransferLeg);
+custom_solution_state!(TransferTimes typeof HashMap<String, TransferTime>);
+
+/// Creates a feature which couples two legs of transfer jobs. Each leg is a separate multi job with the
+/// same transfer key: the inbound leg drops goods at the hub and the outbound leg collects them there,
+/// potentially by a different vehicle. The hub activity of each leg is marked by `JobTransferLeg` dimension.
+/// The collect activity waits till the drop is finished and the handling time has passed. When one of
+/// the legs cannot be assigned, the other one is unassigned too.
+pub fn create_transfers_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> GenericResult<Feature> {
+    let schedule = Arc::new(TransferSchedule { transport, activity });
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TransfersConstraint { code, schedule: schedule.clone() })
+        .with_state(TransfersState { code, schedule })
+        .build()
+}
+
+/// Keeps timing of hub activities of the transfer job in the solution.
+#[derive(Clone)]
+struct TransferTime {
+    handling_time: Duration,
+    /// An actor and a time when the drop is finished.
+    drop: Option<(Arc<Actor>, Timestamp)>,
+    /// An actor, a time when the collect is started and the latest time it can be postponed to.
+    collect: Option<(Arc<Actor>, Timestamp, Timestamp)>,
+}
+
+impl TransferTime {
+    fn is_valid(&self) -> bool {
+        match (&self.drop, &self.collect) {
+            (Some((_, drop)), Some((_, collect, _))) => *drop + self.handling_time <= *collect,
+            _ => true,
+        }
+    }
+}
+
+struct TransfersConstraint {
+    code: ViolationCode,
+    schedule: Arc<TransferSchedule>,
+}
+
+impl FeatureConstraint for TransfersConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                let route = route_ctx.route();
+                let activities = route
+                    .tour
+                    .all_activities()
+                    .take(activity_ctx.index + 1)
+                    .chain(std::iter::once(activity_ctx.target))
+                    .chain(route.tour.all_activities().skip(activity_ctx.index + 1))
+                    .collect::<Vec<_>>();
+
+                if !activities.iter().any(|activity| get_hub_info(activity).is_some()) {
+                    return None;
+                }
+
+                let times = solution_ctx.state.get_transfer_times();
+
+                if self.schedule.is_valid(route, activities.as_slice(), times) {
+                    None
+                } else {
+                    ConstraintViolation::skip(self.code)
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if get_transfer_key(&source).or_else(|| get_transfer_key(&candidate)).is_some() {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct TransfersState {
+    code: ViolationCode,
+    schedule: Arc<TransferSchedule>,
+}
+
+impl FeatureState for TransfersState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.schedule.update(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.schedule.update(solution_ctx);
+
+        // NOTE a transfer is broken when one of its legs is unassigned: goods are either not dropped at
+        // the hub or nobody collects them there. A leg which is still in required list is not considered
+        // as it might be inserted later. Additionally, a transfer is broken when a collect was scheduled
+        // before the drop is handled at the hub, e.g. after the inbound tour was changed by ruin.
+        let pending = solution_ctx.required.iter().filter_map(get_transfer_key).collect::<HashSet<_>>();
+        let failed = solution_ctx
+            .unassigned
+            .keys()
+            .filter_map(get_transfer_key)
+            .filter(|key| !pending.contains(key))
+            .chain(
+                solution_ctx
+                    .state
+                    .get_transfer_times()
+                    .iter()
+                    .flat_map(|times| times.iter())
+                    .filter(|(_, time)| !time.is_valid())
+                    .map(|(key, _)| key),
+            )
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        if failed.is_empty() {
+            return;
+        }
+
+        let mut removed = Vec::default();
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let jobs = route_ctx
+                .route()
+                .tour
+                .jobs()
+                .filter(|job| get_transfer_key(job).is_some_and(|key| failed.contains(key)))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            jobs.into_iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(&job);
+                removed.push(job);
+            });
+        });
+
+        solution_ctx.unassigned.extend(removed.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+
+        self.schedule.update(solution_ctx);
+    }
+}
+
+struct TransferSchedule {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl TransferSchedule {
+    /// Checks whether the route with given activities keeps timing of transfers and no activity is late
+    /// when collect activities wait for goods.
+    fn is_valid(&self, route: &Route, activities: &[&Activity], times: Option<&HashMap<String, TransferTime>>) -> bool {
+        let Some(first) = activities.first() else { return true };
+
+        // NOTE use times of the other leg only if it is served by another tour: a finished drop or
+        // the latest collect start, so the other tour can wait for goods longer
+        let get_other_time = |key: &String, leg: TransferLeg| {
+            let time = times.and_then(|times| times.get(key))?;
+            let (actor, timestamp) = match leg {
+                TransferLeg::Inbound => time.drop.as_ref().map(|(actor, drop)| (actor, *drop)),
+                TransferLeg::Outbound => time.collect.as_ref().map(|(actor, _, latest)| (actor, *latest)),
+            }?;
+
+            (!Arc::ptr_eq(actor, &route.actor)).then_some(timestamp)
+        };
+
+        let mut drops = HashMap::<String, Timestamp>::default();
+        let mut collects = HashMap::<String, Timestamp>::default();
+
+        activities
+            .windows(2)
+            .try_fold(first.schedule.departure, |departure, pair| {
+                let (prev, activity) = (pair[0], pair[1]);
+                let arrival = departure
+                    + self.transport.duration(
+                        route,
+                        prev.place.location,
+                        activity.place.location,
+                        TravelTime::Departure(departure),
+                    );
+
+                let hub = get_hub_info(activity);
+                let time = if hub.is_some() { get_original_time(route, activity) } else { activity.place.time.clone() };
+
+                // NOTE collect activity waits till goods are dropped and handled
+                let arrival = match &hub {
+                    Some((key, TransferLeg::Outbound, handling_time)) => drops
+                        .get(key)
+                        .copied()
+                        .or_else(|| get_other_time(key, TransferLeg::Inbound))
+                        .map_or(arrival, |drop| arrival.max(drop + *handling_time)),
+                    _ => arrival,
+                };
+
+                let start = arrival.max(time.start);
+                if start > time.end {
+                    return None;
+                }
+
+                let departure = if time == activity.place.time {
+                    self.activity.estimate_departure(route, activity, arrival)
+                } else {
+                    let mut original = activity.deep_copy();
+                    original.place.time = time;
+                    self.activity.estimate_departure(route, &original, arrival)
+                };
+
+                match hub {
+                    Some((key, TransferLeg::Inbound, handling_time)) => {
+                        let collect =
+                            collects.get(&key).copied().or_else(|| get_other_time(&key, TransferLeg::Outbound));
+                        if collect.is_some_and(|collect| departure + handling_time > collect) {
+                            return None;
+                        }
+
+                        drops.insert(key, departure);
+                    }
+                    Some((key, TransferLeg::Outbound, _)) => {
+                        collects.insert(key, start);
+                    }
+                    None => {}
+                }
+
+                Some(departure)
+            })
+            .is_some()
+    }
+
+    /// Postpones collect activities till goods are dropped and handled and updates timing of all transfers.
+    fn update(&self, solution_ctx: &mut SolutionContext) {
+        let drops = get_hub_activities(solution_ctx)
+            .filter(|(_, _, (_, leg, _))| *leg == TransferLeg::Inbound)
+            .map(|(_, activity, (key, _, _))| (key, activity.schedule.departure))
+            .collect::<HashMap<_, _>>();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let route = route_ctx.route();
+            let times = route
+                .tour
+                .all_activities()
+                .enumerate()
+                .filter_map(|(idx, activity)| {
+                    let (key, leg, handling_time) = get_hub_info(activity)?;
+                    if leg != TransferLeg::Outbound {
+                        return None;
+                    }
+
+                    let original = get_original_time(route, activity);
+                    let start = drops.get(&key).map_or(original.start, |drop| original.start.max(drop + handling_time));
+                    let time = TimeWindow::new(start.min(original.end), original.end);
+
+                    (time != activity.place.time).then_some((idx, time))
+                })
+                .collect::<Vec<_>>();
+
+            if times.is_empty() {
+                return;
+            }
+
+            times.into_iter().for_each(|(idx, time)| {
+                route_ctx.route_mut().tour.get_mut(idx).expect("invalid activity index").place.time = time;
+            });
+
+            update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+        });
+
+        let times = get_hub_activities(solution_ctx).fold(
+            HashMap::<String, TransferTime>::default(),
+            |mut acc, ((route_ctx, idx), activity, (key, leg, handling_time))| {
+                let time = acc.entry(key).or_insert_with(|| TransferTime { handling_time, drop: None, collect: None });
+                let actor = route_ctx.route().actor.clone();
+
+                // NOTE goods are available at the hub when the drop is finished and taken when the collect is started
+                match leg {
+                    TransferLeg::Inbound => time.drop = Some((actor, activity.schedule.departure)),
+                    TransferLeg::Outbound => {
+                        let start = activity.schedule.arrival.max(activity.place.time.start);
+                        let latest = route_ctx.state().get_latest_arrival_at(idx).copied();
+                        let latest = latest.unwrap_or(activity.place.time.end).max(start);
+
+                        time.collect = Some((actor, start, latest))
+                    }
+                }
+
+                acc
+            },
+        );
+
+        solution_ctx.state.set_transfer_times(times);
+    }
+}
+
+/// Returns hub activities of the solution together with their routes and indices.
+fn get_hub_activities(
+    solution_ctx: &SolutionContext,
+) -> impl Iterator<Item = ((&RouteContext, usize), &Activity, (String, TransferLeg, Duration))> + '_ {
+    solution_ctx.routes.iter().flat_map(|route_ctx| {
+        route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(move |(idx, activity)| get_hub_info(activity).map(|info| ((route_ctx, idx), activity, info)))
+    })
+}
+
+/// Returns transfer key, leg and handling time if the activity is a hub activity of the transfer job.
+fn get_hub_info(activity: &Activity) -> Option<(String, TransferLeg, Duration)> {
+    let single = activity.job.as_ref()?;
+    let leg = *single.dimens.get_job_transfer_leg()?;
+    let multi = Multi::roots(single)?;
+    let key = multi.dimens.get_job_transfer_key()?.clone();
+    let handling_time = multi.dimens.get_job_transfer_handling_time().copied().unwrap_or_default();
+
+    Some((key, leg, handling_time))
+}
+
+fn get_transfer_key(job: &Job) -> Option<&String> {
+    job.dimens().get_job_transfer_key()
+}
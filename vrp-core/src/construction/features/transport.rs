@@ -7,7 +7,9 @@ mod transport_test;
 use super::*;
 use crate::construction::enablers::*;
 use crate::models::common::Timestamp;
-use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
+use crate::models::problem::{
+    ActivityCost, Single, TimeCostKind, TransportCost, TravelTime, get_job_assignment_cost, get_vehicle_time_cost,
+};
 use crate::models::solution::Activity;
 
 // TODO
@@ -394,15 +396,22 @@ impl CostObjective {
             (Cost::default(), Cost::default(), Timestamp::default())
         };
 
-        let new_costs = tp_cost_left + tp_cost_right + act_cost_left + act_cost_right;
+        let fixed_cost = target.job.as_ref().map_or(Cost::default(), |single| {
+            single.dimens.get_job_fixed_cost().copied().unwrap_or_default()
+                + get_job_assignment_cost(single, &route_ctx.route().actor)
+        });
+        let new_costs = tp_cost_left + tp_cost_right + act_cost_left + act_cost_right + fixed_cost;
 
         // no jobs yet or open vrp.
         if !route_ctx.route().tour.has_jobs() {
-            return new_costs;
+            let duration_delta = next.map_or(dep_time_left - prev.schedule.departure, |next| {
+                dep_time_right - next.schedule.departure
+            });
+            return new_costs + self.estimate_overtime(route_ctx, duration_delta);
         }
 
         let Some(next) = next else {
-            return new_costs;
+            return new_costs + self.estimate_overtime(route_ctx, dep_time_left - prev.schedule.departure);
         };
 
         let waiting_time = route_ctx.state().get_waiting_time_at(activity_ctx.index + 1).copied().unwrap_or_default();
@@ -410,12 +419,33 @@ impl CostObjective {
         let (tp_cost_old, act_cost_old, dep_time_old) =
             self.analyze_route_leg(route_ctx, prev, next, prev.schedule.departure);
 
-        let waiting_cost = waiting_time.min(Float::default().max(dep_time_right - dep_time_old))
-            * route_ctx.route().actor.vehicle.costs.per_waiting_time;
+        // NOTE absorbed waiting time is charged at rates in force when the next activity is reached
+        let absorbed_waiting = waiting_time.min(Float::default().max(dep_time_right - dep_time_old));
+        let vehicle = route_ctx.route().actor.vehicle.as_ref();
+        let waiting_cost = get_vehicle_time_cost(
+            vehicle,
+            TimeCostKind::Waiting,
+            next.schedule.arrival,
+            absorbed_waiting,
+            vehicle.costs.per_waiting_time,
+        );
 
         let old_costs = tp_cost_old + act_cost_old + waiting_cost;
 
-        new_costs - old_costs
+        // NOTE approximate tour duration change: departure shift minus absorbed waiting time
+        let duration_delta = (dep_time_right - dep_time_old - waiting_time).max(0.);
+
+        new_costs - old_costs + self.estimate_overtime(route_ctx, duration_delta)
+    }
+
+    fn estimate_overtime(&self, route_ctx: &RouteContext, duration_delta: Duration) -> Cost {
+        let Some(overtime) = route_ctx.route().actor.vehicle.dimens.get_vehicle_overtime_costs() else {
+            return Cost::default();
+        };
+
+        let duration = route_ctx.state().get_total_duration().copied().unwrap_or_default();
+
+        overtime.cost(duration + duration_delta) - overtime.cost(duration)
     }
 
     fn analyze_route_leg(
@@ -3,6 +3,7 @@
 mod context_test;
 
 use crate::construction::enablers::{TotalDistanceTourState, TotalDurationTourState};
+use crate::construction::features::{OutsourcingViolationCodeExtraProperty, get_outsourced_job_cost};
 use crate::construction::heuristics::factories::*;
 use crate::models::GoalContext;
 use crate::models::common::Cost;
@@ -70,14 +71,40 @@ impl InsertionContext {
                 + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
         };
 
-        self.solution.routes.iter().try_fold(Cost::default(), |acc, route_ctx| {
+        // NOTE outsourced jobs are served by a third party
+        let outsourcing_cost = self.problem.extras.get_outsourcing_violation_code().map_or(Cost::default(), |code| {
+            self.solution.unassigned.iter().filter_map(|(job, info)| get_outsourced_job_cost(job, info, *code)).sum()
+        });
+
+        self.solution.routes.iter().try_fold(outsourcing_cost, |acc, route_ctx| {
             let actor = &route_ctx.route.actor;
             let distance = route_ctx.state.get_total_distance();
             let duration = route_ctx.state.get_total_duration();
 
+            let fixed_costs = route_ctx
+                .route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.job.as_ref())
+                .map(|single| {
+                    single.dimens.get_job_fixed_cost().copied().unwrap_or_default()
+                        + get_job_assignment_cost(single, actor)
+                })
+                .sum::<Cost>();
+
             distance.zip(duration).map(|(&distance, &duration)| {
-                acc + get_cost(&actor.vehicle.costs, distance, duration)
+                // NOTE time-of-day rates are charged using actual schedule of the route
+                let vehicle_cost = if actor.vehicle.dimens.get_vehicle_time_rates().is_some() {
+                    let costs = &actor.vehicle.costs;
+                    costs.fixed + costs.per_distance * distance + get_route_time_cost(&route_ctx.route)
+                } else {
+                    get_cost(&actor.vehicle.costs, distance, duration)
+                };
+
+                acc + vehicle_cost
                     + get_cost(&actor.driver.costs, distance, duration)
+                    + actor.vehicle.dimens.get_vehicle_overtime_costs().map_or(0., |costs| costs.cost(duration))
+                    + fixed_costs
             })
         })
     }
@@ -89,6 +116,38 @@ impl InsertionContext {
     }
 }
 
+/// Returns vehicle time cost of the route charged at vehicle's time-of-day rates.
+fn get_route_time_cost(route: &Route) -> Cost {
+    let vehicle = route.actor.vehicle.as_ref();
+    let costs = &vehicle.costs;
+
+    route
+        .tour
+        .all_activities()
+        .zip(route.tour.all_activities().skip(1))
+        .map(|(prev, activity)| {
+            let (departure, arrival) = (prev.schedule.departure, activity.schedule.arrival);
+            let service_start = arrival.max(activity.place.time.start).min(activity.schedule.departure);
+
+            get_vehicle_time_cost(vehicle, TimeCostKind::Driving, departure, arrival - departure, costs.per_driving_time)
+                + get_vehicle_time_cost(
+                    vehicle,
+                    TimeCostKind::Waiting,
+                    arrival,
+                    service_start - arrival,
+                    costs.per_waiting_time,
+                )
+                + get_vehicle_time_cost(
+                    vehicle,
+                    TimeCostKind::Service,
+                    service_start,
+                    activity.schedule.departure - service_start,
+                    costs.per_service_time,
+                )
+        })
+        .sum()
+}
+
 impl HeuristicSolution for InsertionContext {
     fn fitness(&self) -> impl Iterator<Item = Float> {
         self.problem.goal.fitness(self)
@@ -124,6 +124,20 @@ pub fn create_insertion_context_from_solution(
     problem: Arc<Problem>,
     solution: (Solution, Option<Cost>),
     environment: Arc<Environment>,
+) -> InsertionContext {
+    let mut insertion_ctx = create_insertion_context_from_routes(problem, solution, environment);
+
+    update_insertion_context(&mut insertion_ctx);
+
+    insertion_ctx
+}
+
+/// Creates insertion context from existing solution without accepting its state, so the tours are
+/// kept exactly as they are in the solution.
+pub(crate) fn create_insertion_context_from_routes(
+    problem: Arc<Problem>,
+    solution: (Solution, Option<Cost>),
+    environment: Arc<Environment>,
 ) -> InsertionContext {
     let required = solution.0.unassigned.iter().map(|(job, _)| job).cloned().collect();
     let locked = problem.locks.iter().fold(HashSet::new(), |mut acc, lock| {
@@ -146,7 +160,7 @@ pub fn create_insertion_context_from_solution(
 
     let registry = RegistryContext::new(problem.goal.as_ref(), registry);
 
-    let mut insertion_ctx = InsertionContext {
+    InsertionContext {
         problem,
         solution: SolutionContext {
             required,
@@ -158,11 +172,7 @@ pub fn create_insertion_context_from_solution(
             state,
         },
         environment,
-    };
-
-    update_insertion_context(&mut insertion_ctx);
-
-    insertion_ctx
+    }
 }
 
 /// Creates an empty insertion context.
@@ -183,7 +193,7 @@ pub fn create_empty_insertion_context(problem: Arc<Problem>, environment: Arc<En
     }
 }
 
-fn update_insertion_context(insertion_ctx: &mut InsertionContext) {
+pub(crate) fn update_insertion_context(insertion_ctx: &mut InsertionContext) {
     // promote required to ignored when necessary
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
     // promote all required to unassigned to have a valid statistics
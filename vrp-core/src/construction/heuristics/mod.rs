@@ -19,3 +19,9 @@ pub use self::metrics::*;
 
 mod selectors;
 pub use self::selectors::*;
+
+mod suggestions;
+pub use self::suggestions::*;
+
+mod violations;
+pub use self::violations::*;
@@ -0,0 +1,145 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/suggestions_test.rs"]
+mod suggestions_test;
+
+use crate::construction::heuristics::*;
+use crate::models::ViolationCode;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::*;
+use rosomaxa::utils::CollectGroupBy;
+use std::sync::Arc;
+
+/// Specifies a feasible insertion of the job into the existing solution.
+pub struct InsertionSuggestion {
+    /// An actor which serves the job.
+    pub actor: Arc<Actor>,
+    /// Job activities with their schedules and indices in the tour after insertion.
+    pub activities: Vec<(Activity, usize)>,
+    /// A route after insertion.
+    pub route: Route,
+    /// An insertion cost estimated by the goal.
+    pub cost: InsertionCost,
+    /// A change of the total solution cost caused by insertion.
+    pub cost_delta: Cost,
+}
+
+/// Keeps insertion suggestions for the job.
+pub struct InsertionSuggestions {
+    /// A job to be inserted.
+    pub job: Job,
+    /// Feasible insertions sorted by their cost, the best comes first.
+    pub feasible: Vec<InsertionSuggestion>,
+    /// Actors which cannot serve the job with the most frequent violation code.
+    pub infeasible: Vec<(Arc<Actor>, ViolationCode)>,
+}
+
+/// Evaluates insertion of the job at every leg of every route, including empty routes of not used
+/// actors, and returns up to `limit` cheapest feasible positions. The solution itself is not modified.
+pub fn get_insertion_suggestions(
+    insertion_ctx: &InsertionContext,
+    job: &Job,
+    limit: usize,
+) -> GenericResult<InsertionSuggestions> {
+    if insertion_ctx.solution.routes.iter().any(|route_ctx| route_ctx.route().tour.contains(job)) {
+        return Err("cannot suggest insertion for the job which is already assigned".into());
+    }
+
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    insertion_ctx.solution.unassigned.remove(job);
+    insertion_ctx.solution.ignored.retain(|ignored| ignored != job);
+    if !insertion_ctx.solution.required.contains(job) {
+        insertion_ctx.solution.required.push(job.clone());
+    }
+
+    let goal = insertion_ctx.problem.goal.as_ref();
+    let empty_routes = insertion_ctx
+        .solution
+        .registry
+        .resources()
+        .available()
+        .map(|actor| {
+            let mut route_ctx = RouteContext::new(actor);
+            goal.accept_route_state(&mut route_ctx);
+            route_ctx
+        })
+        .collect::<Vec<_>>();
+
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext { goal, job, leg_selection: &leg_selection, result_selector: &result_selector };
+
+    let (mut feasible, infeasible) = insertion_ctx.solution.routes.iter().chain(empty_routes.iter()).fold(
+        (Vec::new(), Vec::new()),
+        |(mut feasible, mut infeasible), route_ctx| {
+            let (successes, failures): (Vec<_>, Vec<_>) = (0..route_ctx.route().tour.legs().count())
+                .map(|leg_idx| {
+                    eval_job_insertion_in_route(
+                        &insertion_ctx,
+                        &eval_ctx,
+                        route_ctx,
+                        InsertionPosition::Concrete(leg_idx),
+                        InsertionResult::make_failure(),
+                    )
+                })
+                .map(InsertionSuccess::try_from)
+                .partition(|result| result.is_ok());
+
+            if successes.is_empty() {
+                let code = failures
+                    .into_iter()
+                    .filter_map(|result| result.err())
+                    .collect_group_by_key(|failure| failure.constraint)
+                    .into_iter()
+                    // NOTE: pick only the most frequent reason
+                    .max_by(|(_, a), (_, b)| a.len().cmp(&b.len()))
+                    .map_or(ViolationCode::unknown(), |(code, _)| code);
+                infeasible.push((route_ctx.route().actor.clone(), code));
+            } else {
+                feasible.extend(successes.into_iter().filter_map(|result| result.ok()));
+            }
+
+            (feasible, infeasible)
+        },
+    );
+
+    feasible.sort_by(|a, b| a.cost.cmp(&b.cost));
+    feasible.truncate(limit);
+
+    let feasible = feasible.into_iter().map(|success| create_suggestion(&insertion_ctx, success)).collect();
+
+    Ok(InsertionSuggestions { job: job.clone(), feasible, infeasible })
+}
+
+/// Applies insertion to the copy of the solution to get actual schedules and cost change.
+fn create_suggestion(insertion_ctx: &InsertionContext, success: InsertionSuccess) -> InsertionSuggestion {
+    let (actor, cost) = (success.actor.clone(), success.cost.clone());
+    let job = success.job.clone();
+
+    let original_cost = insertion_ctx.get_total_cost().unwrap_or_default();
+
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+    apply_insertion_success(&mut new_insertion_ctx, success);
+    new_insertion_ctx.restore();
+
+    let route = new_insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route().actor == actor)
+        .map(|route_ctx| route_ctx.route().deep_copy())
+        .expect("cannot find route after insertion");
+
+    let activities = route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(|(_, activity)| activity.has_same_job(&job))
+        .map(|(idx, activity)| (activity.deep_copy(), idx))
+        .collect();
+
+    let cost_delta = new_insertion_ctx.get_total_cost().unwrap_or_default() - original_cost;
+
+    InsertionSuggestion { actor, activities, route, cost, cost_delta }
+}
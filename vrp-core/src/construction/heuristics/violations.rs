@@ -0,0 +1,127 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/violations_test.rs"]
+mod violations_test;
+
+use crate::construction::heuristics::factories::{create_insertion_context_from_routes, update_insertion_context};
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::models::{Problem, Solution, ViolationCode};
+use rosomaxa::prelude::{Environment, HeuristicSolution};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Specifies a hard constraint violation detected at the activity of the route.
+pub struct ActivityViolation {
+    /// An index of the activity in the tour.
+    pub activity_idx: usize,
+    /// A job which activity belongs to.
+    pub job: Job,
+    /// A code of the violated constraint.
+    pub code: ViolationCode,
+}
+
+/// Creates insertion context from the solution keeping all its activities in tours, so it can be evaluated
+/// as it is. When solution state is accepted, some features remove jobs from tours, e.g. when a shared
+/// resource is overbooked. That's why the state is taken from a restored copy of the context, and jobs
+/// removed there are returned with their unassignment code, if it is known. Unassigned jobs of the solution
+/// keep their unassignment reasons.
+pub fn create_evaluation_context(
+    problem: Arc<Problem>,
+    solution: Solution,
+    environment: Arc<Environment>,
+) -> (InsertionContext, Vec<(Job, ViolationCode)>) {
+    let reasons = solution.unassigned.iter().cloned().collect::<HashMap<_, _>>();
+    let mut insertion_ctx = create_insertion_context_from_routes(problem, (solution, None), environment);
+
+    let goal = insertion_ctx.problem.goal.clone();
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| goal.accept_route_state(route_ctx));
+
+    let mut restored_ctx = insertion_ctx.deep_copy();
+    update_insertion_context(&mut restored_ctx);
+    let restored = restored_ctx.solution;
+
+    let assigned = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+        .cloned()
+        .collect::<HashSet<_>>();
+    let is_assigned = |job: &Job| assigned.contains(job);
+
+    let removed = restored
+        .unassigned
+        .iter()
+        .filter(|(job, _)| is_assigned(job))
+        .map(|(job, info)| {
+            let code = match info {
+                UnassignmentInfo::Simple(code) => *code,
+                _ => ViolationCode::unknown(),
+            };
+
+            (job.clone(), code)
+        })
+        .collect();
+
+    let solution_ctx = &mut insertion_ctx.solution;
+    solution_ctx.required.clear();
+    solution_ctx.ignored = restored.ignored;
+    solution_ctx.unassigned = restored
+        .unassigned
+        .into_iter()
+        .filter(|(job, _)| !is_assigned(job))
+        .map(|(job, info)| match info {
+            UnassignmentInfo::Unknown => (job.clone(), reasons.get(&job).cloned().unwrap_or(info)),
+            _ => (job, info),
+        })
+        .collect();
+    solution_ctx.state = restored.state;
+
+    (insertion_ctx, removed)
+}
+
+/// Replays the route activity by activity starting from an empty tour and evaluates hard constraints
+/// the same way as the solver does when it inserts a job at the end of the tour. Activities are kept
+/// in the route even when they violate some constraint, so all violations of the route are reported.
+pub fn get_route_violations(insertion_ctx: &InsertionContext, route_ctx: &RouteContext) -> Vec<ActivityViolation> {
+    let goal = insertion_ctx.problem.goal.as_ref();
+    let solution_ctx = &insertion_ctx.solution;
+    let route = route_ctx.route();
+
+    let mut replay_ctx = RouteContext::new(route.actor.clone());
+    goal.accept_route_state(&mut replay_ctx);
+
+    let mut violations = Vec::new();
+
+    route.tour.all_activities().enumerate().for_each(|(activity_idx, activity)| {
+        let Some(job) = activity.retrieve_job() else { return };
+
+        let is_new_job = !replay_ctx.route().tour.contains(&job);
+        if let Some(violation) = is_new_job
+            .then(|| goal.evaluate(&MoveContext::route(solution_ctx, &replay_ctx, &job)))
+            .flatten()
+        {
+            violations.push(ActivityViolation { activity_idx, job: job.clone(), code: violation.code });
+        }
+
+        // NOTE insert after the last job activity, so prev is either start or the last job activity
+        let index = replay_ctx.route().tour.job_activity_count();
+        let tour = &replay_ctx.route().tour;
+        let prev = tour.get(index).expect("cannot get previous activity");
+        let activity_ctx = ActivityContext { index, prev, target: activity, next: tour.get(index + 1) };
+
+        if let Some(violation) = goal.evaluate(&MoveContext::activity(solution_ctx, &replay_ctx, &activity_ctx)) {
+            let is_reported =
+                violations.last().is_some_and(|last| last.activity_idx == activity_idx && last.code == violation.code);
+
+            if !is_reported {
+                violations.push(ActivityViolation { activity_idx, job, code: violation.code });
+            }
+        }
+
+        replay_ctx.route_mut().tour.insert_at(activity.deep_copy(), index + 1);
+        goal.accept_route_state(&mut replay_ctx);
+    });
+
+    violations
+}
@@ -11,7 +11,7 @@ use std::ops::{Add, ControlFlow, Mul, Sub};
 const LOAD_DIMENSION_SIZE: usize = 8;
 
 /// Represents a load type used to represent customer's demand or vehicle's load.
-pub trait Load: Add + Sub + PartialOrd + Copy + Default + Debug + Send + Sync {
+pub trait Load: Add + Sub + PartialOrd + Clone + Default + Debug + Send + Sync {
     /// Returns true if it represents an empty load.
     fn is_not_empty(&self) -> bool;
 
@@ -68,7 +68,7 @@ pub enum DemandType {
 impl<T: LoadOps> Demand<T> {
     /// Returns capacity change as difference between pickup and delivery.
     pub fn change(&self) -> T {
-        self.pickup.0 + self.pickup.1 - self.delivery.0 - self.delivery.1
+        self.pickup.0.clone() + self.pickup.1.clone() - self.delivery.0.clone() - self.delivery.1.clone()
     }
 }
 
@@ -80,7 +80,7 @@ impl<T: LoadOps> Default for Demand<T> {
 
 impl<T: LoadOps> Clone for Demand<T> {
     fn clone(&self) -> Self {
-        Self { pickup: self.pickup, delivery: self.delivery }
+        Self { pickup: self.pickup.clone(), delivery: self.delivery.clone() }
     }
 }
 
@@ -216,56 +216,103 @@ impl Display for SingleDimLoad {
     }
 }
 
-/// Specifies multi dimensional load type.
-#[derive(Clone, Copy, Debug)]
+/// Specifies multi dimensional load type. Loads with up to eight dimensions are kept inline without heap
+/// allocations, loads with more dimensions are kept on heap.
+#[derive(Clone, Debug)]
 pub struct MultiDimLoad {
     /// Load data.
-    pub load: [i32; LOAD_DIMENSION_SIZE],
+    data: LoadData,
     /// Actual used size.
     pub size: usize,
 }
 
+/// Keeps load data either inline or on heap. Unused dimensions are always zero.
+#[derive(Clone, Debug)]
+enum LoadData {
+    Inline([i32; LOAD_DIMENSION_SIZE]),
+    Heap(Vec<i32>),
+}
+
 impl MultiDimLoad {
     /// Creates a new instance of `MultiDimLoad`.
     pub fn new(data: Vec<i32>) -> Self {
-        assert!(data.len() <= LOAD_DIMENSION_SIZE);
+        let size = data.len();
 
-        let mut load = [0; LOAD_DIMENSION_SIZE];
-        for (idx, value) in data.iter().enumerate() {
-            load[idx] = *value;
+        if size <= LOAD_DIMENSION_SIZE {
+            let mut load = [0; LOAD_DIMENSION_SIZE];
+            load[..size].copy_from_slice(data.as_slice());
+
+            Self { data: LoadData::Inline(load), size }
+        } else {
+            Self { data: LoadData::Heap(data), size }
         }
+    }
 
-        Self { load, size: data.len() }
+    /// Returns a value of the given dimension or zero if the dimension is not used.
+    pub fn get(&self, idx: usize) -> i32 {
+        self.storage().get(idx).copied().unwrap_or_default()
     }
 
-    fn get(&self, idx: usize) -> i32 {
-        self.load[idx]
+    /// Returns values of used dimensions.
+    pub fn as_slice(&self) -> &[i32] {
+        &self.storage()[..self.size]
     }
 
     /// Converts to vector representation.
     pub fn as_vec(&self) -> Vec<i32> {
-        if self.size == 0 { vec![0] } else { self.load[..self.size].to_vec() }
+        if self.size == 0 { vec![0] } else { self.as_slice().to_vec() }
+    }
+
+    fn storage(&self) -> &[i32] {
+        match &self.data {
+            LoadData::Inline(load) => load.as_slice(),
+            LoadData::Heap(load) => load.as_slice(),
+        }
+    }
+
+    fn storage_mut(&mut self) -> &mut [i32] {
+        match &mut self.data {
+            LoadData::Inline(load) => load.as_mut_slice(),
+            LoadData::Heap(load) => load.as_mut_slice(),
+        }
+    }
+
+    /// Returns pairs of values of all dimensions kept by any of two loads.
+    fn pairs<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = (i32, i32)> + 'a {
+        (0..self.storage().len().max(other.storage().len())).map(|idx| (self.get(idx), other.get(idx)))
+    }
+
+    /// Applies the operation to each dimension, storage is moved to heap only when `rhs` has more dimensions
+    /// than can be kept inline.
+    fn combine(mut self, rhs: &Self, op: impl Fn(i32, i32) -> i32) -> Self {
+        if rhs.storage().len() > self.storage().len() {
+            let mut load = rhs.storage().iter().map(|_| 0).collect::<Vec<_>>();
+            load[..self.storage().len()].copy_from_slice(self.storage());
+            self.data = LoadData::Heap(load);
+        }
+
+        self.storage_mut().iter_mut().enumerate().for_each(|(idx, value)| *value = op(*value, rhs.get(idx)));
+        self.size = self.size.max(rhs.size);
+
+        self
     }
 }
 
 impl Load for MultiDimLoad {
     fn is_not_empty(&self) -> bool {
-        self.size == 0 || self.load.iter().any(|v| *v != 0)
+        self.size == 0 || self.storage().iter().any(|v| *v != 0)
     }
 
     fn max_load(self, other: Self) -> Self {
-        let mut result = self;
-        result.load.iter_mut().zip(other.load.iter()).for_each(|(a, b)| *a = (*a).max(*b));
-
-        result
+        self.combine(&other, |a, b| a.max(b))
     }
 
     fn can_fit(&self, other: &Self) -> bool {
-        self.load.iter().zip(other.load.iter()).all(|(a, b)| a >= b)
+        self.pairs(other).all(|(a, b)| a >= b)
     }
 
     fn ratio(&self, other: &Self) -> Float {
-        self.load.iter().zip(other.load.iter()).fold(0., |acc, (a, b)| (*a as Float / *b as Float).max(acc))
+        self.pairs(other).fold(0., |acc, (a, b)| (a as Float / b as Float).max(acc))
     }
 }
 
@@ -273,7 +320,7 @@ impl LoadOps for MultiDimLoad {}
 
 impl Default for MultiDimLoad {
     fn default() -> Self {
-        Self { load: [0; LOAD_DIMENSION_SIZE], size: 0 }
+        Self { data: LoadData::Inline([0; LOAD_DIMENSION_SIZE]), size: 0 }
     }
 }
 
@@ -281,19 +328,7 @@ impl Add for MultiDimLoad {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        fn sum(acc: MultiDimLoad, rhs: &MultiDimLoad) -> MultiDimLoad {
-            let mut dimens = acc;
-
-            for (idx, value) in rhs.load.iter().enumerate() {
-                dimens.load[idx] += *value;
-            }
-
-            dimens.size = dimens.size.max(rhs.size);
-
-            dimens
-        }
-
-        if self.load.len() >= rhs.load.len() { sum(self, &rhs) } else { sum(rhs, &self) }
+        self.combine(&rhs, |a, b| a + b)
     }
 }
 
@@ -301,15 +336,7 @@ impl Sub for MultiDimLoad {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut dimens = self;
-
-        for (idx, value) in rhs.load.iter().enumerate() {
-            dimens.load[idx] -= *value;
-        }
-
-        dimens.size = dimens.size.max(rhs.size);
-
-        dimens
+        self.combine(&rhs, |a, b| a - b)
     }
 }
 
@@ -341,7 +368,7 @@ impl Mul<Float> for MultiDimLoad {
     fn mul(self, value: Float) -> Self::Output {
         let mut dimens = self;
 
-        dimens.load.iter_mut().for_each(|item| {
+        dimens.storage_mut().iter_mut().for_each(|item| {
             *item = (*item as Float * value).round() as i32;
         });
 
@@ -357,6 +384,6 @@ impl Sum for MultiDimLoad {
 
 impl Display for MultiDimLoad {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.load)
+        write!(f, "{:?}", self.storage())
     }
 }
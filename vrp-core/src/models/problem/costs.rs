@@ -2,6 +2,7 @@
 #[path = "../../../tests/unit/models/problem/costs_test.rs"]
 mod costs_test;
 
+use super::{TimeCostKind, Vehicle, VehicleTimeRatesDimension};
 use crate::models::common::*;
 use crate::models::solution::{Activity, Route};
 use rosomaxa::prelude::{Float, GenericError, GenericResult};
@@ -36,9 +37,6 @@ pub trait ActivityCost: Send + Sync {
     ) -> Cost {
         let actor = route.actor.as_ref();
 
-        let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0. };
-        let service = activity.place.duration;
-
         // Check if tiered costs are available for time-based calculations
         let (driver_service_rate, vehicle_service_rate, driver_waiting_rate, vehicle_waiting_rate) = 
             if actor.driver.tiered_costs.is_some() || actor.vehicle.tiered_costs.is_some() {
@@ -72,8 +70,13 @@ pub trait ActivityCost: Send + Sync {
                  actor.driver.costs.per_waiting_time, actor.vehicle.costs.per_waiting_time)
             };
 
-        waiting * (driver_waiting_rate + vehicle_waiting_rate)
-            + service * (driver_service_rate + vehicle_service_rate)
+        get_activity_time_cost(
+            &actor.vehicle,
+            activity,
+            arrival,
+            (driver_waiting_rate, vehicle_waiting_rate),
+            (driver_service_rate, vehicle_service_rate),
+        )
     }
 
     /// Calculates route totals for tiered cost evaluation.
@@ -210,9 +213,6 @@ impl ActivityCost for CoordinatedCostCalculator {
         let route_totals = route_totals.unwrap_or_else(|| self.get_or_calculate_route_totals(route));
         
         let actor = route.actor.as_ref();
-        let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0. };
-        let service = activity.place.duration;
-
         // Check if tiered costs are available for time-based calculations
         let (driver_service_rate, vehicle_service_rate, driver_waiting_rate, vehicle_waiting_rate) = 
             if actor.driver.tiered_costs.is_some() || actor.vehicle.tiered_costs.is_some() {
@@ -245,8 +245,13 @@ impl ActivityCost for CoordinatedCostCalculator {
                  actor.driver.costs.per_waiting_time, actor.vehicle.costs.per_waiting_time)
             };
 
-        waiting * (driver_waiting_rate + vehicle_waiting_rate)
-            + service * (driver_service_rate + vehicle_service_rate)
+        get_activity_time_cost(
+            &actor.vehicle,
+            activity,
+            arrival,
+            (driver_waiting_rate, vehicle_waiting_rate),
+            (driver_service_rate, vehicle_service_rate),
+        )
     }
 
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
@@ -299,7 +304,7 @@ impl TransportCost for CoordinatedCostCalculator {
             };
 
         distance * (driver_distance_rate + vehicle_distance_rate)
-            + duration * (driver_time_rate + vehicle_time_rate)
+            + get_travel_time_cost(&actor.vehicle, travel_time, duration, (driver_time_rate, vehicle_time_rate))
     }
 
     fn get_route_totals(&self, route: &Route) -> (Distance, Duration) {
@@ -371,7 +376,7 @@ pub trait TransportCost: Send + Sync {
             };
 
         distance * (driver_distance_rate + vehicle_distance_rate)
-            + duration * (driver_time_rate + vehicle_time_rate)
+            + get_travel_time_cost(&actor.vehicle, travel_time, duration, (driver_time_rate, vehicle_time_rate))
     }
 
     /// Gets the total distance and duration for the entire route.
@@ -407,6 +412,55 @@ pub trait TransportCost: Send + Sync {
     fn size(&self) -> usize;
 }
 
+/// Returns vehicle cost of the time interval charged at vehicle's time-of-day rates, if they are specified,
+/// or at the regular rate.
+pub fn get_vehicle_time_cost(
+    vehicle: &Vehicle,
+    kind: TimeCostKind,
+    start: Timestamp,
+    duration: Duration,
+    regular_rate: Float,
+) -> Cost {
+    vehicle
+        .dimens
+        .get_vehicle_time_rates()
+        .map_or_else(|| duration * regular_rate, |rates| rates.cost(kind, start, start + duration, regular_rate))
+}
+
+/// Returns waiting and service cost of the activity using driver and vehicle rates.
+fn get_activity_time_cost(
+    vehicle: &Vehicle,
+    activity: &Activity,
+    arrival: Timestamp,
+    (driver_waiting_rate, vehicle_waiting_rate): (Float, Float),
+    (driver_service_rate, vehicle_service_rate): (Float, Float),
+) -> Cost {
+    let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0. };
+    let service = activity.place.duration;
+    let service_start = arrival + waiting;
+
+    waiting * driver_waiting_rate
+        + get_vehicle_time_cost(vehicle, TimeCostKind::Waiting, arrival, waiting, vehicle_waiting_rate)
+        + service * driver_service_rate
+        + get_vehicle_time_cost(vehicle, TimeCostKind::Service, service_start, service, vehicle_service_rate)
+}
+
+/// Returns driving cost using driver and vehicle rates.
+fn get_travel_time_cost(
+    vehicle: &Vehicle,
+    travel_time: TravelTime,
+    duration: Duration,
+    (driver_time_rate, vehicle_time_rate): (Float, Float),
+) -> Cost {
+    let start = match travel_time {
+        TravelTime::Arrival(arrival) => arrival - duration,
+        TravelTime::Departure(departure) => departure,
+    };
+
+    duration * driver_time_rate
+        + get_vehicle_time_cost(vehicle, TimeCostKind::Driving, start, duration, vehicle_time_rate)
+}
+
 /// A simple implementation of transport costs around a single matrix.
 /// This implementation is used to support examples and simple use cases.
 pub struct SimpleTransportCost {
@@ -27,6 +27,132 @@ pub struct Costs {
     pub per_service_time: Float,
 }
 
+/// Represents overtime costs: an extra cost applied only to the part of the tour duration which
+/// exceeds the regular (contractual) duration.
+#[derive(Clone, Debug)]
+pub struct OvertimeCosts {
+    /// A regular tour duration, time beyond it is considered as overtime.
+    pub regular_duration: Duration,
+    /// An extra cost per overtime unit. Tier thresholds are measured from the regular duration.
+    pub per_overtime: TieredCost,
+}
+
+impl OvertimeCosts {
+    /// Returns extra cost for the given tour duration.
+    pub fn cost(&self, duration: Duration) -> Cost {
+        let overtime = (duration - self.regular_duration).max(0.);
+
+        self.per_overtime.calculate_cost_with_mode(overtime, &TieredCostCalculationMode::Cumulative)
+    }
+}
+
+custom_dimension!(pub VehicleOvertimeCosts typeof OvertimeCosts);
+
+/// Specifies a kind of time which is charged by time costs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeCostKind {
+    /// A driving time.
+    Driving,
+    /// A waiting time.
+    Waiting,
+    /// A service time.
+    Service,
+}
+
+/// Represents rates which are in force within a daily time window, e.g. at night or on weekends.
+#[derive(Clone, Debug)]
+pub struct TimeRate {
+    /// Weekdays (1 is Monday, 7 is Sunday) on which the time window starts. Empty means every day.
+    pub weekdays: Vec<u8>,
+    /// A start of the time window as offset from midnight.
+    pub start: Duration,
+    /// An end of the time window as offset from midnight. If it is not after the start, the window ends next day.
+    pub end: Duration,
+    /// Cost per driving time unit, if omitted, a regular rate is used.
+    pub per_driving_time: Option<Float>,
+    /// Cost per waiting time unit, if omitted, a regular rate is used.
+    pub per_waiting_time: Option<Float>,
+    /// Cost per service time unit, if omitted, a regular rate is used.
+    pub per_service_time: Option<Float>,
+}
+
+impl TimeRate {
+    fn rate(&self, kind: TimeCostKind) -> Option<Float> {
+        match kind {
+            TimeCostKind::Driving => self.per_driving_time,
+            TimeCostKind::Waiting => self.per_waiting_time,
+            TimeCostKind::Service => self.per_service_time,
+        }
+    }
+}
+
+/// Represents time-of-day dependent rates: each part of the time interval is charged at the rate in force
+/// while it happens. Timestamps are interpreted as seconds since Unix epoch in UTC. When time windows of
+/// multiple rates overlap, the first rate is used.
+#[derive(Clone, Debug)]
+pub struct TimeRates {
+    /// Rates with their time windows.
+    pub rates: Vec<TimeRate>,
+}
+
+impl TimeRates {
+    /// Returns cost of the given time interval using regular rate outside of rates' time windows.
+    pub fn cost(&self, kind: TimeCostKind, start: Timestamp, end: Timestamp, regular_rate: Float) -> Cost {
+        const DAY: Float = 86400.;
+
+        if end <= start {
+            return Cost::default();
+        }
+
+        // NOTE a window which starts on the previous day can still be in force at the interval start
+        let (first_day, last_day) = ((start / DAY).floor() as i64 - 1, (end / DAY).floor() as i64);
+        let windows = (first_day..=last_day)
+            .flat_map(|day| {
+                // NOTE 1970-01-01 is Thursday
+                let weekday = ((day + 3).rem_euclid(7) + 1) as u8;
+                let offset = day as Float * DAY;
+
+                self.rates.iter().enumerate().filter_map(move |(idx, rate)| {
+                    let value = rate.rate(kind)?;
+                    if !rate.weekdays.is_empty() && !rate.weekdays.contains(&weekday) {
+                        return None;
+                    }
+
+                    let window_end = if rate.end > rate.start { rate.end } else { rate.end + DAY };
+                    let (window_start, window_end) = ((offset + rate.start).max(start), (offset + window_end).min(end));
+
+                    (window_start < window_end).then_some((idx, window_start, window_end, value))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if windows.is_empty() {
+            return (end - start) * regular_rate;
+        }
+
+        let mut points =
+            windows.iter().flat_map(|&(_, start, end, _)| [start, end]).chain([start, end]).collect::<Vec<_>>();
+        points.sort_by(|a, b| a.total_cmp(b));
+        points.dedup();
+
+        points
+            .windows(2)
+            .map(|pair| {
+                let (from, to) = (pair[0], pair[1]);
+                let rate = windows
+                    .iter()
+                    .filter(|&&(_, start, end, _)| start <= from && to <= end)
+                    .min_by_key(|&&(idx, ..)| idx)
+                    .map_or(regular_rate, |&(_, _, _, value)| value);
+
+                (to - from) * rate
+            })
+            .sum()
+    }
+}
+
+custom_dimension!(pub VehicleTimeRates typeof TimeRates);
+
 
 
 /// Represents driver detail (reserved for future use).
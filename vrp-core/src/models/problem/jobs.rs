@@ -4,7 +4,7 @@ mod jobs_test;
 
 use crate::construction::clustering::dbscan::create_job_clusters;
 use crate::models::common::*;
-use crate::models::problem::{Costs, Fleet, TransportCost};
+use crate::models::problem::{Actor, Costs, Fleet, TransportCost, VehicleIdDimension};
 use crate::utils::{Either, short_type_name};
 use rosomaxa::prelude::{Float, GenericResult, InfoLogger};
 use rosomaxa::utils::{Timer, parallel_collect};
@@ -15,6 +15,21 @@ use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Weak};
 
 custom_dimension!(pub JobId typeof String);
+custom_dimension!(pub JobFixedCost typeof Cost);
+custom_dimension!(pub JobAssignmentCosts typeof HashMap<String, Cost>);
+custom_dimension!(pub JobOutsourcingCost typeof Cost);
+
+/// Returns a cost of the job assignment to the given actor. The cost is kept by vehicle id in the
+/// `JobAssignmentCosts` dimension of the job's first task, so it is charged once per job.
+pub fn get_job_assignment_cost(single: &Single, actor: &Actor) -> Cost {
+    single
+        .dimens
+        .get_job_assignment_costs()
+        .zip(actor.vehicle.dimens.get_vehicle_id())
+        .and_then(|(costs, vehicle_id)| costs.get(vehicle_id))
+        .copied()
+        .unwrap_or_default()
+}
 
 /// Represents a job variant.
 #[derive(Clone)]
@@ -86,6 +86,9 @@ pub mod search;
 mod heuristic;
 pub use self::heuristic::*;
 
+mod session;
+pub use self::session::*;
+
 /// A type which encapsulates information needed to perform a solution refinement process.
 pub struct RefinementContext {
     /// Original problem definition.
@@ -0,0 +1,280 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/session_test.rs"]
+mod session_test;
+
+use crate::construction::heuristics::{InsertionContext, UnassignmentInfo};
+use crate::models::problem::{Actor, ActorDetail, Fleet, Job, Jobs, Vehicle};
+use crate::models::solution::{Registry, Route};
+use crate::models::{Lock, LockDetail, Problem, Solution};
+use crate::solver::{RefinementContext, VrpConfigBuilder, get_default_heuristic};
+use rosomaxa::evolution::EvolutionSimulator;
+use rosomaxa::evolution::strategies::Iterative;
+use rosomaxa::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Keeps a problem definition within its solutions alive between multiple small changes, such as
+/// adding or removing jobs and vehicles. After each change, a short bounded search is run, starting
+/// from the solutions found previously instead of building them from scratch.
+///
+/// NOTE: new jobs and vehicles should be compatible with the features defined in the problem's goal.
+pub struct SolverSession {
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    solutions: Vec<InsertionContext>,
+    max_generations: usize,
+    max_time: Option<usize>,
+    population_size: usize,
+}
+
+impl SolverSession {
+    /// Creates a new instance of `SolverSession`. No search is performed till the first change or
+    /// explicit [`SolverSession::solve`] call.
+    pub fn new(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
+        Self { problem, environment, solutions: vec![], max_generations: 200, max_time: None, population_size: 4 }
+    }
+
+    /// Sets max amount of generations used by search run after each change. Default is 200.
+    pub fn with_max_generations(mut self, max_generations: usize) -> Self {
+        self.max_generations = max_generations;
+        self
+    }
+
+    /// Sets max time in seconds used by search run after each change. Default is no limit.
+    pub fn with_max_time(mut self, max_time: Option<usize>) -> Self {
+        self.max_time = max_time;
+        self
+    }
+
+    /// Sets max amount of solutions kept between changes. Default is 4.
+    pub fn with_population_size(mut self, population_size: usize) -> Self {
+        self.population_size = population_size.max(1);
+        self
+    }
+
+    /// Returns the current problem definition.
+    pub fn problem(&self) -> Arc<Problem> {
+        self.problem.clone()
+    }
+
+    /// Returns the best known solution, if any.
+    pub fn best_solution(&self) -> Option<Solution> {
+        self.solutions.first().map(|insertion_ctx| insertion_ctx.deep_copy().into())
+    }
+
+    /// Runs a bounded search starting from the known solutions and returns the best one.
+    pub fn solve(&mut self) -> GenericResult<Solution> {
+        let problem = self.problem.clone();
+        let environment = self.environment.clone();
+
+        let solutions = std::mem::take(&mut self.solutions);
+        let max_init_size = if solutions.is_empty() { None } else { Some(solutions.len()) };
+        let heuristic = get_default_heuristic(problem.clone(), environment.clone());
+
+        let config = VrpConfigBuilder::new(problem.clone())
+            .set_environment(environment.clone())
+            .prebuild()?
+            .with_init_solutions(solutions, max_init_size)
+            .with_strategy(Box::new(Iterative::<RefinementContext, _, _>::new(heuristic, self.population_size)))
+            .with_max_generations(Some(self.max_generations))
+            .with_max_time(self.max_time)
+            .build()?;
+
+        let (solutions, metrics) = EvolutionSimulator::new(config)?.run()?;
+        self.solutions = solutions;
+
+        self.solutions
+            .first()
+            .map(|insertion_ctx| (insertion_ctx.deep_copy(), metrics).into())
+            .ok_or_else(|| "cannot find any solution".into())
+    }
+
+    /// Adds new jobs to the problem and runs a bounded search.
+    pub fn add_jobs(&mut self, jobs: Vec<Job>) -> GenericResult<Solution> {
+        let existing = self.problem.jobs.all().iter().collect::<HashSet<_>>();
+        if jobs.iter().any(|job| existing.contains(job)) {
+            return Err("cannot add job which is already present in the problem".into());
+        }
+
+        let all_jobs = self.problem.jobs.all().iter().cloned().chain(jobs.iter().cloned()).collect();
+        let problem = self.create_problem(self.problem.fleet.clone(), all_jobs, &HashSet::default())?;
+
+        self.update(problem, &HashSet::default(), &jobs);
+        self.solve()
+    }
+
+    /// Removes jobs from the problem and runs a bounded search.
+    pub fn remove_jobs(&mut self, jobs: &[Job]) -> GenericResult<Solution> {
+        let removed = jobs.iter().cloned().collect::<HashSet<_>>();
+        let existing = self.problem.jobs.all().iter().collect::<HashSet<_>>();
+        if removed.iter().any(|job| !existing.contains(job)) {
+            return Err("cannot remove job which is not present in the problem".into());
+        }
+
+        let all_jobs = self.problem.jobs.all().iter().filter(|job| !removed.contains(*job)).cloned().collect();
+        let problem = self.create_problem(self.problem.fleet.clone(), all_jobs, &removed)?;
+
+        self.update(problem, &removed, &[]);
+        self.solve()
+    }
+
+    /// Adds new vehicles to the fleet and runs a bounded search.
+    pub fn add_vehicles(&mut self, vehicles: Vec<Vehicle>) -> GenericResult<Solution> {
+        let vehicles = self.problem.fleet.vehicles.iter().cloned().chain(vehicles.into_iter().map(Arc::new)).collect();
+
+        self.update_fleet(vehicles)
+    }
+
+    /// Removes vehicles from the fleet and runs a bounded search. Jobs served by removed vehicles
+    /// become unassigned and are reinserted by the search.
+    pub fn remove_vehicles(&mut self, vehicles: &[Arc<Vehicle>]) -> GenericResult<Solution> {
+        let is_removed = |vehicle: &Arc<Vehicle>| vehicles.iter().any(|removed| Arc::ptr_eq(removed, vehicle));
+
+        if !vehicles.iter().all(|vehicle| self.problem.fleet.vehicles.iter().any(|v| Arc::ptr_eq(v, vehicle))) {
+            return Err("cannot remove vehicle which is not present in the fleet".into());
+        }
+
+        let vehicles = self.problem.fleet.vehicles.iter().filter(|vehicle| !is_removed(vehicle)).cloned().collect();
+
+        self.update_fleet(vehicles)
+    }
+
+    fn update_fleet(&mut self, vehicles: Vec<Arc<Vehicle>>) -> GenericResult<Solution> {
+        if vehicles.is_empty() {
+            return Err("cannot remove all vehicles from the fleet".into());
+        }
+
+        let fleet = Arc::new(create_fleet(self.problem.fleet.as_ref(), vehicles));
+        let jobs = self.problem.jobs.all().to_vec();
+        let problem = self.create_problem(fleet, jobs, &HashSet::default())?;
+
+        self.update(problem, &HashSet::default(), &[]);
+        self.solve()
+    }
+
+    fn create_problem(&self, fleet: Arc<Fleet>, jobs: Vec<Job>, removed: &HashSet<Job>) -> GenericResult<Problem> {
+        let jobs = Jobs::new(fleet.as_ref(), jobs, self.problem.transport.as_ref(), &self.environment.logger)?;
+
+        let locks = self
+            .problem
+            .locks
+            .iter()
+            .filter_map(|lock| {
+                let details = lock
+                    .details
+                    .iter()
+                    .filter_map(|detail| {
+                        let jobs = detail.jobs.iter().filter(|job| !removed.contains(*job)).cloned().collect::<Vec<_>>();
+                        (!jobs.is_empty()).then(|| LockDetail::new(detail.order.clone(), detail.position.clone(), jobs))
+                    })
+                    .collect::<Vec<_>>();
+
+                (!details.is_empty()).then(|| Arc::new(Lock::new(lock.condition_fn.clone(), details, lock.is_lazy)))
+            })
+            .collect();
+
+        Ok(Problem {
+            fleet,
+            jobs: Arc::new(jobs),
+            locks,
+            goal: self.problem.goal.clone(),
+            activity: self.problem.activity.clone(),
+            transport: self.problem.transport.clone(),
+            extras: self.problem.extras.clone(),
+        })
+    }
+
+    fn update(&mut self, problem: Problem, removed: &HashSet<Job>, added: &[Job]) {
+        let problem = Arc::new(problem);
+        let actors = problem.fleet.actors.iter().map(|actor| (get_actor_key(actor), actor.clone())).collect();
+
+        self.solutions = std::mem::take(&mut self.solutions)
+            .into_iter()
+            .map(|insertion_ctx| {
+                let solution = remap_solution(insertion_ctx, &problem, &actors, removed, added);
+                InsertionContext::new_from_solution(problem.clone(), (solution, None), self.environment.clone())
+            })
+            .collect();
+
+        self.problem = problem;
+    }
+}
+
+type ActorKey = (usize, ActorDetail);
+
+fn get_actor_key(actor: &Actor) -> ActorKey {
+    (Arc::as_ptr(&actor.vehicle) as usize, actor.detail.clone())
+}
+
+/// Creates a new fleet keeping actor groups of existing vehicles. Each new vehicle gets its own group.
+fn create_fleet(fleet: &Fleet, vehicles: Vec<Arc<Vehicle>>) -> Fleet {
+    let groups = fleet
+        .groups
+        .iter()
+        .flat_map(|(group, actors)| actors.iter().map(move |actor| (get_actor_key(actor), *group)))
+        .collect::<HashMap<_, _>>();
+    let next_group = groups.values().max().map_or(0, |group| group + 1);
+    let new_vehicles = vehicles
+        .iter()
+        .filter(|vehicle| !fleet.vehicles.iter().any(|existing| Arc::ptr_eq(existing, vehicle)))
+        .enumerate()
+        .map(|(idx, vehicle)| (Arc::as_ptr(vehicle) as usize, next_group + idx))
+        .collect::<HashMap<_, _>>();
+
+    Fleet::new(fleet.drivers.clone(), vehicles, move |_| {
+        let groups = groups.clone();
+        let new_vehicles = new_vehicles.clone();
+        move |actor: &Actor| {
+            let key = get_actor_key(actor);
+            groups.get(&key).or_else(|| new_vehicles.get(&key.0)).copied().unwrap_or(next_group)
+        }
+    })
+}
+
+/// Maps the solution to the new problem: replaces actors, removes jobs and adds new ones as unassigned.
+fn remap_solution(
+    insertion_ctx: InsertionContext,
+    problem: &Problem,
+    actors: &HashMap<ActorKey, Arc<Actor>>,
+    removed: &HashSet<Job>,
+    added: &[Job],
+) -> Solution {
+    let mut registry = Registry::new(problem.fleet.as_ref(), insertion_ctx.environment.random.clone());
+
+    // NOTE ignored jobs are not kept in solution, so return them back as unassigned
+    let ignored = insertion_ctx.solution.ignored.clone();
+    let solution: Solution = insertion_ctx.into();
+
+    let mut unassigned = solution
+        .unassigned
+        .into_iter()
+        .chain(ignored.into_iter().map(|job| (job, UnassignmentInfo::Unknown)))
+        .filter(|(job, _)| !removed.contains(job))
+        .chain(added.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)))
+        .collect::<Vec<_>>();
+
+    let routes = solution
+        .routes
+        .into_iter()
+        .filter_map(|mut route| {
+            let jobs = route.tour.jobs().cloned().collect::<Vec<_>>();
+            jobs.iter().filter(|job| removed.contains(*job)).for_each(|job| {
+                route.tour.remove(job);
+            });
+
+            match actors.get(&get_actor_key(route.actor.as_ref())) {
+                Some(actor) => {
+                    registry.use_actor(actor);
+                    Some(Route { actor: actor.clone(), tour: route.tour })
+                }
+                None => {
+                    let jobs = route.tour.jobs().cloned().collect::<Vec<_>>();
+                    unassigned.extend(jobs.into_iter().map(|job| (job, UnassignmentInfo::Unknown)));
+                    None
+                }
+            }
+        })
+        .collect();
+
+    Solution { cost: solution.cost, registry, routes, unassigned, telemetry: None }
+}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_sessions(sessions: &[(Timestamp, Timestamp)]) -> Vec<TimeWindow> {
+    sessions.iter().map(|&(start, end)| TimeWindow::new(start, end)).collect()
+}
+
+parameterized_test! {can_get_max_overlaps, (sessions, session, expected), {
+    let sessions = create_sessions(sessions.as_slice());
+
+    assert_eq!(get_max_overlaps(sessions.iter(), &TimeWindow::new(session.0, session.1)), expected);
+}}
+
+can_get_max_overlaps! {
+    case01_no_sessions: (vec![], (0., 10.), 0),
+    case02_no_overlap: (vec![(0., 10.), (20., 30.)], (10., 20.), 0),
+    case03_one_overlap: (vec![(0., 10.), (20., 30.)], (5., 15.), 1),
+    case04_sequential_overlaps: (vec![(0., 10.), (10., 20.)], (5., 15.), 1),
+    case05_parallel_overlaps: (vec![(0., 10.), (5., 20.)], (5., 15.), 2),
+}
+
+parameterized_test! {can_get_slack, (sessions, capacity, session, expected), {
+    let sessions = create_sessions(sessions.as_slice());
+
+    assert_eq!(get_slack(sessions.iter(), capacity, &TimeWindow::new(session.0, session.1)), expected);
+}}
+
+can_get_slack! {
+    case01_no_sessions: (vec![], 1, (0., 10.), Duration::MAX),
+    case02_next_session: (vec![(20., 30.)], 1, (0., 10.), 10.),
+    case03_next_session_below_capacity: (vec![(20., 30.)], 2, (0., 10.), Duration::MAX),
+    case04_next_sessions_at_capacity: (vec![(20., 30.), (25., 30.)], 2, (0., 10.), 15.),
+    case05_already_overbooked: (vec![(5., 30.)], 1, (0., 10.), 0.),
+}
+
+parameterized_test! {can_get_overbooked_sessions, (sessions, expected), {
+    can_get_overbooked_sessions_impl(sessions, expected);
+}}
+
+can_get_overbooked_sessions! {
+    case01_within_capacity: (vec![(0, 1, (0., 10.)), (0, 1, (10., 20.))], vec![]),
+    case02_overbooked: (vec![(0, 1, (0., 10.)), (0, 1, (5., 15.))], vec![1]),
+    case03_different_resources: (vec![(0, 1, (0., 10.)), (1, 1, (5., 15.))], vec![]),
+    case04_parallel_capacity: (vec![(0, 2, (0., 10.)), (0, 2, (5., 15.)), (0, 2, (6., 12.))], vec![2]),
+    case05_first_come_first_served: (vec![(0, 1, (5., 15.)), (0, 1, (0., 10.))], vec![0]),
+}
+
+fn can_get_overbooked_sessions_impl(sessions: Vec<(usize, usize, (Timestamp, Timestamp))>, expected: Vec<usize>) {
+    let sessions = sessions
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (resource_id, capacity, (start, end)))| {
+            (ResourceSession { resource_id, capacity, activity_idx: 0, time: TimeWindow::new(start, end) }, idx)
+        })
+        .collect();
+
+    let result = get_overbooked_sessions(sessions, |_| true).into_iter().map(|(_, idx)| idx).collect::<Vec<_>>();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_get_resource_slacks() {
+    let fleet = test_fleet();
+    let route = RouteBuilder::default()
+        .with_vehicle(&fleet, "v1")
+        .add_activities((1..=3).map(|location| ActivityBuilder::with_location(location).build()))
+        .build();
+    let sessions = [(1, 2.), (3, 5.)]
+        .into_iter()
+        .map(|(activity_idx, slack)| ResourceSession {
+            resource_id: 0,
+            capacity: 1,
+            activity_idx,
+            time: TimeWindow::new(0., slack),
+        })
+        .collect::<Vec<_>>();
+
+    let slacks = get_resource_slacks(&route, sessions.as_slice(), |session| session.time.end);
+
+    assert_eq!(slacks, vec![2., 2., 5., 5., Duration::MAX]);
+}
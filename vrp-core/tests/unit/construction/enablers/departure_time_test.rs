@@ -113,3 +113,26 @@ fn can_recede_departure_time_impl(
 
     assert_eq!(departure_time, expected);
 }
+
+#[test]
+fn can_keep_fixed_departure_time() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location_and_tw(10, TimeWindow::new(50., 100.)).build())
+                .build(),
+        )
+        .build();
+    let (route, state) = route_ctx.as_mut();
+    route.tour.get_mut(0).unwrap().schedule.departure = 20.;
+    state.set_latest_arrival_states(vec![0., 100.]);
+    assert!(try_advance_departure_time(&route_ctx, &TestTransportCost::default(), true).is_some());
+    assert!(try_recede_departure_time(&route_ctx).is_some());
+
+    route_ctx.state_mut().set_fixed_departure(true);
+
+    assert_eq!(try_advance_departure_time(&route_ctx, &TestTransportCost::default(), true), None);
+    assert_eq!(try_recede_departure_time(&route_ctx), None);
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+use std::iter::once;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn failure() -> Option<ConstraintViolation> {
+    ConstraintViolation::skip(VIOLATION_CODE)
+}
+
+type CompartmentData = (i32, Vec<&'static str>);
+
+fn create_compartments(compartments: Vec<CompartmentData>) -> Vec<Compartment<SingleDimLoad>> {
+    compartments
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (capacity, products))| Compartment {
+            id: format!("c{idx}"),
+            capacity: SingleDimLoad::new(capacity),
+            products: products.into_iter().map(|product| product.to_string()).collect(),
+        })
+        .collect()
+}
+
+fn create_single(product: &str, size: i32) -> Single {
+    let mut single = TestSingleBuilder::default().demand(create_simple_demand(size)).build();
+    single.dimens.set_job_product(product.to_string());
+
+    single
+}
+
+fn create_activity(product: &str, size: i32) -> Activity {
+    ActivityBuilder::default().job(Some(Arc::new(create_single(product, size)))).build()
+}
+
+parameterized_test! {can_assign_compartments, (compartments, demands, expected), {
+    can_assign_compartments_impl(compartments, demands, expected);
+}}
+
+can_assign_compartments! {
+    case01_single_product: (vec![(10, vec![])], vec![("a", 5)], Some(vec![Some(0)])),
+    case02_one_product_per_compartment: (vec![(10, vec![])], vec![("a", 5), ("b", 5)], None),
+    case03_allowed_products: (
+        vec![(10, vec!["b"]), (10, vec!["a"])], vec![("a", 5), ("b", 5)], Some(vec![Some(1), Some(0)])
+    ),
+    case04_not_allowed_product: (vec![(10, vec!["b"]), (10, vec!["b"])], vec![("a", 5)], None),
+    case05_split_product: (vec![(10, vec![]), (10, vec![])], vec![("a", 15)], Some(vec![Some(0), Some(0)])),
+    case06_too_big_product: (vec![(10, vec![]), (10, vec![])], vec![("a", 25)], None),
+    case07_backtrack: (
+        vec![(10, vec![]), (10, vec!["a"])], vec![("a", 5), ("b", 5)], Some(vec![Some(1), Some(0)])
+    ),
+    case08_no_products: (vec![(10, vec![])], vec![], Some(vec![None])),
+}
+
+fn can_assign_compartments_impl(
+    compartments: Vec<CompartmentData>,
+    demands: Vec<(&str, i32)>,
+    expected: Option<Vec<Option<usize>>>,
+) {
+    let compartments = create_compartments(compartments);
+    let products = demands.iter().map(|(product, _)| *product).collect::<Vec<_>>();
+    let peaks = demands.iter().map(|(_, size)| SingleDimLoad::new(*size)).collect::<Vec<_>>();
+
+    let result = assign_compartments(compartments.as_slice(), products.as_slice(), peaks.as_slice());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_get_product_loads() {
+    let singles = [create_single("a", -3), create_single("b", -2), create_single("a", -1), create_single("b", 4)];
+
+    let (products, loads) = get_product_loads::<SingleDimLoad>(once(None).chain(singles.iter().map(Some)));
+
+    assert_eq!(products, vec!["a", "b"]);
+    assert_eq!(
+        loads.iter().map(|loads| loads.iter().map(|load| load.value).collect::<Vec<_>>()).collect::<Vec<_>>(),
+        vec![vec![4, 2], vec![1, 2], vec![1, 0], vec![0, 0], vec![0, 4]]
+    );
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (compartments, route_jobs, target, expected), {
+    can_evaluate_activity_insertion_impl(compartments, route_jobs, target, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_same_product: (vec![(10, vec![])], vec![("a", -5)], ("a", -5), None),
+    case02_same_product_overload: (vec![(10, vec![])], vec![("a", -5)], ("a", -6), failure()),
+    case03_different_product: (vec![(10, vec![])], vec![("a", -5)], ("b", -1), failure()),
+    case04_free_compartment: (vec![(10, vec![]), (5, vec![])], vec![("a", -5)], ("b", -5), None),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    compartments: Vec<CompartmentData>,
+    route_jobs: Vec<(&str, i32)>,
+    target: (&str, i32),
+    expected: Option<ConstraintViolation>,
+) {
+    let mut vehicle = TestVehicleBuilder::default().id("v1").build();
+    vehicle.dimens.set_vehicle_compartments(create_compartments(compartments));
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activities(route_jobs.into_iter().map(|(product, size)| create_activity(product, size)))
+                .build(),
+        )
+        .build();
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &create_activity(target.0, target.1),
+        next: route_ctx.route().tour.get(2),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint = create_compartments_feature::<SingleDimLoad, _>("compartments", VIOLATION_CODE, |_| false)
+        .unwrap()
+        .constraint
+        .unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
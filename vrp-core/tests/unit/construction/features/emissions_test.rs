@@ -0,0 +1,53 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, SingleDimLoad, TimeWindow};
+
+fn create_emission_model() -> EmissionModel {
+    EmissionModel { consumption: 1., full_consumption: 3., idle_consumption: 2., emission_factor: 0.5 }
+}
+
+parameterized_test! {can_calculate_driving_emissions, (load_ratio, expected), {
+    can_calculate_driving_emissions_impl(load_ratio, expected);
+}}
+
+can_calculate_driving_emissions! {
+    case01_empty: (0., 5.),
+    case02_half_loaded: (0.5, 10.),
+    case03_full_loaded: (1., 15.),
+    case04_overloaded: (2., 15.),
+    case05_undefined: (Float::NAN, 5.),
+}
+
+fn can_calculate_driving_emissions_impl(load_ratio: Float, expected: Float) {
+    let result = create_emission_model().get_driving_emissions(10., load_ratio);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_route_emissions() {
+    let mut vehicle = test_vehicle_with_id("v1");
+    vehicle.dimens.set_vehicle_capacity(SingleDimLoad::new(4)).set_vehicle_emissions(create_emission_model());
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location(10).schedule(Schedule::new(10., 10.)).build())
+                .add_activity(
+                    ActivityBuilder::with_location_and_tw(20, TimeWindow::new(25., 100.))
+                        .schedule(Schedule::new(20., 25.))
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+    route_ctx
+        .state_mut()
+        .set_current_capacity_states([4, 2, 0, 0].into_iter().map(SingleDimLoad::new).collect::<Vec<_>>());
+
+    let result = get_route_emissions::<SingleDimLoad>(&route_ctx, TestTransportCost::new_shared().as_ref());
+
+    assert_eq!(result, 40.);
+}
@@ -0,0 +1,145 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+struct VehicleIdDimenKey;
+struct JobTypeDimenKey;
+
+fn create_hours_of_service_feature(max_driving_time: Duration, max_total_driving_time: Option<Duration>) -> Feature {
+    fn is_rest_single(single: &Single) -> bool {
+        single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "rest")
+    }
+
+    HoursOfServiceFeatureBuilder::new("hours_of_service")
+        .set_transport(TestTransportCost::new_shared())
+        .set_violation_code(VIOLATION_CODE)
+        .set_limits(move |_: &Actor| Some(HoursOfServiceLimits { max_driving_time, max_total_driving_time }))
+        .set_is_rest_single(is_rest_single)
+        .set_belongs_to_route(|route, job| {
+            job.as_single()
+                .filter(|single| is_rest_single(single))
+                .and_then(|single| single.dimens.get_value::<VehicleIdDimenKey, String>())
+                .zip(route.actor.vehicle.dimens.get_vehicle_id())
+                .is_some_and(|(a, b)| a == b)
+        })
+        .build()
+        .unwrap()
+}
+
+fn rest(location: Location) -> Activity {
+    ActivityBuilder::with_location(location)
+        .job(Some(
+            TestSingleBuilder::default()
+                .id("rest")
+                .property::<JobTypeDimenKey, _>("rest".to_string())
+                .property::<VehicleIdDimenKey, _>("v1".to_string())
+                .build_shared(),
+        ))
+        .build()
+}
+
+fn create_route_ctx(activities: &[Location], rests: Vec<(usize, Location)>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_ovrp_vehicle("v1")).build();
+
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activities(activities.iter().enumerate().map(|(idx, &location)| {
+                    ActivityBuilder::with_location(location)
+                        .schedule(Schedule::new(location as Float, location as Float))
+                        .job(Some(TestSingleBuilder::default().id(&format!("job{}", idx + 1)).build_shared()))
+                        .build()
+                }))
+                .build(),
+        )
+        .build();
+
+    rests.into_iter().for_each(|(rest_idx, rest_location)| {
+        route_ctx.route_mut().tour.insert_at(rest(rest_location), rest_idx);
+    });
+
+    route_ctx
+}
+
+parameterized_test! {can_accumulate_driving_time, (rests, activities, expected_counters, expected_total), {
+    can_accumulate_driving_time_impl(rests, activities, expected_counters, expected_total);
+}}
+
+can_accumulate_driving_time! {
+    case01_single_rest: (vec![(2, 5)], vec![5, 10, 15], vec![0., 5., 5., 5., 10.], 15.),
+    case02_no_rests: (vec![], vec![5, 10, 15], vec![0., 5., 10., 15.], 15.),
+    case03_rest_at_end: (vec![(4, 15)], vec![5, 10, 15], vec![0., 5., 10., 15., 15.], 15.),
+}
+
+fn can_accumulate_driving_time_impl(
+    rests: Vec<(usize, Location)>,
+    activities: Vec<Location>,
+    expected_counters: Vec<Duration>,
+    expected_total: Duration,
+) {
+    let mut route_ctx = create_route_ctx(&activities, rests);
+    let state = create_hours_of_service_feature(20., None).state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    (0..route_ctx.route().tour.total()).for_each(|activity_idx| {
+        let counter = route_ctx.state().get_rest_driving_time_at(activity_idx).copied().unwrap_or_default();
+        assert_eq!(counter, expected_counters[activity_idx], "doesn't match for: {activity_idx}");
+    });
+    assert_eq!(route_ctx.state().get_total_driving_time().copied(), Some(expected_total));
+}
+
+parameterized_test! {can_evaluate_insertion, (limits, rests, insertion_data, activities, expected), {
+    can_evaluate_insertion_impl(limits, rests, insertion_data, activities, expected);
+}}
+
+can_evaluate_insertion! {
+    case01_reject_without_rest: ((10., None), vec![], (2, 20, (2, 3)), vec![5, 10, 15],
+        ConstraintViolation::skip(VIOLATION_CODE),
+    ),
+    case02_accept_after_rest: ((10., None), vec![(2, 5)], (3, 12, (3, 4)), vec![5, 10, 15],
+        None,
+    ),
+    case03_reject_after_rest: ((10., None), vec![(2, 5)], (3, 25, (3, 4)), vec![5, 10, 15],
+        ConstraintViolation::skip(VIOLATION_CODE),
+    ),
+    case04_reject_total: ((20., Some(18.)), vec![(2, 5)], (4, 20, (4, 5)), vec![5, 10, 15],
+        ConstraintViolation::skip(VIOLATION_CODE),
+    ),
+    case05_accept_total: ((20., Some(20.)), vec![(2, 5)], (4, 20, (4, 5)), vec![5, 10, 15],
+        None,
+    ),
+}
+
+fn can_evaluate_insertion_impl(
+    limits: (Duration, Option<Duration>),
+    rests: Vec<(usize, Location)>,
+    insertion_data: (usize, Location, (usize, usize)),
+    activities: Vec<Location>,
+    expected: Option<ConstraintViolation>,
+) {
+    let (index, new_location, (prev, next)) = insertion_data;
+    let mut route_ctx = create_route_ctx(&activities, rests);
+    let feature = create_hours_of_service_feature(limits.0, limits.1);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(prev).unwrap(),
+            target: &ActivityBuilder::with_location(new_location)
+                .job(Some(TestSingleBuilder::default().build_shared()))
+                .build(),
+            next: route_ctx.route().tour.get(next),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
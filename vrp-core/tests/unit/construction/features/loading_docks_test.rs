@@ -0,0 +1,85 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, SingleDimLoad};
+
+fn create_dock(capacity: usize, times: Vec<(Timestamp, Timestamp)>) -> LoadingDock {
+    LoadingDock {
+        id: "dock".to_string(),
+        location: 0,
+        capacity,
+        loading_time: 5.,
+        unit_loading_time: 2.,
+        times: times.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect(),
+    }
+}
+
+fn create_docks(dock: LoadingDock) -> LoadingDocks {
+    LoadingDocks::new(
+        vec![dock],
+        |single| single.dimens.get_job_id().is_some_and(|id| id == "reload"),
+        |load: &SingleDimLoad| load.value as Float,
+    )
+}
+
+fn create_activity(id: &str, location: Location, size: i32, schedule: (Timestamp, Timestamp)) -> Activity {
+    ActivityBuilder::with_location(location)
+        .job(Some(TestSingleBuilder::default().id(id).demand(create_simple_demand(size)).build_shared()))
+        .schedule(Schedule::new(schedule.0, schedule.1))
+        .build()
+}
+
+#[test]
+fn can_get_sessions() {
+    let docks = create_docks(create_dock(1, vec![(0., 1000.)]));
+    let mut route = RouteBuilder::default()
+        .with_vehicle(&test_fleet(), "v1")
+        .add_activity(create_activity("job1", 10, -2, (30., 40.)))
+        .add_activity(create_activity("job2", 20, 1, (50., 60.)))
+        .add_activity(create_activity("reload", 0, 0, (80., 100.)))
+        .add_activity(create_activity("job3", 30, -3, (130., 140.)))
+        .build();
+    route.tour.get_mut(0).unwrap().schedule = Schedule::new(20., 20.);
+
+    let sessions = docks
+        .get_sessions(&route)
+        .into_iter()
+        .map(|session| (session.activity_idx, session.time.start, session.time.end))
+        .collect::<Vec<_>>();
+
+    assert_eq!(sessions, vec![(0, 11., 20.), (3, 80., 100.)]);
+}
+
+parameterized_test! {can_find_departure, (capacity, times, others, duration, departure, expected), {
+    can_find_departure_impl(capacity, times, others, duration, departure, expected);
+}}
+
+can_find_departure! {
+    case01_no_others: (1, vec![(0., 1000.)], vec![], 10., 0., Some(10.)),
+    case02_keep_departure: (1, vec![(0., 1000.)], vec![], 10., 50., Some(50.)),
+    case03_wait_for_other: (1, vec![(0., 1000.)], vec![(0., 20.)], 10., 0., Some(30.)),
+    case04_use_free_dock: (2, vec![(0., 1000.)], vec![(0., 20.)], 10., 0., Some(10.)),
+    case05_wait_for_gap: (1, vec![(0., 1000.)], vec![(0., 20.), (25., 40.)], 10., 0., Some(50.)),
+    case06_use_gap: (1, vec![(0., 1000.)], vec![(0., 20.), (30., 40.)], 10., 0., Some(30.)),
+    case07_wait_for_opening: (1, vec![(100., 1000.)], vec![], 10., 0., Some(110.)),
+    case08_closed: (1, vec![(0., 5.)], vec![], 10., 0., None),
+    case09_next_opening: (1, vec![(0., 15.), (20., 100.)], vec![(0., 10.)], 10., 0., Some(30.)),
+}
+
+fn can_find_departure_impl(
+    capacity: usize,
+    times: Vec<(Timestamp, Timestamp)>,
+    others: Vec<(Timestamp, Timestamp)>,
+    duration: Duration,
+    departure: Timestamp,
+    expected: Option<Timestamp>,
+) {
+    let dock = create_dock(capacity, times);
+    let others = others.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>();
+    let route = RouteBuilder::default().with_vehicle(&test_fleet(), "v1").build();
+
+    let result = find_departure(&route, &dock, others.as_slice(), duration, departure);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,122 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+/// Creates activities from the sequence where each item is a job id with `+` for pickup and `-` for delivery.
+/// Missing items are the parts of multi jobs which are not present in the sequence.
+/// Multi jobs are returned too as singles keep only weak reference to them.
+fn create_activities(sequence: &str, missing: &str) -> (Vec<Activity>, Vec<Arc<Multi>>) {
+    let parse = |items: &str| {
+        items.split_whitespace().map(|item| (item[..1].to_string(), item.ends_with('+'))).collect::<Vec<_>>()
+    };
+    let (sequence, missing) = (parse(sequence), parse(missing));
+
+    let mut job_ids = sequence.iter().map(|(job_id, _)| job_id.clone()).collect::<Vec<_>>();
+    job_ids.dedup();
+    let multis = job_ids.into_iter().fold(HashMap::<String, Arc<Multi>>::default(), |mut acc, job_id| {
+        if !acc.contains_key(&job_id) {
+            let singles = sequence
+                .iter()
+                .chain(missing.iter())
+                .filter(|(other_id, _)| *other_id == job_id)
+                .map(|(_, is_pickup)| {
+                    let demand = if *is_pickup { Demand::pudo_pickup(1) } else { Demand::pudo_delivery(1) };
+                    TestSingleBuilder::default().demand(demand).build_shared()
+                })
+                .collect();
+            acc.insert(job_id.clone(), test_multi_with_id(job_id.as_str(), singles));
+        }
+        acc
+    });
+
+    let mut used = HashMap::<String, usize>::default();
+    let activities = sequence
+        .into_iter()
+        .map(|(job_id, _)| {
+            let idx = used.entry(job_id.clone()).or_default();
+            let single = multis[&job_id].jobs[*idx].clone();
+            *idx += 1;
+
+            ActivityBuilder::default().job(Some(single)).build()
+        })
+        .collect();
+
+    (activities, multis.into_values().collect())
+}
+
+parameterized_test! {can_detect_loading_policy_violation, (policy, sequence, missing, expected), {
+    can_detect_loading_policy_violation_impl(policy, sequence, missing, expected);
+}}
+
+can_detect_loading_policy_violation! {
+    case01_lifo_nested: (LoadingPolicy::Lifo, "a+ b+ b- a-", "", None),
+    case02_lifo_crossed: (LoadingPolicy::Lifo, "a+ b+ a- b-", "", Some("a")),
+    case03_fifo_crossed: (LoadingPolicy::Fifo, "a+ b+ a- b-", "", None),
+    case04_fifo_nested: (LoadingPolicy::Fifo, "a+ b+ b- a-", "", Some("b")),
+    case05_lifo_partial: (LoadingPolicy::Lifo, "a+ b+ a-", "b-", None),
+    case06_lifo_many_deliveries: (LoadingPolicy::Lifo, "a+ b+ b- a- a-", "", None),
+    case07_lifo_many_deliveries_blocked: (LoadingPolicy::Lifo, "a+ a- b+ a- b-", "", Some("a")),
+    case08_lifo_many_pickups: (LoadingPolicy::Lifo, "a+ a+ b+ b- a-", "", None),
+    case09_lifo_many_pickups_split: (LoadingPolicy::Lifo, "a+ b+ a+ a- b-", "", Some("a")),
+    case10_fifo_many_pickups: (LoadingPolicy::Fifo, "a+ a+ b+ a- b-", "", None),
+}
+
+fn can_detect_loading_policy_violation_impl(
+    policy: LoadingPolicy,
+    sequence: &str,
+    missing: &str,
+    expected: Option<&str>,
+) {
+    let (activities, _multis) = create_activities(sequence, missing);
+
+    let result = get_loading_policy_violation::<SingleDimLoad>(policy, activities.iter());
+
+    assert_eq!(result.as_ref().map(|job| get_job_id(job).as_str()), expected);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (policy, index, expected), {
+    can_evaluate_activity_insertion_impl(policy, index, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_lifo_last: (Some(LoadingPolicy::Lifo), 3, None),
+    case02_lifo_middle: (Some(LoadingPolicy::Lifo), 2, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_fifo_middle: (Some(LoadingPolicy::Fifo), 2, None),
+    case04_fifo_last: (Some(LoadingPolicy::Fifo), 3, ConstraintViolation::skip(VIOLATION_CODE)),
+    case05_no_policy: (None, 2, None),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    policy: Option<LoadingPolicy>,
+    index: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let (mut activities, _multis) = create_activities("a+ b+ b- a-", "");
+    let target = activities.pop().unwrap();
+    let mut vehicle = TestVehicleBuilder::default().id("v1").build();
+    if let Some(policy) = policy {
+        vehicle.dimens.set_vehicle_loading_policy(policy);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint =
+        create_loading_policy_feature::<SingleDimLoad>("loading_policy", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
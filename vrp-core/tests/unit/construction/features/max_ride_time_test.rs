@@ -0,0 +1,138 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::Schedule;
+use crate::models::problem::JobIdDimension;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn failure() -> Option<ConstraintViolation> {
+    ConstraintViolation::skip(VIOLATION_CODE)
+}
+
+/// Creates a pickup and delivery job with given locations and delivery time window start.
+fn create_ride_job(max_ride_time: MaxRideTime, locations: (Location, Location), delivery_start: Float) -> Arc<Multi> {
+    let create_single = |location: Location, start: Float| {
+        TestSingleBuilder::default().location(Some(location)).times(vec![TimeWindow::new(start, 1000.)]).build_shared()
+    };
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("job".to_string()).set_job_max_ride_time(max_ride_time);
+
+    Multi::new_shared(vec![create_single(locations.0, 0.), create_single(locations.1, delivery_start)], dimens)
+}
+
+fn create_activity(single: &Arc<Single>) -> Activity {
+    let location = single.places[0].location.unwrap();
+    let time = single.places[0].times[0].as_time_window().unwrap();
+
+    ActivityBuilder::with_location_and_tw(location, time).job(Some(single.clone())).build()
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+fn get_activity(route_ctx: &RouteContext, idx: usize) -> &Activity {
+    route_ctx.route().tour.get(idx).unwrap()
+}
+
+fn create_feature() -> Feature {
+    create_max_ride_time_feature(
+        "max_ride_time",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_evaluate_delivery_insertion, (max_ride_time, delivery_start, index, expected), {
+    can_evaluate_delivery_insertion_impl(max_ride_time, delivery_start, index, expected);
+}}
+
+can_evaluate_delivery_insertion! {
+    case01_duration_next: (MaxRideTime::Duration(15.), 0., 1, None),
+    case02_duration_after_other: (MaxRideTime::Duration(15.), 0., 2, failure()),
+    case03_factor_next: (MaxRideTime::Factor(2.), 0., 1, None),
+    case04_factor_after_other: (MaxRideTime::Factor(2.), 0., 2, failure()),
+    case05_big_factor_after_other: (MaxRideTime::Factor(3.), 0., 2, None),
+    case06_waiting_before_pickup: (MaxRideTime::Duration(15.), 30., 1, None),
+}
+
+fn can_evaluate_delivery_insertion_impl(
+    max_ride_time: MaxRideTime,
+    delivery_start: Float,
+    index: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let multi = create_ride_job(max_ride_time, (10, 20), delivery_start);
+    let route_ctx = create_route_ctx(vec![create_activity(&multi.jobs[0]), ActivityBuilder::with_location(30).build()]);
+    let target = create_activity(&multi.jobs[1]);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint = create_feature().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_other_job_insertion, (index, expected), {
+    can_evaluate_other_job_insertion_impl(index, expected);
+}}
+
+can_evaluate_other_job_insertion! {
+    case01_in_between: (1, failure()),
+    case02_after: (2, None),
+}
+
+fn can_evaluate_other_job_insertion_impl(index: usize, expected: Option<ConstraintViolation>) {
+    let multi = create_ride_job(MaxRideTime::Duration(50.), (10, 20), 0.);
+    let route_ctx = create_route_ctx(vec![create_activity(&multi.jobs[0]), create_activity(&multi.jobs[1])]);
+    let target = ActivityBuilder::with_location(50).build();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint = create_feature().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_move_waiting_before_pickup() {
+    let multi = create_ride_job(MaxRideTime::Duration(15.), (10, 20), 30.);
+    let mut route_ctx = create_route_ctx(vec![create_activity(&multi.jobs[0]), create_activity(&multi.jobs[1])]);
+    let state = create_feature().state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    assert_eq!(get_activity(&route_ctx, 1).place.time, TimeWindow::new(20., 1000.));
+    assert_eq!(get_activity(&route_ctx, 1).schedule, Schedule::new(10., 20.));
+    assert_eq!(get_activity(&route_ctx, 2).schedule, Schedule::new(30., 30.));
+
+    // NOTE original time window is restored when waiting is not needed anymore
+    route_ctx.route_mut().tour.get_mut(2).unwrap().place.time = TimeWindow::new(0., 1000.);
+    state.accept_route_state(&mut route_ctx);
+
+    assert_eq!(get_activity(&route_ctx, 1).place.time, TimeWindow::new(0., 1000.));
+    assert_eq!(get_activity(&route_ctx, 1).schedule, Schedule::new(10., 10.));
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::solution::Activity;
+
+fn create_activity(job_id: &str, location: Location) -> Activity {
+    ActivityBuilder::with_location(location).job(Some(TestSingleBuilder::default().id(job_id).build_shared())).build()
+}
+
+fn create_test_route() -> Route {
+    RouteBuilder::default()
+        .with_vehicle(&test_fleet(), "v1")
+        .add_activity(create_activity("job1", 10))
+        .add_activity(create_activity("job2", 20))
+        .add_activity(create_activity("job3", 30))
+        .build()
+}
+
+fn create_baseline(assignments: &[(&str, &str, usize, Timestamp)], config: MinimizeChangesConfig) -> BaselinePlan {
+    let assignments = assignments
+        .iter()
+        .map(|&(job_id, vehicle_id, position, arrival)| {
+            (job_id.to_string(), BaselineAssignment { vehicle_id: vehicle_id.to_string(), position, arrival })
+        })
+        .collect();
+
+    BaselinePlan::new(assignments, config)
+}
+
+fn create_test_config() -> MinimizeChangesConfig {
+    MinimizeChangesConfig { max_position_shift: 1, max_arrival_shift: 5., ..MinimizeChangesConfig::default() }
+}
+
+parameterized_test! {can_get_changes, (assignments, expected), {
+    can_get_changes_impl(assignments, expected);
+}}
+
+can_get_changes! {
+    case01_no_changes: (vec![("job1", "v1", 1, 10.), ("job2", "v1", 2, 20.), ("job3", "v1", 3, 30.)], (0, 0, 0)),
+    case02_vehicle_change: (vec![("job1", "v1", 1, 10.), ("job2", "v2", 2, 20.)], (1, 0, 0)),
+    case03_position_change: (vec![("job3", "v1", 1, 30.)], (0, 1, 0)),
+    case04_position_within_threshold: (vec![("job3", "v1", 2, 30.)], (0, 0, 0)),
+    case05_arrival_change: (vec![("job1", "v1", 1, 20.), ("job2", "v1", 2, 24.)], (0, 0, 1)),
+    case06_all_changes: (vec![("job3", "v2", 1, 0.)], (1, 1, 1)),
+    case07_no_baseline: (vec![], (0, 0, 0)),
+}
+
+fn can_get_changes_impl(assignments: Vec<(&str, &str, usize, Timestamp)>, expected: (usize, usize, usize)) {
+    let baseline = create_baseline(assignments.as_slice(), create_test_config());
+    let route = create_test_route();
+
+    let changes = baseline.get_changes(std::iter::once(&route));
+
+    assert_eq!((changes.vehicle, changes.position, changes.arrival), expected);
+}
+
+#[test]
+fn can_get_penalty() {
+    let config = MinimizeChangesConfig {
+        vehicle_penalty: 10.,
+        position_penalty: 2.,
+        arrival_penalty: 0.5,
+        ..create_test_config()
+    };
+    let baseline = create_baseline(&[], config);
+
+    let penalty = baseline.get_penalty(&SolutionChanges { vehicle: 2, position: 3, arrival: 4 });
+
+    assert_eq!(penalty, 28.);
+}
@@ -0,0 +1,140 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+parameterized_test! {can_evaluate_activity_insertion, (outsourcing_cost, expected), {
+    can_evaluate_activity_insertion_impl(outsourcing_cost, expected);
+}}
+
+// NOTE the insertion cost is 80: travel there and back costs 4 per distance unit
+can_evaluate_activity_insertion! {
+    case01_no_outsourcing_cost: (None, None),
+    case02_cheaper_to_serve: (Some(100.), None),
+    case03_cheaper_to_outsource: (Some(50.), ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_activity_insertion_impl(outsourcing_cost: Option<Cost>, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(10));
+    if let Some(outsourcing_cost) = outsourcing_cost {
+        builder.dimens_mut().set_job_outsourcing_cost(outsourcing_cost);
+    }
+    let target = ActivityBuilder::with_location(10).job(Some(builder.build_shared())).build();
+
+    let result = evaluate_insertion(&route_ctx, 0, &target);
+
+    assert_eq!(result, expected);
+}
+
+fn evaluate_insertion(route_ctx: &RouteContext, prev_idx: usize, target: &Activity) -> Option<ConstraintViolation> {
+    let activity_ctx = ActivityContext {
+        index: prev_idx,
+        prev: route_ctx.route().tour.get(prev_idx).unwrap(),
+        target,
+        next: route_ctx.route().tour.get(prev_idx + 1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let feature = create_outsourcing_feature(
+        "outsourcing",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap();
+
+    feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_evaluate_pickup_delivery_insertion, (outsourcing_cost, expected), {
+    can_evaluate_pickup_delivery_insertion_impl(outsourcing_cost, expected);
+}}
+
+// NOTE the insertion cost of the whole job is 160 while the delivery detour alone costs 80
+can_evaluate_pickup_delivery_insertion! {
+    case01_cheaper_to_serve: (200., None),
+    case02_cheaper_to_outsource: (100., ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_pickup_delivery_insertion_impl(outsourcing_cost: Cost, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut dimens = Dimensions::default();
+    dimens.set_job_outsourcing_cost(outsourcing_cost);
+    let multi = Multi::new_shared(
+        vec![
+            TestSingleBuilder::default().location(Some(10)).build_shared(),
+            TestSingleBuilder::default().location(Some(20)).build_shared(),
+        ],
+        dimens,
+    );
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location(10).job(Some(multi.jobs[0].clone())).build())
+                .build(),
+        )
+        .build();
+    let target = ActivityBuilder::with_location(20).job(Some(multi.jobs[1].clone())).build();
+
+    let result = evaluate_insertion(&route_ctx, 1, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_insertion_into_empty_route, (has_other_job, expected), {
+    can_evaluate_insertion_into_empty_route_impl(has_other_job, expected);
+}}
+
+// NOTE the detour costs 80 and the vehicle fixed cost is 100 while the outsourcing cost is 150
+can_evaluate_insertion_into_empty_route! {
+    case01_empty_route: (false, ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_used_route: (true, None),
+}
+
+fn can_evaluate_insertion_into_empty_route_impl(has_other_job: bool, expected: Option<ConstraintViolation>) {
+    let vehicle = Vehicle { costs: Costs { fixed: 100., ..test_costs() }, ..test_vehicle_with_id("v1") };
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_builder = RouteBuilder::default();
+    route_builder.with_vehicle(&fleet, "v1");
+    if has_other_job {
+        route_builder.add_activity(ActivityBuilder::with_location(10).build());
+    }
+    let route_ctx = RouteContextBuilder::default().with_route(route_builder.build()).build();
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(10)).dimens_mut().set_job_outsourcing_cost(150.);
+    let target = ActivityBuilder::with_location(10).job(Some(builder.build_shared())).build();
+
+    let result = evaluate_insertion(&route_ctx, if has_other_job { 1 } else { 0 }, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_get_outsourced_job_cost, (outsourcing_cost, info, expected), {
+    can_get_outsourced_job_cost_impl(outsourcing_cost, info, expected);
+}}
+
+can_get_outsourced_job_cost! {
+    case01_outsourced: (Some(50.), UnassignmentInfo::Simple(VIOLATION_CODE), Some(50.)),
+    case02_other_reason: (Some(50.), UnassignmentInfo::Simple(ViolationCode(2)), None),
+    case03_unknown_reason: (Some(50.), UnassignmentInfo::Unknown, None),
+    case04_no_outsourcing_cost: (None, UnassignmentInfo::Simple(VIOLATION_CODE), None),
+}
+
+fn can_get_outsourced_job_cost_impl(outsourcing_cost: Option<Cost>, info: UnassignmentInfo, expected: Option<Cost>) {
+    let mut builder = TestSingleBuilder::default();
+    if let Some(outsourcing_cost) = outsourcing_cost {
+        builder.dimens_mut().set_job_outsourcing_cost(outsourcing_cost);
+    }
+    let job = builder.build_as_job_ref();
+
+    let result = get_outsourced_job_cost(&job, &info, VIOLATION_CODE);
+
+    assert_eq!(result, expected);
+}
@@ -10,6 +10,10 @@ struct VehicleIdDimenKey;
 struct JobTypeDimenKey;
 
 fn create_recharge_feature(limit: Distance) -> Feature {
+    create_recharge_feature_with_initial_distance(limit, None)
+}
+
+fn create_recharge_feature_with_initial_distance(limit: Distance, initial_distance: Option<Distance>) -> Feature {
     fn is_recharge_single(single: &Single) -> bool {
         single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "recharge")
     }
@@ -18,6 +22,7 @@ fn create_recharge_feature(limit: Distance) -> Feature {
         .set_transport(TestTransportCost::new_shared())
         .set_violation_code(VIOLATION_CODE)
         .set_distance_limit(move |_: &Actor| Some(limit))
+        .set_initial_distance(move |_: &Actor| initial_distance)
         .set_is_recharge_single(is_recharge_single)
         .set_belongs_to_route(|route, job| {
             job.as_single()
@@ -42,11 +47,51 @@ fn recharge(location: Location) -> Activity {
         .build()
 }
 
+fn create_battery_recharge_feature(is_activity_set: bool) -> Feature {
+    let builder = RechargeFeatureBuilder::new("recharge")
+        .set_transport(TestTransportCost::new_shared())
+        .set_violation_code(VIOLATION_CODE)
+        .set_distance_limit(|_: &Actor| None)
+        .set_is_recharge_single(|single| single.dimens.get_job_id().is_some_and(|id| id == "recharge"))
+        .set_belongs_to_route(|_, job| job.dimens().get_job_id().is_some_and(|id| id == "recharge"));
+
+    if is_activity_set { builder.set_activity(TestActivityCost::new_shared()) } else { builder }.build().unwrap()
+}
+
+fn create_battery(initial_charge: Float, consumption: Float) -> BatteryModel {
+    BatteryModel {
+        capacity: 20.,
+        initial_charge,
+        reserve: 2.,
+        consumption,
+        load_consumption: 0.,
+        charging_curve: vec![(10., 1.), (20., 0.5)],
+    }
+}
+
 fn create_route_ctx(activities: &[Location], recharges: Vec<(usize, Location)>, is_open_end: bool) -> RouteContext {
-    let fleet = FleetBuilder::default()
-        .add_driver(test_driver())
-        .add_vehicle(if is_open_end { test_ovrp_vehicle("v1") } else { test_vehicle_with_id("v1") })
-        .build();
+    let vehicle = if is_open_end { test_ovrp_vehicle("v1") } else { test_vehicle_with_id("v1") };
+
+    create_route_ctx_with_vehicle(activities, recharges, vehicle)
+}
+
+fn create_battery_route_ctx(
+    activities: &[Location],
+    recharges: Vec<(usize, Location)>,
+    battery: BatteryModel,
+) -> RouteContext {
+    let mut vehicle = test_ovrp_vehicle("v1");
+    vehicle.dimens.set_vehicle_battery(Arc::new(battery));
+
+    create_route_ctx_with_vehicle(activities, recharges, vehicle)
+}
+
+fn create_route_ctx_with_vehicle(
+    activities: &[Location],
+    recharges: Vec<(usize, Location)>,
+    vehicle: Vehicle,
+) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
 
     let mut route_ctx = RouteContextBuilder::default()
         .with_route(
@@ -109,21 +154,31 @@ fn can_accumulate_distance_impl(
     });
 }
 
-parameterized_test! {can_evaluate_insertion, (limit, recharges, insertion_data, activities, expected), {
-    can_evaluate_insertion_impl(limit, recharges, insertion_data, activities, expected);
+parameterized_test! {can_evaluate_insertion, (limit, initial_distance, recharges, insertion_data, activities, expected), {
+    can_evaluate_insertion_impl(limit, initial_distance, recharges, insertion_data, activities, expected);
 }}
 
 can_evaluate_insertion! {
-    case01_reject_before_recharge: (20., vec![(2, 8)], (1, 16, (1, 2)), vec![5, 10, 15],
+    case01_reject_before_recharge: (20., None, vec![(2, 8)], (1, 16, (1, 2)), vec![5, 10, 15],
+        ConstraintViolation::skip(VIOLATION_CODE),
+    ),
+    case02_accept_after_recharge: (20., None, vec![(2, 8)], (1, 16, (2, 3)), vec![5, 10, 15],
+        None,
+    ),
+    case03_accept_with_initial_distance: (20., Some(10.), vec![(2, 8)], (1, 6, (1, 2)), vec![5, 10, 15],
+        None,
+    ),
+    case04_reject_with_initial_distance: (20., Some(15.), vec![(2, 8)], (1, 6, (1, 2)), vec![5, 10, 15],
         ConstraintViolation::skip(VIOLATION_CODE),
     ),
-    case02_accept_after_recharge: (20., vec![(2, 8)], (1, 16, (2, 3)), vec![5, 10, 15],
+    case05_ignore_initial_distance_after_recharge: (20., Some(15.), vec![(2, 8)], (3, 16, (2, 3)), vec![5, 10, 15],
         None,
     ),
 }
 
 fn can_evaluate_insertion_impl(
     limit: Distance,
+    initial_distance: Option<Distance>,
     recharges: Vec<(usize, Location)>,
     insertion_data: (usize, Location, (usize, usize)),
     activities: Vec<Location>,
@@ -131,7 +186,7 @@ fn can_evaluate_insertion_impl(
 ) {
     let (index, new_location, (prev, next)) = insertion_data;
     let mut route_ctx = create_route_ctx(&activities, recharges, true);
-    let feature = create_recharge_feature(limit);
+    let feature = create_recharge_feature_with_initial_distance(limit, initial_distance);
     let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
     state.accept_route_state(&mut route_ctx);
 
@@ -206,3 +261,204 @@ fn can_accept_recharge_in_long_empty_route() {
 
     assert_eq!(result, None);
 }
+
+parameterized_test! {can_get_charging_duration, (from, to, expected), {
+    let battery = create_battery(20., 1.);
+
+    assert_eq!(battery.get_charging_duration(from, to), expected);
+}}
+
+can_get_charging_duration! {
+    case01_first_segment: (2., 8., 6.),
+    case02_two_segments: (4., 16., 18.),
+    case03_second_segment: (12., 20., 16.),
+    case04_above_curve: (18., 22., 8.),
+    case05_nothing: (10., 10., 0.),
+    case06_round_up: (9.5, 10., 1.),
+}
+
+parameterized_test! {can_get_charge_levels, (initial_charge, legs, expected), {
+    let battery = create_battery(initial_charge, 1.);
+
+    assert_eq!(battery.get_charge_levels(legs.as_slice()), expected);
+}}
+
+can_get_charge_levels! {
+    case01_no_recharge: (20., vec![(0., false), (5., false), (3., false)],
+        vec![(20., 20.), (15., 15.), (12., 12.)]
+    ),
+    case02_partial_recharge: (20., vec![(0., false), (10., false), (6., true), (4., false), (10., false)],
+        vec![(20., 20.), (10., 10.), (4., 16.), (12., 12.), (2., 2.)]
+    ),
+    case03_limited_by_capacity: (10., vec![(0., false), (6., true), (30., false)],
+        vec![(10., 10.), (4., 20.), (-10., -10.)]
+    ),
+    case04_no_recharge_needed: (20., vec![(0., false), (2., true), (1., false)],
+        vec![(20., 20.), (18., 18.), (17., 17.)]
+    ),
+}
+
+#[test]
+fn can_accumulate_energy_and_update_recharge_duration() {
+    let mut route_ctx = create_battery_route_ctx(&[5, 10, 15], vec![(2, 8)], create_battery(20., 2.));
+    let state = create_battery_recharge_feature(true).state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let energies = (0..route_ctx.route().tour.total())
+        .map(|idx| route_ctx.state().get_recharge_energy_at(idx).copied().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(energies, vec![0., 10., 16., 4., 14.]);
+    let recharge = route_ctx.route().tour.get(2).unwrap();
+    assert_eq!(recharge.place.duration, 18.);
+    assert_eq!(recharge.schedule.departure - recharge.schedule.arrival, 18.);
+}
+
+parameterized_test! {can_evaluate_insertion_with_battery, (initial_charge, new_location, expected), {
+    can_evaluate_insertion_with_battery_impl(initial_charge, new_location, expected);
+}}
+
+can_evaluate_insertion_with_battery! {
+    case01_accept_within_charge: (20., 18, None),
+    case02_reject_below_reserve: (20., 21, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_reject_low_initial_charge: (15., 16, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_insertion_with_battery_impl(
+    initial_charge: Float,
+    new_location: Location,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut route_ctx = create_battery_route_ctx(&[5, 10, 15], vec![], create_battery(initial_charge, 1.));
+    let feature = create_battery_recharge_feature(false);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index: 3,
+            prev: route_ctx.route().tour.get(3).unwrap(),
+            target: &ActivityBuilder::with_location(new_location)
+                .job(Some(TestSingleBuilder::default().build_shared()))
+                .build(),
+            next: None,
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_charging_time_with_time_windows, (time_window, insertion, expected), {
+    can_evaluate_charging_time_with_time_windows_impl(time_window, insertion, expected);
+}}
+
+can_evaluate_charging_time_with_time_windows! {
+    case01_reject_extra_charging_before: ((3, 30.), (4, 16, false), ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_accept_extra_charging_before: ((3, 32.), (4, 16, false), None),
+    case03_reject_new_recharge_charging: ((4, 38.), (3, 12, true), ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_accept_new_recharge_charging: ((4, 39.), (3, 12, true), None),
+}
+
+fn can_evaluate_charging_time_with_time_windows_impl(
+    time_window: (usize, Timestamp),
+    insertion: (usize, Location, bool),
+    expected: Option<ConstraintViolation>,
+) {
+    let ((tw_idx, latest_arrival), (index, location, is_recharge)) = (time_window, insertion);
+    let mut route_ctx = create_battery_route_ctx(&[5, 10, 15], vec![(2, 8)], create_battery(20., 2.));
+    route_ctx.route_mut().tour.get_mut(tw_idx).unwrap().place.time = TimeWindow::new(0., latest_arrival);
+    let feature = create_battery_recharge_feature(true);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+    let target = if is_recharge {
+        recharge(location)
+    } else {
+        ActivityBuilder::with_location(location).job(Some(TestSingleBuilder::default().build_shared())).build()
+    };
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_remove_overbooked_recharge_locally, (limit, expected), {
+    can_remove_overbooked_recharge_locally_impl(limit, expected);
+}}
+
+// NOTE the tour is 0 -> 20 -> [22] -> 24 -> 0: the route without the recharge needs 48,
+// without the recharge and the trip after it needs 40
+can_remove_overbooked_recharge_locally! {
+    case01_recharge_only: (50., (vec!["job1", "job2"], 1)),
+    case02_recharge_and_trip: (45., (vec!["job1"], 2)),
+    case03_whole_route: (30., (vec![], 3)),
+}
+
+fn can_remove_overbooked_recharge_locally_impl(limit: Distance, expected: (Vec<&str>, usize)) {
+    let (expected_jobs, expected_removed) = expected;
+    let is_recharge_single = |single: &Single| single.dimens.get_job_id().is_some_and(|id| id == "recharge");
+    let state = ChargerState {
+        capacity_fn: Arc::new(move |activity: &Activity| {
+            activity.job.as_ref().filter(|single| is_recharge_single(single)).map(|_| (1, 0))
+        }),
+        counter: MarkerIntervalCounter {
+            counter_fn: Arc::new(|_, _| Float::default()),
+            leg_counter_fn: Arc::new(|_, _, from, to| fake_routing(from.place.location, to.place.location)),
+            limit_fn: Arc::new(move |_, _| Some(limit)),
+        },
+        recharge_single_fn: Arc::new(is_recharge_single),
+    };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let create_route_ctx = |vehicle: &str, charging: (Timestamp, Timestamp)| {
+        let job = |id: &str, location: Location| {
+            ActivityBuilder::with_location(location)
+                .job(Some(TestSingleBuilder::default().id(id).location(Some(location)).build_shared()))
+                .build()
+        };
+        let recharge = ActivityBuilder::with_location(22)
+            .schedule(Schedule::new(charging.0, charging.1))
+            .job(Some(TestSingleBuilder::default().id("recharge").location(Some(22)).build_shared()))
+            .build();
+
+        RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&fleet, vehicle)
+                    .add_activities(vec![job("job1", 20), recharge, job("job2", 24)])
+                    .build(),
+            )
+            .build()
+    };
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route_ctx("v1", (22., 32.)), create_route_ctx("v2", (25., 35.))])
+        .build()
+        .solution;
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    let get_job_ids = |route_idx: usize| {
+        solution_ctx.routes[route_idx]
+            .route()
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.job.as_ref().and_then(|single| single.dimens.get_job_id().cloned()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(get_job_ids(0), vec!["job1", "recharge", "job2"]);
+    assert_eq!(get_job_ids(1), expected_jobs);
+    assert_eq!(solution_ctx.required.len() + solution_ctx.ignored.len(), expected_removed);
+    assert_eq!(solution_ctx.ignored.len(), 1);
+}
@@ -227,7 +227,7 @@ fn can_remove_trivial_reloads_when_used_from_capacity_constraint_impl(
         .with_routes(vec![create_route_context(vec![capacity], activities)])
         .build()
         .solution;
-    let reload_feature = create_simple_reload_feature::<MultiDimLoad, _>(move |capacity| *capacity * threshold);
+    let reload_feature = create_simple_reload_feature::<MultiDimLoad, _>(move |capacity| capacity.clone() * threshold);
 
     let min_jobs_feature = MinimizeUnassignedBuilder::new("min_jobs").build().unwrap();
     let features = vec![reload_feature, min_jobs_feature];
@@ -0,0 +1,76 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::JobIdDimension;
+use crate::models::solution::Activity;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn failure() -> Option<ConstraintViolation> {
+    ConstraintViolation::skip(VIOLATION_CODE)
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    let mut vehicle = TestVehicleBuilder::default();
+    vehicle.id("v1").dimens_mut().set_vehicle_shift_gaps(vec![TimeWindow::new(50., 100.)]);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle.build()).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+fn create_feature() -> Feature {
+    create_shift_gaps_feature(
+        "shift_gaps",
+        VIOLATION_CODE,
+        |single: &Single| single.dimens.get_job_id().is_some_and(|id| id == "gap"),
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (job_id, location, start, index, expected), {
+    can_evaluate_activity_insertion_impl(job_id, location, start, index, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01_before_gap: ("job", 48, 0., 2, None),
+    case02_inside_gap: ("job", 60, 0., 2, failure()),
+    case03_after_gap: ("job", 60, 100., 2, None),
+    case04_gap_job_inside_gap: ("gap", 60, 0., 2, None),
+    case05_pushes_next_into_gap: ("job", 30, 40., 1, failure()),
+    case06_does_not_push_next: ("job", 30, 0., 1, None),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    job_id: &str,
+    location: Location,
+    start: Float,
+    index: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let route_ctx =
+        create_route_ctx(vec![ActivityBuilder::with_location(10).build(), ActivityBuilder::with_location(45).build()]);
+    let target = ActivityBuilder::with_location_and_tw(location, TimeWindow::new(start, 1000.))
+        .job(Some(TestSingleBuilder::default().id(job_id).location(Some(location)).build_shared()))
+        .build();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint = create_feature().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
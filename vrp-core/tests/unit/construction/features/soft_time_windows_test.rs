@@ -0,0 +1,148 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::solution::{Commute, CommuteInfo};
+
+fn create_soft_time_window(max_earliness: Duration, max_lateness: Duration) -> SoftTimeWindow {
+    SoftTimeWindow { max_earliness, max_lateness, earliness_cost: 1., lateness_cost: 2. }
+}
+
+fn create_soft_activity(location: Location, tw: TimeWindow, soft: SoftTimeWindow, arrival: Timestamp) -> Activity {
+    let mut single = TestSingleBuilder::default().location(Some(location)).duration(0.).times(vec![tw.clone()]).build();
+    single.dimens.set_job_soft_time_windows(vec![Some(soft)]);
+
+    ActivityBuilder::with_location_tw_and_duration(location, tw, 0.)
+        .schedule(Schedule::new(arrival, arrival))
+        .job(Some(Arc::new(single)))
+        .build()
+}
+
+fn create_route_ctx(activities: Vec<Activity>) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_start(ActivityBuilder::with_location(0).job(None).schedule(Schedule::new(0., 0.)).build())
+                .add_activities(activities)
+                .build(),
+        )
+        .build()
+}
+
+parameterized_test! {can_get_deviation, (service_start, expected), {
+    can_get_deviation_impl(service_start, expected);
+}}
+
+can_get_deviation! {
+    case01_inside: (150., (0., 0.)),
+    case02_early: (95., (5., 0.)),
+    case03_late: (210., (0., 10.)),
+    case04_at_widened_start: (90., (10., 0.)),
+}
+
+fn can_get_deviation_impl(service_start: Timestamp, expected: (Duration, Duration)) {
+    let soft = create_soft_time_window(10., 20.);
+
+    let result = soft.get_deviation(&TimeWindow::new(90., 220.), service_start);
+
+    assert_eq!(result, expected);
+    assert_eq!(soft.get_penalty(&TimeWindow::new(90., 220.), service_start), expected.0 + expected.1 * 2.);
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let feature = create_soft_time_windows_feature(
+        "soft_time_windows",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap();
+    let route_ctx = create_route_ctx(vec![
+        create_soft_activity(10, TimeWindow::new(0., 15.), create_soft_time_window(0., 5.), 13.),
+        create_soft_activity(20, TimeWindow::new(25., 50.), create_soft_time_window(5., 0.), 25.),
+    ]);
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let result = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(result, 3. * 2. + 5.);
+}
+
+parameterized_test! {can_estimate_insertion, (target_location, target_duration, expected), {
+    can_estimate_insertion_impl(target_location, target_duration, expected);
+}}
+
+can_estimate_insertion! {
+    case01_no_delay: (5, 0., 0.),
+    case02_delay_absorbed_by_waiting: (5, 3., 3. * 2.),
+    case03_delay_propagated: (5, 8., 8. * 2. - 3.),
+}
+
+fn can_estimate_insertion_impl(target_location: Location, target_duration: Duration, expected: Cost) {
+    let objective = create_soft_time_windows_feature(
+        "soft_time_windows",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+    .objective
+    .unwrap();
+    let route_ctx = create_route_ctx(vec![
+        create_soft_activity(10, TimeWindow::new(0., 20.), create_soft_time_window(0., 10.), 10.),
+        create_soft_activity(20, TimeWindow::new(25., 50.), create_soft_time_window(5., 0.), 20.),
+    ]);
+    let target = ActivityBuilder::with_location_tw_and_duration(target_location, TimeWindow::max(), target_duration)
+        .job(None)
+        .build();
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+    let result = objective.estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_use_same_service_start_for_deviation_and_penalty, (arrival, commuting, expected), {
+    can_use_same_service_start_for_deviation_and_penalty_impl(arrival, commuting, expected);
+}}
+
+can_use_same_service_start_for_deviation_and_penalty! {
+    case01_early_inside_band: (95., 0., (95., 5.)),
+    case02_early_inside_band_with_commute: (92., 3., (95., 5.)),
+    case03_arrival_before_band: (80., 0., (90., 10.)),
+    case04_inside_preferred: (150., 0., (150., 0.)),
+}
+
+fn can_use_same_service_start_for_deviation_and_penalty_impl(
+    arrival: Timestamp,
+    commuting: Duration,
+    expected: (Timestamp, Duration),
+) {
+    let (expected_start, expected_earliness) = expected;
+    let feature = create_soft_time_windows_feature(
+        "soft_time_windows",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap();
+    let mut activity = create_soft_activity(10, TimeWindow::new(90., 220.), create_soft_time_window(10., 20.), arrival);
+    activity.commute = Some(Commute {
+        forward: CommuteInfo { location: 10, distance: 0., duration: commuting },
+        backward: CommuteInfo { location: 10, distance: 0., duration: 0. },
+    });
+
+    let service_start = get_activity_service_start(&activity, activity.schedule.arrival);
+    let deviation = get_activity_time_window_deviation(&activity, service_start);
+    let insertion_ctx =
+        TestInsertionContextBuilder::default().with_routes(vec![create_route_ctx(vec![activity])]).build();
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(service_start, expected_start);
+    assert_eq!(deviation, (expected_earliness, 0.));
+    assert_eq!(fitness, expected_earliness);
+}
@@ -0,0 +1,157 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_part(key: &str) -> Arc<Single> {
+    create_part_with_max_visits(key, None)
+}
+
+fn create_part_with_max_visits(key: &str, max_visits: Option<usize>) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.id(key).duration(10.).dimens_mut().set_job_split_key(key.to_string());
+    if let Some(max_visits) = max_visits {
+        builder.dimens_mut().set_job_split_max_visits(max_visits);
+    }
+
+    builder.build_shared()
+}
+
+fn create_activity(part: &Arc<Single>) -> Activity {
+    ActivityBuilder::with_location_tw_and_duration(DEFAULT_JOB_LOCATION, DEFAULT_ACTIVITY_TIME_WINDOW, 10.)
+        .job(Some(part.clone()))
+        .build()
+}
+
+fn create_route(fleet: &Fleet, vehicle: &str, parts: &[Arc<Single>]) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(fleet, vehicle)
+                .add_activities(parts.iter().map(create_activity))
+                .build(),
+        )
+        .build()
+}
+
+fn create_feature() -> Feature {
+    create_split_jobs_feature(
+        "split_jobs",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_unassign_incomplete_split_jobs, (routes, required, unassigned, expected), {
+    can_unassign_incomplete_split_jobs_impl(routes, required, unassigned, expected);
+}}
+
+can_unassign_incomplete_split_jobs! {
+    case01_all_assigned: (vec![vec!["a", "a"], vec!["a"]], vec![], vec![], (3, 0)),
+    case02_part_unassigned: (vec![vec!["a"], vec!["a"]], vec![], vec!["a"], (0, 3)),
+    case03_part_required: (vec![vec!["a"]], vec!["a"], vec![], (1, 0)),
+    case04_part_required_and_unassigned: (vec![vec!["a"]], vec!["a"], vec!["a"], (1, 1)),
+    case05_other_job_kept: (vec![vec!["a", "b"], vec!["b"]], vec![], vec!["a"], (2, 2)),
+}
+
+fn can_unassign_incomplete_split_jobs_impl(
+    routes: Vec<Vec<&str>>,
+    required: Vec<&str>,
+    unassigned: Vec<&str>,
+    expected: (usize, usize),
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let routes = routes
+        .into_iter()
+        .zip(["v1", "v2"])
+        .map(|(keys, vehicle)| {
+            create_route(&fleet, vehicle, keys.into_iter().map(create_part).collect::<Vec<_>>().as_slice())
+        })
+        .collect();
+    let unassigned =
+        unassigned.into_iter().map(|key| (Job::Single(create_part(key)), UnassignmentInfo::Unknown)).collect();
+    let mut solution_ctx =
+        TestInsertionContextBuilder::default().with_routes(routes).with_unassigned(unassigned).build().solution;
+    solution_ctx.required = required.into_iter().map(|key| Job::Single(create_part(key))).collect();
+    let get_assigned = |solution_ctx: &SolutionContext| {
+        solution_ctx.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>()
+    };
+    let original_assigned = get_assigned(&solution_ctx);
+    let state = create_feature().state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    let assigned = get_assigned(&solution_ctx);
+    assert_eq!((assigned, solution_ctx.unassigned.len()), expected);
+    let removed = solution_ctx
+        .unassigned
+        .values()
+        .filter(|code| matches!(code, UnassignmentInfo::Simple(code) if *code == VIOLATION_CODE))
+        .count();
+    assert_eq!(removed, original_assigned - assigned);
+}
+
+parameterized_test! {can_limit_split_job_visits, (max_visits, target, prev_idx, expected), {
+    can_limit_split_job_visits_impl(max_visits, target, prev_idx, expected);
+}}
+
+can_limit_split_job_visits! {
+    case01_continue_visit: (Some(2), "a", 1, None),
+    case02_new_visit_over_limit: (Some(2), "a", 3, Some(VIOLATION_CODE)),
+    case03_new_visit_within_limit: (Some(3), "a", 3, None),
+    case04_break_visit_over_limit: (Some(2), "b", 1, Some(VIOLATION_CODE)),
+    case05_break_visit_within_limit: (Some(3), "b", 1, None),
+    case06_no_limit: (None, "a", 3, None),
+}
+
+fn can_limit_split_job_visits_impl(
+    max_visits: Option<usize>,
+    target: &str,
+    prev_idx: usize,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let a = || create_part_with_max_visits("a", max_visits);
+    let routes = vec![create_route(&fleet, "v1", &[a(), a(), create_part("b")]), create_route(&fleet, "v2", &[a()])];
+    let feature = create_feature();
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(routes).build().solution;
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.first().unwrap();
+    let target = create_activity(&create_part_with_max_visits(target, max_visits));
+    let activity_ctx = ActivityContext {
+        index: prev_idx,
+        prev: route_ctx.route().tour.get(prev_idx).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(prev_idx + 1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_serve_consecutive_parts_as_single_visit() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let parts = ["a", "a", "a", "b", "b"].into_iter().map(create_part).collect::<Vec<_>>();
+    let mut route_ctx = create_route(&fleet, "v1", parts.as_slice());
+    let state = create_feature().state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let activities = route_ctx.route().tour.all_activities().filter(|activity| activity.job.is_some());
+    let (durations, departures): (Vec<_>, Vec<_>) =
+        activities.map(|activity| (activity.place.duration, activity.schedule.departure)).unzip();
+    assert_eq!(durations, vec![10., 0., 0., 10., 0.]);
+    assert_eq!(departures, vec![10., 10., 10., 20., 20.]);
+}
@@ -0,0 +1,105 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, TimeWindow};
+
+fn create_route_ctx(fleet: &Fleet, locations: &[Location], end: Timestamp) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(fleet, "v1")
+                .add_activities(locations.iter().map(|&location| ActivityBuilder::with_location(location).build()))
+                .build(),
+        )
+        .build();
+    route_ctx.route_mut().tour.all_activities_mut().last().unwrap().schedule = Schedule::new(end, end);
+
+    route_ctx
+}
+
+fn estimate_insertion(feature: Feature, other_route_end: Option<Timestamp>, target: Activity) -> Cost {
+    let fleet = test_fleet();
+    let route_ctx = create_route_ctx(&fleet, &[10], 20.);
+    let other_routes = other_route_end.map(|end| create_route_ctx(&fleet, &[], end));
+    let solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(std::iter::once(route_ctx.deep_copy()).chain(other_routes).collect())
+        .build()
+        .solution;
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    feature.objective.unwrap().estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_estimate_makespan_insertion, (other_route_end, expected), {
+    can_estimate_makespan_insertion_impl(other_route_end, expected);
+}}
+
+// NOTE insertion of the activity at location 15 between 10 and the depot shifts the route end from 20 to 30
+can_estimate_makespan_insertion! {
+    case01_single_route: (None, 10.),
+    case02_shorter_route: (Some(25.), 5.),
+    case03_longer_route: (Some(40.), 0.),
+}
+
+fn can_estimate_makespan_insertion_impl(other_route_end: Option<Timestamp>, expected: Cost) {
+    let feature =
+        create_minimize_makespan_feature("makespan", TestTransportCost::new_shared(), TestActivityCost::new_shared())
+            .unwrap();
+
+    let result = estimate_insertion(feature, other_route_end, ActivityBuilder::with_location(15).build());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_waiting_time_insertion, (tw_start, expected), {
+    can_estimate_waiting_time_insertion_impl(tw_start, expected);
+}}
+
+can_estimate_waiting_time_insertion! {
+    case01_no_waiting: (0., 0.),
+    case02_waiting_at_target: (20., 5.),
+}
+
+fn can_estimate_waiting_time_insertion_impl(tw_start: Timestamp, expected: Cost) {
+    let feature = create_minimize_waiting_time_feature(
+        "waiting_time",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap();
+    let target = ActivityBuilder::with_location_and_tw(15, TimeWindow::new(tw_start, 1000.)).build();
+
+    let result = estimate_insertion(feature, None, target);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let fleet = test_fleet();
+    let mut route_ctx = create_route_ctx(&fleet, &[10], 30.);
+    let activity = route_ctx.route_mut().tour.get_mut(1).unwrap();
+    activity.place.time = TimeWindow::new(15., 1000.);
+    activity.schedule = Schedule::new(10., 15.);
+    let insertion_ctx =
+        TestInsertionContextBuilder::default().with_routes(vec![route_ctx, create_route_ctx(&fleet, &[], 20.)]).build();
+    let makespan =
+        create_minimize_makespan_feature("makespan", TestTransportCost::new_shared(), TestActivityCost::new_shared())
+            .unwrap();
+    let waiting_time = create_minimize_waiting_time_feature(
+        "waiting_time",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap();
+
+    assert_eq!(makespan.objective.unwrap().fitness(&insertion_ctx), 30.);
+    assert_eq!(waiting_time.objective.unwrap().fitness(&insertion_ctx), 5.);
+}
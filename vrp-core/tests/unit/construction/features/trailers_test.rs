@@ -0,0 +1,100 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+/// Creates jobs from the sequence where `d` is decouple, `c` is couple, `jN` is a delivery with demand N
+/// and `xN` is a delivery with demand N which is not allowed with the trailer. Decouple and couple
+/// activities belong to one trailer job which is returned too as singles keep only weak reference to it.
+fn create_activities(sequence: &str) -> (Vec<Activity>, Arc<Multi>) {
+    let create_operation = |operation: TrailerOperation| {
+        let mut builder = TestSingleBuilder::default();
+        builder.dimens_mut().set_job_trailer_operation(operation);
+        builder.build_shared()
+    };
+    let trailer = test_multi_with_id(
+        "trailer",
+        vec![create_operation(TrailerOperation::Decouple), create_operation(TrailerOperation::Couple)],
+    );
+
+    let activities = sequence
+        .split_whitespace()
+        .map(|item| {
+            let single = match item {
+                "d" => trailer.jobs[0].clone(),
+                "c" => trailer.jobs[1].clone(),
+                _ => {
+                    let mut builder = TestSingleBuilder::default();
+                    builder.id(item).demand(Demand::delivery(item[1..].parse().unwrap()));
+                    if item.starts_with('x') {
+                        builder.dimens_mut().set_job_trailer_allowed(false);
+                    }
+                    builder.build_shared()
+                }
+            };
+
+            ActivityBuilder::default().job(Some(single)).build()
+        })
+        .collect();
+
+    (activities, trailer)
+}
+
+parameterized_test! {can_check_trailer_usage, (sequence, expected), {
+    can_check_trailer_usage_impl(sequence, expected);
+}}
+
+can_check_trailer_usage! {
+    case01_no_operations: ("j2 j3", true),
+    case02_forbidden_with_trailer: ("x3 d c", false),
+    case03_forbidden_without_trailer: ("j2 d x3 c", true),
+    case04_load_left_in_trailer: ("d x3 c j5", true),
+    case05_vehicle_overloaded: ("d x5 c", false),
+    case06_partially_inserted: ("j2 d x3", true),
+    case07_couple_before_decouple: ("c j2 d", false),
+    case08_forbidden_after_couple: ("d c x2", false),
+}
+
+fn can_check_trailer_usage_impl(sequence: &str, expected: bool) {
+    let (activities, _trailer) = create_activities(sequence);
+    let activities = activities.iter().collect::<Vec<_>>();
+
+    let result = is_valid_trailer_usage(&SingleDimLoad::new(10), &SingleDimLoad::new(6), activities.as_slice());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_remove_invalid_jobs, (sequence, expected_jobs, expected_required, expected_ignored), {
+    can_remove_invalid_jobs_impl(sequence, expected_jobs, expected_required, expected_ignored);
+}}
+
+can_remove_invalid_jobs! {
+    case01_valid: ("j2 d x3 c", 3, 0, 0),
+    case02_empty_trailer_job: ("j2 d c", 1, 0, 1),
+    case03_forbidden_with_trailer: ("x3 j2", 1, 1, 0),
+}
+
+fn can_remove_invalid_jobs_impl(
+    sequence: &str,
+    expected_jobs: usize,
+    expected_required: usize,
+    expected_ignored: usize,
+) {
+    let (activities, _trailer) = create_activities(sequence);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    let state =
+        create_trailer_feature::<SingleDimLoad, _>("trailer", VIOLATION_CODE, |_, _| true).unwrap().state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), expected_jobs);
+    assert_eq!(solution_ctx.required.len(), expected_required);
+    assert_eq!(solution_ctx.ignored.len(), expected_ignored);
+}
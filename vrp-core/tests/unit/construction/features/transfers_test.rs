@@ -0,0 +1,157 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Schedule, TimeWindow};
+use crate::models::problem::JobIdDimension;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn failure() -> Option<ConstraintViolation> {
+    ConstraintViolation::skip(VIOLATION_CODE)
+}
+
+fn create_single(location: Location, end: Float, leg: Option<TransferLeg>) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location)).times(vec![TimeWindow::new(0., end)]);
+    if let Some(leg) = leg {
+        builder.dimens_mut().set_job_transfer_leg(leg);
+    }
+
+    builder.build_shared()
+}
+
+/// Creates inbound and outbound legs of the transfer job with the hub at location 20.
+fn create_transfer(key: &str, handling_time: Duration, collect_end: Float) -> (Arc<Multi>, Arc<Multi>) {
+    let create_leg = |singles: Vec<Arc<Single>>| {
+        let mut dimens = Dimensions::default();
+        dimens
+            .set_job_id(key.to_string())
+            .set_job_transfer_key(key.to_string())
+            .set_job_transfer_handling_time(handling_time);
+
+        Multi::new_shared(singles, dimens)
+    };
+
+    (
+        create_leg(vec![create_single(10, 1000., None), create_single(20, 1000., Some(TransferLeg::Inbound))]),
+        create_leg(vec![create_single(20, collect_end, Some(TransferLeg::Outbound)), create_single(30, 1000., None)]),
+    )
+}
+
+fn create_activity(single: &Arc<Single>) -> Activity {
+    let location = single.places[0].location.unwrap();
+    let time = single.places[0].times[0].as_time_window().unwrap();
+
+    ActivityBuilder::with_location_and_tw(location, time).job(Some(single.clone())).build()
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle: &str, legs: &[&Arc<Multi>]) -> RouteContext {
+    let activities = legs.iter().flat_map(|leg| leg.jobs.iter().map(create_activity)).collect::<Vec<_>>();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(fleet, vehicle).add_activities(activities).build())
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+fn create_feature() -> Feature {
+    create_transfers_feature(
+        "transfers",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_evaluate_collect_insertion, (handling_time, collect_end, expected), {
+    can_evaluate_collect_insertion_impl(handling_time, collect_end, expected);
+}}
+
+can_evaluate_collect_insertion! {
+    case01_no_handling_time: (0., 1000., None),
+    case02_waiting_for_handling: (5., 1000., None),
+    case03_handling_after_collect_end: (5., 22., failure()),
+}
+
+fn can_evaluate_collect_insertion_impl(
+    handling_time: Duration,
+    collect_end: Float,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_fleet();
+    let (inbound, outbound) = create_transfer("job", handling_time, collect_end);
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route_ctx(&fleet, "v1", &[&inbound])])
+        .build()
+        .solution;
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_insertion(&mut solution_ctx, 0, &Job::Multi(inbound));
+    let route_ctx = create_route_ctx(&fleet, "v2", &[]);
+    let target = create_activity(&outbound.jobs[0]);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_postpone_collect_till_goods_are_handled() {
+    let fleet = create_fleet();
+    let (inbound, outbound) = create_transfer("job", 5., 1000.);
+    let routes = vec![create_route_ctx(&fleet, "v1", &[&inbound]), create_route_ctx(&fleet, "v2", &[&outbound])];
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(routes).build().solution;
+    let state = create_feature().state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    let collect = solution_ctx.routes[1].route().tour.get(1).unwrap();
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(collect.place.time, TimeWindow::new(25., 1000.));
+    assert_eq!(collect.schedule, Schedule::new(20., 25.));
+}
+
+parameterized_test! {can_unassign_broken_transfers, (collect_end, is_outbound_unassigned, expected), {
+    can_unassign_broken_transfers_impl(collect_end, is_outbound_unassigned, expected);
+}}
+
+can_unassign_broken_transfers! {
+    case01_both_assigned: (1000., false, (2, 0)),
+    case02_leg_unassigned: (1000., true, (0, 2)),
+    case03_handling_after_collect_end: (22., false, (0, 2)),
+}
+
+fn can_unassign_broken_transfers_impl(collect_end: Float, is_outbound_unassigned: bool, expected: (usize, usize)) {
+    let fleet = create_fleet();
+    let (inbound, outbound) = create_transfer("job", 5., collect_end);
+    let (routes, unassigned) = if is_outbound_unassigned {
+        (vec![create_route_ctx(&fleet, "v1", &[&inbound])], vec![(Job::Multi(outbound), UnassignmentInfo::Unknown)])
+    } else {
+        (vec![create_route_ctx(&fleet, "v1", &[&inbound]), create_route_ctx(&fleet, "v2", &[&outbound])], vec![])
+    };
+    let mut solution_ctx =
+        TestInsertionContextBuilder::default().with_routes(routes).with_unassigned(unassigned).build().solution;
+    let state = create_feature().state.unwrap();
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    let assigned = solution_ctx.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>();
+    assert_eq!((assigned, solution_ctx.unassigned.len()), expected);
+}
@@ -0,0 +1,141 @@
+use super::*;
+use crate::construction::features::*;
+use crate::helpers::models::problem::TestTransportCost;
+use crate::models::common::{Demand, SingleDimLoad, TimeWindow};
+use crate::models::problem::{JobIdDimension, SingleBuilder, Vehicle, VehicleBuilder, VehicleDetailBuilder};
+use crate::models::problem::{Single, VehicleIdDimension};
+use crate::models::solution::{Place, Registry, Route, Tour};
+use crate::models::{GoalContextBuilder, Problem, ProblemBuilder, Solution};
+
+const CAPACITY_CODE: ViolationCode = ViolationCode(1);
+
+fn create_job(id: &str, location: usize) -> Job {
+    SingleBuilder::default()
+        .id(id)
+        .location(location)
+        .unwrap()
+        .times(vec![TimeWindow::new(0., 1000.)])
+        .unwrap()
+        .demand(Demand::delivery(1))
+        .build_as_job()
+        .unwrap()
+}
+
+fn create_vehicle(id: &str, capacity: i32) -> Vehicle {
+    VehicleBuilder::default()
+        .id(id)
+        .set_distance_cost(1.)
+        .capacity(SingleDimLoad::new(capacity))
+        .add_detail(VehicleDetailBuilder::default().set_start_location(0).set_start_time(0.).build().unwrap())
+        .build()
+        .unwrap()
+}
+
+fn create_problem(jobs: Vec<Job>, vehicles: Vec<Vehicle>) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let features = vec![
+        MinimizeUnassignedBuilder::new("min_jobs").build().unwrap(),
+        TransportFeatureBuilder::new("min_distance")
+            .set_transport_cost(transport.clone())
+            .build_minimize_distance()
+            .unwrap(),
+        CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").set_violation_code(CAPACITY_CODE).build().unwrap(),
+    ];
+    let goal = GoalContextBuilder::with_features(&features).unwrap().build().unwrap();
+
+    Arc::new(
+        ProblemBuilder::default()
+            .add_jobs(jobs.into_iter())
+            .add_vehicles(vehicles.into_iter())
+            .with_transport_cost(transport)
+            .with_goal(goal)
+            .build()
+            .unwrap(),
+    )
+}
+
+fn get_job(problem: &Problem, id: &str) -> Job {
+    problem.jobs.all().iter().find(|job| job.dimens().get_job_id().is_some_and(|job_id| job_id == id)).cloned().unwrap()
+}
+
+fn create_insertion_ctx(problem: Arc<Problem>, vehicle_id: &str, job_ids: &[&str]) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let mut registry = Registry::new(problem.fleet.as_ref(), environment.random.clone());
+    let actor = problem.fleet.actors.iter().find(|actor| actor.vehicle.dimens.get_vehicle_id().unwrap() == vehicle_id);
+    let actor = actor.cloned().unwrap();
+    registry.use_actor(&actor);
+
+    let mut tour = Tour::new(actor.as_ref());
+    job_ids.iter().for_each(|job_id| {
+        let single: Arc<Single> = get_job(problem.as_ref(), job_id).as_single().cloned().unwrap();
+        let location = single.places[0].location.unwrap();
+        let mut activity = Activity::new_with_job(single);
+        activity.place = Place { idx: 0, location, duration: 0., time: TimeWindow::new(0., 1000.) };
+        tour.insert_last(activity);
+    });
+
+    let unassigned = problem
+        .jobs
+        .all()
+        .iter()
+        .filter(|job| !tour.contains(job))
+        .map(|job| (job.clone(), UnassignmentInfo::Unknown))
+        .collect();
+    let solution = Solution { cost: 0., registry, routes: vec![Route { actor, tour }], unassigned, telemetry: None };
+
+    InsertionContext::new_from_solution(problem, (solution, None), environment)
+}
+
+#[test]
+fn can_suggest_insertion_positions() {
+    let jobs = vec![create_job("job1", 1), create_job("job2", 5), create_job("job3", 2)];
+    let problem = create_problem(jobs, vec![create_vehicle("v1", 10), create_vehicle("v2", 0)]);
+    let insertion_ctx = create_insertion_ctx(problem.clone(), "v1", &["job1", "job2"]);
+    let job = get_job(problem.as_ref(), "job3");
+
+    let suggestions = get_insertion_suggestions(&insertion_ctx, &job, 2).unwrap();
+
+    assert_eq!(suggestions.feasible.len(), 2);
+    let result = suggestions
+        .feasible
+        .iter()
+        .map(|suggestion| {
+            let vehicle_id = suggestion.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap();
+            let (activity, idx) = &suggestion.activities[0];
+            (vehicle_id, *idx, activity.schedule.arrival, suggestion.cost_delta)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result, vec![("v1".to_string(), 2, 2., 0.), ("v1".to_string(), 1, 2., 2.)]);
+
+    assert_eq!(suggestions.infeasible.len(), 1);
+    assert_eq!(suggestions.infeasible[0].0.vehicle.dimens.get_vehicle_id().unwrap(), "v2");
+    assert_eq!(suggestions.infeasible[0].1, CAPACITY_CODE);
+    assert!(!insertion_ctx.solution.routes[0].route().tour.contains(&job));
+}
+
+#[test]
+fn can_suggest_insertion_into_unused_vehicle() {
+    let jobs = vec![create_job("job1", 1), create_job("job2", 3)];
+    let problem = create_problem(jobs, vec![create_vehicle("v1", 1), create_vehicle("v2", 1)]);
+    let insertion_ctx = create_insertion_ctx(problem.clone(), "v1", &["job1"]);
+    let job = get_job(problem.as_ref(), "job2");
+
+    let suggestions = get_insertion_suggestions(&insertion_ctx, &job, 10).unwrap();
+
+    assert_eq!(suggestions.feasible.len(), 1);
+    assert_eq!(suggestions.feasible[0].actor.vehicle.dimens.get_vehicle_id().unwrap(), "v2");
+    assert_eq!(suggestions.feasible[0].activities[0].1, 1);
+    assert_eq!(suggestions.feasible[0].cost_delta, 3.);
+    assert_eq!(suggestions.infeasible.len(), 1);
+    assert_eq!(suggestions.infeasible[0].1, CAPACITY_CODE);
+}
+
+#[test]
+fn can_detect_already_assigned_job() {
+    let problem = create_problem(vec![create_job("job1", 1)], vec![create_vehicle("v1", 10)]);
+    let insertion_ctx = create_insertion_ctx(problem.clone(), "v1", &["job1"]);
+
+    let result = get_insertion_suggestions(&insertion_ctx, &get_job(problem.as_ref(), "job1"), 1);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::construction::features::*;
+use crate::helpers::models::problem::TestTransportCost;
+use crate::models::common::{Demand, SingleDimLoad, TimeWindow};
+use crate::models::problem::{JobIdDimension, SingleBuilder, Vehicle, VehicleBuilder, VehicleDetailBuilder};
+use crate::models::problem::{Single, VehicleIdDimension};
+use crate::models::solution::{Activity, Place, Registry, Route, Tour};
+use crate::models::{GoalContextBuilder, Problem, ProblemBuilder, Solution};
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+const CAPACITY_CODE: ViolationCode = ViolationCode(1);
+
+fn create_job(id: &str, location: usize) -> Job {
+    SingleBuilder::default()
+        .id(id)
+        .location(location)
+        .unwrap()
+        .times(vec![TimeWindow::new(0., 1000.)])
+        .unwrap()
+        .demand(Demand::delivery(1))
+        .build_as_job()
+        .unwrap()
+}
+
+fn create_vehicle(id: &str, capacity: i32) -> Vehicle {
+    VehicleBuilder::default()
+        .id(id)
+        .set_distance_cost(1.)
+        .capacity(SingleDimLoad::new(capacity))
+        .add_detail(VehicleDetailBuilder::default().set_start_location(0).set_start_time(0.).build().unwrap())
+        .build()
+        .unwrap()
+}
+
+fn create_problem(jobs: Vec<Job>, vehicles: Vec<Vehicle>) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let features = vec![
+        MinimizeUnassignedBuilder::new("min_jobs").build().unwrap(),
+        TransportFeatureBuilder::new("min_distance")
+            .set_transport_cost(transport.clone())
+            .build_minimize_distance()
+            .unwrap(),
+        CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").set_violation_code(CAPACITY_CODE).build().unwrap(),
+    ];
+    let goal = GoalContextBuilder::with_features(&features).unwrap().build().unwrap();
+
+    Arc::new(
+        ProblemBuilder::default()
+            .add_jobs(jobs.into_iter())
+            .add_vehicles(vehicles.into_iter())
+            .with_transport_cost(transport)
+            .with_goal(goal)
+            .build()
+            .unwrap(),
+    )
+}
+
+fn create_insertion_ctx(problem: Arc<Problem>, job_ids: &[&str]) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let mut registry = Registry::new(problem.fleet.as_ref(), environment.random.clone());
+    let actor = problem.fleet.actors.first().cloned().unwrap();
+    registry.use_actor(&actor);
+
+    let mut tour = Tour::new(actor.as_ref());
+    job_ids.iter().for_each(|job_id| {
+        let job = problem.jobs.all().iter().find(|job| job.dimens().get_job_id().unwrap() == job_id).cloned();
+        let single: Arc<Single> = job.unwrap().as_single().cloned().unwrap();
+        let location = single.places[0].location.unwrap();
+        let mut activity = Activity::new_with_job(single);
+        activity.place = Place { idx: 0, location, duration: 0., time: TimeWindow::new(0., 1000.) };
+        tour.insert_last(activity);
+    });
+
+    let routes = vec![Route { actor, tour }];
+    let solution = Solution { cost: 0., registry, routes, unassigned: Default::default(), telemetry: None };
+
+    InsertionContext::new_from_solution(problem, (solution, None), environment)
+}
+
+parameterized_test! {can_detect_route_violations, (capacity, expected), {
+    can_detect_route_violations_impl(capacity, expected);
+}}
+
+can_detect_route_violations! {
+    case01_no_violations: (3, vec![]),
+    case02_one_violation: (2, vec![("job3".to_string(), 3)]),
+    case03_two_violations: (1, vec![("job2".to_string(), 2), ("job3".to_string(), 3)]),
+}
+
+fn can_detect_route_violations_impl(capacity: i32, expected: Vec<(String, usize)>) {
+    let jobs = vec![create_job("job1", 1), create_job("job2", 2), create_job("job3", 3)];
+    let problem = create_problem(jobs, vec![create_vehicle("v1", capacity)]);
+    let insertion_ctx = create_insertion_ctx(problem, &["job1", "job2", "job3"]);
+    let route_ctx = &insertion_ctx.solution.routes[0];
+    assert_eq!(route_ctx.route().actor.vehicle.dimens.get_vehicle_id().unwrap(), "v1");
+
+    let violations = get_route_violations(&insertion_ctx, route_ctx);
+
+    assert!(violations.iter().all(|violation| violation.code == CAPACITY_CODE));
+    let violations = violations
+        .iter()
+        .map(|violation| (violation.job.dimens().get_job_id().cloned().unwrap(), violation.activity_idx))
+        .collect::<Vec<_>>();
+    assert_eq!(violations, expected);
+    assert_eq!(route_ctx.route().tour.job_count(), 3);
+}
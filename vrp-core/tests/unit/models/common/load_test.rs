@@ -121,4 +121,27 @@ mod multi {
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
     }
+
+    #[test]
+    fn can_use_many_dimens() {
+        let many = |value: i32, last: i32| from_vec((0..11).map(|idx| if idx == 10 { last } else { value }).collect());
+
+        assert_eq!(many(1, 2).size, 11);
+        assert_eq!(many(1, 2).as_vec().len(), 11);
+        assert_eq!(many(1, 2).get(10), 2);
+        assert_eq!(many(1, 2).get(11), 0);
+
+        assert_eq!(many(1, 2) + many(1, 3), many(2, 5));
+        assert_eq!(many(1, 2) - many(1, 2), MultiDimLoad::default());
+        assert_eq!(from_vec(vec![1; 8]) + many(1, 2), many(2, 2) - from_vec(vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 1]));
+        assert_eq!(many(1, 2) - from_vec(vec![1; 8]), from_vec(vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 2]));
+        assert_eq!(many(1, 2) * 2., many(2, 4));
+
+        assert!(many(1, 3).can_fit(&many(1, 2)));
+        assert!(!many(1, 2).can_fit(&many(1, 3)));
+        assert!(!from_vec(vec![10; 8]).can_fit(&many(1, 1)));
+        assert!(many(1, 1).can_fit(&from_vec(vec![1; 8])));
+        assert_eq!(many(1, 2).partial_cmp(&many(2, 3)), Some(Ordering::Less));
+        assert_eq!(from_vec(vec![1; 8]).max_load(many(0, 3)), from_vec(vec![1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 3]));
+    }
 }
@@ -1,3 +1,4 @@
+use super::*;
 use crate::helpers::models::problem::{FleetBuilder, test_driver, test_vehicle};
 
 #[test]
@@ -19,3 +20,55 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1, profile2]
     )
 }
+
+parameterized_test! {can_calculate_overtime_cost, (per_overtime, duration, expected), {
+    can_calculate_overtime_cost_impl(per_overtime, duration, expected);
+}}
+
+can_calculate_overtime_cost! {
+    case01_fixed_no_overtime: (TieredCost::Fixed(2.), 80., 0.),
+    case02_fixed_overtime: (TieredCost::Fixed(2.), 130., 60.),
+    case03_tiered_first_tier: (create_tiers(), 110., 10.),
+    case04_tiered_second_tier: (create_tiers(), 150., 20. + 30. * 3.),
+}
+
+fn create_tiers() -> TieredCost {
+    TieredCost::tiered(vec![CostTier::new(0., 1.).unwrap(), CostTier::new(20., 3.).unwrap()]).unwrap()
+}
+
+fn can_calculate_overtime_cost_impl(per_overtime: TieredCost, duration: Duration, expected: Cost) {
+    let overtime = OvertimeCosts { regular_duration: 100., per_overtime };
+
+    assert_eq!(overtime.cost(duration), expected);
+}
+
+parameterized_test! {can_calculate_time_rates_cost, (kind, start, end, expected), {
+    can_calculate_time_rates_cost_impl(kind, start, end, expected);
+}}
+
+// NOTE 1970-01-01 is Thursday, regular rate is 1, night rate is 2, Sunday rate is 3
+can_calculate_time_rates_cost! {
+    case01_regular_time: (TimeCostKind::Driving, 10. * 3600., 11. * 3600., 3600.),
+    case02_night_starts: (TimeCostKind::Driving, 21. * 3600., 23. * 3600., 3600. + 3600. * 2.),
+    case03_night_from_previous_day: (TimeCostKind::Driving, 5. * 3600., 7. * 3600., 3600. * 2. + 3600.),
+    case04_over_midnight: (TimeCostKind::Driving, 23. * 3600., 25. * 3600., 7200. * 2.),
+    case05_sunday_first: (TimeCostKind::Driving, 3. * 86400. + 23. * 3600., 4. * 86400., 3600. * 3.),
+    case06_no_kind_rate: (TimeCostKind::Waiting, 21. * 3600., 23. * 3600., 7200.),
+    case07_empty_interval: (TimeCostKind::Driving, 22. * 3600., 22. * 3600., 0.),
+}
+
+fn can_calculate_time_rates_cost_impl(kind: TimeCostKind, start: Timestamp, end: Timestamp, expected: Cost) {
+    let create_rate = |weekdays: Vec<u8>, start: Duration, end: Duration, rate: Float| TimeRate {
+        weekdays,
+        start,
+        end,
+        per_driving_time: Some(rate),
+        per_waiting_time: None,
+        per_service_time: None,
+    };
+    let rates = TimeRates {
+        rates: vec![create_rate(vec![7], 0., 0., 3.), create_rate(vec![], 22. * 3600., 6. * 3600., 2.)],
+    };
+
+    assert_eq!(rates.cost(kind, start, end, 1.), expected);
+}
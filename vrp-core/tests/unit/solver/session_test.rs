@@ -0,0 +1,143 @@
+use super::*;
+use crate::construction::features::*;
+use crate::helpers::models::problem::TestTransportCost;
+use crate::models::common::{Demand, SingleDimLoad, TimeWindow};
+use crate::models::problem::{JobIdDimension, SingleBuilder, VehicleBuilder, VehicleDetailBuilder, VehicleIdDimension};
+use crate::models::{GoalContextBuilder, ProblemBuilder};
+
+fn create_job(id: &str, location: usize) -> Job {
+    SingleBuilder::default()
+        .id(id)
+        .location(location)
+        .unwrap()
+        .times(vec![TimeWindow::new(0., 1000.)])
+        .unwrap()
+        .demand(Demand::delivery(1))
+        .build_as_job()
+        .unwrap()
+}
+
+fn create_vehicle(id: &str, capacity: i32) -> Vehicle {
+    VehicleBuilder::default()
+        .id(id)
+        .set_distance_cost(1.)
+        .capacity(SingleDimLoad::new(capacity))
+        .add_detail(
+            VehicleDetailBuilder::default()
+                .set_start_location(0)
+                .set_start_time(0.)
+                .set_end_location(0)
+                .set_end_time(1000.)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+}
+
+fn create_session(jobs: Vec<Job>, vehicles: Vec<Vehicle>) -> SolverSession {
+    let transport = TestTransportCost::new_shared();
+    let features = vec![
+        MinimizeUnassignedBuilder::new("min_jobs").build().unwrap(),
+        TransportFeatureBuilder::new("min_distance")
+            .set_transport_cost(transport.clone())
+            .build_minimize_distance()
+            .unwrap(),
+        CapacityFeatureBuilder::<SingleDimLoad>::new("capacity").build().unwrap(),
+    ];
+    let goal = GoalContextBuilder::with_features(&features).unwrap().build().unwrap();
+    let problem = ProblemBuilder::default()
+        .add_jobs(jobs.into_iter())
+        .add_vehicles(vehicles.into_iter())
+        .with_transport_cost(transport)
+        .with_goal(goal)
+        .with_logger(Arc::new(|_| ()))
+        .build()
+        .unwrap();
+    let environment = Arc::new(Environment { logger: Arc::new(|_| ()), ..Environment::default() });
+
+    SolverSession::new(Arc::new(problem), environment).with_max_generations(10)
+}
+
+fn get_assigned_ids(solution: &Solution) -> Vec<String> {
+    let mut ids = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.jobs())
+        .filter_map(|job| job.dimens().get_job_id().cloned())
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+fn find_job(session: &SolverSession, id: &str) -> Job {
+    let problem = session.problem();
+
+    problem.jobs.all().iter().find(|job| job.dimens().get_job_id().is_some_and(|job_id| job_id == id)).cloned().unwrap()
+}
+
+#[test]
+fn can_add_and_remove_jobs() {
+    let mut session = create_session(vec![create_job("job1", 1), create_job("job2", 2)], vec![create_vehicle("v1", 10)]);
+    let solution = session.solve().unwrap();
+    assert_eq!(get_assigned_ids(&solution), vec!["job1", "job2"]);
+
+    let solution = session.add_jobs(vec![create_job("job3", 3), create_job("job4", 4)]).unwrap();
+    assert_eq!(session.problem().jobs.size(), 4);
+    assert_eq!(get_assigned_ids(&solution), vec!["job1", "job2", "job3", "job4"]);
+
+    let job2 = find_job(&session, "job2");
+    let solution = session.remove_jobs(&[job2]).unwrap();
+    assert_eq!(session.problem().jobs.size(), 3);
+    assert_eq!(get_assigned_ids(&solution), vec!["job1", "job3", "job4"]);
+    assert!(solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_add_and_remove_vehicles() {
+    let jobs = (1..=4).map(|idx| create_job(&format!("job{idx}"), idx)).collect();
+    let mut session = create_session(jobs, vec![create_vehicle("v1", 2), create_vehicle("v2", 2)]);
+    let solution = session.solve().unwrap();
+    assert_eq!(solution.routes.len(), 2);
+    assert!(solution.unassigned.is_empty());
+
+    let v2 = session.problem().fleet.vehicles[1].clone();
+    let solution = session.remove_vehicles(&[v2]).unwrap();
+    assert_eq!(session.problem().fleet.vehicles.len(), 1);
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.unassigned.len(), 2);
+
+    let solution = session.add_vehicles(vec![create_vehicle("v3", 2)]).unwrap();
+    assert_eq!(solution.routes.len(), 2);
+    assert!(solution.unassigned.is_empty());
+    let mut vehicle_ids =
+        solution.routes.iter().filter_map(|route| route.actor.vehicle.dimens.get_vehicle_id()).collect::<Vec<_>>();
+    vehicle_ids.sort();
+    assert_eq!(vehicle_ids, vec!["v1", "v3"]);
+}
+
+#[test]
+fn can_keep_solution_between_changes() {
+    let mut session = create_session(vec![create_job("job1", 1), create_job("job2", 2)], vec![create_vehicle("v1", 10)]);
+    assert!(session.best_solution().is_none());
+
+    session.solve().unwrap();
+    let before = session.best_solution().unwrap();
+    let solution = session.add_vehicles(vec![create_vehicle("v2", 10)]).unwrap();
+
+    assert_eq!(get_assigned_ids(&before), get_assigned_ids(&solution));
+    assert_eq!(solution.cost, before.cost);
+}
+
+#[test]
+fn can_detect_invalid_changes() {
+    let job = create_job("job1", 1);
+    let mut session = create_session(vec![job.clone()], vec![create_vehicle("v1", 10)]);
+
+    assert!(session.add_jobs(vec![find_job(&session, "job1")]).is_err());
+    assert!(session.remove_jobs(&[create_job("job2", 2)]).is_err());
+    assert!(session.remove_vehicles(&[Arc::new(create_vehicle("v2", 10))]).is_err());
+    let vehicles = session.problem().fleet.vehicles.clone();
+    assert!(session.remove_vehicles(vehicles.as_slice()).is_err());
+}
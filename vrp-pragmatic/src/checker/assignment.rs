@@ -8,13 +8,21 @@ use crate::format::solution::activity_matcher::*;
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::common::{Load, MultiDimLoad};
 use vrp_core::models::solution::Place;
 use vrp_core::prelude::GenericResult;
 use vrp_core::utils::GenericError;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicles(ctx), check_jobs_presence(ctx), check_jobs_match(ctx), check_groups(ctx)])
+    combine_error_results(&[
+        check_vehicles(ctx),
+        check_jobs_presence(ctx),
+        check_jobs_match(ctx),
+        check_groups(ctx),
+        check_split_jobs(ctx),
+        check_transfers(ctx),
+    ])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
@@ -69,7 +77,12 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
 
-                if asgn.tour_info != tour_info {
+                // NOTE split and transfer jobs can be served by multiple tours
+                let is_shared = all_jobs
+                    .get(&activity.job_id)
+                    .is_some_and(|job| job.split.is_some() || job.transfer.is_some());
+
+                if asgn.tour_info != tour_info && !is_shared {
                     return Err(GenericError::from(format!("job served in multiple tours: '{}'", activity.job_id)));
                 }
 
@@ -94,25 +107,29 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
             + job.replacements.as_ref().map_or(0, |r| r.len());
         let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
 
-        if expected_tasks != assigned_tasks {
+        if expected_tasks != assigned_tasks && job.split.is_none() {
             return Err(GenericError::from(format!(
                 "not all tasks served for '{id}', expected: {expected_tasks}, assigned: {assigned_tasks}"
             )));
         }
 
-        if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
+        // NOTE order of transfer job activities is checked separately
+        let is_transfer = job.transfer.is_some();
+        if !is_transfer && !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
             return Err(GenericError::from(format!("found pickup after delivery for '{id}'")));
         }
 
         Ok(())
     })?;
 
+    // NOTE outsourced jobs are not served by the fleet, so they are checked together with unassigned
     let all_unassigned_jobs = ctx
         .solution
         .unassigned
         .iter()
         .flat_map(|jobs| jobs.iter().filter(|job| !job.job_id.ends_with("_break")))
         .map(|job| job.job_id.clone())
+        .chain(ctx.solution.outsourced.iter().flatten().map(|job| job.job_id.clone()))
         .collect::<Vec<_>>();
 
     let unique_unassigned_jobs = all_unassigned_jobs.iter().cloned().collect::<HashSet<_>>();
@@ -177,7 +194,7 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
                                             }
                                         }
                                         Ok(Some(JobInfo(_, _, place, time))) => {
-                                            is_valid_job_info(ctx, stop, activity, *idx, place, time)
+                                            is_valid_job_info(ctx, tour, stop, activity, *idx, place, time)
                                         }
                                         _ => false,
                                     }
@@ -208,6 +225,7 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
 
 fn is_valid_job_info(
     ctx: &CheckerContext,
+    tour: &Tour,
     stop: &PointStop,
     activity: &Activity,
     activity_idx: usize,
@@ -218,7 +236,9 @@ fn is_valid_job_info(
     let parking = ctx.clustering.as_ref().map(|config| config.serving.get_parking()).unwrap_or(0.);
     let commute_profile = ctx.clustering.as_ref().map(|config| config.profile.clone());
     let domain_commute = ctx.get_commute_info(commute_profile, parking, stop, activity_idx);
-    let extra_time = get_extra_time(stop, activity, &place).unwrap_or(0.);
+    let extra_time = get_extra_time(stop, activity, &place).unwrap_or(0.)
+        + get_charging_time(ctx, tour, stop, activity)
+        + get_docking_time(ctx, stop, activity);
 
     match (&ctx.clustering, &activity.commute, domain_commute) {
         (_, _, Err(_)) | (_, None, Ok(Some(_))) | (_, Some(_), Ok(None)) | (&None, &Some(_), Ok(Some(_))) => true,
@@ -255,6 +275,30 @@ fn is_valid_job_info(
     }
 }
 
+/// Returns time spent to charge electric vehicle's battery at recharge activity.
+pub(crate) fn get_charging_time(ctx: &CheckerContext, tour: &Tour, stop: &PointStop, activity: &Activity) -> Float {
+    if activity.activity_type != "recharge" {
+        return 0.;
+    }
+
+    ctx.get_vehicle_shift(tour)
+        .ok()
+        .and_then(|shift| shift.recharges.and_then(|recharges| recharges.battery))
+        .zip(stop.charge.as_ref())
+        .map_or(0., |(battery, charge)| {
+            create_battery_model(&battery).get_charging_duration(charge.arrival, charge.departure)
+        })
+}
+
+/// Returns time spent to load vehicle at the depot loading dock during reload activity.
+pub(crate) fn get_docking_time(ctx: &CheckerContext, stop: &PointStop, activity: &Activity) -> Float {
+    if activity.activity_type != "reload" {
+        return 0.;
+    }
+
+    stop.dock.as_ref().and_then(|slot| ctx.get_dock_loading_duration(&slot.id, stop)).unwrap_or(0.)
+}
+
 fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
     let violations = ctx
         .solution
@@ -287,3 +331,105 @@ fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
         Err(format!("job groups are not respected: '{err_info}'").into())
     }
 }
+
+/// Checks that parts of split jobs sum up to the requested demand and respect split limits.
+fn check_split_jobs(ctx: &CheckerContext) -> GenericResult<()> {
+    let split_jobs = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .filter(|activity| matches!(activity.activity_type.as_str(), "pickup" | "delivery"))
+        .filter_map(|activity| {
+            ctx.get_job_by_id(&activity.job_id).filter(|job| job.split.is_some()).map(|job| (job, activity))
+        })
+        .fold(HashMap::<&str, (&Job, Vec<MultiDimLoad>)>::default(), |mut acc, (job, activity)| {
+            let part = activity.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+            acc.entry(job.id.as_str()).or_insert_with(|| (job, vec![])).1.push(part);
+            acc
+        });
+
+    split_jobs.into_iter().try_for_each(|(job_id, (job, parts))| {
+        let split = job.split.as_ref().expect("split job should have split property");
+        let expected = job
+            .pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .flatten()
+            .filter_map(|task| task.demand.clone())
+            .fold(MultiDimLoad::default(), |acc, demand| acc + MultiDimLoad::new(demand));
+        let actual = parts.iter().fold(MultiDimLoad::default(), |acc, part| acc + part.clone());
+
+        if expected != actual {
+            return Err(format!(
+                "parts of split job '{job_id}' do not sum up to its demand: expected {:?}, got {:?}",
+                expected.as_vec(),
+                actual.as_vec()
+            )
+            .into());
+        }
+
+        if split.max_splits.is_some_and(|max_splits| parts.len() > max_splits) {
+            return Err(
+                format!("split job '{job_id}' is served by {} visits which is more than allowed", parts.len()).into()
+            );
+        }
+
+        let min_size = split.min_size.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+        if parts.iter().any(|part| !part.can_fit(&min_size)) {
+            return Err(format!("split job '{job_id}' has a part which is smaller than its min size").into());
+        }
+
+        Ok(())
+    })
+}
+
+/// Checks that goods of transfer jobs are dropped at the hub and collected there after handling time.
+fn check_transfers(ctx: &CheckerContext) -> GenericResult<()> {
+    let transfers = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(move |stop| stop.activities().iter().map(move |activity| (tour, stop, activity)))
+        })
+        .filter(|(_, _, activity)| {
+            matches!(activity.activity_type.as_str(), "pickup" | "drop" | "collect" | "delivery")
+        })
+        .filter_map(|(tour, stop, activity)| {
+            let transfer = ctx.get_job_by_id(&activity.job_id).and_then(|job| job.transfer.as_ref())?;
+            Some((activity.job_id.as_str(), transfer, (tour, stop, activity)))
+        })
+        .fold(HashMap::<&str, (&JobTransfer, Vec<_>)>::default(), |mut acc, (job_id, transfer, visit)| {
+            acc.entry(job_id).or_insert_with(|| (transfer, vec![])).1.push(visit);
+            acc
+        });
+
+    transfers.into_iter().try_for_each(|(job_id, (transfer, visits))| {
+        let find = |activity_type: &str| visits.iter().find(|(_, _, activity)| activity.activity_type == activity_type);
+        let (Some(pickup), Some(drop), Some(collect), Some(delivery)) =
+            (find("pickup"), find("drop"), find("collect"), find("delivery"))
+        else {
+            return Err(format!("transfer job '{job_id}' is not served completely").into());
+        };
+
+        if visits.len() != 4 || !std::ptr::eq(pickup.0, drop.0) || !std::ptr::eq(collect.0, delivery.0) {
+            return Err(format!("transfer job '{job_id}' has invalid legs").into());
+        }
+
+        let is_at_hub = |(_, stop, activity): &&(&Tour, &Stop, &Activity)| {
+            ctx.get_activity_location(stop, activity).is_some_and(|location| location == transfer.location)
+        };
+        if !is_at_hub(&drop) || !is_at_hub(&collect) {
+            return Err(format!("transfer job '{job_id}' is not transferred at the hub").into());
+        }
+
+        let drop_end = ctx.get_activity_time(drop.1, drop.2).end;
+        let collect_start = ctx.get_activity_time(collect.1, collect.2).end - transfer.duration;
+        if drop_end + transfer.handling_time > collect_start {
+            return Err(format!("transfer job '{job_id}' is collected at the hub before its goods are handled").into());
+        }
+
+        Ok(())
+    })
+}
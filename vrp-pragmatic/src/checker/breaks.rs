@@ -10,7 +10,7 @@ use vrp_core::utils::GenericError;
 
 /// Checks that breaks are properly assigned.
 pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_break_assignment(context)])
+    combine_error_results(&[check_break_assignment(context), check_overnight_assignment(context)])
 }
 
 fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
@@ -20,7 +20,7 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
             .stops
             .iter()
             .flat_map(|stop| stop.activities().iter())
-            .filter(|activity| activity.activity_type == "break")
+            .filter(|activity| activity.activity_type == "break" && activity.job_id != "rest")
             .count();
         let matched_break_count = tour.stops.iter().try_fold(0, |acc, stop| {
             stop.activities()
@@ -191,6 +191,82 @@ pub(crate) fn get_break_time_window(tour: &Tour, vehicle_break: &VehicleBreak) -
     }
 }
 
+/// Checks that multi-day tours have overnight stops between their days and serve no jobs at night.
+fn check_overnight_assignment(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let Some(overnight) = vehicle.overnight.as_ref() else { return Ok(()) };
+
+        let tour_end = tour.stops.last().map_or(0., |stop| parse_time(&stop.schedule().arrival));
+        let overnights = tour
+            .stops
+            .iter()
+            .flat_map(|stop| {
+                stop.activities()
+                    .iter()
+                    .filter(|activity| activity.activity_type == "overnight")
+                    .map(move |activity| (stop, activity))
+            })
+            .map(|(stop, activity)| {
+                (context.get_activity_time(stop, activity), context.get_activity_location(stop, activity))
+            })
+            .collect::<Vec<_>>();
+
+        if overnights.len() >= vehicle.shifts.len() {
+            return Err(format!("too many overnight stops in tour '{}'", tour.vehicle_id).into());
+        }
+
+        let has_violation = context.solution.violations.as_ref().is_some_and(|violations| {
+            violations.iter().any(|violation| match violation {
+                Violation::Overnight { vehicle_id, shift_index } => {
+                    *vehicle_id == tour.vehicle_id && *shift_index == tour.shift_index
+                }
+                _ => false,
+            })
+        });
+
+        vehicle.shifts.windows(2).try_for_each(|shifts| {
+            let night_end = parse_time(&shifts[1].start.earliest);
+            let night_start = shifts[0].end.as_ref().map_or(night_end, |end| parse_time(&end.latest));
+
+            let night = shifts[0].end.as_ref().map_or("", |end| end.latest.as_str());
+
+            let served_at_night = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+                .filter(|(_, activity)| activity.activity_type != "overnight")
+                .find(|(stop, activity)| {
+                    let time = context.get_activity_time(stop, activity);
+                    time.start < night_end && time.end > night_start
+                });
+            if let Some((_, activity)) = served_at_night {
+                return Err(format!(
+                    "activity '{}' of tour '{}' is served between shifts, night starts at {night}",
+                    activity.job_id, tour.vehicle_id
+                )
+                .into());
+            }
+
+            let stay = overnights.iter().find(|(time, _)| time.start <= night_start && time.end >= night_end);
+
+            match (stay, overnight.locations.as_ref()) {
+                (Some((_, location)), Some(locations))
+                    if !location.as_ref().is_some_and(|location| locations.contains(location)) =>
+                {
+                    Err(format!("overnight stop of tour '{}' is not at allowed location", tour.vehicle_id).into())
+                }
+                (None, _) if tour_end > night_start && !has_violation => Err(format!(
+                    "overnight stop is missing in tour '{}', night starts at {night}",
+                    tour.vehicle_id
+                )
+                .into()),
+                _ => Ok(()),
+            }
+        })
+    })
+}
+
 fn get_break_violation_count(solution: &Solution, tour: &Tour) -> usize {
     solution.violations.as_ref().map_or(0, |violations| {
         violations
@@ -4,6 +4,7 @@ mod capacity_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use std::collections::VecDeque;
 use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 use vrp_core::prelude::GenericResult;
@@ -11,13 +12,24 @@ use vrp_core::prelude::GenericResult;
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
 /// * load change is correct
+/// * compartments are used correctly
+/// * loading policy is followed
+/// * trailer is used correctly
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
+    combine_error_results(&[
+        check_vehicle_load_assignment(context),
+        check_resource_consumption(context),
+        check_compartments(context),
+        check_loading_policy(context),
+        check_trailers(context),
+    ])
 }
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let trailer_capacity = context.get_trailer(&tour.vehicle_id)?.map(|trailer| trailer.capacity.clone());
+        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone())
+            + MultiDimLoad::new(trailer_capacity.unwrap_or_default());
         let intervals = get_intervals(context, tour);
 
         intervals
@@ -32,7 +44,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()>
                         Ok(match demand {
                             (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
                             (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
-                            (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand, acc.1 + demand),
+                            (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand.clone(), acc.1 + demand),
                             _ => acc,
                         })
                     },
@@ -53,7 +65,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()>
                                 let activity_type = context.get_activity_type(tour, to, activity)?;
                                 let (demand_type, demand) =
                                     if activity.activity_type == "arrival" || activity.activity_type == "reload" {
-                                        (DemandType::StaticDelivery, end_pickup)
+                                        (DemandType::StaticDelivery, end_pickup.clone())
                                     } else {
                                         get_demand(context, activity, &activity_type)?
                                     };
@@ -95,8 +107,10 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
         .resources
         .iter()
         .flat_map(|resources| resources.iter().cloned())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id, MultiDimLoad::new(capacity)),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id, MultiDimLoad::new(capacity))),
+            VehicleResource::Charger { .. } => None,
+            VehicleResource::Dock { .. } => None,
         })
         .collect::<HashMap<_, _>>();
 
@@ -136,17 +150,17 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
         })
         .fold(HashMap::default(), |mut acc, (resource_id, consumption)| {
             let entry = acc.entry(resource_id).or_default();
-            *entry = *entry + consumption;
+            *entry = entry.clone() + consumption;
 
             acc
         });
 
     consumption.into_iter().try_for_each(|(resource_id, consumed)| {
-        let available = *resources.get(&resource_id).ok_or_else(|| {
+        let available = resources.get(&resource_id).ok_or_else(|| {
             GenericError::from(format!("cannot find resource '{resource_id}' in list of available resources"))
         })?;
 
-        if consumed > available {
+        if consumed > *available {
             Err(GenericError::from(format!(
                 "consumed more resource '{resource_id}' than available: {consumed} vs {available}"
             )))
@@ -156,6 +170,170 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+/// Checks that vehicle compartments are used correctly:
+/// * compartment keeps only allowed product type which is not changed within a trip
+/// * compartment load does not exceed its capacity
+/// * product of each served job is assigned to some compartment
+fn check_compartments(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let Some(compartments) = vehicle.compartments.as_ref() else { return Ok(()) };
+
+        let mut trip_products: HashMap<&String, Option<&String>> = HashMap::default();
+
+        tour.stops.iter().filter_map(|stop| stop.as_point()).try_for_each::<_, GenericResult<_>>(|stop| {
+            if stop.activities.iter().any(|activity| activity.activity_type == "reload") {
+                trip_products.clear();
+            }
+
+            let stop_compartments = stop.compartments.as_ref().ok_or_else(|| {
+                GenericError::from(format!("compartments are not reported in tour '{}'", tour.vehicle_id))
+            })?;
+
+            stop_compartments.iter().try_for_each::<_, GenericResult<_>>(|stop_compartment| {
+                let compartment = compartments.iter().find(|c| c.id == stop_compartment.id).ok_or_else(|| {
+                    format!("unknown compartment '{}' in tour '{}'", stop_compartment.id, tour.vehicle_id)
+                })?;
+
+                if let Some(product) = stop_compartment.product.as_ref() {
+                    let is_allowed = compartment.products.as_ref().is_none_or(|products| products.contains(product));
+                    if !is_allowed {
+                        return Err(format!(
+                            "product '{product}' is not allowed in compartment '{}' in tour '{}'",
+                            compartment.id, tour.vehicle_id
+                        )
+                        .into());
+                    }
+                }
+
+                if *trip_products.entry(&stop_compartment.id).or_insert(stop_compartment.product.as_ref())
+                    != stop_compartment.product.as_ref()
+                {
+                    return Err(format!(
+                        "product of compartment '{}' is changed within a trip in tour '{}'",
+                        compartment.id, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                let capacity = MultiDimLoad::new(compartment.capacity.clone());
+                if !capacity.can_fit(&MultiDimLoad::new(stop_compartment.load.clone())) {
+                    return Err(format!(
+                        "load exceeds capacity of compartment '{}' in tour '{}'",
+                        compartment.id, tour.vehicle_id
+                    )
+                    .into());
+                }
+
+                Ok(())
+            })?;
+
+            // NOTE service jobs have no demand, so their product does not occupy a compartment
+            stop.activities
+                .iter()
+                .filter(|activity| activity.activity_type != "service")
+                .filter_map(|activity| context.get_job_by_id(&activity.job_id))
+                .filter_map(|job| job.product.as_ref())
+                .try_for_each(|product| {
+                    if stop_compartments.iter().any(|c| c.product.as_ref() == Some(product)) {
+                        Ok(())
+                    } else {
+                        Err(format!("product '{product}' has no compartment assigned in tour '{}'", tour.vehicle_id)
+                            .into())
+                    }
+                })
+        })
+    })
+}
+
+fn check_loading_policy(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let Some(policy) = context.get_vehicle(&tour.vehicle_id)?.loading_policy else { return Ok(()) };
+
+        // NOTE only jobs with both pickups and deliveries are affected
+        let mut remaining = HashMap::<&str, usize>::default();
+        let mut goods = VecDeque::<&str>::default();
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .filter_map(|activity| {
+                let job = context.get_job_by_id(&activity.job_id)?;
+                let deliveries = job.pickups.as_ref().and(job.deliveries.as_ref())?;
+
+                match activity.activity_type.as_str() {
+                    "pickup" | "collect" => Some((job.id.as_str(), true, deliveries.len())),
+                    "delivery" | "drop" => Some((job.id.as_str(), false, deliveries.len())),
+                    _ => None,
+                }
+            })
+            .try_for_each(|(job_id, is_pickup, deliveries)| {
+                if is_pickup {
+                    goods.push_back(job_id);
+                    return Ok(());
+                }
+
+                let accessible = match policy {
+                    VehicleLoadingPolicy::Lifo => goods.iter().rev().take_while(|other| **other == job_id).count(),
+                    VehicleLoadingPolicy::Fifo => goods.iter().take_while(|other| **other == job_id).count(),
+                };
+                let remaining = remaining.entry(job_id).or_insert(deliveries);
+                *remaining = remaining.saturating_sub(1);
+
+                let is_last = *remaining == 0;
+                let loaded = goods.iter().filter(|other| **other == job_id).count();
+
+                if accessible == 0 || (is_last && accessible != loaded) {
+                    return Err(
+                        format!("loading policy is violated for job '{job_id}' in tour '{}'", tour.vehicle_id).into()
+                    );
+                }
+
+                if is_last {
+                    match policy {
+                        VehicleLoadingPolicy::Lifo => goods.truncate(goods.len() - accessible),
+                        VehicleLoadingPolicy::Fifo => drop(goods.drain(..accessible)),
+                    }
+                }
+
+                Ok(())
+            })
+    })
+}
+
+/// Checks that trailer is decoupled before it is coupled back, it is attached at the end of the tour
+/// and jobs which are not allowed with the trailer are served only while it is parked.
+fn check_trailers(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let has_trailer = context.get_trailer(&tour.vehicle_id)?.is_some();
+        let vehicle_id = tour.vehicle_id.as_str();
+
+        let mut activities = tour.stops.iter().flat_map(|stop| stop.activities().iter());
+
+        let is_coupled = activities.try_fold::<_, _, GenericResult<_>>(true, |is_coupled, activity| {
+            let activity_type = activity.activity_type.as_str();
+            match (activity_type, is_coupled) {
+                ("decouple", true) => Ok(false),
+                ("couple", false) => Ok(true),
+                ("decouple", false) | ("couple", true) => {
+                    Err(format!("unexpected trailer operation '{activity_type}' in tour '{vehicle_id}'").into())
+                }
+                _ => {
+                    let is_allowed = context.get_job_by_id(&activity.job_id).and_then(|job| job.trailer_allowed);
+                    if has_trailer && is_coupled && is_allowed == Some(false) {
+                        let job_id = &activity.job_id;
+                        return Err(format!("job '{job_id}' is served with trailer in tour '{vehicle_id}'").into());
+                    }
+
+                    Ok(is_coupled)
+                }
+            }
+        })?;
+
+        if is_coupled { Ok(()) } else { Err(format!("trailer is not coupled back in tour '{vehicle_id}'").into()) }
+    })
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -176,7 +354,12 @@ fn get_demand(
         |job, task| {
             let is_dynamic = job.pickups.as_ref().is_some_and(|p| !p.is_empty())
                 && job.deliveries.as_ref().is_some_and(|p| !p.is_empty());
-            let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+            // NOTE split job activity serves only a part of the task demand
+            let demand = activity
+                .demand
+                .clone()
+                .or_else(|| task.demand.clone())
+                .map_or_else(MultiDimLoad::default, MultiDimLoad::new);
 
             (is_dynamic, demand)
         },
@@ -185,8 +368,8 @@ fn get_demand(
 
     let demand_type = match (is_dynamic, activity.activity_type.as_ref()) {
         (_, "replacement") => DemandType::StaticPickupDelivery,
-        (true, "pickup") => DemandType::DynamicPickup,
-        (true, "delivery") => DemandType::DynamicDelivery,
+        (true, "pickup" | "collect") => DemandType::DynamicPickup,
+        (true, "delivery" | "drop") => DemandType::DynamicDelivery,
         (false, "pickup") => DemandType::StaticPickup,
         (false, "delivery") => DemandType::StaticDelivery,
         _ => DemandType::None,
@@ -0,0 +1,432 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/jobs_test.rs"]
+mod jobs_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+use vrp_core::models::common::Timestamp;
+use vrp_core::prelude::GenericResult;
+
+/// Checks job level rules: skills, compatibility, same assignee, affinity, sync, sequence, max ride time
+/// and soft time windows.
+pub fn check_jobs(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[
+        check_skills(context),
+        check_compatibility(context),
+        check_same_assignee(context),
+        check_affinity(context),
+        check_sync(context),
+        check_sequence(context),
+        check_max_ride_time(context),
+        check_soft_time_windows(context),
+    ])
+}
+
+/// Keeps information about the first visit of the job in the solution.
+struct JobVisit<'a> {
+    job: &'a Job,
+    tour: &'a Tour,
+    /// Activity's service time.
+    time: TimeWindow,
+}
+
+const DAY_DURATION: Duration = 24. * 3600.;
+
+/// NOTE time is rounded to seconds in the solution.
+const RIDE_TIME_TOLERANCE: Duration = 1.;
+
+/// NOTE time is rounded to seconds in the solution.
+const SOFT_TIME_TOLERANCE: Duration = 1.;
+
+/// NOTE the same defaults are used by the corresponding features.
+const DEFAULT_SYNC_TOLERANCE: Duration = 900.;
+const DEFAULT_AFFINITY_TOLERANCE: Duration = 4. * 3600.;
+const DEFAULT_SEQUENCE_TOLERANCE_DAYS: Float = 0.25;
+
+fn check_skills(context: &CheckerContext) -> GenericResult<()> {
+    get_job_visits(context).iter().try_for_each(|visit| {
+        let Some(skills) = visit.job.skills.as_ref() else { return Ok(()) };
+
+        let vehicle_skills = context.get_vehicle(&visit.tour.vehicle_id)?.skills.as_ref();
+        let has_skill = |skill: &String| vehicle_skills.is_some_and(|vehicle_skills| vehicle_skills.contains(skill));
+
+        let is_all_of = skills.all_of.as_ref().is_none_or(|all_of| all_of.iter().all(has_skill));
+        let is_one_of = skills.one_of.as_ref().is_none_or(|one_of| one_of.is_empty() || one_of.iter().any(has_skill));
+        let is_none_of = skills.none_of.as_ref().is_none_or(|none_of| !none_of.iter().any(has_skill));
+
+        if is_all_of && is_one_of && is_none_of {
+            Ok(())
+        } else {
+            Err(format!(
+                "skills violation for job '{}': vehicle '{}' has skills {:?}",
+                visit.job.id, visit.tour.vehicle_id, vehicle_skills
+            )
+            .into())
+        }
+    })
+}
+
+fn check_compatibility(context: &CheckerContext) -> GenericResult<()> {
+    let visits = get_job_visits(context);
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        let compatibilities = visits
+            .iter()
+            .filter(|visit| std::ptr::eq(visit.tour, tour))
+            .filter_map(|visit| visit.job.compatibility.as_ref())
+            .collect::<HashSet<_>>();
+
+        if compatibilities.len() > 1 {
+            Err(format!(
+                "compatibility violation in tour of vehicle '{}', shift index {}: {:?}",
+                tour.vehicle_id, tour.shift_index, compatibilities
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn check_same_assignee(context: &CheckerContext) -> GenericResult<()> {
+    group_visits(get_job_visits(context), |job| job.same_assignee_key.clone()).into_iter().try_for_each(
+        |(key, visits)| {
+            let vehicle_ids = visits.iter().map(|visit| visit.tour.vehicle_id.as_str()).collect::<HashSet<_>>();
+
+            if vehicle_ids.len() > 1 {
+                Err(format!("same assignee '{key}' violation: jobs are served by vehicles {vehicle_ids:?}").into())
+            } else {
+                Ok(())
+            }
+        },
+    )
+}
+
+fn check_affinity(context: &CheckerContext) -> GenericResult<()> {
+    let get_affinity = |job: &Job| job.affinity.as_ref().map(|affinity| affinity.key.clone());
+
+    group_visits(get_job_visits(context), get_affinity).into_iter().try_for_each(|(key, mut visits)| {
+        let vehicle_ids = visits.iter().map(|visit| visit.tour.vehicle_id.as_str()).collect::<HashSet<_>>();
+        if vehicle_ids.len() > 1 {
+            return Err(format!("affinity '{key}' violation: jobs are served by vehicles {vehicle_ids:?}").into());
+        }
+
+        visits.sort_by_key(|visit| visit.job.affinity.as_ref().map(|affinity| affinity.sequence));
+        let affinities = visits.iter().filter_map(|visit| visit.job.affinity.as_ref()).collect::<Vec<_>>();
+
+        let has_invalid_sequence = affinities.iter().any(|affinity| affinity.sequence >= affinity.duration_days);
+        let has_duplicates = affinities.windows(2).any(|pair| pair[0].sequence == pair[1].sequence);
+        let has_different_durations = affinities.windows(2).any(|pair| pair[0].duration_days != pair[1].duration_days);
+        if has_invalid_sequence || has_duplicates || has_different_durations {
+            return Err(format!("affinity '{key}' violation: invalid sequences assigned").into());
+        }
+
+        // NOTE consecutive days are validated using job time windows relative to the lowest sequence
+        let scheduled = visits
+            .iter()
+            .filter_map(|visit| {
+                let sequence = visit.job.affinity.as_ref()?.sequence as Float;
+                let (start, end) = get_job_time_window(visit.job)?;
+                let day_duration = if end > start { end - start } else { DAY_DURATION };
+
+                Some((visit.job, start, sequence * day_duration))
+            })
+            .collect::<Vec<_>>();
+
+        let Some(base) = scheduled.first().map(|(_, start, offset)| start - offset) else { return Ok(()) };
+
+        scheduled.iter().try_for_each(|(job, start, offset)| {
+            if (start - (base + offset)).abs() > DEFAULT_AFFINITY_TOLERANCE {
+                Err(format!("affinity '{key}' violation: job '{}' is not scheduled on consecutive day", job.id).into())
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+fn check_sync(context: &CheckerContext) -> GenericResult<()> {
+    let get_sync_key = |job: &Job| job.sync.as_ref().map(|sync| sync.key.clone());
+    let visits = group_visits(get_job_visits(context), get_sync_key);
+
+    visits.into_iter().try_for_each(|(key, visits)| {
+        let required = context
+            .problem
+            .plan
+            .jobs
+            .iter()
+            .filter_map(|job| job.sync.as_ref())
+            .filter(|sync| sync.key == key)
+            .map(|sync| sync.vehicles_required as usize)
+            .max()
+            .unwrap_or_default();
+
+        if visits.len() != required {
+            return Err(format!(
+                "sync '{key}' violation: expected all {required} jobs to be assigned, got {}",
+                visits.len()
+            )
+            .into());
+        }
+
+        let vehicle_ids = visits.iter().map(|visit| visit.tour.vehicle_id.as_str()).collect::<HashSet<_>>();
+        if vehicle_ids.len() != visits.len() {
+            return Err(format!("sync '{key}' violation: jobs have to be served by distinct vehicles").into());
+        }
+
+        let indices = visits.iter().filter_map(|visit| visit.job.sync.as_ref()).map(|sync| sync.index);
+        if indices.collect::<HashSet<_>>().len() != visits.len() {
+            return Err(format!("sync '{key}' violation: jobs have duplicated sync indices").into());
+        }
+
+        let tolerance = visits
+            .iter()
+            .filter_map(|visit| visit.job.sync.as_ref().map(|sync| sync.tolerance.unwrap_or(DEFAULT_SYNC_TOLERANCE)))
+            .fold(Float::MAX, |acc, tolerance| acc.min(tolerance));
+        let (earliest, latest) = visits
+            .iter()
+            .fold((Float::MAX, Float::MIN), |(earliest, latest), visit| {
+                (earliest.min(visit.time.start), latest.max(visit.time.start))
+            });
+
+        if latest - earliest > tolerance {
+            Err(format!(
+                "sync '{key}' violation: service start difference {} exceeds tolerance {tolerance}",
+                latest - earliest
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+fn check_sequence(context: &CheckerContext) -> GenericResult<()> {
+    let get_sequence_key = |job: &Job| job.sequence.as_ref().map(|sequence| sequence.key.clone());
+
+    group_visits(get_job_visits(context), get_sequence_key).into_iter().try_for_each(|(key, mut visits)| {
+        visits.sort_by_key(|visit| visit.job.sequence.as_ref().map(|sequence| sequence.order));
+
+        // NOTE strict ordering: the sequence can be served only from its first job without gaps
+        let has_gaps = visits
+            .iter()
+            .enumerate()
+            .any(|(idx, visit)| visit.job.sequence.as_ref().is_none_or(|sequence| sequence.order as usize != idx));
+        if has_gaps {
+            return Err(format!("sequence '{key}' violation: jobs are not assigned in strict order").into());
+        }
+
+        visits.windows(2).try_for_each(|pair| {
+            let (prev, next) = (&pair[0], &pair[1]);
+            let sequence = next.job.sequence.as_ref().expect("sequence");
+            let (min_gap, max_gap) = (sequence.days_between_min.unwrap_or(1), sequence.days_between_max.unwrap_or(1));
+
+            let is_valid = if prev.tour.vehicle_id == next.tour.vehicle_id {
+                let shift_gap = next.tour.shift_index.saturating_sub(prev.tour.shift_index);
+                shift_gap >= min_gap as usize && shift_gap <= max_gap as usize
+            } else {
+                let time_gap_days =
+                    (get_sequence_time(context, next)? - get_sequence_time(context, prev)?) / DAY_DURATION;
+                time_gap_days >= min_gap as Float - DEFAULT_SEQUENCE_TOLERANCE_DAYS
+                    && time_gap_days <= max_gap as Float + DEFAULT_SEQUENCE_TOLERANCE_DAYS
+            };
+
+            if is_valid {
+                Ok(())
+            } else {
+                Err(format!(
+                    "sequence '{key}' violation: gap between jobs '{}' and '{}' is not in [{min_gap}, {max_gap}]",
+                    prev.job.id, next.job.id
+                )
+                .into())
+            }
+        })
+    })
+}
+
+fn check_max_ride_time(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let mut rides = HashMap::<&str, Vec<(&Stop, &Activity)>>::default();
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| matches!(activity.activity_type.as_str(), "pickup" | "delivery"))
+            .filter(|(_, activity)| {
+                context.get_job_by_id(&activity.job_id).is_some_and(|job| job.max_ride_time.is_some())
+            })
+            .for_each(|(stop, activity)| rides.entry(activity.job_id.as_str()).or_default().push((stop, activity)));
+
+        rides.into_iter().try_for_each(|(job_id, activities)| {
+            let job = context.get_job_by_id(job_id).expect("job should be present");
+            let max_ride_time = job.max_ride_time.as_ref().expect("max ride time should be present");
+            let (Some(&(first_stop, first)), Some(&(last_stop, last))) = (activities.first(), activities.last()) else {
+                return Ok(());
+            };
+
+            let departure = context.get_activity_time(first_stop, first).end;
+            let ride_time = context.get_activity_time(last_stop, last).start - departure;
+
+            let limit = match max_ride_time {
+                JobMaxRideTime::Duration(duration) => *duration,
+                JobMaxRideTime::Factor(factor) => {
+                    let get_location_index = |stop: &Stop, activity: &Activity| {
+                        context
+                            .get_activity_location(stop, activity)
+                            .ok_or_else(|| GenericError::from(format!("cannot get location of job '{job_id}'")))
+                            .and_then(|location| context.get_location_index(&location))
+                    };
+                    let profile = context.get_vehicle_profile(&tour.vehicle_id)?;
+                    let (_, direct) = context.get_matrix_data(
+                        &profile,
+                        get_location_index(first_stop, first)?,
+                        get_location_index(last_stop, last)?,
+                    )?;
+
+                    direct as Float * factor
+                }
+            };
+
+            if ride_time > limit + RIDE_TIME_TOLERANCE {
+                Err(format!(
+                    "max ride time violation for job '{job_id}' in tour '{}': ride time {ride_time} exceeds {limit}",
+                    tour.vehicle_id
+                )
+                .into())
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+fn check_soft_time_windows(context: &CheckerContext) -> GenericResult<()> {
+    context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .try_for_each(|(stop, activity)| {
+            let Some(job) = context.get_job_by_id(&activity.job_id) else { return Ok(()) };
+            let tasks = match activity.activity_type.as_str() {
+                "pickup" => job.pickups.as_ref(),
+                "delivery" => job.deliveries.as_ref(),
+                "service" => job.services.as_ref(),
+                "replacement" => job.replacements.as_ref(),
+                _ => None,
+            };
+            let places = tasks
+                .into_iter()
+                .flatten()
+                .flat_map(|task| task.places.iter())
+                .filter(|place| activity.job_tag.is_none() || place.tag == activity.job_tag)
+                .filter_map(|place| place.times.as_ref().zip(place.soft_times.as_ref()))
+                .collect::<Vec<_>>();
+
+            if places.is_empty() {
+                return Ok(());
+            }
+
+            let arrival = context.get_activity_time(stop, activity).start;
+            let reported = (activity.earliness.unwrap_or(0) as Float, activity.lateness.unwrap_or(0) as Float);
+
+            let is_valid = places.into_iter().any(|(times, soft)| {
+                let (max_earliness, max_lateness) =
+                    (soft.max_earliness.unwrap_or_default(), soft.max_lateness.unwrap_or_default());
+
+                // NOTE service starts immediately on arrival within allowed earliness
+                let deviation = times.iter().map(|tw| parse_time_window(tw)).find_map(|tw| {
+                    if arrival > tw.end + max_lateness + SOFT_TIME_TOLERANCE {
+                        return None;
+                    }
+
+                    let start = arrival.max(tw.start - max_earliness);
+                    Some(((tw.start - start).max(0.), (start - tw.end).max(0.)))
+                });
+
+                deviation.is_some_and(|(earliness, lateness)| {
+                    earliness <= max_earliness + SOFT_TIME_TOLERANCE
+                        && lateness <= max_lateness + SOFT_TIME_TOLERANCE
+                        && (earliness - reported.0).abs() <= SOFT_TIME_TOLERANCE
+                        && (lateness - reported.1).abs() <= SOFT_TIME_TOLERANCE
+                })
+            });
+
+            if is_valid {
+                Ok(())
+            } else {
+                Err(format!(
+                    "soft time window violation for job '{}': earliness {} or lateness {} is not within limits",
+                    job.id, reported.0, reported.1
+                )
+                .into())
+            }
+        })
+}
+
+fn get_job_visits(context: &CheckerContext) -> Vec<JobVisit<'_>> {
+    let mut visited = HashSet::new();
+
+    context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().flat_map(move |stop| stop.activities().iter().map(move |activity| (tour, stop, activity)))
+        })
+        .filter(|(_, _, activity)| {
+            matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+        })
+        .filter_map(|(tour, stop, activity)| {
+            context.job_map.get(activity.job_id.as_str()).map(|job| JobVisit {
+                job,
+                tour,
+                time: context.get_activity_time(stop, activity),
+            })
+        })
+        .filter(|visit| visited.insert(visit.job.id.clone()))
+        .collect()
+}
+
+fn group_visits<'a>(
+    visits: Vec<JobVisit<'a>>,
+    key_fn: impl Fn(&Job) -> Option<String>,
+) -> HashMap<String, Vec<JobVisit<'a>>> {
+    visits.into_iter().fold(HashMap::new(), |mut acc, visit| {
+        if let Some(key) = key_fn(visit.job) {
+            acc.entry(key).or_default().push(visit);
+        }
+
+        acc
+    })
+}
+
+/// Returns the first time window of the job's first place.
+fn get_job_time_window(job: &Job) -> Option<(Timestamp, Timestamp)> {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .flat_map(|task| task.places.first())
+        .next()
+        .and_then(|place| place.times.as_ref())
+        .and_then(|times| times.first())
+        .map(|time| {
+            let time = parse_time_window(time);
+            (time.start, time.end)
+        })
+}
+
+/// Returns time used to validate sequence gaps: job's time window start or shift start as a fallback.
+fn get_sequence_time(context: &CheckerContext, visit: &JobVisit) -> GenericResult<Timestamp> {
+    if let Some((start, _)) = get_job_time_window(visit.job) {
+        return Ok(start);
+    }
+
+    context
+        .get_vehicle(&visit.tour.vehicle_id)?
+        .shifts
+        .get(visit.tour.shift_index)
+        .map(|shift| parse_time(&shift.start.earliest))
+        .ok_or_else(|| format!("cannot find shift for vehicle '{}'", visit.tour.vehicle_id).into())
+}
@@ -3,13 +3,21 @@
 mod limits_test;
 
 use super::*;
+use crate::checker::assignment::get_charging_time;
 use crate::utils::combine_error_results;
 use vrp_core::models::common::Distance;
 use vrp_core::prelude::GenericResult;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_recharge_limits(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_shift_time(context),
+        check_recharge_limits(context),
+        check_charger_resources(context),
+        check_dock_resources(context),
+        check_hours_of_service_limits(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
@@ -77,8 +85,7 @@ fn check_shift_time(context: &CheckerContext) -> GenericResult<()> {
         let departure = parse_time(&start.schedule().departure);
         let arrival = parse_time(&end.schedule().arrival);
 
-        let has_match = vehicle
-            .shifts
+        let has_match = get_tour_shifts(vehicle)
             .iter()
             .map(|shift| {
                 let start = parse_time(&shift.start.earliest);
@@ -111,30 +118,327 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
             return Ok(());
         }
 
-        stops
-            .windows(2)
-            .try_fold(Distance::default(), |acc, stops| {
+        match (recharge.battery.as_ref(), recharge.max_distance) {
+            (Some(battery), _) => check_battery_charge(tour, stops.as_slice(), battery),
+            (None, Some(max_distance)) => check_recharge_distance(
+                tour,
+                stops.as_slice(),
+                max_distance,
+                recharge.initial_distance.unwrap_or_default(),
+            ),
+            (None, None) => Ok(()),
+        }
+    })
+}
+
+fn check_recharge_distance(
+    tour: &Tour,
+    stops: &[&PointStop],
+    max_distance: Float,
+    initial_distance: Float,
+) -> GenericResult<()> {
+    stops
+        .windows(2)
+        .try_fold(initial_distance, |acc, stops| {
+            let (prev, next) = match stops {
+                [prev, next] => (prev, next),
+                _ => unreachable!(),
+            };
+
+            let delta = (next.distance - prev.distance) as Distance;
+            let total_distance = acc + delta;
+
+            if total_distance > max_distance {
+                return Err(format!(
+                    "recharge distance violation: expected limit is {}, got {}, vehicle id '{}', shift index: {}",
+                    max_distance, total_distance, tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+
+            let has_recharge = next.activities.iter().any(|activity| activity.activity_type == "recharge");
+
+            Ok(if has_recharge { Distance::default() } else { total_distance })
+        })
+        .map(|_| ())
+}
+
+/// Checks that reported battery charge levels are consistent with consumption and never drop below reserve.
+fn check_battery_charge(tour: &Tour, stops: &[&PointStop], battery: &VehicleBattery) -> GenericResult<()> {
+    const TOLERANCE: Float = 1E-6;
+
+    let battery = create_battery_model(battery);
+    let legs = get_charge_legs(stops.iter().copied(), &battery);
+    let get_error = |stop_idx: usize, message: String| -> GenericError {
+        format!(
+            "battery charge violation: {message} at stop {stop_idx}, vehicle id '{}', shift index: {}",
+            tour.vehicle_id, tour.shift_index
+        )
+        .into()
+    };
+
+    stops
+        .iter()
+        .zip(legs)
+        .enumerate()
+        .try_fold(battery.initial_charge, |level, (stop_idx, (stop, (energy, is_recharge)))| {
+            let charge = stop.charge.as_ref().ok_or_else(|| get_error(stop_idx, "no charge level".to_string()))?;
+
+            let expected_arrival = level - energy;
+            if (charge.arrival - expected_arrival).abs() > TOLERANCE {
+                return Err(get_error(
+                    stop_idx,
+                    format!("expected arrival level is {expected_arrival}, got {}", charge.arrival),
+                ));
+            }
+
+            if charge.arrival < battery.reserve - TOLERANCE {
+                return Err(get_error(
+                    stop_idx,
+                    format!("level {} is below reserve {}", charge.arrival, battery.reserve),
+                ));
+            }
+
+            let is_departure_valid = if is_recharge {
+                charge.departure >= charge.arrival && charge.departure <= battery.capacity + TOLERANCE
+            } else {
+                (charge.departure - charge.arrival).abs() <= TOLERANCE
+            };
+
+            if !is_departure_valid {
+                return Err(get_error(
+                    stop_idx,
+                    format!("unexpected departure level {}, arrival level is {}", charge.departure, charge.arrival),
+                ));
+            }
+
+            Ok(charge.departure)
+        })
+        .map(|_| ())
+}
+
+/// Checks that amount of vehicles charged at the same time at stations with a shared charger resource
+/// does not exceed amount of chargers.
+fn check_charger_resources(context: &CheckerContext) -> GenericResult<()> {
+    let capacities = context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Charger { id, capacity } => Some((id.clone(), *capacity)),
+            VehicleResource::Reload { .. } => None,
+            VehicleResource::Dock { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let sessions = context.solution.tours.iter().flat_map(|tour| {
+        tour.stops.iter().flat_map(move |stop| {
+            stop.activities().iter().filter_map(move |activity| {
+                let Ok(ActivityType::Recharge(station)) = context.get_activity_type(tour, stop, activity) else {
+                    return None;
+                };
+                let resource_id = station.resource_id?;
+                let charging_time =
+                    stop.as_point().map_or(0., |point| get_charging_time(context, tour, point, activity));
+                let end = context.get_activity_time(stop, activity).end;
+
+                Some((resource_id, TimeWindow::new(end - station.duration - charging_time, end)))
+            })
+        })
+    });
+
+    let sessions = sessions.fold(HashMap::<_, Vec<_>>::default(), |mut acc, (resource_id, session)| {
+        acc.entry(resource_id).or_default().push(session);
+        acc
+    });
+
+    sessions.into_iter().try_for_each(|(resource_id, sessions)| {
+        let capacity = *capacities.get(&resource_id).ok_or_else(|| {
+            GenericError::from(format!("cannot find charger resource '{resource_id}' in list of available resources"))
+        })?;
+
+        let max_overlaps = sessions
+            .iter()
+            .map(|session| sessions.iter().filter(|other| other.start <= session.start && session.start < other.end))
+            .map(|overlaps| overlaps.count())
+            .max()
+            .unwrap_or_default();
+
+        if max_overlaps > capacity {
+            Err(format!("charger resource '{resource_id}' is used by {max_overlaps} vehicles, capacity is {capacity}")
+                .into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Checks that loading dock slots are consistent with dock definitions:
+/// * slot is at the dock location and within dock opening hours
+/// * slot is long enough to load the vehicle
+/// * amount of vehicles loaded at the same time does not exceed dock capacity
+fn check_dock_resources(context: &CheckerContext) -> GenericResult<()> {
+    let docks = context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, location, capacity, times, .. } => {
+                let times = times
+                    .as_ref()
+                    .map(|times| times.iter().map(|tw| parse_time_window(tw)).collect::<Vec<_>>())
+                    .unwrap_or_else(|| vec![TimeWindow::max()]);
+                Some((id.clone(), (context.coord_index.get_by_loc(location), *capacity, times)))
+            }
+            VehicleResource::Reload { .. } | VehicleResource::Charger { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let sessions = context.solution.tours.iter().try_fold(HashMap::<_, Vec<_>>::default(), |mut acc, tour| {
+        tour.stops.iter().filter_map(|stop| stop.as_point()).try_for_each::<_, GenericResult<_>>(|stop| {
+            let Some(slot) = stop.dock.as_ref() else { return Ok(()) };
+
+            let (location, _, times) = docks.get(&slot.id).ok_or_else(|| {
+                GenericError::from(format!("cannot find dock resource '{}' in list of available resources", slot.id))
+            })?;
+
+            if context.coord_index.get_by_loc(&stop.location) != *location {
+                return Err(
+                    format!("dock '{}' is used at wrong location, vehicle id '{}'", slot.id, tour.vehicle_id).into()
+                );
+            }
+
+            let session = TimeWindow::new(parse_time(&slot.start), parse_time(&slot.end));
+            let loading_duration = context.get_dock_loading_duration(&slot.id, stop).unwrap_or_default();
+            if session.duration() + 1. < loading_duration {
+                return Err(format!(
+                    "dock '{}' slot is too short, expected: not less than {}, got: {}, vehicle id '{}'",
+                    slot.id,
+                    loading_duration,
+                    session.duration(),
+                    tour.vehicle_id
+                )
+                .into());
+            }
+
+            if !times.iter().any(|time| time.contains(session.start) && time.contains(session.end)) {
+                return Err(format!(
+                    "dock '{}' is used outside its opening hours, vehicle id '{}'",
+                    slot.id, tour.vehicle_id
+                )
+                .into());
+            }
+
+            acc.entry(slot.id.clone()).or_default().push(session);
+
+            Ok(())
+        })?;
+
+        Ok::<_, GenericError>(acc)
+    })?;
+
+    sessions.into_iter().try_for_each(|(dock_id, sessions)| {
+        let capacity = docks.get(&dock_id).map_or(0, |(_, capacity, _)| *capacity);
+
+        let max_overlaps = sessions
+            .iter()
+            .map(|session| sessions.iter().filter(|other| other.start <= session.start && session.start < other.end))
+            .map(|overlaps| overlaps.count())
+            .max()
+            .unwrap_or_default();
+
+        if max_overlaps > capacity {
+            Err(format!("dock resource '{dock_id}' is used by {max_overlaps} vehicles, capacity is {capacity}").into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Checks that driver hours-of-service rules are not violated:
+/// * max driving time without a rest
+/// * max daily driving time
+/// * min daily rest between consecutive shifts
+fn check_hours_of_service_limits(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let Some(hours_of_service) = vehicle.hours_of_service.as_ref() else { return Ok(()) };
+
+        let (_, total_driving_time) = tour.stops.windows(2).try_fold::<_, _, GenericResult<_>>(
+            (Duration::default(), Duration::default()),
+            |(acc, total), stops| {
                 let (prev, next) = match stops {
                     [prev, next] => (prev, next),
                     _ => unreachable!(),
                 };
 
-                let delta = (next.distance - prev.distance) as Distance;
-                let total_distance = acc + delta;
+                let driving_time = parse_time(&next.schedule().arrival) - parse_time(&prev.schedule().departure);
+                let accumulated = acc + driving_time;
 
-                if total_distance > recharge.max_distance {
+                if accumulated > hours_of_service.max_driving_time {
                     return Err(format!(
-                        "recharge distance violation: expected limit is {}, got {}, vehicle id '{}', shift index: {}",
-                        recharge.max_distance, total_distance, tour.vehicle_id, tour.shift_index
+                        "driving time without rest violation: expected limit is {}, got {}, vehicle id '{}', \
+                         shift index: {}",
+                        hours_of_service.max_driving_time, accumulated, tour.vehicle_id, tour.shift_index
                     )
                     .into());
                 }
 
-                let has_recharge = next.activities.iter().any(|activity| activity.activity_type == "recharge");
+                let has_rest = next.activities().iter().any(|activity| {
+                    let time = context.get_activity_time(next, activity);
+                    activity.activity_type == "break"
+                        && activity.job_id == "rest"
+                        && time.duration() >= hours_of_service.rest_duration
+                });
 
-                Ok(if has_recharge { Distance::default() } else { total_distance })
-            })
-            .map(|_| ())
+                Ok((if has_rest { Duration::default() } else { accumulated }, total + driving_time))
+            },
+        )?;
+
+        match hours_of_service.max_daily_driving_time {
+            Some(max_daily_driving_time) if total_driving_time > max_daily_driving_time => Err(format!(
+                "daily driving time violation: expected limit is {}, got {}, vehicle id '{}', shift index: {}",
+                max_daily_driving_time, total_driving_time, tour.vehicle_id, tour.shift_index
+            )
+            .into()),
+            _ => Ok(()),
+        }
+    })?;
+
+    let mut tours = context.solution.tours.iter().collect::<Vec<_>>();
+    tours.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id).then(a.shift_index.cmp(&b.shift_index)));
+
+    tours.windows(2).try_for_each(|tours| {
+        let (prev, next) = match tours {
+            [prev, next] => (prev, next),
+            _ => unreachable!(),
+        };
+
+        if prev.vehicle_id != next.vehicle_id {
+            return Ok(());
+        }
+
+        let vehicle = context.get_vehicle(&prev.vehicle_id)?;
+        let Some(min_daily_rest) = vehicle.hours_of_service.as_ref().and_then(|hos| hos.min_daily_rest) else {
+            return Ok(());
+        };
+
+        let (Some(prev_end), Some(next_start)) = (prev.stops.last(), next.stops.first()) else { return Ok(()) };
+        let rest = parse_time(&next_start.schedule().departure) - parse_time(&prev_end.schedule().arrival);
+
+        if rest < min_daily_rest {
+            Err(format!(
+                "daily rest violation: expected at least {}, got {}, vehicle id '{}', shift indices: {} and {}",
+                min_daily_rest, rest, prev.vehicle_id, prev.shift_index, next.shift_index
+            )
+            .into())
+        } else {
+            Ok(())
+        }
     })
 }
 
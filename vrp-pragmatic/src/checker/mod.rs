@@ -42,8 +42,11 @@ enum ActivityType {
     Terminal,
     Job(Job),
     Break(VehicleBreak),
+    Rest(VehicleHoursOfService),
     Reload(VehicleReload),
     Recharge(VehicleRechargeStation),
+    Overnight(VehicleOvernight),
+    Trailer(TrailerParking),
 }
 
 impl CheckerContext {
@@ -79,6 +82,7 @@ impl CheckerContext {
             .chain(check_assignment(self).err())
             .chain(check_routing(self).err())
             .chain(check_limits(self).err())
+            .chain(check_jobs(self).err())
             .flatten()
             .fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
                 if !used.contains(&error) {
@@ -102,6 +106,14 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find vehicle with id '{vehicle_id}'").into())
     }
 
+    fn get_trailer(&self, vehicle_id: &str) -> GenericResult<Option<&Trailer>> {
+        let trailer_id = self.get_vehicle(vehicle_id)?.trailer_id.as_ref();
+
+        Ok(trailer_id.and_then(|trailer_id| {
+            self.problem.fleet.trailers.iter().flatten().find(|trailer| trailer.id == *trailer_id)
+        }))
+    }
+
     fn get_vehicle_profile(&self, vehicle_id: &str) -> GenericResult<Profile> {
         let profile = &self.get_vehicle(vehicle_id)?.profile;
         let index = self
@@ -113,6 +125,17 @@ impl CheckerContext {
         Ok(Profile { index, scale: profile.scale.unwrap_or(1.) })
     }
 
+    /// Gets loading duration of the vehicle at the dock with given id using stop's load.
+    fn get_dock_loading_duration(&self, dock_id: &str, stop: &PointStop) -> Option<Float> {
+        self.problem.fleet.resources.iter().flatten().find_map(|resource| match resource {
+            VehicleResource::Dock { id, loading_time, unit_loading_time, .. } if id == dock_id => {
+                let load = stop.load.iter().sum::<i32>() as Float;
+                Some(loading_time + unit_loading_time.unwrap_or(0.) * load)
+            }
+            _ => None,
+        })
+    }
+
     /// Gets activity operation time range in seconds since Unix epoch.
     fn get_activity_time(&self, stop: &Stop, activity: &Activity) -> TimeWindow {
         let schedule = stop.schedule();
@@ -144,9 +167,8 @@ impl CheckerContext {
             ),
         );
 
-        self.get_vehicle(&tour.vehicle_id)?
-            .shifts
-            .iter()
+        get_tour_shifts(self.get_vehicle(&tour.vehicle_id)?)
+            .into_iter()
             .find(|shift| {
                 let shift_time = TimeWindow::new(
                     parse_time(&shift.start.earliest),
@@ -154,7 +176,6 @@ impl CheckerContext {
                 );
                 shift_time.intersects(&tour_time)
             })
-            .cloned()
             .ok_or_else(|| format!("cannot find shift for tour with vehicle if: '{}'", tour.vehicle_id).into())
     }
 
@@ -172,13 +193,20 @@ impl CheckerContext {
         match activity.activity_type.as_str() {
             "departure" | "arrival" => Ok(ActivityType::Terminal),
 
-            "pickup" | "delivery" | "service" | "replacement" => {
+            "pickup" | "delivery" | "service" | "replacement" | "drop" | "collect" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id).into()),
                     |job| Ok(ActivityType::Job(job.clone())),
                 )
             }
 
+            "break" if activity.job_id == "rest" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .hours_of_service
+                .as_ref()
+                .map(|hours_of_service| ActivityType::Rest(hours_of_service.clone()))
+                .ok_or_else(|| format!("cannot find hours of service for tour '{}'", tour.vehicle_id).into()),
+
             "break" => shift
                 .breaks
                 .as_ref()
@@ -214,6 +242,24 @@ impl CheckerContext {
                 .map(|r| ActivityType::Recharge(r.clone()))
                 .ok_or_else(|| format!("cannot find recharge for tour '{}'", tour.vehicle_id).into()),
 
+            "overnight" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .overnight
+                .as_ref()
+                .map(|overnight| ActivityType::Overnight(overnight.clone()))
+                .ok_or_else(|| format!("cannot find overnight for tour '{}'", tour.vehicle_id).into()),
+
+            "decouple" | "couple" => self
+                .get_trailer(&tour.vehicle_id)?
+                .iter()
+                .flat_map(|trailer| trailer.parkings.iter())
+                .find(|parking| {
+                    location.as_ref().is_some_and(|location| parking.location == *location)
+                        && parking.tag == activity.job_tag
+                })
+                .map(|parking| ActivityType::Trailer(parking.clone()))
+                .ok_or_else(|| format!("cannot find trailer parking for tour '{}'", tour.vehicle_id).into()),
+
             _ => Err(format!("unknown activity type: '{}'", activity.activity_type).into()),
         }
     }
@@ -383,8 +429,9 @@ fn match_job_task<'a>(
     tasks_fn: impl Fn(&'a Vec<JobTask>) -> Option<&'a JobTask>,
 ) -> Option<&'a JobTask> {
     let tasks = match activity_type {
-        "pickup" => job.pickups.as_ref(),
-        "delivery" => job.deliveries.as_ref(),
+        // NOTE goods of transfer job are dropped and collected at the hub with the same demand
+        "pickup" | "drop" => job.pickups.as_ref(),
+        "delivery" | "collect" => job.deliveries.as_ref(),
         "service" => job.services.as_ref(),
         "replacement" => job.replacements.as_ref(),
         _ => None,
@@ -448,12 +495,23 @@ fn get_profile_index(problem: &Problem, matrices: &[Matrix]) -> GenericResult<Ha
         .collect::<HashMap<_, _>>())
 }
 
+/// Returns shifts used by vehicle tours: consecutive shifts of a multi-day tour are chained into one.
+fn get_tour_shifts(vehicle: &VehicleType) -> Vec<VehicleShift> {
+    match (vehicle.overnight.as_ref(), vehicle.shifts.first(), vehicle.shifts.last()) {
+        (Some(_), Some(first), Some(last)) => vec![VehicleShift { end: last.end.clone(), ..first.clone() }],
+        _ => vehicle.shifts.clone(),
+    }
+}
+
 mod assignment;
 use crate::checker::assignment::check_assignment;
 
 mod capacity;
 use crate::checker::capacity::check_vehicle_load;
 
+mod jobs;
+use crate::checker::jobs::check_jobs;
+
 mod limits;
 use crate::checker::limits::check_limits;
 
@@ -40,6 +40,10 @@ impl CoordIndex {
                 .for_each(|place| {
                     index.add(&place.location);
                 });
+
+            if let Some(transfer) = &job.transfer {
+                index.add(&transfer.location);
+            }
         });
 
         // process fleet
@@ -71,8 +75,20 @@ impl CoordIndex {
                     recharges.stations.iter().for_each(|station| index.add(&station.location));
                 }
             });
+
+            if let Some(locations) = vehicle.overnight.as_ref().and_then(|overnight| overnight.locations.as_ref()) {
+                locations.iter().for_each(|location| index.add(location));
+            }
         });
 
+        problem
+            .fleet
+            .trailers
+            .iter()
+            .flatten()
+            .flat_map(|trailer| trailer.parkings.iter())
+            .for_each(|parking| index.add(&parking.location));
+
         index.max_matrix_index = index.direct_index.len().max(1) - 1;
 
         let start_offset = index.direct_index.len() * index.direct_index.len();
@@ -200,19 +200,47 @@ const AFFINITY_CONSTRAINT_CODE: ViolationCode = ViolationCode(16);
 const SYNC_CONSTRAINT_CODE: ViolationCode = ViolationCode(18);
 const SAME_ASSIGNEE_CONSTRAINT_CODE: ViolationCode = ViolationCode(19);
 const JOB_SEQUENCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(20);
+const HOURS_OF_SERVICE_CONSTRAINT_CODE: ViolationCode = ViolationCode(21);
+const OVERNIGHT_CONSTRAINT_CODE: ViolationCode = ViolationCode(22);
+const CHARGER_RESOURCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(23);
+const DOCK_RESOURCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(24);
+const COMPARTMENT_CONSTRAINT_CODE: ViolationCode = ViolationCode(25);
+const LOADING_POLICY_CONSTRAINT_CODE: ViolationCode = ViolationCode(26);
+const MAX_RIDE_TIME_CONSTRAINT_CODE: ViolationCode = ViolationCode(27);
+const SPLIT_JOB_CONSTRAINT_CODE: ViolationCode = ViolationCode(28);
+const TRAILER_CONSTRAINT_CODE: ViolationCode = ViolationCode(29);
+const TRANSFER_CONSTRAINT_CODE: ViolationCode = ViolationCode(30);
+const OUTSOURCING_CONSTRAINT_CODE: ViolationCode = ViolationCode(31);
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
 
-pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty};
+/// Returns an id of outbound leg of the transfer job in the job index.
+pub(crate) fn get_transfer_outbound_id(job_id: &str) -> String {
+    format!("{job_id}_outbound")
+}
+
+/// Returns an id of the split job part in the job index. The first part is stored under the job id.
+pub(crate) fn get_split_part_id(job_id: &str, part_idx: usize) -> String {
+    if part_idx == 0 { job_id.to_string() } else { format!("{job_id}_part_{part_idx}") }
+}
+
+pub use self::properties::{
+    BaselinePlanExtraProperty, CoordIndexExtraProperty, JobIndexExtraProperty, LoadingDocksExtraProperty,
+    ObjectiveNamesExtraProperty,
+};
 
 mod properties {
     use crate::format::{CoordIndex, JobIndex};
+    use vrp_core::construction::features::{BaselinePlan, LoadingDocks};
     use vrp_core::custom_extra_property;
     use vrp_core::models::Extras;
 
     custom_extra_property!(pub JobIndex typeof JobIndex);
     custom_extra_property!(pub CoordIndex typeof CoordIndex);
+    custom_extra_property!(pub ObjectiveNames typeof Vec<String>);
+    custom_extra_property!(pub BaselinePlan typeof BaselinePlan);
+    custom_extra_property!(pub LoadingDocks typeof LoadingDocks);
 }
 
 /// Get job and coord indices from extras
@@ -7,10 +7,15 @@ use super::*;
 use crate::Location as ApiLocation;
 use crate::format::UnknownLocationFallback;
 use crate::get_unique_locations;
+use crate::parse_time_of_day;
 use crate::utils::get_approx_transportation;
 use std::collections::HashSet;
 use vrp_core::construction::enablers::create_typed_actor_groups;
-use vrp_core::construction::features::{VehicleCapacityDimension, VehicleSkillsDimension};
+use vrp_core::construction::features::{
+    BatteryModel, Compartment, EmissionModel, LoadingDock, LoadingDocks, LoadingPolicy, VehicleBatteryDimension,
+    VehicleCapacityDimension, VehicleCompartmentsDimension, VehicleEmissionsDimension, VehicleLoadingPolicyDimension,
+    VehicleShiftGapsDimension, VehicleSkillsDimension, VehicleTrailerCapacityDimension,
+};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 
@@ -143,7 +148,37 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
 
-        for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
+        // NOTE vehicle capacity includes capacity of its trailer, trailer capacity is kept separately
+        let trailer_capacity = vehicle
+            .trailer_id
+            .as_ref()
+            .and_then(|trailer_id| api_problem.fleet.trailers.iter().flatten().find(|t| t.id == *trailer_id))
+            .map(|trailer| trailer.capacity.clone());
+        let capacity = trailer_capacity.as_ref().map_or_else(
+            || vehicle.capacity.clone(),
+            |trailer_capacity| {
+                let get_value = |capacity: &[i32], idx: usize| capacity.get(idx).copied().unwrap_or(0);
+                (0..vehicle.capacity.len().max(trailer_capacity.len()))
+                    .map(|idx| get_value(&vehicle.capacity, idx) + get_value(trailer_capacity, idx))
+                    .collect()
+            },
+        );
+
+        // NOTE consecutive shifts of a multi-day tour are chained into one shift
+        let shifts = if vehicle.overnight.is_some() {
+            let chained = vehicle.shifts.first().zip(vehicle.shifts.last());
+            chained.map(|(first, last)| vec![(0, first, last)]).unwrap_or_default()
+        } else {
+            vehicle.shifts.iter().enumerate().map(|(idx, shift)| (idx, shift, shift)).collect::<Vec<_>>()
+        };
+
+        for (shift_index, shift, last_shift) in shifts {
+            let battery = shift
+                .recharges
+                .as_ref()
+                .and_then(|recharges| recharges.battery.as_ref())
+                .map(|battery| Arc::new(create_battery_model(battery)));
+
             let start = {
                 let location = coord_index.get_by_loc(&shift.start.location).unwrap();
                 let earliest = parse_time(&shift.start.earliest);
@@ -151,7 +186,7 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 (location, earliest, latest)
             };
 
-            let end = shift.end.as_ref().map(|end| {
+            let end = last_shift.end.as_ref().map(|end| {
                 let location = coord_index.get_by_loc(&end.location).unwrap();
                 let time = parse_time(&end.latest);
                 (location, time)
@@ -181,15 +216,78 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 }
 
                 if props.has_multi_dimen_capacity {
-                    dimens.set_vehicle_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
+                    dimens.set_vehicle_capacity(MultiDimLoad::new(capacity.clone()));
                 } else {
-                    dimens.set_vehicle_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
+                    dimens.set_vehicle_capacity(SingleDimLoad::new(*capacity.first().unwrap()));
+                }
+
+                if let Some(trailer_capacity) = trailer_capacity.as_ref() {
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_vehicle_trailer_capacity(MultiDimLoad::new(trailer_capacity.clone()));
+                    } else {
+                        dimens.set_vehicle_trailer_capacity(SingleDimLoad::new(*trailer_capacity.first().unwrap()));
+                    }
+                }
+
+                if let Some(compartments) = vehicle.compartments.as_ref() {
+                    dimens.set_vehicle_compartments(
+                        compartments
+                            .iter()
+                            .map(|compartment| Compartment {
+                                id: compartment.id.clone(),
+                                capacity: MultiDimLoad::new(compartment.capacity.clone()),
+                                products: compartment.products.clone().unwrap_or_default(),
+                            })
+                            .collect(),
+                    );
+                }
+
+                if let Some(loading_policy) = vehicle.loading_policy {
+                    dimens.set_vehicle_loading_policy(match loading_policy {
+                        VehicleLoadingPolicy::Lifo => LoadingPolicy::Lifo,
+                        VehicleLoadingPolicy::Fifo => LoadingPolicy::Fifo,
+                    });
                 }
 
                 if let Some(skills) = vehicle.skills.as_ref() {
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
+                if let Some(battery) = battery.as_ref() {
+                    dimens.set_vehicle_battery(battery.clone());
+                }
+
+                if let Some(overtime) = shift.overtime.as_ref() {
+                    dimens.set_vehicle_overtime_costs(OvertimeCosts {
+                        regular_duration: overtime.regular_duration,
+                        per_overtime: overtime.cost.clone().into(),
+                    });
+                }
+
+                if let Some(time_rates) = vehicle.costs.time_rates.as_ref() {
+                    dimens.set_vehicle_time_rates(TimeRates {
+                        rates: time_rates
+                            .iter()
+                            .map(|rate| TimeRate {
+                                weekdays: rate.weekdays.clone().unwrap_or_default(),
+                                start: parse_time_of_day(&rate.start).unwrap_or_default(),
+                                end: parse_time_of_day(&rate.end).unwrap_or_default(),
+                                per_driving_time: rate.driving,
+                                per_waiting_time: rate.waiting,
+                                per_service_time: rate.service,
+                            })
+                            .collect(),
+                    });
+                }
+
+                if let Some(emissions) = vehicle.emissions.as_ref() {
+                    dimens.set_vehicle_emissions(create_emission_model(emissions));
+                }
+
+                if vehicle.overnight.is_some() {
+                    dimens.set_vehicle_shift_gaps(get_shift_gaps(vehicle));
+                }
+
                 vehicles.push(Arc::new(Vehicle {
                     profile: profile.clone(),
                     costs: costs.clone(),
@@ -258,3 +356,83 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         })
         .collect()
 }
+
+/// Converts battery model from api units (kWh, km, kW) to internal ones (kWh, meters, seconds).
+pub(crate) fn create_battery_model(battery: &VehicleBattery) -> BatteryModel {
+    BatteryModel {
+        capacity: battery.capacity,
+        initial_charge: battery.initial_charge.unwrap_or(battery.capacity),
+        reserve: battery.reserve.unwrap_or_default(),
+        consumption: battery.consumption / 1000.,
+        load_consumption: battery.load_consumption.unwrap_or_default() / 1000.,
+        charging_curve: battery.charging_curve.iter().map(|point| (point.level, point.power / 3600.)).collect(),
+    }
+}
+
+fn create_emission_model(emissions: &VehicleEmissions) -> EmissionModel {
+    EmissionModel {
+        consumption: emissions.consumption / 1000.,
+        full_consumption: emissions.full_consumption.unwrap_or(emissions.consumption) / 1000.,
+        idle_consumption: emissions.idle_consumption.unwrap_or_default() / 3600.,
+        emission_factor: emissions.emission_factor,
+    }
+}
+
+/// Reads loading docks from fleet resources. Docks with location not used in the problem are ignored.
+pub(super) fn read_loading_docks(
+    api_problem: &ApiProblem,
+    props: &ProblemProperties,
+    coord_index: &CoordIndex,
+) -> Option<LoadingDocks> {
+    let docks = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, location, capacity, loading_time, unit_loading_time, times } => {
+                Some(LoadingDock {
+                    id: id.clone(),
+                    location: coord_index.get_by_loc(location)?,
+                    capacity: *capacity,
+                    loading_time: *loading_time,
+                    unit_loading_time: unit_loading_time.unwrap_or_default(),
+                    times: times.as_ref().map_or_else(
+                        || vec![TimeWindow::max()],
+                        |times| times.iter().map(|tw| parse_time_window(tw)).collect(),
+                    ),
+                })
+            }
+            VehicleResource::Reload { .. } | VehicleResource::Charger { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    if docks.is_empty() {
+        return None;
+    }
+
+    fn is_reload_single(single: &Single) -> bool {
+        single.dimens.get_job_type().is_some_and(|job_type| job_type == "reload")
+    }
+
+    // NOTE load is a sum of all load dimensions
+    Some(if props.has_multi_dimen_capacity {
+        LoadingDocks::new(docks, is_reload_single, |load: &MultiDimLoad| load.as_slice().iter().sum::<i32>() as Float)
+    } else {
+        LoadingDocks::new(docks, is_reload_single, |load: &SingleDimLoad| load.value as Float)
+    })
+}
+
+/// Returns times between consecutive shifts of the vehicle when it stays at overnight stop.
+fn get_shift_gaps(vehicle: &VehicleType) -> Vec<TimeWindow> {
+    vehicle
+        .shifts
+        .windows(2)
+        .filter_map(|shifts| {
+            let end = shifts[0].end.as_ref().map(|end| parse_time(&end.latest))?;
+            let start = parse_time(&shifts[1].start.earliest);
+
+            (start > end).then(|| TimeWindow::new(end, start))
+        })
+        .collect()
+}
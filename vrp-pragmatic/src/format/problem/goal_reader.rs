@@ -5,18 +5,20 @@ use vrp_core::construction::clustering::vicinity::ClusterInfoDimension;
 use vrp_core::construction::enablers::FeatureCombinator;
 use vrp_core::construction::features::*;
 use vrp_core::models::common::{Demand, LoadOps, MultiDimLoad, SingleDimLoad};
-use vrp_core::models::problem::{Actor, Single, TransportCost, ActivityCost};
+use vrp_core::models::problem::{ActivityCost, Actor, Single, TransportCost};
 use vrp_core::models::solution::Route;
 use vrp_core::models::{Feature, FeatureObjective, GoalBuilder, GoalContext, GoalContextBuilder};
 use vrp_core::rosomaxa::evolution::objectives::dominance_order;
 
+/// Creates goal context together with names of objectives in the order of their fitness values.
 pub(super) fn create_goal_context(
     api_problem: &ApiProblem,
     blocks: &ProblemBlocks,
     props: &ProblemProperties,
-) -> GenericResult<GoalContext> {
+) -> GenericResult<(GoalContext, Vec<String>)> {
     // determine features from objective definition
     let feature_layers = get_objective_feature_layers(api_problem, blocks, props)?;
+    let objective_names = get_objective_names(&feature_layers);
     let (mut features, goal_builder) = get_features_with_goal(&feature_layers)?;
 
     if props.has_unreachable_locations {
@@ -25,6 +27,80 @@ pub(super) fn create_goal_context(
 
     features.push(get_capacity_feature("capacity", api_problem, blocks, props)?);
 
+    if let Some(loading_docks) = blocks.loading_docks.clone() {
+        features.push(create_loading_docks_feature(
+            "loading_docks",
+            DOCK_RESOURCE_CONSTRAINT_CODE,
+            loading_docks,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_split_jobs {
+        features.push(create_split_jobs_feature(
+            "split_jobs",
+            SPLIT_JOB_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_compartments {
+        features.push(create_compartments_feature::<MultiDimLoad, _>(
+            "compartments",
+            COMPARTMENT_CONSTRAINT_CODE,
+            |single: &Single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "reload"),
+        )?);
+    }
+
+    if props.has_loading_policy {
+        features.push(if props.has_multi_dimen_capacity {
+            create_loading_policy_feature::<MultiDimLoad>("loading_policy", LOADING_POLICY_CONSTRAINT_CODE)?
+        } else {
+            create_loading_policy_feature::<SingleDimLoad>("loading_policy", LOADING_POLICY_CONSTRAINT_CODE)?
+        });
+    }
+
+    if props.has_trailers {
+        let belongs_to_route = |route: &Route, job: &CoreJob| {
+            job.as_multi().and_then(|multi| multi.jobs.first()).is_some_and(|single| is_correct_vehicle(route, single))
+        };
+
+        features.push(if props.has_multi_dimen_capacity {
+            create_trailer_feature::<MultiDimLoad, _>("trailer", TRAILER_CONSTRAINT_CODE, belongs_to_route)?
+        } else {
+            create_trailer_feature::<SingleDimLoad, _>("trailer", TRAILER_CONSTRAINT_CODE, belongs_to_route)?
+        });
+    }
+
+    if props.has_max_ride_time {
+        features.push(create_max_ride_time_feature(
+            "max_ride_time",
+            MAX_RIDE_TIME_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_transfers {
+        features.push(create_transfers_feature(
+            "transfers",
+            TRANSFER_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
+    if props.has_outsourcing {
+        features.push(create_outsourcing_feature(
+            "outsourcing",
+            OUTSOURCING_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
+    }
+
     if props.has_tour_travel_limits {
         features.push(get_tour_limit_feature(
             "tour_limit",
@@ -39,7 +115,22 @@ pub(super) fn create_goal_context(
     }
 
     if props.has_recharges {
-        features.push(get_recharge_feature("recharge", api_problem, blocks.transport.clone())?);
+        features.push(get_recharge_feature("recharge", api_problem, blocks, props)?);
+    }
+
+    if props.has_hours_of_service {
+        features.push(get_hours_of_service_feature("hours_of_service", api_problem, blocks.transport.clone())?);
+    }
+
+    if props.has_overnights {
+        features.push(create_overnight_feature("overnight")?);
+        features.push(create_shift_gaps_feature(
+            "shift_gaps",
+            OVERNIGHT_CONSTRAINT_CODE,
+            is_overnight_job,
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        )?);
     }
 
     if props.has_order && !features.iter().any(|f| f.name == "tour_order") {
@@ -89,7 +180,9 @@ pub(super) fn create_goal_context(
         )?);
     }
 
-    GoalContextBuilder::with_features(&features)?.set_main_goal(goal_builder.build()?).build()
+    let goal = GoalContextBuilder::with_features(&features)?.set_main_goal(goal_builder.build()?).build()?;
+
+    Ok((goal, objective_names))
 }
 
 /// Layer retains information about whether a feature is defined as standalone or as having some competitive.
@@ -101,6 +194,16 @@ enum FeatureLayer {
     Multi { composition_type: MultiStrategy, features: Vec<Feature> },
 }
 
+fn get_objective_names(feature_layers: &[FeatureLayer]) -> Vec<String> {
+    feature_layers
+        .iter()
+        .flat_map(|layer| match layer {
+            FeatureLayer::Single(feature) => vec![feature.name.clone()],
+            FeatureLayer::Multi { features, .. } => features.iter().map(|feature| feature.name.clone()).collect(),
+        })
+        .collect()
+}
+
 fn get_objective_feature_layers(
     api_problem: &ApiProblem,
     blocks: &ProblemBlocks,
@@ -182,7 +285,18 @@ fn get_objective_feature_layer(
             .set_job_estimator({
                 let break_value = *breaks;
                 let default_value = 1.;
-                move |_, job| {
+                move |solution_ctx, job| {
+                    // NOTE jobs which are cheaper to outsource are compared with served ones by cost
+                    let is_outsourced = solution_ctx
+                        .unassigned
+                        .get(job)
+                        .and_then(|info| get_outsourced_job_cost(job, info, OUTSOURCING_CONSTRAINT_CODE))
+                        .is_some();
+
+                    if is_outsourced {
+                        return 0.;
+                    }
+
                     if let Some(clusters) = job.dimens().get_cluster_info() {
                         clusters.len() as Float * default_value
                     } else {
@@ -196,6 +310,19 @@ fn get_objective_feature_layer(
             .build(),
 
         Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
+        Objective::MinimizeMakespan => {
+            create_minimize_makespan_feature("min_makespan", blocks.transport.clone(), blocks.activity.clone())
+        }
+        Objective::MinimizeWaitingTime => {
+            create_minimize_waiting_time_feature("min_waiting_time", blocks.transport.clone(), blocks.activity.clone())
+        }
+        Objective::MinimizeEmissions => {
+            if props.has_multi_dimen_capacity {
+                create_minimize_emissions_feature::<MultiDimLoad>("min_emissions", blocks.transport.clone())
+            } else {
+                create_minimize_emissions_feature::<SingleDimLoad>("min_emissions", blocks.transport.clone())
+            }
+        }
         Objective::BalanceMaxLoad => {
             if props.has_multi_dimen_capacity {
                 create_max_load_balanced_feature::<MultiDimLoad>(
@@ -203,8 +330,8 @@ fn get_objective_feature_layer(
                     |loaded, capacity| {
                         let mut max_ratio = Float::default();
 
-                        for (idx, value) in capacity.load.iter().enumerate() {
-                            let ratio = loaded.load[idx] as Float / *value as Float;
+                        for (idx, value) in capacity.as_slice().iter().enumerate() {
+                            let ratio = loaded.get(idx) as Float / *value as Float;
                             max_ratio = max_ratio.max(ratio);
                         }
 
@@ -228,7 +355,17 @@ fn get_objective_feature_layer(
         }
         Objective::TourOrder => create_tour_order_soft_feature("tour_order", get_tour_order_fn()),
         Objective::FastService => get_fast_service_feature("fast_service", blocks),
+        Objective::MinimizeTimeWindowPenalty => create_soft_time_windows_feature(
+            "min_time_window_penalty",
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+        ),
         Objective::HierarchicalAreas { levels } => get_hierarchical_areas_feature(blocks, *levels),
+        Objective::MinimizeChanges { .. } => create_minimize_changes_feature(
+            "min_changes",
+            blocks.baseline.clone().ok_or_else(|| GenericError::from("baseline solution is not set"))?,
+            blocks.transport.clone(),
+        ),
         Objective::MultiObjective { objectives, strategy: composition_type } => {
             let features = objectives
                 .iter()
@@ -272,6 +409,59 @@ fn get_hierarchical_areas_feature(blocks: &ProblemBlocks, levels: usize) -> Gene
     })
 }
 
+/// Creates a baseline plan from the first minimize changes objective if it is specified.
+pub(super) fn get_baseline_plan(api_problem: &ApiProblem) -> Option<BaselinePlan> {
+    let (baseline, max_position_shift, max_arrival_shift, penalties) = api_problem
+        .objectives
+        .iter()
+        .flatten()
+        .flat_map(|objective| match objective {
+            Objective::MultiObjective { objectives, .. } => objectives.iter().collect(),
+            _ => vec![objective],
+        })
+        .find_map(|objective| match objective {
+            Objective::MinimizeChanges { baseline, max_position_shift, max_arrival_shift, penalties } => {
+                Some((baseline, max_position_shift, max_arrival_shift, penalties))
+            }
+            _ => None,
+        })?;
+
+    let default_config = MinimizeChangesConfig::default();
+    let get_penalty = |penalty_fn: fn(&ChangePenalties) -> Option<Float>, default: Float| {
+        penalties.as_ref().and_then(penalty_fn).unwrap_or(default)
+    };
+    let config = MinimizeChangesConfig {
+        max_position_shift: max_position_shift.unwrap_or(default_config.max_position_shift),
+        max_arrival_shift: max_arrival_shift.unwrap_or(default_config.max_arrival_shift),
+        vehicle_penalty: get_penalty(|penalties| penalties.vehicle, default_config.vehicle_penalty),
+        position_penalty: get_penalty(|penalties| penalties.position, default_config.position_penalty),
+        arrival_penalty: get_penalty(|penalties| penalties.arrival, default_config.arrival_penalty),
+    };
+
+    let mut assignments = HashMap::new();
+    baseline.tours.iter().for_each(|tour| {
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .enumerate()
+            .filter(|(_, (_, activity))| {
+                matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+            })
+            .for_each(|(position, (stop, activity))| {
+                // NOTE baseline times are expected to be checked by validation (see E1609)
+                let arrival = activity.time.as_ref().map_or(stop.schedule().arrival.as_str(), |time| &time.start);
+
+                assignments.entry(activity.job_id.clone()).or_insert_with(|| BaselineAssignment {
+                    vehicle_id: tour.vehicle_id.clone(),
+                    position,
+                    arrival: parse_time(arrival),
+                });
+            })
+    });
+
+    Some(BaselinePlan::new(assignments, config))
+}
+
 fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Objective> {
     if let Some(objectives) = api_problem.objectives.clone() {
         objectives
@@ -279,6 +469,10 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ob
         let mut objectives =
             vec![Objective::MinimizeUnassigned { breaks: Some(1.) }, Objective::MinimizeTours, Objective::MinimizeCost];
 
+        if props.has_soft_time_windows {
+            objectives.insert(2, Objective::MinimizeTimeWindowPenalty)
+        }
+
         if props.has_value {
             objectives.insert(0, Objective::MaximizeValue { breaks: None })
         }
@@ -410,7 +604,7 @@ fn create_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<Float,
 
     let builder = ReloadFeatureFactory::new(name)
         .set_capacity_code(CAPACITY_CONSTRAINT_CODE)
-        .set_load_schedule_threshold(move |capacity: &T| *capacity * RELOAD_THRESHOLD)
+        .set_load_schedule_threshold(move |capacity: &T| capacity.clone() * RELOAD_THRESHOLD)
         .set_is_reload_single(is_reload_single)
         .set_belongs_to_route(|route: &Route, job: &CoreJob| {
             job.as_single().is_some_and(|single| is_reload_single(single.as_ref()) && is_correct_vehicle(route, single))
@@ -425,7 +619,9 @@ fn create_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<Float,
         let total_jobs = blocks.jobs.size();
         builder
             .set_resource_code(RELOAD_RESOURCE_CONSTRAINT_CODE)
-            .set_shared_demand_capacity(|single| single.dimens.get_job_demand().map(|demand| demand.delivery.0))
+            .set_shared_demand_capacity(|single| {
+                single.dimens.get_job_demand::<T>().map(|demand| demand.delivery.0.clone())
+            })
             .set_shared_resource_capacity(move |activity| {
                 activity
                     .job
@@ -433,7 +629,7 @@ fn create_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<Float,
                     .filter(|single| is_reload_single(single.as_ref()))
                     .and_then(|single| reload_resources.get(&CoreJob::Single(single.clone())).cloned())
             })
-            .set_load_schedule_threshold(move |capacity: &T| *capacity * RELOAD_THRESHOLD)
+            .set_load_schedule_threshold(move |capacity: &T| capacity.clone() * RELOAD_THRESHOLD)
             .set_is_partial_solution(move |solution_ctx| solution_ctx.get_jobs_amount() != total_jobs)
             .build_shared()
     }
@@ -488,7 +684,8 @@ fn get_tour_limit_feature(
 fn get_recharge_feature(
     name: &str,
     api_problem: &ApiProblem,
-    transport: Arc<dyn TransportCost>,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
 ) -> GenericResult<Feature> {
     fn is_recharge_single(single: &Single) -> bool {
         single.dimens.get_job_type().is_some_and(|job_type| job_type == "recharge")
@@ -500,28 +697,99 @@ fn get_recharge_feature(
                 .shifts
                 .iter()
                 .enumerate()
-                .flat_map(|(shift_idx, shift)| {
-                    shift.recharges.as_ref().map(|recharges| (shift_idx, recharges.max_distance))
+                .filter_map(|(shift_idx, shift)| {
+                    let recharges = shift.recharges.as_ref()?;
+                    Some((shift_idx, (recharges.max_distance?, recharges.initial_distance.unwrap_or_default())))
                 })
-                .for_each(|(shift_idx, max_distance)| {
-                    acc.entry(vehicle_type.type_id.clone()).or_default().insert(shift_idx, max_distance);
+                .for_each(|(shift_idx, distances)| {
+                    acc.entry(vehicle_type.type_id.clone()).or_default().insert(shift_idx, distances);
                 });
 
             acc
         });
+    // NOTE distance limit index keeps max distance and initial distance per vehicle type and shift
+    let distance_limit_index = Arc::new(distance_limit_index);
+    let get_distances = move |actor: &Actor| {
+        actor.vehicle.dimens.get_vehicle_type().zip(actor.vehicle.dimens.get_shift_index().copied()).and_then(
+            |(type_id, shift_idx)| distance_limit_index.get(type_id).and_then(|idx| idx.get(&shift_idx).copied()),
+        )
+    };
 
-    RechargeFeatureBuilder::new(name)
+    let builder = RechargeFeatureBuilder::new(name)
         .set_violation_code(RECHARGE_CONSTRAINT_CODE)
-        .set_transport(transport)
+        .set_transport(blocks.transport.clone())
+        .set_activity(blocks.activity.clone());
+
+    // NOTE payload is a sum of all load dimensions
+    let builder = if props.has_multi_dimen_capacity {
+        builder.set_payload(|load: &MultiDimLoad| load.as_slice().iter().sum::<i32>() as Float)
+    } else {
+        builder.set_payload(|load: &SingleDimLoad| load.value as Float)
+    };
+
+    let job_index = blocks.job_index.as_ref().ok_or("misconfiguration in goal reader: job index is not set")?;
+    let charger_resources = get_charger_resources(api_problem, job_index);
+
+    let builder = if charger_resources.is_empty() {
+        builder
+    } else {
+        builder.set_charger_code(CHARGER_RESOURCE_CONSTRAINT_CODE).set_charger_capacity(move |activity| {
+            activity
+                .job
+                .as_ref()
+                .filter(|single| is_recharge_single(single.as_ref()))
+                .and_then(|single| charger_resources.get(&CoreJob::Single(single.clone())).cloned())
+        })
+    };
+
+    builder
         .set_is_recharge_single(is_recharge_single)
         .set_belongs_to_route(|route, job| {
             job.as_single()
                 .is_some_and(|single| is_recharge_single(single.as_ref()) && is_correct_vehicle(route, single))
         })
-        .set_distance_limit(move |actor| {
-            actor.vehicle.dimens.get_vehicle_type().zip(actor.vehicle.dimens.get_shift_index().copied()).and_then(
-                |(type_id, shift_idx)| distance_limit_index.get(type_id).and_then(|idx| idx.get(&shift_idx).copied()),
-            )
+        .set_distance_limit({
+            let get_distances = get_distances.clone();
+            move |actor| get_distances(actor).map(|(max_distance, _)| max_distance)
+        })
+        .set_initial_distance(move |actor| get_distances(actor).map(|(_, initial_distance)| initial_distance))
+        .build()
+}
+
+fn get_hours_of_service_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost>,
+) -> GenericResult<Feature> {
+    fn is_rest_single(single: &Single) -> bool {
+        single.dimens.get_job_type().is_some_and(|job_type| job_type == "rest")
+    }
+
+    let limits_index: HashMap<_, _> = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle_type| {
+            vehicle_type.hours_of_service.as_ref().map(|hos| {
+                let limits = HoursOfServiceLimits {
+                    max_driving_time: hos.max_driving_time,
+                    max_total_driving_time: hos.max_daily_driving_time,
+                };
+
+                (vehicle_type.type_id.clone(), limits)
+            })
+        })
+        .collect();
+
+    HoursOfServiceFeatureBuilder::new(name)
+        .set_violation_code(HOURS_OF_SERVICE_CONSTRAINT_CODE)
+        .set_transport(transport)
+        .set_is_rest_single(is_rest_single)
+        .set_belongs_to_route(|route, job| {
+            job.as_single().is_some_and(|single| is_rest_single(single.as_ref()) && is_correct_vehicle(route, single))
+        })
+        .set_limits(move |actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|type_id| limits_index.get(type_id).cloned())
         })
         .build()
 }
@@ -541,8 +809,10 @@ where
         .as_ref()
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id.clone(), capacity.clone())),
+            VehicleResource::Charger { .. } => None,
+            VehicleResource::Dock { .. } => None,
         })
         .collect::<Vec<_>>();
     let total_resources_specified = available_resources.len();
@@ -589,6 +859,58 @@ where
         .collect()
 }
 
+fn get_charger_resources(
+    api_problem: &ApiProblem,
+    job_index: &JobIndex,
+) -> HashMap<CoreJob, (usize, SharedResourceId)> {
+    let available_resources = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Charger { id, capacity } => Some((id.clone(), *capacity)),
+            VehicleResource::Reload { .. } => None,
+            VehicleResource::Dock { .. } => None,
+        })
+        .enumerate()
+        .map(|(idx, (id, capacity))| (id, (capacity, idx)))
+        .collect::<HashMap<_, _>>();
+
+    api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle
+                .shifts
+                .iter()
+                .enumerate()
+                .flat_map(|(shift_idx, vehicle_shift)| {
+                    vehicle_shift
+                        .recharges
+                        .iter()
+                        .flat_map(|recharges| recharges.stations.iter())
+                        .enumerate()
+                        .map(move |(station_idx, station)| (shift_idx, station_idx + 1, station))
+                })
+                .filter_map(|(shift_idx, place_idx, station)| {
+                    station
+                        .resource_id
+                        .as_ref()
+                        .and_then(|resource_id| available_resources.get(resource_id))
+                        .map(|&resource| (shift_idx, place_idx, resource))
+                })
+                .flat_map(move |(shift_idx, place_idx, resource)| {
+                    vehicle.vehicle_ids.iter().filter_map(move |vehicle_id| {
+                        let job_id = format!("{vehicle_id}_recharge_{shift_idx}_{place_idx}");
+                        job_index.get(&job_id).map(|job| (job.clone(), resource))
+                    })
+                })
+        })
+        .collect()
+}
+
 fn create_optional_break_feature(name: &str) -> GenericResult<Feature> {
     fn is_break_job(single: &Single) -> bool {
         single.dimens.get_job_type().is_some_and(|job_type| job_type == "break")
@@ -605,12 +927,29 @@ fn create_optional_break_feature(name: &str) -> GenericResult<Feature> {
         .build()
 }
 
+fn is_overnight_job(single: &Single) -> bool {
+    single.dimens.get_job_type().is_some_and(|job_type| job_type == "overnight")
+}
+
+fn create_overnight_feature(name: &str) -> GenericResult<Feature> {
+    // NOTE overnight stop is modeled as a break which is required only when the tour lasts longer than its day
+    BreakFeatureBuilder::new(name)
+        .set_violation_code(OVERNIGHT_CONSTRAINT_CODE)
+        .set_is_break_single(is_overnight_job)
+        .set_belongs_to_route(|route, job| {
+            let Some(single) = job.as_single().filter(|single| is_overnight_job(single)) else { return false };
+            is_correct_vehicle(route, single)
+        })
+        .set_policy(|_| BreakPolicy::SkipIfArrivalBeforeEnd)
+        .build()
+}
+
 fn get_tour_order_fn() -> TourOrderFn {
     TourOrderFn::Left(Arc::new(|single| {
         single.dimens.get_job_order().copied().map(|order| OrderResult::Value(order as Float)).unwrap_or_else(|| {
             single.dimens.get_job_type().map_or(OrderResult::Default, |v| {
                 match v.as_str() {
-                    "break" | "reload" => OrderResult::Ignored,
+                    "break" | "reload" | "rest" | "overnight" | "decouple" | "couple" => OrderResult::Ignored,
                     // job without value
                     _ => OrderResult::Default,
                 }
@@ -1,19 +1,23 @@
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::JobSkills as ApiJobSkills;
 use crate::format::problem::*;
-use crate::format::{JobIndex, Location};
+use crate::format::{JobIndex, Location, get_split_part_id, get_transfer_outbound_id};
 use crate::utils::VariableJobPermutation;
 use std::collections::HashMap;
 use std::sync::Arc;
 use vrp_core::{
     construction::features::{
-        BreakPolicy, JobAffinityDimension, JobAffinitySequenceDimension, JobAffinityDurationDaysDimension, JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobSkills as FeatureJobSkills,
-        JobSameAssigneeKeyDimension, JobSequenceKeyDimension, JobSequenceOrderDimension, JobSequenceDaysBetweenMinDimension, JobSequenceDaysBetweenMaxDimension,
+        BreakPolicy, JobAffinityDimension, JobSoftTimeWindowsDimension, SoftTimeWindow, JobAffinitySequenceDimension, JobAffinityDurationDaysDimension, JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobSkills as FeatureJobSkills,
+        JobProductDimension, JobSameAssigneeKeyDimension, JobSequenceKeyDimension, JobSequenceOrderDimension, JobSequenceDaysBetweenMinDimension, JobSequenceDaysBetweenMaxDimension,
         JobSkillsDimension, JobSyncGroupDimension, JobSyncIndexDimension, JobSyncSizeDimension, JobSyncToleranceDimension,
+        JobMaxRideTimeDimension, JobSplitKeyDimension, JobSplitMaxVisitsDimension, MaxRideTime, JobTrailerAllowedDimension,
+        JobTrailerOperationDimension, TrailerOperation, JobTransferHandlingTimeDimension, JobTransferKeyDimension,
+        JobTransferLegDimension, TransferLeg,
     },
     models::common::*,
     models::problem::{
-        Actor, Fleet, Job, JobIdDimension, Jobs, Multi, Place, Single, TransportCost, VehicleIdDimension,
+        Actor, Fleet, Job, JobAssignmentCostsDimension, JobFixedCostDimension, JobIdDimension,
+        JobOutsourcingCostDimension, Jobs, Multi, Place, Single, TransportCost, VehicleIdDimension,
     },
     models::{Lock, LockDetail, LockOrder, LockPosition},
 };
@@ -21,6 +25,9 @@ use vrp_core::{
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
 
+// NOTE a driving time horizon used to estimate amount of rests when shift has no end
+const MAX_REST_DRIVING_TIME: Duration = 86400.;
+
 type PlaceData = (Option<Location>, Duration, Vec<TimeSpan>, Option<String>);
 type ApiJob = crate::format::problem::Job;
 
@@ -79,7 +86,7 @@ pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<
                 .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
                 .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
                     let job_id = match job.as_str() {
-                        "break" | "reload" | "recharge" => {
+                        "break" | "reload" | "recharge" | "overnight" => {
                             let entry = indexer.entry(job.clone()).or_insert(1_usize);
                             let job_index = *entry;
                             *entry += 1;
@@ -124,26 +131,120 @@ fn read_required_jobs(
         let demand = match activity_type {
             "pickup" => Demand { pickup: demand, delivery: absent },
             "delivery" => Demand { pickup: absent, delivery: demand },
-            "replacement" => Demand { pickup: demand, delivery: demand },
-            "service" => Demand { pickup: absent, delivery: absent },
+            "replacement" => Demand { pickup: demand.clone(), delivery: demand },
+            "service" => Demand { pickup: absent.clone(), delivery: absent },
             _ => panic!("invalid activity type."),
         };
 
+        let soft_times = task.places.iter().map(get_soft_time_window).collect::<Vec<_>>();
         let places = task
             .places
             .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
+            .zip(soft_times.iter())
+            .map(|(p, soft)| (Some(p.location.clone()), p.duration, parse_soft_times(&p.times, soft), p.tag.clone()))
             .collect();
 
-        get_single_with_dimens(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_dimens(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+
+        if soft_times.iter().any(|soft| soft.is_some()) {
+            single.dimens.set_job_soft_time_windows(soft_times);
+        }
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
+        // NOTE assignment costs are kept only in the first task, so they are charged once per job
+        let assignment_costs = get_assignment_costs(api_problem, job);
+        let set_assignment_costs = |single: &mut Single| {
+            if let Some(costs) = assignment_costs.as_ref() {
+                single.dimens.set_job_assignment_costs(costs.clone());
+            }
+        };
+
+        if let Some((tasks, activity_type)) = get_split_tasks(job) {
+            // NOTE each part is a separate job with the same id, so they are indexed by their position
+            let parts = tasks
+                .iter()
+                .map(|task| {
+                    let mut single = get_single_from_task(task, activity_type, true);
+                    single.dimens.set_job_split_key(job.id.clone());
+                    if let Some(max_splits) = job.split.as_ref().and_then(|split| split.max_splits) {
+                        single.dimens.set_job_split_max_visits(max_splits);
+                    }
+                    set_assignment_costs(&mut single);
+                    if let Some(product) = job.product.as_ref() {
+                        single.dimens.set_job_product(product.clone());
+                    }
+
+                    get_single_job(job, single, None)
+                })
+                .collect::<Vec<_>>();
+
+            parts.iter().enumerate().for_each(|(part_idx, part)| {
+                job_index.insert(get_split_part_id(&job.id, part_idx), part.clone());
+            });
+            jobs.extend(parts);
+
+            return;
+        }
+
+        if let Some(transfer) = job.transfer.as_ref() {
+            // NOTE validation guarantees that transfer job has exactly one pickup and one delivery
+            let pickup = job.pickups.iter().flatten().next().expect("transfer job should have pickup");
+            let delivery = job.deliveries.iter().flatten().next().expect("transfer job should have delivery");
+            let hub =
+                vec![(Some(transfer.location.clone()), transfer.duration, parse_times(&None), transfer.tag.clone())];
+
+            let get_hub_single = |activity_type: &str, task: &JobTask, leg: TransferLeg| {
+                let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
+                let demand = match leg {
+                    TransferLeg::Inbound => Demand { pickup: (empty(), empty()), delivery: (empty(), capacity) },
+                    TransferLeg::Outbound => Demand { pickup: (empty(), capacity), delivery: (empty(), empty()) },
+                };
+
+                let mut single =
+                    get_single_with_dimens(hub.clone(), demand, &None, activity_type, has_multi_dimens, coord_index);
+                single.dimens.set_job_transfer_leg(leg);
+
+                single
+            };
+
+            // NOTE goods are dropped at the hub by inbound leg and collected there by outbound leg
+            let legs = [
+                vec![
+                    get_single_from_task(pickup, "pickup", false),
+                    get_hub_single("drop", pickup, TransferLeg::Inbound),
+                ],
+                vec![
+                    get_hub_single("collect", delivery, TransferLeg::Outbound),
+                    get_single_from_task(delivery, "delivery", false),
+                ],
+            ]
+            .map(|mut singles| {
+                set_assignment_costs(&mut singles[0]);
+                if let Some(product) = job.product.as_ref() {
+                    singles.iter_mut().for_each(|single| {
+                        single.dimens.set_job_product(product.clone());
+                    });
+                }
+
+                get_multi_job(job, singles, 1, None, random)
+            });
+
+            job_index.insert(job.id.clone(), legs[0].clone());
+            job_index.insert(get_transfer_outbound_id(&job.id), legs[1].clone());
+            jobs.extend(legs);
+
+            return;
+        }
+
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
 
-        let singles =
+        let mut singles =
             job.pickups
                 .iter()
                 .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
@@ -163,12 +264,21 @@ fn read_required_jobs(
                 .collect::<Vec<_>>();
 
         assert!(!singles.is_empty());
+        set_assignment_costs(&mut singles[0]);
 
+        if let Some(product) = job.product.as_ref() {
+            singles.iter_mut().for_each(|single| {
+                single.dimens.set_job_product(product.clone());
+            });
+        }
+
+        // NOTE outsourcing is not supported for split and transfer jobs, so plan default is applied only here
+        let outsourcing_cost = job.outsourcing_cost.or(api_problem.plan.outsourcing_cost);
         let problem_job = if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
+            get_multi_job(job, singles, deliveries_start_index, outsourcing_cost, random)
         } else {
-            get_single_job(job, singles.into_iter().next().unwrap())
+            get_single_job(job, singles.into_iter().next().unwrap(), outsourcing_cost)
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -178,11 +288,88 @@ fn read_required_jobs(
     (jobs, vec![])
 }
 
+/// Returns assignment costs of the job by vehicle id. A job specific cost for vehicle id has precedence over
+/// the one for vehicle type which, in turn, has precedence over a zone or default price of vehicle price list.
+fn get_assignment_costs(api_problem: &ApiProblem, job: &ApiJob) -> Option<HashMap<String, Cost>> {
+    let job_costs = job.assignment_costs.as_deref().unwrap_or_default();
+
+    let costs = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(move |vehicle_id| (vehicle, vehicle_id)))
+        .filter_map(|(vehicle, vehicle_id)| {
+            let price_list = vehicle.costs.price_list.as_deref().unwrap_or_default();
+
+            job_costs
+                .iter()
+                .find(|cost| cost.vehicle_id.as_ref() == Some(vehicle_id))
+                .or_else(|| job_costs.iter().find(|cost| cost.type_id.as_ref() == Some(&vehicle.type_id)))
+                .map(|cost| cost.cost)
+                .or_else(|| {
+                    price_list
+                        .iter()
+                        .find(|price| price.zone.is_some() && price.zone == job.zone)
+                        .or_else(|| price_list.iter().find(|price| price.zone.is_none()))
+                        .map(|price| price.cost)
+                })
+                .filter(|cost| *cost != 0.)
+                .map(|cost| (vehicle_id.clone(), cost))
+        })
+        .collect::<HashMap<_, _>>();
+
+    (!costs.is_empty()).then_some(costs)
+}
+
+/// Max amount of parts the demand of split job is divided into.
+const MAX_SPLIT_PARTS: usize = 16;
+
+/// Returns tasks of split job parts where the demand of the original task is divided evenly. Consecutive
+/// parts served by the same vehicle form a single visit, so the parts are small enough to let the solver
+/// choose the amount of visits and their sizes.
+fn get_split_tasks(job: &ApiJob) -> Option<(Vec<JobTask>, &'static str)> {
+    let split = job.split.as_ref()?;
+    let (task, activity_type) = match (job.pickups.as_deref(), job.deliveries.as_deref()) {
+        (Some([task]), None | Some([])) => (task, "pickup"),
+        (None | Some([]), Some([task])) => (task, "delivery"),
+        _ => return None,
+    };
+    let demand = task.demand.clone().unwrap_or_default();
+
+    // NOTE each part is not smaller than min size, so any visit respects it too
+    let limit = split
+        .min_size
+        .iter()
+        .flat_map(|min_size| min_size.iter().zip(demand.iter()))
+        .filter(|(min_size, _)| **min_size > 0)
+        .map(|(min_size, demand)| (demand / min_size).max(0) as usize)
+        .min()
+        .unwrap_or(usize::MAX);
+    let max_demand = demand.iter().copied().max().unwrap_or_default().max(0) as usize;
+    let parts = max_demand.min(MAX_SPLIT_PARTS).min(limit).max(1);
+
+    let tasks = (0..parts)
+        .map(|part_idx| {
+            let demand = demand
+                .iter()
+                .map(|value| value / parts as i32 + i32::from((part_idx as i32) < value % parts as i32))
+                .collect();
+
+            JobTask { demand: Some(demand), ..task.clone() }
+        })
+        .collect();
+
+    Some((tasks, activity_type))
+}
+
 fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job_index: &mut JobIndex) -> Vec<Job> {
     let mut jobs = vec![];
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
-        for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
+        // NOTE shifts of a multi-day tour are chained, so only the first one is used
+        let shift_count = if vehicle.overnight.is_some() { 1 } else { vehicle.shifts.len() };
+
+        for (shift_index, shift) in vehicle.shifts.iter().enumerate().take(shift_count) {
             if let Some(breaks) = &shift.breaks {
                 read_optional_breaks(coord_index, job_index, &mut jobs, vehicle, shift_index, breaks);
             }
@@ -194,6 +381,20 @@ fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job
             if let Some(recharges) = &shift.recharges {
                 read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
             }
+
+            if let Some(hours_of_service) = &vehicle.hours_of_service {
+                read_rests(coord_index, job_index, &mut jobs, vehicle, shift, shift_index, hours_of_service);
+            }
+
+            if let Some(trailer) = vehicle.trailer_id.as_ref().and_then(|trailer_id| {
+                api_problem.fleet.trailers.iter().flatten().find(|trailer| trailer.id == *trailer_id)
+            }) {
+                read_trailers(coord_index, job_index, &mut jobs, vehicle, shift_index, trailer);
+            }
+        }
+
+        if let Some(overnight) = &vehicle.overnight {
+            read_overnights(coord_index, job_index, &mut jobs, vehicle, overnight);
         }
     });
 
@@ -276,6 +477,7 @@ fn read_reloads(
             duration: reload.duration,
             times: reload.times.clone(),
             tag: reload.tag.clone(),
+            soft_times: None,
         }),
     )
 }
@@ -295,10 +497,143 @@ fn read_recharges(
         jobs,
         vehicle,
         shift_index,
-        recharges.stations.iter().cloned(),
+        recharges.stations.iter().map(|station| JobPlace {
+            location: station.location.clone(),
+            duration: station.duration,
+            times: station.times.clone(),
+            tag: station.tag.clone(),
+            soft_times: None,
+        }),
     )
 }
 
+fn read_rests(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift: &VehicleShift,
+    shift_index: usize,
+    hours_of_service: &VehicleHoursOfService,
+) {
+    // NOTE driving time within the shift cannot exceed its daily limit or the shift duration
+    let max_driving_time = hours_of_service.max_daily_driving_time.unwrap_or_else(|| {
+        shift
+            .end
+            .as_ref()
+            .map_or(MAX_REST_DRIVING_TIME, |end| parse_time(&end.latest) - parse_time(&shift.start.earliest))
+    });
+    let rest_count = ((max_driving_time / hours_of_service.max_driving_time).ceil() as usize).saturating_sub(1).max(1);
+
+    (1..=rest_count)
+        .flat_map(|rest_idx| {
+            vehicle.vehicle_ids.iter().map(move |vehicle_id| {
+                let job_id = format!("{vehicle_id}_rest_{shift_index}_{rest_idx}");
+                let places = vec![(None, hours_of_service.rest_duration, parse_times(&None), None)];
+
+                let job = get_conditional_job(coord_index, vehicle_id.clone(), &job_id, "rest", shift_index, places);
+
+                (job_id, job)
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
+fn read_overnights(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    overnight: &VehicleOvernight,
+) {
+    let locations = overnight.locations.as_ref().map_or(vec![None], |locations| {
+        locations.iter().cloned().map(Some).collect()
+    });
+
+    (1..)
+        .zip(vehicle.shifts.windows(2))
+        .flat_map(|(night_idx, shifts)| {
+            // NOTE the vehicle stays at the overnight stop from the end of one shift till the start of the next one
+            let night_end = parse_time(&shifts[1].start.earliest);
+            let night_start = shifts[0].end.as_ref().map_or(night_end, |end| parse_time(&end.latest));
+            let times = vec![TimeSpan::Window(TimeWindow::new(night_start, night_start))];
+            let duration = night_end - night_start;
+
+            let locations = &locations;
+            vehicle.vehicle_ids.iter().map(move |vehicle_id| {
+                let job_id = format!("{vehicle_id}_overnight_0_{night_idx}");
+                let places =
+                    locations.iter().map(|location| (location.clone(), duration, times.clone(), None)).collect();
+
+                let mut job = get_conditional_job(coord_index, vehicle_id.clone(), &job_id, "overnight", 0, places);
+                job.dimens.set_break_policy(BreakPolicy::SkipIfArrivalBeforeEnd);
+
+                if let Some(cost) = overnight.cost {
+                    job.dimens.set_job_fixed_cost(cost);
+                }
+
+                (job_id, job)
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
+fn read_trailers(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    trailer: &Trailer,
+) {
+    (1..)
+        .zip(trailer.parkings.iter())
+        .flat_map(|(parking_idx, parking)| {
+            vehicle.vehicle_ids.iter().map(move |vehicle_id| {
+                let job_id = format!("{vehicle_id}_trailer_{shift_index}_{parking_idx}");
+                let times = parse_times(&parking.times);
+                let places = vec![(Some(parking.location.clone()), parking.duration, times, parking.tag.clone())];
+
+                // NOTE trailer is decoupled and coupled back at the same parking
+                let singles = [("decouple", TrailerOperation::Decouple), ("couple", TrailerOperation::Couple)]
+                    .into_iter()
+                    .map(|(job_type, operation)| {
+                        let mut single = get_conditional_job(
+                            coord_index,
+                            vehicle_id.clone(),
+                            &job_id,
+                            job_type,
+                            shift_index,
+                            places.clone(),
+                        );
+                        single.dimens.set_job_trailer_operation(operation);
+
+                        Arc::new(single)
+                    })
+                    .collect();
+
+                let mut dimens: Dimensions = Default::default();
+                dimens
+                    .set_job_id(job_id.clone())
+                    .set_job_type("trailer".to_string())
+                    .set_shift_index(shift_index)
+                    .set_vehicle_id(vehicle_id.clone());
+
+                (job_id, Job::Multi(Multi::new_shared(singles, dimens)))
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .for_each(|(job_id, job)| {
+            job_index.insert(job_id, job.clone());
+            jobs.push(job);
+        });
+}
+
 fn read_specific_job_places(
     job_type: &str,
     coord_index: &CoordIndex,
@@ -398,8 +733,8 @@ fn get_single_with_dimens(
         dimens.set_job_demand(demand)
     } else {
         dimens.set_job_demand(Demand {
-            pickup: (SingleDimLoad::new(demand.pickup.0.load[0]), SingleDimLoad::new(demand.pickup.1.load[0])),
-            delivery: (SingleDimLoad::new(demand.delivery.0.load[0]), SingleDimLoad::new(demand.delivery.1.load[0])),
+            pickup: (SingleDimLoad::new(demand.pickup.0.get(0)), SingleDimLoad::new(demand.pickup.1.get(0))),
+            delivery: (SingleDimLoad::new(demand.delivery.0.get(0)), SingleDimLoad::new(demand.delivery.1.get(0))),
         })
     }
     .set_job_type(activity_type.to_string());
@@ -456,19 +791,52 @@ fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
     if let Some(skills) = get_skills(&job.skills) {
         dimens.set_job_skills(skills);
     }
+
+    if let Some(product) = job.product.clone() {
+        dimens.set_job_product(product);
+    }
+
+    if let Some(transfer) = job.transfer.as_ref() {
+        dimens.set_job_transfer_key(job.id.clone()).set_job_transfer_handling_time(transfer.handling_time);
+    }
+
+    if let Some(trailer_allowed) = job.trailer_allowed {
+        dimens.set_job_trailer_allowed(trailer_allowed);
+    }
 }
 
-fn get_single_job(job: &ApiJob, single: Single) -> Job {
+fn get_single_job(job: &ApiJob, single: Single, outsourcing_cost: Option<Cost>) -> Job {
     let mut single = single;
     fill_dimens(job, &mut single.dimens);
 
+    if let Some(outsourcing_cost) = outsourcing_cost {
+        single.dimens.set_job_outsourcing_cost(outsourcing_cost);
+    }
+
     Job::Single(Arc::new(single))
 }
 
-fn get_multi_job(job: &ApiJob, singles: Vec<Single>, deliveries_start_index: usize, random: &Arc<dyn Random>) -> Job {
+fn get_multi_job(
+    job: &ApiJob,
+    singles: Vec<Single>,
+    deliveries_start_index: usize,
+    outsourcing_cost: Option<Cost>,
+    random: &Arc<dyn Random>,
+) -> Job {
     let mut dimens: Dimensions = Default::default();
     fill_dimens(job, &mut dimens);
 
+    if let Some(outsourcing_cost) = outsourcing_cost {
+        dimens.set_job_outsourcing_cost(outsourcing_cost);
+    }
+
+    if let Some(max_ride_time) = job.max_ride_time.as_ref() {
+        dimens.set_job_max_ride_time(match max_ride_time {
+            JobMaxRideTime::Duration(duration) => MaxRideTime::Duration(*duration),
+            JobMaxRideTime::Factor(factor) => MaxRideTime::Factor(*factor),
+        });
+    }
+
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
     let multi = if singles.len() == 2 && deliveries_start_index == 1 {
@@ -512,3 +880,32 @@ fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
     })
 }
+
+fn parse_soft_times(times: &Option<Vec<Vec<String>>>, soft: &Option<SoftTimeWindow>) -> Vec<TimeSpan> {
+    let times = parse_times(times);
+
+    // NOTE widen time windows by tolerance: the penalty is applied by soft time windows objective
+    match soft {
+        Some(soft) => times
+            .into_iter()
+            .map(|span| match span {
+                TimeSpan::Window(tw) => {
+                    TimeSpan::Window(TimeWindow::new(tw.start - soft.max_earliness, tw.end + soft.max_lateness))
+                }
+                span => span,
+            })
+            .collect(),
+        None => times,
+    }
+}
+
+fn get_soft_time_window(place: &JobPlace) -> Option<SoftTimeWindow> {
+    // NOTE soft extension makes sense only when time windows are specified
+    place.times.as_ref().and(place.soft_times.as_ref()).map(|soft| SoftTimeWindow {
+        max_earliness: soft.max_earliness.unwrap_or_default(),
+        max_lateness: soft.max_lateness.unwrap_or_default(),
+        // NOTE costs are specified per minute, but time is measured in seconds
+        earliness_cost: soft.earliness_cost.unwrap_or_default() / 60.,
+        lateness_cost: soft.lateness_cost.unwrap_or_default() / 60.,
+    })
+}
@@ -4,6 +4,7 @@ use super::*;
 use crate::parse_time;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::features::{BaselinePlan, LoadingDocks};
 use vrp_core::models::Lock;
 use vrp_core::models::common::TimeWindow;
 use vrp_core::prelude::{ActivityCost, Fleet as CoreFleet, Jobs as CoreJobs, TransportCost};
@@ -22,6 +23,7 @@ mod clustering_reader;
 
 mod fleet_reader;
 pub use self::fleet_reader::create_approx_matrices;
+pub(crate) use self::fleet_reader::create_battery_model;
 
 mod goal_reader;
 mod job_reader;
@@ -29,6 +31,9 @@ mod job_reader;
 mod problem_reader;
 use self::problem_reader::{map_to_problem_with_approx, map_to_problem_with_matrices};
 
+mod replanning;
+pub use self::replanning::*;
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     /// Reads problem defined in pragmatic format.
@@ -98,11 +103,20 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
 /// Keeps track of problem properties (e.g. features).
 struct ProblemProperties {
     has_multi_dimen_capacity: bool,
+    has_compartments: bool,
+    has_loading_policy: bool,
+    has_max_ride_time: bool,
+    has_split_jobs: bool,
+    has_trailers: bool,
+    has_transfers: bool,
+    has_outsourcing: bool,
     has_breaks: bool,
     has_skills: bool,
     has_unreachable_locations: bool,
     has_reloads: bool,
     has_recharges: bool,
+    has_hours_of_service: bool,
+    has_overnights: bool,
     has_order: bool,
     has_group: bool,
     has_value: bool,
@@ -113,6 +127,7 @@ struct ProblemProperties {
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
     has_sync: bool,
+    has_soft_time_windows: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -124,6 +139,8 @@ struct ProblemBlocks {
     activity: Arc<dyn ActivityCost>,
     locks: Vec<Arc<Lock>>,
     reserved_times_index: ReservedTimesIndex,
+    baseline: Option<Arc<BaselinePlan>>,
+    loading_docks: Option<Arc<LoadingDocks>>,
 }
 
 fn parse_time_window(tw: &[String]) -> TimeWindow {
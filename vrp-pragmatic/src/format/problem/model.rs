@@ -4,6 +4,7 @@ mod model_test;
 
 extern crate serde_json;
 
+use crate::format::solution::Solution;
 use crate::format::{FormatError, Location, MultiFormatError};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -54,8 +55,28 @@ pub struct JobSkills {
     pub none_of: Option<Vec<String>>,
 }
 
+/// Specifies a soft extension of job place time windows: the place can be served outside of its
+/// time windows within given tolerance, but such deviation is penalized.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPlaceSoftTimes {
+    /// Max allowed earliness in seconds. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_earliness: Option<Float>,
+    /// Max allowed lateness in seconds. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lateness: Option<Float>,
+    /// A cost per minute of earliness. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliness_cost: Option<Float>,
+    /// A cost per minute of lateness. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness_cost: Option<Float>,
+}
+
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobPlace {
     /// A job place location.
     pub location: Location,
@@ -68,6 +89,9 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// A soft extension of place time windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_times: Option<JobPlaceSoftTimes>,
 }
 
 /// Specifies a job task.
@@ -126,6 +150,58 @@ pub struct JobSequence {
     pub days_between_max: Option<u32>,
 }
 
+/// Specifies a max ride time of pickup and delivery job: a max time between departure from
+/// the first pickup and service start at the last delivery.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobMaxRideTime {
+    /// An absolute max ride duration.
+    Duration(Float),
+    /// A factor of the direct travel time from the first pickup to the last delivery.
+    Factor(Float),
+}
+
+/// Specifies how the demand of the job can be split between multiple visits.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSplit {
+    /// A min demand served by a single visit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<Vec<i32>>,
+    /// A max amount of visits. Sizes of visits are decided by the solver. If omitted, the amount is not limited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_splits: Option<usize>,
+}
+
+/// Specifies a hub where goods of pickup and delivery job can be transferred between vehicles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobTransfer {
+    /// A hub location.
+    pub location: Location,
+    /// A duration of drop and collect activities at the hub.
+    pub duration: Float,
+    /// A min time between the end of drop and the start of collect needed to handle goods at the hub.
+    pub handling_time: Float,
+    /// A tag which will be propagated back within hub activities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// Specifies a cost of the job assignment to a vehicle. Either vehicle type id or vehicle id should be set.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobAssignmentCost {
+    /// A vehicle type id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_id: Option<String>,
+    /// A vehicle id, it has precedence over vehicle type id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_id: Option<String>,
+    /// A cost added to the tour cost when the job is assigned to the vehicle.
+    pub cost: Float,
+}
+
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
 /// which follows these rules:
 /// * all of them should be completed or none of them.
@@ -182,6 +258,38 @@ pub struct Job {
     /// Job synchronization: requires multiple vehicles to work on the same job simultaneously.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sync: Option<JobSync>,
+
+    /// A product type: it is used to assign job's load to vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+
+    /// A max ride time of pickup and delivery job.
+    #[serde(rename = "maxRideTime", skip_serializing_if = "Option::is_none")]
+    pub max_ride_time: Option<JobMaxRideTime>,
+
+    /// Allows to split job's demand between multiple visits, potentially done by different vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split: Option<JobSplit>,
+
+    /// Specifies whether the job can be served by the vehicle with attached trailer. Default is true.
+    #[serde(rename = "trailerAllowed", skip_serializing_if = "Option::is_none")]
+    pub trailer_allowed: Option<bool>,
+
+    /// Allows to transfer goods of pickup and delivery job between vehicles at the hub.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer: Option<JobTransfer>,
+
+    /// Job assignment costs which depend on the vehicle serving the job.
+    #[serde(rename = "assignmentCosts", skip_serializing_if = "Option::is_none")]
+    pub assignment_costs: Option<Vec<JobAssignmentCost>>,
+
+    /// A job zone: it is used to find job assignment cost in vehicle price lists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+
+    /// A cost of outsourcing the job instead of serving it by the fleet.
+    #[serde(rename = "outsourcingCost", skip_serializing_if = "Option::is_none")]
+    pub outsourcing_cost: Option<Float>,
 }
 
 // region Clustering
@@ -285,6 +393,10 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// A default outsourcing cost of jobs which have no own outsourcing cost.
+    #[serde(rename = "outsourcingCost", skip_serializing_if = "Option::is_none")]
+    pub outsourcing_cost: Option<Float>,
 }
 
 // endregion
@@ -485,6 +597,52 @@ pub struct VehicleCosts {
     /// Calculation mode for tiered costs (defaults to "highestTier" for backward compatibility).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub calculation_mode: Option<TieredCostCalculationMode>,
+
+    /// Time-of-day dependent rates which override time cost within their time windows.
+    #[serde(rename = "timeRates", skip_serializing_if = "Option::is_none")]
+    pub time_rates: Option<Vec<VehicleTimeRate>>,
+
+    /// A price list with costs charged per assigned job, e.g. negotiated with a subcontractor.
+    #[serde(rename = "priceList", skip_serializing_if = "Option::is_none")]
+    pub price_list: Option<Vec<VehiclePrice>>,
+}
+
+/// Specifies a price charged by vehicle per assigned job.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehiclePrice {
+    /// A job zone. If omitted, the price is used for jobs without more specific price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+
+    /// A price per assigned job.
+    pub cost: Float,
+}
+
+/// Specifies vehicle time cost rates which are in force within a daily time window.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTimeRate {
+    /// Weekdays (1 is Monday, 7 is Sunday) on which the time window starts. If omitted, every day is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekdays: Option<Vec<u8>>,
+
+    /// A start of the time window in `HH:MM` format (UTC).
+    pub start: String,
+
+    /// An end of the time window in `HH:MM` format (UTC). If it is not after the start, the window ends next day.
+    pub end: String,
+
+    /// Cost per driving time unit. If omitted, the vehicle's time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driving: Option<Float>,
+
+    /// Cost per service time unit. If omitted, the vehicle's time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Float>,
+
+    /// Cost per waiting time unit. If omitted, the vehicle's time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<Float>,
 }
 
 /// Specifies vehicle shift start.
@@ -540,6 +698,22 @@ pub struct VehicleShift {
     /// Vehicle recharge stations information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recharges: Option<VehicleRecharges>,
+
+    /// Vehicle overtime pricing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<VehicleOvertime>,
+}
+
+/// Specifies extra cost applied to the part of the shift which exceeds regular duration.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleOvertime {
+    /// A regular shift duration (in seconds), time beyond it is considered as overtime.
+    pub regular_duration: Float,
+
+    /// An extra cost per overtime unit on top of the vehicle's time cost - can be fixed or tiered.
+    /// Tier thresholds are measured from the regular duration.
+    pub cost: TieredCost,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
@@ -570,14 +744,82 @@ pub struct VehicleReload {
 #[serde(rename_all = "camelCase")]
 pub struct VehicleRecharges {
     /// Maximum traveled distance before recharge station has to be visited.
-    pub max_distance: Float,
+    /// Ignored when battery model is specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<Float>,
+
+    /// Distance already traveled since the last recharge at the start of the shift. Default is zero.
+    /// Ignored when battery model is specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_distance: Option<Float>,
+
+    /// Specifies battery model of an electric vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery: Option<VehicleBattery>,
 
     /// Specifies list of recharge station. Each can be visited only once.
     pub stations: Vec<VehicleRechargeStation>,
 }
 
-/// Specifies type alias for vehicle recharge station.
-pub type VehicleRechargeStation = JobPlace;
+/// Specifies battery model of an electric vehicle. Energy is measured in kWh.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleBattery {
+    /// Battery capacity.
+    pub capacity: Float,
+
+    /// Charge level at the start of the shift. Default is battery capacity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_charge: Option<Float>,
+
+    /// Minimum charge level which has to be kept all the time. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserve: Option<Float>,
+
+    /// Energy consumption of an empty vehicle per km.
+    pub consumption: Float,
+
+    /// Extra energy consumption per km per load unit. Load is a sum of all capacity dimensions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_consumption: Option<Float>,
+
+    /// Charger power curve: power (kW) is used while charge level is below given one.
+    pub charging_curve: Vec<ChargingCurvePoint>,
+}
+
+/// Specifies a point of the charger power curve.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChargingCurvePoint {
+    /// Charge level (kWh) up to which the power is used.
+    pub level: Float,
+
+    /// Charging power in kW.
+    pub power: Float,
+}
+
+/// Specifies a place where vehicle can be recharged.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleRechargeStation {
+    /// A station location.
+    pub location: Location,
+
+    /// A recharge duration (service time).
+    pub duration: Float,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// A shared charger resource id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+}
 
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize, Default)]
@@ -708,6 +950,14 @@ pub struct VehicleType {
     /// Vehicle capacity.
     pub capacity: Vec<i32>,
 
+    /// Vehicle compartments. Each compartment keeps only one product type per trip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<VehicleCompartment>>,
+
+    /// Vehicle loading policy for pickup and delivery jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading_policy: Option<VehicleLoadingPolicy>,
+
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
@@ -715,6 +965,102 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Driver hours-of-service rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours_of_service: Option<VehicleHoursOfService>,
+
+    /// A trailer id. Each vehicle of the type pulls its own trailer of this kind.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailer_id: Option<String>,
+
+    /// Multi-day tour settings. When specified, consecutive shifts of a vehicle are chained into one tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overnight: Option<VehicleOvernight>,
+
+    /// Vehicle emission model used to estimate CO2 emissions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<VehicleEmissions>,
+}
+
+/// Specifies vehicle emission model. Consumption is measured in fuel (or energy) units, e.g. liters or kWh.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleEmissions {
+    /// Consumption of an empty vehicle per km.
+    pub consumption: Float,
+
+    /// Consumption of a fully loaded vehicle per km. Consumption of a partially loaded vehicle is interpolated
+    /// linearly using the max ratio of the current load to capacity. Default is the same as `consumption`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_consumption: Option<Float>,
+
+    /// Consumption per hour while vehicle is idling, e.g. waiting for the time window start. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_consumption: Option<Float>,
+
+    /// Amount of CO2 (kg) emitted per consumption unit.
+    pub emission_factor: Float,
+}
+
+/// Specifies the order in which goods of pickup and delivery jobs can be unloaded from the vehicle.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VehicleLoadingPolicy {
+    /// Last in, first out: only goods which were loaded last can be delivered.
+    Lifo,
+    /// First in, first out: only goods which were loaded first can be delivered.
+    Fifo,
+}
+
+/// Specifies a vehicle compartment.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleCompartment {
+    /// A compartment id.
+    pub id: String,
+
+    /// A compartment capacity. With flexible dividers, it is a max size of the compartment.
+    pub capacity: Vec<i32>,
+
+    /// Product types which can be loaded into the compartment. If not set, any product is allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<String>>,
+}
+
+/// Specifies driver hours-of-service rules. Rests are reported as break activities in the solution.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleHoursOfService {
+    /// Max accumulated driving time (in seconds) after which a rest is required.
+    pub max_driving_time: Float,
+
+    /// A rest duration (in seconds).
+    pub rest_duration: Float,
+
+    /// Max total driving time (in seconds) per shift.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_daily_driving_time: Option<Float>,
+
+    /// Min rest (in seconds) between two consecutive shifts of the same vehicle. It is not enforced by
+    /// the solver: shift time windows have to be separated by it which is checked by validation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_daily_rest: Option<Float>,
+}
+
+/// Specifies overnight stops of a multi-day tour. The vehicle ends a shift at an overnight stop and starts
+/// the next one from the same place.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleOvernight {
+    /// Allowed overnight stop locations. If not specified, the vehicle stays overnight at the location
+    /// of its last activity of the day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locations: Option<Vec<Location>>,
+
+    /// A cost per night.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<Float>,
 }
 
 /// Specifies a vehicle profile.
@@ -753,6 +1099,66 @@ pub enum VehicleResource {
         /// A total resource capacity.
         capacity: Vec<i32>,
     },
+    /// A shared charger resource.
+    #[serde(rename(deserialize = "charger", serialize = "charger"))]
+    Charger {
+        /// Resource id.
+        id: String,
+        /// An amount of chargers which can be used at the same time.
+        capacity: usize,
+    },
+    /// A shared loading docks resource at depot location. Vehicles are loaded there when their shift
+    /// starts at this location or they reload there.
+    #[serde(rename(deserialize = "dock", serialize = "dock"))]
+    #[serde(rename_all = "camelCase")]
+    Dock {
+        /// Resource id.
+        id: String,
+        /// A depot location.
+        location: Location,
+        /// An amount of docks which can be used at the same time.
+        capacity: usize,
+        /// A fixed loading duration.
+        loading_time: Float,
+        /// An extra loading duration per unit of load (sum of all load dimensions).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unit_loading_time: Option<Float>,
+        /// Docks opening hours as a list of time windows with time specified in RFC3339 format.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        times: Option<Vec<Vec<String>>>,
+    },
+}
+
+/// Specifies a trailer which can be detached from the vehicle and parked, so the vehicle alone can
+/// serve jobs which are not accessible with the trailer.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Trailer {
+    /// A trailer id.
+    pub id: String,
+
+    /// A trailer capacity.
+    pub capacity: Vec<i32>,
+
+    /// Places where the trailer can be parked.
+    pub parkings: Vec<TrailerParking>,
+}
+
+/// Specifies a place where the trailer can be parked.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TrailerParking {
+    /// A place location.
+    pub location: Location,
+
+    /// A duration of decouple and couple activities (each of them).
+    pub duration: Float,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activities in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// Specifies fleet.
@@ -767,6 +1173,10 @@ pub struct Fleet {
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Specifies trailers which can be pulled by vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailers: Option<Vec<Trailer>>,
 }
 
 // endregion
@@ -810,6 +1220,15 @@ pub enum Objective {
     /// An objective to minimize sum of arrival times from all routes.
     MinimizeArrivalTime,
 
+    /// An objective to minimize makespan: the latest end time among all routes.
+    MinimizeMakespan,
+
+    /// An objective to minimize total waiting time of all routes.
+    MinimizeWaitingTime,
+
+    /// An objective to minimize total CO2 emissions estimated using vehicle emission models.
+    MinimizeEmissions,
+
     /// An objective to balance max load across all tours.
     BalanceMaxLoad,
 
@@ -834,12 +1253,33 @@ pub enum Objective {
     /// An objective to prefer jobs to be served as soon as possible.
     FastService,
 
+    /// An objective to minimize penalty of serving jobs outside of their time windows within
+    /// tolerance specified by soft time windows.
+    MinimizeTimeWindowPenalty,
+
     /// An objective to consider hierarchy of areas while serving jobs.
     HierarchicalAreas {
         /// Number of levels in area hierarchy.
         levels: usize,
     },
 
+    /// An objective to minimize changes of the solution relative to the baseline solution, e.g. previously
+    /// communicated plan.
+    #[serde(rename_all = "camelCase")]
+    MinimizeChanges {
+        /// A baseline solution.
+        baseline: Box<Solution>,
+        /// Max amount of positions job can be moved within the tour without penalty. Default is 2.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_position_shift: Option<usize>,
+        /// Max shift of job's arrival time (in seconds) without penalty. Default is 1800.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_arrival_shift: Option<Float>,
+        /// Specifies penalties for different types of changes.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        penalties: Option<ChangePenalties>,
+    },
+
     /// A multi objective allows to define multiple competitive objectives at the same layer of hierarchy.
     MultiObjective {
         /// An objective composition type.
@@ -864,6 +1304,20 @@ pub enum MultiStrategy {
     },
 }
 
+/// Specifies penalties used by minimize changes objective.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ChangePenalties {
+    /// A penalty for job served by another vehicle. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle: Option<Float>,
+    /// A penalty for job moved by more than allowed amount of positions. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Float>,
+    /// A penalty for job with arrival time shifted by more than allowed threshold. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrival: Option<Float>,
+}
+
 // endregion
 
 // region Common
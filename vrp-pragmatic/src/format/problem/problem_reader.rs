@@ -1,12 +1,13 @@
 use super::*;
 use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
-use crate::format::problem::goal_reader::create_goal_context;
+use crate::format::problem::goal_reader::{create_goal_context, get_baseline_plan};
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
-use crate::format::{FormatError, JobIndex};
+use crate::format::{BaselinePlanExtraProperty, FormatError, JobIndex, LoadingDocksExtraProperty};
 use crate::validation::ValidationContext;
 use crate::{CoordIndex, parse_time};
 use vrp_core::construction::enablers::*;
+use vrp_core::construction::features::OutsourcingViolationCodeExtraProperty;
 use vrp_core::models::problem::CoordinatedCostCalculator;
 use vrp_core::models::Extras;
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
@@ -47,9 +48,19 @@ pub(super) fn map_to_problem(
     extras.set_job_index(job_index.clone());
     blocks.job_index = Some(job_index);
 
-    let goal = Arc::new(create_goal_context(&api_problem, &blocks, &props).map_err(to_multi_format_error)?);
+    let (goal, objective_names) = create_goal_context(&api_problem, &blocks, &props).map_err(to_multi_format_error)?;
+    extras.set_objective_names(Arc::new(objective_names));
 
-    let ProblemBlocks { jobs, fleet, transport, activity, locks, reserved_times_index, .. } = blocks;
+    let ProblemBlocks { jobs, fleet, transport, activity, locks, reserved_times_index, baseline, loading_docks, .. } =
+        blocks;
+
+    if let Some(baseline) = baseline {
+        extras.set_baseline_plan(baseline);
+    }
+
+    if let Some(loading_docks) = loading_docks {
+        extras.set_loading_docks(loading_docks);
+    }
 
     if let Some(config) = create_cluster_config(&api_problem).map_err(to_multi_format_error)? {
         extras.set_cluster_config(Arc::new(config));
@@ -59,7 +70,11 @@ pub(super) fn map_to_problem(
         extras.set_reserved_times(Arc::new(reserved_times_index));
     }
 
-    Ok(CoreProblem { fleet, jobs, locks, goal, activity, transport, extras: Arc::new(extras) })
+    if props.has_outsourcing {
+        extras.set_outsourcing_violation_code(Arc::new(OUTSOURCING_CONSTRAINT_CODE));
+    }
+
+    Ok(CoreProblem { fleet, jobs, locks, goal: Arc::new(goal), activity, transport, extras: Arc::new(extras) })
 }
 
 fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> ReservedTimesIndex {
@@ -121,7 +136,12 @@ fn to_multi_format_error(error: GenericError) -> MultiFormatError {
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
     let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
+    let has_compartments = api_problem.fleet.vehicles.iter().any(|t| t.compartments.is_some());
+    let has_loading_policy = api_problem.fleet.vehicles.iter().any(|t| t.loading_policy.is_some());
+    // NOTE compartments are always modeled with multi dimensional load
+    let has_multi_dimen_capacity = has_compartments
+        || api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
+        || api_problem.fleet.trailers.iter().flatten().any(|t| t.capacity.len() > 1)
         || api_problem
             .plan
             .jobs
@@ -136,6 +156,9 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_breaks = shift_has_fn(|s| s.breaks.as_ref().is_some_and(|b| !b.is_empty()));
     let has_reloads = shift_has_fn(|s| s.reloads.as_ref().is_some_and(|r| !r.is_empty()));
     let has_recharges = shift_has_fn(|s| s.recharges.as_ref().is_some());
+    let has_hours_of_service = api_problem.fleet.vehicles.iter().any(|v| v.hours_of_service.is_some());
+    let has_overnights = api_problem.fleet.vehicles.iter().any(|v| v.overnight.is_some());
+    let has_trailers = api_problem.fleet.vehicles.iter().any(|v| v.trailer_id.is_some());
 
     let has_order = api_problem
         .plan
@@ -151,6 +174,11 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_affinity = api_problem.plan.jobs.iter().any(|job| job.affinity.is_some());
     let has_same_assignee = api_problem.plan.jobs.iter().any(|job| job.same_assignee_key.is_some());
     let has_sequence = api_problem.plan.jobs.iter().any(|job| job.sequence.is_some());
+    let has_max_ride_time = api_problem.plan.jobs.iter().any(|job| job.max_ride_time.is_some());
+    let has_split_jobs = api_problem.plan.jobs.iter().any(|job| job.split.is_some());
+    let has_transfers = api_problem.plan.jobs.iter().any(|job| job.transfer.is_some());
+    let has_outsourcing = api_problem.plan.outsourcing_cost.is_some()
+        || api_problem.plan.jobs.iter().any(|job| job.outsourcing_cost.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().is_some_and(|l| l.tour_size.is_some()));
 
@@ -161,14 +189,30 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|v| v.limits.as_ref().is_some_and(|l| l.max_duration.or(l.max_distance).or(l.max_activity_duration).is_some()));
 
     let has_sync = api_problem.plan.jobs.iter().any(|job| job.sync.is_some());
+    let has_soft_time_windows = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.soft_times.is_some());
 
     ProblemProperties {
         has_multi_dimen_capacity,
+        has_compartments,
+        has_loading_policy,
+        has_max_ride_time,
+        has_split_jobs,
+        has_trailers,
+        has_transfers,
+        has_outsourcing,
         has_breaks,
         has_skills,
         has_unreachable_locations,
         has_reloads,
         has_recharges,
+        has_hours_of_service,
+        has_overnights,
         has_order,
         has_group,
         has_value,
@@ -179,6 +223,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_tour_size_limits,
         has_tour_travel_limits,
         has_sync,
+        has_soft_time_windows,
     }
 }
 
@@ -263,5 +308,7 @@ fn get_problem_blocks(
         activity,
         locks,
         reserved_times_index,
+        baseline: get_baseline_plan(api_problem).map(Arc::new),
+        loading_docks: read_loading_docks(api_problem, problem_props, &coord_index).map(Arc::new),
     })
 }
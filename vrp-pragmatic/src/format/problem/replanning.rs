@@ -0,0 +1,772 @@
+//! Specifies logic to create a re-planning problem from a partially executed plan.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/replanning_test.rs"]
+mod replanning_test;
+
+use crate::format::Location;
+use crate::format::problem::*;
+use crate::format::solution::{Solution, Statistic, Stop, Tour};
+use crate::{format_time, parse_time_safe};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+use std::iter::once;
+use vrp_core::prelude::{Float, GenericError};
+
+/// Specifies an execution status of the job from the previous plan.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum JobExecutionStatus {
+    /// Job is completely served and excluded from re-planning.
+    Done,
+    /// Job is being served. For a job with pickups and deliveries, it means that all pickups are done
+    /// and deliveries are still on board. For other jobs, it means that vehicle is at job's location.
+    InProgress,
+    /// Job is cancelled and excluded from re-planning.
+    Cancelled,
+}
+
+/// Specifies execution state of the job from the previous plan.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobExecutionState {
+    /// Job id.
+    pub job_id: String,
+    /// Job execution status.
+    pub status: JobExecutionStatus,
+}
+
+/// Specifies execution state of the vehicle.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleExecutionState {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Current vehicle location.
+    pub location: Location,
+}
+
+/// Specifies a context of re-planning: current time and execution state of the previous plan.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplanningContext {
+    /// Current time in RFC3339 format.
+    pub time: String,
+    /// Current vehicle states.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicles: Option<Vec<VehicleExecutionState>>,
+    /// Job execution states. Jobs without state are considered as not yet served.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jobs: Option<Vec<JobExecutionState>>,
+    /// New jobs to be added to the plan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_jobs: Option<Vec<Job>>,
+}
+
+/// Deserializes re-planning context from json.
+pub fn deserialize_replanning_context<R: Read>(reader: BufReader<R>) -> Result<ReplanningContext, GenericError> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize re-planning context: {err}").into())
+}
+
+/// Creates a problem which contains only the remaining part of the previous plan:
+/// * done and cancelled jobs are removed
+/// * vehicles which have started their tour get own vehicle type with the shift started at
+///   the current time and location, reduced capacity and limits, break offsets, overtime regular
+///   duration and recharge state are adjusted by the elapsed time and the executed part of the tour
+/// * jobs which are on board of started vehicles are locked to them
+/// * shifts which are already finished are removed, shifts which are not started yet cannot start
+///   earlier than the current time
+/// * new jobs are added to the plan
+///
+/// Use `merge_replanning_solution` to get back a solution of the original problem.
+pub fn create_replanning_problem(
+    problem: &Problem,
+    solution: &Solution,
+    context: &ReplanningContext,
+) -> Result<Problem, GenericError> {
+    let now = parse_time_safe(&context.time)?;
+
+    let job_map = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+    let statuses = get_job_statuses(context, &job_map)?;
+    let locations = get_vehicle_locations(problem, context)?;
+
+    let progresses = get_vehicle_progresses(problem, solution, &job_map, &statuses, &locations, now)?;
+
+    let (vehicles, shift_index_map) = create_vehicles(problem, &progresses, now);
+    let jobs = create_jobs(problem, context, &statuses)?;
+    let relations = create_relations(problem, &jobs, &progresses, &shift_index_map);
+
+    Ok(Problem {
+        plan: Plan { jobs, relations, ..problem.plan.clone() },
+        fleet: Fleet { vehicles, ..problem.fleet.clone() },
+        objectives: problem.objectives.clone(),
+    })
+}
+
+/// Merges a solution of the re-planning problem with the executed part of the previous solution:
+/// * executed stops of started vehicles are kept as a prefix of their tours
+/// * vehicle type ids and shift indices refer to the original problem
+/// * distance and load of re-planned stops include the executed part of the tour
+///
+/// NOTE: tour and solution statistics are kept as is, so they cover only the re-planned part.
+pub fn merge_replanning_solution(
+    problem: &Problem,
+    solution: &Solution,
+    context: &ReplanningContext,
+    new_solution: &Solution,
+) -> Result<Solution, GenericError> {
+    let now = parse_time_safe(&context.time)?;
+
+    let job_map = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+    let statuses = get_job_statuses(context, &job_map)?;
+    let locations = get_vehicle_locations(problem, context)?;
+
+    let progresses = get_vehicle_progresses(problem, solution, &job_map, &statuses, &locations, now)?;
+    let (_, shift_index_map) = create_vehicles(problem, &progresses, now);
+
+    let type_ids = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(move |vehicle_id| (vehicle_id.as_str(), vehicle)))
+        .map(|(vehicle_id, vehicle)| (vehicle_id, vehicle.type_id.as_str()))
+        .collect::<HashMap<_, _>>();
+
+    let tours = new_solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let shift_index = shift_index_map
+                .get(&tour.vehicle_id)
+                .and_then(|indices| indices.get(tour.shift_index))
+                .copied()
+                .ok_or_else(|| {
+                    format!("cannot map shift index {} of vehicle '{}' back", tour.shift_index, tour.vehicle_id)
+                })?;
+            let type_id = type_ids
+                .get(tour.vehicle_id.as_str())
+                .ok_or_else(|| format!("unknown vehicle id in re-planning solution: '{}'", tour.vehicle_id))?;
+
+            let stops = match progresses.get(&tour.vehicle_id) {
+                Some(progress) if progress.executed_shift_index == shift_index => merge_stops(progress, &tour.stops),
+                _ => tour.stops.clone(),
+            };
+
+            Ok(Tour { type_id: type_id.to_string(), shift_index, stops, ..tour.clone() })
+        })
+        .collect::<Result<Vec<_>, GenericError>>()?;
+
+    // NOTE keep executed part of the tours which are not continued in the new solution
+    let mut executed_tours = progresses
+        .iter()
+        .filter(|(_, progress)| !progress.executed_stops.is_empty())
+        .filter(|(vehicle_id, progress)| {
+            !tours
+                .iter()
+                .any(|tour| &tour.vehicle_id == *vehicle_id && tour.shift_index == progress.executed_shift_index)
+        })
+        .map(|(vehicle_id, progress)| Tour {
+            vehicle_id: vehicle_id.clone(),
+            type_id: type_ids[vehicle_id.as_str()].to_string(),
+            shift_index: progress.executed_shift_index,
+            stops: progress.executed_stops.clone(),
+            statistic: Statistic::default(),
+        })
+        .collect::<Vec<_>>();
+    executed_tours.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id));
+
+    Ok(Solution { tours: tours.into_iter().chain(executed_tours).collect(), ..new_solution.clone() })
+}
+
+/// Keeps track of progress of the vehicle which has already started its tour.
+struct VehicleProgress {
+    shift_index: usize,
+    location: Location,
+    start_time: Float,
+    taken_breaks: usize,
+    used_capacity: Vec<i32>,
+    used_distance: Float,
+    elapsed_duration: Float,
+    recharge_distance: Float,
+    charge_level: Option<Float>,
+    served_activities: usize,
+    on_board_jobs: Vec<String>,
+    executed_shift_index: usize,
+    executed_stops: Vec<Stop>,
+}
+
+type ShiftIndexMap = HashMap<String, Vec<usize>>;
+
+fn get_job_statuses<'a>(
+    context: &'a ReplanningContext,
+    job_map: &HashMap<&str, &Job>,
+) -> Result<HashMap<&'a str, &'a JobExecutionStatus>, GenericError> {
+    context.jobs.iter().flatten().try_fold(HashMap::default(), |mut statuses, state| {
+        if !job_map.contains_key(state.job_id.as_str()) {
+            return Err(format!("unknown job id in re-planning context: '{}'", state.job_id).into());
+        }
+
+        if statuses.insert(state.job_id.as_str(), &state.status).is_some() {
+            return Err(format!("duplicated job id in re-planning context: '{}'", state.job_id).into());
+        }
+
+        Ok(statuses)
+    })
+}
+
+fn get_vehicle_locations<'a>(
+    problem: &Problem,
+    context: &'a ReplanningContext,
+) -> Result<HashMap<&'a str, &'a Location>, GenericError> {
+    let vehicle_ids =
+        problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.vehicle_ids.iter()).collect::<HashSet<_>>();
+
+    context.vehicles.iter().flatten().try_fold(HashMap::default(), |mut locations, state| {
+        if !vehicle_ids.contains(&state.vehicle_id) {
+            return Err(format!("unknown vehicle id in re-planning context: '{}'", state.vehicle_id).into());
+        }
+
+        if locations.insert(state.vehicle_id.as_str(), &state.location).is_some() {
+            return Err(format!("duplicated vehicle id in re-planning context: '{}'", state.vehicle_id).into());
+        }
+
+        Ok(locations)
+    })
+}
+
+fn get_vehicle_progresses(
+    problem: &Problem,
+    solution: &Solution,
+    job_map: &HashMap<&str, &Job>,
+    statuses: &HashMap<&str, &JobExecutionStatus>,
+    locations: &HashMap<&str, &Location>,
+    now: Float,
+) -> Result<HashMap<String, VehicleProgress>, GenericError> {
+    let tours = solution.tours.iter().fold(HashMap::<_, Vec<_>>::default(), |mut tours, tour| {
+        tours.entry(tour.vehicle_id.as_str()).or_default().push(tour);
+        tours
+    });
+
+    // NOTE in progress job with pickups has to be on board of some vehicle
+    let assigned = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .map(|activity| activity.job_id.as_str())
+        .collect::<HashSet<_>>();
+    if let Some(job_id) = statuses
+        .iter()
+        .filter(|(job_id, status)| {
+            **status == &JobExecutionStatus::InProgress && has_pickups_and_deliveries(job_map[*job_id])
+        })
+        .map(|(job_id, _)| *job_id)
+        .find(|job_id| !assigned.contains(job_id))
+    {
+        return Err(format!("job '{job_id}' is in progress, but not assigned in the previous solution").into());
+    }
+
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(move |vehicle_id| (vehicle, vehicle_id)))
+        .try_fold(HashMap::default(), |mut progresses, (vehicle, vehicle_id)| {
+            // NOTE use the latest tour with served jobs or the earliest one if nothing is served yet
+            let vehicle_tours = tours.get(vehicle_id.as_str()).map(|tours| tours.as_slice()).unwrap_or_default();
+            let tour = vehicle_tours
+                .iter()
+                .filter(|tour| {
+                    tour.stops.iter().flat_map(|stop| stop.activities().iter()).any(|activity| {
+                        is_served_activity(job_map, statuses, &activity.job_id, &activity.activity_type)
+                    })
+                })
+                .max_by_key(|tour| tour.shift_index)
+                .or_else(|| vehicle_tours.iter().min_by_key(|tour| tour.shift_index))
+                .copied();
+            let location = locations.get(vehicle_id.as_str()).copied();
+
+            if let Some(progress) = get_vehicle_progress(vehicle, tour, location, job_map, statuses, now)? {
+                progresses.insert(vehicle_id.clone(), progress);
+            }
+
+            Ok(progresses)
+        })
+}
+
+fn get_vehicle_progress(
+    vehicle: &VehicleType,
+    tour: Option<&Tour>,
+    location: Option<&Location>,
+    job_map: &HashMap<&str, &Job>,
+    statuses: &HashMap<&str, &JobExecutionStatus>,
+    now: Float,
+) -> Result<Option<VehicleProgress>, GenericError> {
+    let get_status = |job_id: &str| statuses.get(job_id).copied();
+    let is_served =
+        |job_id: &str, activity_type: &str| is_served_activity(job_map, statuses, job_id, activity_type);
+
+    let stops = tour.map(|tour| tour.stops.as_slice()).unwrap_or_default();
+    let last_executed = stops
+        .iter()
+        .rposition(|stop| stop.activities().iter().any(|activity| is_served(&activity.job_id, &activity.activity_type)));
+
+    if last_executed.is_none() && location.is_none() {
+        return Ok(None);
+    }
+
+    let shift_index = get_active_shift_index(vehicle, tour.map_or(0, |tour| tour.shift_index), now)?;
+    let shift = &vehicle.shifts[shift_index];
+
+    let location = location
+        .cloned()
+        .or_else(|| {
+            last_executed.and_then(|idx| stops[..=idx].iter().rev().find_map(|stop| stop.location().cloned()))
+        })
+        .unwrap_or_else(|| shift.start.location.clone());
+
+    let is_executed_stop = |idx: usize, stop: &Stop| {
+        last_executed.is_some_and(|last| idx <= last)
+            || parse_time_safe(&stop.schedule().departure).is_ok_and(|departure| departure <= now)
+    };
+
+    let mut start_time = now;
+    let mut taken_breaks = 0;
+    let mut used_capacity = vec![0; vehicle.capacity.len()];
+    let mut served_activities = 0;
+    let mut on_board_jobs = Vec::<String>::default();
+
+    // NOTE static deliveries are loaded at the start or at the last executed reload and stay
+    //      on board till the next reload, done static pickups stay on board till the next reload
+    let last_reload = stops
+        .iter()
+        .enumerate()
+        .filter(|(idx, stop)| is_executed_stop(*idx, stop))
+        .filter(|(_, stop)| stop.activities().iter().any(|activity| activity.activity_type == "reload"))
+        .map(|(idx, _)| idx)
+        .next_back();
+    let next_reload = stops
+        .iter()
+        .enumerate()
+        .skip(last_reload.map_or(0, |idx| idx + 1))
+        .find(|(_, stop)| stop.activities().iter().any(|activity| activity.activity_type == "reload"))
+        .map(|(idx, _)| idx);
+    let is_loading_segment =
+        |idx: usize| last_reload.is_none_or(|reload| idx > reload) && next_reload.is_none_or(|reload| idx < reload);
+
+    stops.iter().enumerate().for_each(|(idx, stop)| {
+        stop.activities().iter().for_each(|activity| {
+            let job_id = activity.job_id.as_str();
+
+            if activity.activity_type == "break" && job_id == "break" {
+                taken_breaks += if is_executed_stop(idx, stop) { 1 } else { 0 };
+                return;
+            }
+
+            let Some(job) = job_map.get(job_id) else { return };
+            let status = get_status(job_id);
+
+            if is_served(job_id, &activity.activity_type) {
+                served_activities += 1;
+            }
+
+            if status == Some(&JobExecutionStatus::InProgress) && !has_pickups_and_deliveries(job) {
+                let end = activity.time.as_ref().map_or(&stop.schedule().departure, |time| &time.end);
+                start_time = start_time.max(parse_time_safe(end).unwrap_or(now));
+            }
+
+            let is_static_delivery = job.pickups.is_none() && job.deliveries.is_some();
+            let is_static_pickup = job.pickups.is_some() && job.deliveries.is_none();
+            let is_pending = status.is_none();
+            let is_served_or_in_progress = !is_pending && status != Some(&JobExecutionStatus::Cancelled);
+            let is_in_progress_with_deliveries =
+                status == Some(&JobExecutionStatus::InProgress) && has_pickups_and_deliveries(job);
+
+            let is_on_board = (is_static_delivery && is_pending && is_loading_segment(idx))
+                || (is_in_progress_with_deliveries && activity.activity_type == "delivery");
+
+            if is_on_board && !on_board_jobs.iter().any(|id| id == job_id) {
+                on_board_jobs.push(job_id.to_string());
+            }
+
+            if is_static_pickup && is_served_or_in_progress && is_loading_segment(idx) {
+                let demand = get_task_demand(job.pickups.as_ref(), &activity.job_tag);
+                used_capacity.iter_mut().zip(demand.iter()).for_each(|(used, demand)| *used += *demand);
+            }
+        })
+    });
+
+    let executed_count = stops
+        .iter()
+        .enumerate()
+        .filter(|(idx, stop)| is_executed_stop(*idx, stop))
+        .map(|(idx, _)| idx + 1)
+        .next_back()
+        .unwrap_or_default();
+    let executed = &stops[..executed_count];
+    let get_distance = |stop: &Stop| stop.as_point().map(|point| point.distance as Float);
+
+    let tour_start = stops.first().and_then(|stop| parse_time_safe(&stop.schedule().departure).ok());
+    let elapsed_duration = tour_start.map_or(0., |tour_start| (start_time - tour_start).max(0.));
+    let used_distance = executed.iter().rev().find_map(get_distance).unwrap_or_default();
+
+    let recharge_distance = match executed
+        .iter()
+        .rposition(|stop| stop.activities().iter().any(|activity| activity.activity_type == "recharge"))
+    {
+        Some(idx) => used_distance - get_distance(&executed[idx]).unwrap_or_default(),
+        None => used_distance + shift.recharges.as_ref().and_then(|recharges| recharges.initial_distance).unwrap_or(0.),
+    };
+    let charge_level = executed
+        .iter()
+        .rev()
+        .find_map(|stop| stop.as_point().and_then(|point| point.charge.as_ref()))
+        .map(|charge| charge.departure);
+
+    // NOTE keep special activities (e.g. departure or break) and served activities of executed stops
+    let executed_stops = executed
+        .iter()
+        .cloned()
+        .filter_map(|mut stop| {
+            stop.activities_mut().retain(|activity| {
+                !job_map.contains_key(activity.job_id.as_str()) || is_served(&activity.job_id, &activity.activity_type)
+            });
+
+            if stop.activities().is_empty() { None } else { Some(stop) }
+        })
+        .collect();
+
+    Ok(Some(VehicleProgress {
+        shift_index,
+        location,
+        start_time,
+        taken_breaks,
+        used_capacity,
+        used_distance,
+        elapsed_duration,
+        recharge_distance,
+        charge_level,
+        served_activities,
+        on_board_jobs,
+        executed_shift_index: tour.map_or(shift_index, |tour| tour.shift_index),
+        executed_stops,
+    }))
+}
+
+fn get_active_shift_index(vehicle: &VehicleType, tour_shift_index: usize, now: Float) -> Result<usize, GenericError> {
+    if tour_shift_index >= vehicle.shifts.len() {
+        return Err(format!("invalid shift index {tour_shift_index} for vehicle type '{}'", vehicle.type_id).into());
+    }
+
+    Ok(vehicle
+        .shifts
+        .iter()
+        .enumerate()
+        .skip(tour_shift_index)
+        .find(|(_, shift)| !is_finished_shift(shift, now))
+        .map_or(tour_shift_index, |(idx, _)| idx))
+}
+
+fn create_vehicles(
+    problem: &Problem,
+    progresses: &HashMap<String, VehicleProgress>,
+    now: Float,
+) -> (Vec<VehicleType>, ShiftIndexMap) {
+    let mut shift_index_map = ShiftIndexMap::default();
+
+    let vehicles = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            let (started, idle): (Vec<_>, Vec<_>) =
+                vehicle.vehicle_ids.iter().partition(|vehicle_id| progresses.contains_key(vehicle_id.as_str()));
+
+            let kept_shifts = (0..vehicle.shifts.len())
+                .filter(|idx| !is_finished_shift(&vehicle.shifts[*idx], now))
+                .collect::<Vec<_>>();
+            let idle = if idle.is_empty() || kept_shifts.is_empty() {
+                None
+            } else {
+                idle.iter().for_each(|vehicle_id| {
+                    shift_index_map.insert(vehicle_id.to_string(), kept_shifts.clone());
+                });
+
+                Some(VehicleType {
+                    vehicle_ids: idle.into_iter().cloned().collect(),
+                    shifts: kept_shifts.iter().map(|idx| clamp_shift(&vehicle.shifts[*idx], now)).collect(),
+                    ..vehicle.clone()
+                })
+            };
+
+            let started = started
+                .into_iter()
+                .map(|vehicle_id| {
+                    let progress = &progresses[vehicle_id.as_str()];
+                    let kept_shifts = once(progress.shift_index)
+                        .chain(kept_shifts.iter().copied().filter(|idx| *idx > progress.shift_index))
+                        .collect::<Vec<_>>();
+                    shift_index_map.insert(vehicle_id.clone(), kept_shifts.clone());
+
+                    create_started_vehicle(vehicle, vehicle_id, progress, &kept_shifts, now)
+                })
+                .collect::<Vec<_>>();
+
+            idle.into_iter().chain(started)
+        })
+        .collect();
+
+    (vehicles, shift_index_map)
+}
+
+fn create_started_vehicle(
+    vehicle: &VehicleType,
+    vehicle_id: &str,
+    progress: &VehicleProgress,
+    kept_shifts: &[usize],
+    now: Float,
+) -> VehicleType {
+    let active_shift = &vehicle.shifts[progress.shift_index];
+    let active_shift = VehicleShift {
+        start: ShiftStart {
+            earliest: format_time(progress.start_time),
+            latest: None,
+            location: progress.location.clone(),
+        },
+        breaks: active_shift
+            .breaks
+            .as_ref()
+            .map(|breaks| {
+                breaks
+                    .iter()
+                    .skip(progress.taken_breaks)
+                    .map(|vehicle_break| shift_break_offsets(vehicle_break, progress.elapsed_duration))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|breaks| !breaks.is_empty()),
+        recharges: active_shift.recharges.as_ref().map(|recharges| VehicleRecharges {
+            initial_distance: recharges.max_distance.map(|_| progress.recharge_distance),
+            battery: recharges.battery.as_ref().map(|battery| VehicleBattery {
+                initial_charge: progress.charge_level.or(battery.initial_charge),
+                ..battery.clone()
+            }),
+            ..recharges.clone()
+        }),
+        overtime: active_shift.overtime.as_ref().map(|overtime| VehicleOvertime {
+            regular_duration: (overtime.regular_duration - progress.elapsed_duration).max(0.),
+            ..overtime.clone()
+        }),
+        ..active_shift.clone()
+    };
+
+    let shifts = once(active_shift)
+        .chain(kept_shifts.iter().skip(1).map(|idx| clamp_shift(&vehicle.shifts[*idx], now)))
+        .collect();
+
+    let capacity = vehicle
+        .capacity
+        .iter()
+        .zip(progress.used_capacity.iter())
+        .map(|(capacity, used)| (capacity - used).max(0))
+        .collect();
+
+    let limits = vehicle.limits.as_ref().map(|limits| VehicleLimits {
+        max_distance: limits.max_distance.map(|value| (value - progress.used_distance).max(0.)),
+        max_duration: limits.max_duration.map(|value| (value - progress.elapsed_duration).max(0.)),
+        tour_size: limits.tour_size.map(|value| value.saturating_sub(progress.served_activities)),
+        ..limits.clone()
+    });
+
+    VehicleType {
+        type_id: format!("{}_{}", vehicle.type_id, vehicle_id),
+        vehicle_ids: vec![vehicle_id.to_string()],
+        shifts,
+        capacity,
+        limits,
+        ..vehicle.clone()
+    }
+}
+
+fn create_jobs(
+    problem: &Problem,
+    context: &ReplanningContext,
+    statuses: &HashMap<&str, &JobExecutionStatus>,
+) -> Result<Vec<Job>, GenericError> {
+    let jobs = problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| match statuses.get(job.id.as_str()) {
+            None => Some(job.clone()),
+            Some(JobExecutionStatus::InProgress) if has_pickups_and_deliveries(job) => {
+                Some(Job { pickups: None, replacements: None, services: None, ..job.clone() })
+            }
+            Some(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    if let Some(job) = context.new_jobs.iter().flatten().find(|job| !ids.insert(job.id.as_str())) {
+        return Err(format!("duplicated job id in re-planning context: '{}'", job.id).into());
+    }
+
+    Ok(jobs.into_iter().chain(context.new_jobs.iter().flatten().cloned()).collect())
+}
+
+fn create_relations(
+    problem: &Problem,
+    jobs: &[Job],
+    progresses: &HashMap<String, VehicleProgress>,
+    shift_index_map: &ShiftIndexMap,
+) -> Option<Vec<Relation>> {
+    let original_ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let remaining_ids = jobs.iter().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let on_board_ids = progresses
+        .values()
+        .flat_map(|progress| progress.on_board_jobs.iter().map(|job_id| job_id.as_str()))
+        .collect::<HashSet<_>>();
+
+    let relations = problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .filter_map(|relation| {
+            let shift_index = match relation.shift_index {
+                Some(shift_index) => Some(
+                    shift_index_map
+                        .get(&relation.vehicle_id)?
+                        .iter()
+                        .position(|idx| *idx == shift_index)?,
+                ),
+                None => None,
+            };
+
+            // NOTE keep special activities (e.g. break or departure), but remove served and on board jobs
+            let job_ids = relation
+                .jobs
+                .iter()
+                .filter(|job_id| {
+                    let job_id = job_id.as_str();
+                    !original_ids.contains(job_id) || (remaining_ids.contains(job_id) && !on_board_ids.contains(job_id))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if job_ids.iter().any(|job_id| remaining_ids.contains(job_id.as_str())) {
+                Some(Relation { jobs: job_ids, shift_index, ..relation.clone() })
+            } else {
+                None
+            }
+        })
+        .chain(progresses.iter().filter(|(_, progress)| !progress.on_board_jobs.is_empty()).map(
+            |(vehicle_id, progress)| Relation {
+                type_field: RelationType::Any,
+                jobs: progress.on_board_jobs.clone(),
+                vehicle_id: vehicle_id.clone(),
+                shift_index: Some(0),
+            },
+        ))
+        .collect::<Vec<_>>();
+
+    if relations.is_empty() { None } else { Some(relations) }
+}
+
+/// Reduces break time offsets which are relative to the shift start by the elapsed time.
+fn shift_break_offsets(vehicle_break: &VehicleBreak, elapsed: Float) -> VehicleBreak {
+    let shift = |offset: Float| (offset - elapsed).max(0.);
+
+    match vehicle_break {
+        VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeOffset(offsets), places, policy } => {
+            VehicleBreak::Optional {
+                time: VehicleOptionalBreakTime::TimeOffset(offsets.iter().copied().map(shift).collect()),
+                places: places.clone(),
+                policy: policy.clone(),
+            }
+        }
+        VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime { earliest, latest }, duration } => {
+            VehicleBreak::Required {
+                time: VehicleRequiredBreakTime::OffsetTime { earliest: shift(*earliest), latest: shift(*latest) },
+                duration: *duration,
+            }
+        }
+        _ => vehicle_break.clone(),
+    }
+}
+
+/// Appends re-planned stops to the executed ones: the departure of the re-planned tour is removed,
+/// distance and load are adjusted by the executed part of the tour.
+fn merge_stops(progress: &VehicleProgress, stops: &[Stop]) -> Vec<Stop> {
+    let used_distance = progress.used_distance.round() as i64;
+    let mut is_loading_segment = true;
+
+    let replanned = stops.iter().cloned().filter_map(|mut stop| {
+        stop.activities_mut().retain(|activity| activity.activity_type != "departure");
+        if stop.activities().is_empty() {
+            return None;
+        }
+
+        if let Stop::Point(point) = &mut stop {
+            point.distance += used_distance;
+        }
+
+        // NOTE static pickups served before re-planning stay on board till the next reload
+        is_loading_segment &= !stop.activities().iter().any(|activity| activity.activity_type == "reload");
+        if is_loading_segment {
+            stop.load_mut().iter_mut().zip(progress.used_capacity.iter()).for_each(|(load, used)| *load += *used);
+        }
+
+        Some(stop)
+    });
+
+    progress.executed_stops.iter().cloned().chain(replanned).collect()
+}
+
+fn is_finished_shift(shift: &VehicleShift, now: Float) -> bool {
+    shift.end.as_ref().and_then(|end| parse_time_safe(&end.latest).ok()).is_some_and(|latest| latest < now)
+}
+
+fn clamp_shift(shift: &VehicleShift, now: Float) -> VehicleShift {
+    let clamp = |time: &String| {
+        if parse_time_safe(time).is_ok_and(|time| time < now) { format_time(now) } else { time.clone() }
+    };
+
+    VehicleShift {
+        start: ShiftStart {
+            earliest: clamp(&shift.start.earliest),
+            latest: shift.start.latest.as_ref().map(clamp),
+            location: shift.start.location.clone(),
+        },
+        ..shift.clone()
+    }
+}
+
+fn is_served_activity(
+    job_map: &HashMap<&str, &Job>,
+    statuses: &HashMap<&str, &JobExecutionStatus>,
+    job_id: &str,
+    activity_type: &str,
+) -> bool {
+    match statuses.get(job_id) {
+        Some(JobExecutionStatus::Done) => true,
+        Some(JobExecutionStatus::InProgress) => {
+            activity_type == "pickup" || !job_map.get(job_id).is_some_and(|job| has_pickups_and_deliveries(job))
+        }
+        _ => false,
+    }
+}
+
+fn has_pickups_and_deliveries(job: &Job) -> bool {
+    job.pickups.is_some() && job.deliveries.is_some()
+}
+
+fn get_task_demand(tasks: Option<&Vec<JobTask>>, tag: &Option<String>) -> Vec<i32> {
+    let tasks = tasks.map(|tasks| tasks.as_slice()).unwrap_or_default();
+
+    tasks
+        .iter()
+        .find(|task| tag.is_none() || task.places.iter().any(|place| place.tag == *tag))
+        .or(tasks.first())
+        .and_then(|task| task.demand.clone())
+        .unwrap_or_default()
+}
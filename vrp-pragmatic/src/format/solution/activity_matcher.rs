@@ -2,11 +2,12 @@ use crate::format::problem::VehicleBreak;
 use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime};
 use crate::format::solution::{Activity as FormatActivity, Schedule as FormatSchedule, Tour as FormatTour};
 use crate::format::solution::{PointStop, TransitStop};
-use crate::format::{CoordIndex, JobIndex, PlaceTagsDimension};
+use crate::format::{CoordIndex, JobIndex, JobTypeDimension, PlaceTagsDimension, get_transfer_outbound_id};
 use crate::parse_time;
 use std::collections::HashSet;
 use std::iter::once;
 use std::sync::Arc;
+use vrp_core::construction::features::JobTransferKeyDimension;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, JobIdDimension, Single};
 use vrp_core::models::solution::{Activity, Place};
@@ -38,9 +39,29 @@ pub(crate) fn try_match_point_job(
 
     match activity.activity_type.as_str() {
         "departure" | "arrival" => Ok(None),
-        "pickup" | "delivery" | "replacement" | "service" => {
+        "pickup" | "delivery" | "replacement" | "service" | "drop" | "collect" => {
             let job =
                 job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
+
+            if job.dimens().get_job_transfer_key().is_some() {
+                // NOTE each activity type is used only once within both legs of the transfer job
+                let outbound_id = get_transfer_outbound_id(&activity.job_id);
+                let outbound = job_index.get(&outbound_id).ok_or_else(|| format!("unknown job id: '{outbound_id}'"))?;
+
+                return once(job)
+                    .chain(once(outbound))
+                    .filter_map(|leg| leg.as_multi().map(|multi| (leg, multi)))
+                    .flat_map(|(leg, multi)| multi.jobs.iter().map(move |single| (leg, single)))
+                    .filter(|(_, single)| single.dimens.get_job_type().is_some_and(|job_type| job_type == ctx.act_type))
+                    .filter_map(|(leg, single)| {
+                        match_place(single, true, &ctx)
+                            .map(|place| JobInfo(leg.clone(), single.clone(), place, ctx.time.clone()))
+                    })
+                    .next()
+                    .map(Some)
+                    .ok_or_else(|| format!("cannot match job '{}'", activity.job_id).into());
+            }
+
             let singles = match job {
                 Job::Single(single) => Either::Left(once(single)),
                 Job::Multi(multi) => {
@@ -68,7 +89,24 @@ pub(crate) fn try_match_point_job(
 
             Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
         }
-        "break" | "reload" | "recharge" => Ok(Some(
+        "break" if activity.job_id == "rest" => {
+            // NOTE rests are identical, so match them by their order in the tour
+            let rest_idx = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|other| other.activity_type == "break" && other.job_id == "rest")
+                .position(|other| std::ptr::eq(other, activity))
+                .ok_or_else(|| format!("cannot find rest activity in tour '{}'", tour.vehicle_id))?;
+
+            let job_id = format!("{}_rest_{}_{}", tour.vehicle_id, tour.shift_index, rest_idx + 1);
+            let job = job_index.get(&job_id).ok_or_else(|| format!("unknown rest job id: '{job_id}'"))?;
+            let single = job.as_single().ok_or_else(|| format!("rest job '{job_id}' is not a single job"))?;
+            let place = match_place(single, false, &ctx).ok_or_else(|| format!("cannot match rest '{job_id}'"))?;
+
+            Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
+        }
+        "break" | "reload" | "recharge" | "overnight" => Ok(Some(
             (1..)
                 .map(|idx| format!("{}_{}_{}_{}", tour.vehicle_id, activity.activity_type, tour.shift_index, idx))
                 .map(|job_id| job_index.get(&job_id))
@@ -80,6 +118,20 @@ pub(crate) fn try_match_point_job(
                 .next()
                 .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
         )),
+        "decouple" | "couple" => Ok(Some(
+            (1..)
+                .map(|idx| format!("{}_trailer_{}_{}", tour.vehicle_id, tour.shift_index, idx))
+                .map(|job_id| job_index.get(&job_id))
+                .take_while(|job| job.is_some())
+                .filter_map(|job| job.and_then(|job| job.as_multi().map(|multi| (job.clone(), multi.clone()))))
+                .flat_map(|(job, multi)| multi.jobs.clone().into_iter().map(move |single| (job.clone(), single)))
+                .filter(|(_, single)| single.dimens.get_job_type().is_some_and(|job_type| job_type == ctx.act_type))
+                .filter_map(|(job, single)| {
+                    match_place(&single, false, &ctx).map(|place| JobInfo(job, single, place, ctx.time.clone()))
+                })
+                .next()
+                .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
+        )),
         _ => Err(format!("unknown activity type: {}", activity.activity_type).into()),
     }
 }
@@ -0,0 +1,171 @@
+//! Specifies logic to evaluate a solution which is, probably, edited manually.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/evaluation_test.rs"]
+mod evaluation_test;
+
+use super::solution_writer::{create_tour, get_activity_job_id, get_activity_type};
+use super::*;
+use crate::format::problem::{Matrix, PragmaticProblem, Problem as ApiProblem};
+use serde::{Deserialize, Serialize};
+use std::io::{BufWriter, Write};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{ActivityViolation, create_evaluation_context, get_route_violations};
+use vrp_core::models::solution::Route;
+use vrp_core::prelude::{Environment, Float, GenericError};
+use vrp_core::solver::processing::ReservedTimesExtraProperty;
+
+/// Specifies a hard constraint violation at the stop of the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StopViolation {
+    /// A violation code.
+    pub code: String,
+    /// A human readable description.
+    pub description: String,
+    /// Job id.
+    pub job_id: String,
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// An index of the stop in the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_index: Option<usize>,
+}
+
+/// Specifies a contribution of the objective to the solution fitness.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectiveValue {
+    /// An objective name.
+    pub name: String,
+    /// A fitness value as it is calculated by the solver.
+    pub value: Float,
+}
+
+/// Specifies an evaluation result of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionEvaluation {
+    /// A solution with recomputed schedules, statistics and costs.
+    pub solution: ApiSolution,
+    /// Hard constraint violations.
+    pub violations: Vec<StopViolation>,
+    /// Objective values in the order of their priority.
+    pub objectives: Vec<ObjectiveValue>,
+}
+
+/// Evaluates the solution using the same constraints and objectives as the solver does.
+/// Schedules are recomputed from the order of activities, so their times in the solution are
+/// used only to match job places. Activities are never removed from tours: instead, all hard
+/// constraint violations are reported within the stop where they happen. This includes jobs which
+/// the solver would unassign, e.g. when a shared resource like a loading dock is overbooked.
+pub fn evaluate_solution(
+    problem: ApiProblem,
+    matrices: Option<Vec<Matrix>>,
+    solution: ApiSolution,
+) -> Result<SolutionEvaluation, GenericError> {
+    let problem = Arc::new(
+        (problem, matrices).read_pragmatic().map_err(|errs| format!("cannot read pragmatic problem: '{errs}'"))?,
+    );
+    let environment = Arc::new(Environment::default());
+
+    let solution = create_init_solution(solution, problem.clone(), environment.random.clone())?;
+    let (insertion_ctx, removed_jobs) = create_evaluation_context(problem.clone(), solution, environment);
+
+    let (_, coord_index) = get_indices(&problem.extras)?;
+    let empty_reserved_times = Default::default();
+    let reserved_times_index = problem.extras.get_reserved_times();
+    let reserved_times_index = reserved_times_index.as_ref().unwrap_or(&empty_reserved_times);
+
+    let violations = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let route = route_ctx.route();
+            let tour = create_tour(problem.as_ref(), route, &coord_index, reserved_times_index);
+
+            let mut violations = get_route_violations(&insertion_ctx, route_ctx);
+
+            // NOTE jobs which would be removed from the tour by some feature are reported at their first activity
+            // unless there is already a violation reported for them
+            violations.extend(
+                removed_jobs
+                    .iter()
+                    .filter(|(job, _)| violations.iter().all(|violation| violation.job != *job))
+                    .filter_map(|(job, code)| {
+                        route.tour.index(job).map(|activity_idx| ActivityViolation {
+                            activity_idx,
+                            job: job.clone(),
+                            code: *code,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            violations.sort_by_key(|violation| violation.activity_idx);
+
+            violations
+                .into_iter()
+                .map(|violation| {
+                    let (code, description) = map_code_reason(violation.code);
+                    let (job_id, stop_index) = get_job_id_with_stop_index(route, &tour, violation.activity_idx);
+
+                    StopViolation {
+                        code: code.to_string(),
+                        description: description.to_string(),
+                        job_id,
+                        vehicle_id: tour.vehicle_id.clone(),
+                        shift_index: tour.shift_index,
+                        stop_index,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let objective_names = problem.extras.get_objective_names().unwrap_or_default();
+    let objectives = problem
+        .goal
+        .fitness(&insertion_ctx)
+        .enumerate()
+        .map(|(idx, value)| ObjectiveValue {
+            name: objective_names.get(idx).cloned().unwrap_or_else(|| format!("objective_{idx}")),
+            value,
+        })
+        .collect();
+
+    let solution = create_solution(problem.as_ref(), &insertion_ctx.into(), &PragmaticOutputType::OnlyPragmatic);
+
+    Ok(SolutionEvaluation { solution, violations, objectives })
+}
+
+/// Serializes solution evaluation into json format.
+pub fn serialize_solution_evaluation<W: Write>(
+    evaluation: &SolutionEvaluation,
+    writer: &mut BufWriter<W>,
+) -> Result<(), GenericError> {
+    serde_json::to_writer_pretty(writer, evaluation).map_err(|err| err.to_string().into())
+}
+
+/// Matches core activity with the activity in the tour using order of activities with the same job id.
+fn get_job_id_with_stop_index(route: &Route, tour: &Tour, activity_idx: usize) -> (String, Option<usize>) {
+    let get_job_id = |activity| get_activity_job_id(activity, get_activity_type(activity).map_or("arrival", |t| t));
+
+    let activity = route.tour.get(activity_idx).expect("cannot get activity");
+    let job_id = get_job_id(activity);
+    let occurrence =
+        route.tour.all_activities().take(activity_idx).filter(|activity| get_job_id(activity) == job_id).count();
+
+    let stop_index = tour
+        .stops
+        .iter()
+        .enumerate()
+        .flat_map(|(stop_index, stop)| stop.activities().iter().map(move |activity| (stop_index, activity)))
+        .filter(|(_, activity)| activity.job_id == job_id)
+        .nth(occurrence)
+        .map(|(stop_index, _)| stop_index);
+
+    (job_id, stop_index)
+}
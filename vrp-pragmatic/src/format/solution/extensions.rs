@@ -11,6 +11,9 @@ impl Add for Statistic {
             duration: self.duration + rhs.duration,
             activity_duration: self.activity_duration + rhs.activity_duration,
             activity_distance: self.activity_distance + rhs.activity_distance,
+            assignment_cost: self.assignment_cost + rhs.assignment_cost,
+            outsourcing_cost: self.outsourcing_cost + rhs.outsourcing_cost,
+            emissions: self.emissions + rhs.emissions,
             times: Timing {
                 driving: self.times.driving + rhs.times.driving,
                 serving: self.times.serving + rhs.times.serving,
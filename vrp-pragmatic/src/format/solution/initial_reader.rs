@@ -3,15 +3,20 @@
 mod initial_reader_test;
 
 use crate::format::solution::Activity as FormatActivity;
+use crate::format::solution::Solution as FormatSolution;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
 use crate::format::solution::activity_matcher::{JobInfo, try_match_point_job};
-use crate::format::solution::{deserialize_solution, map_reason_code};
-use crate::format::{CoordIndex, JobIndex, ShiftIndexDimension, VehicleTypeDimension, get_indices};
+use crate::format::solution::{deserialize_solution, get_split_part_demand, map_reason_code};
+use crate::format::{
+    CoordIndex, JobIndex, OUTSOURCING_CONSTRAINT_CODE, ShiftIndexDimension, VehicleTypeDimension, get_indices,
+    get_split_part_id,
+};
 use crate::parse_time;
 use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::features::JobSplitKeyDimension;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Job, JobIdDimension, VehicleIdDimension};
@@ -30,6 +35,16 @@ pub fn read_init_solution<R: Read>(
 ) -> Result<Solution, GenericError> {
     let solution = deserialize_solution(solution).map_err(|err| format!("cannot deserialize solution: {err}"))?;
 
+    create_init_solution(solution, problem, random)
+}
+
+/// Creates core solution from pragmatic one.
+/// NOTE: Solution feasibility is not checked.
+pub(crate) fn create_init_solution(
+    solution: FormatSolution,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random>,
+) -> Result<Solution, GenericError> {
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
 
@@ -80,11 +95,38 @@ pub fn read_init_solution<R: Read>(
                 .map(|reason| UnassignmentInfo::Simple(map_reason_code(&reason.code)))
                 .ok_or_else(|| format!("cannot get reason for: {unassigned_job:?}"))?;
 
+            // NOTE all parts of split job share the same unassigned job entry
+            let parts = if job.dimens().get_job_split_key().is_some() {
+                get_all_split_parts(&unassigned_job.job_id, job_index.as_ref()).collect()
+            } else {
+                vec![job]
+            };
+
+            parts.into_iter().for_each(|part| {
+                added_jobs.insert(part.clone());
+                acc.push((part, code.clone()));
+            });
+
+            Ok(acc)
+        })?;
+
+    // NOTE outsourced jobs are not served by the fleet because it is cheaper to outsource them
+    let outsourced = solution
+        .outsourced
+        .unwrap_or_default()
+        .iter()
+        .try_fold::<Vec<_>, _, Result<_, GenericError>>(Default::default(), |mut acc, outsourced_job| {
+            let job = job_index
+                .get(&outsourced_job.job_id)
+                .cloned()
+                .ok_or_else(|| format!("cannot get job id for: {outsourced_job:?}"))?;
+
             added_jobs.insert(job.clone());
-            acc.push((job, code));
+            acc.push((job, UnassignmentInfo::Simple(OUTSOURCING_CONSTRAINT_CODE)));
 
             Ok(acc)
         })?;
+    unassigned.extend(outsourced);
 
     unassigned.extend(
         problem
@@ -117,6 +159,19 @@ fn try_insert_activity(
     };
 
     match try_match_point_job(tour, stop, activity, job_index, coord_index)? {
+        Some(JobInfo(job, _, place, time)) if job.dimens().get_job_split_key().is_some() => {
+            get_split_parts(activity, job_index, added_jobs)?.into_iter().for_each(|part| {
+                let single = part.to_single().clone();
+                added_jobs.insert(part);
+
+                route.tour.insert_last(Activity {
+                    place: place.clone(),
+                    schedule: Schedule { arrival: time.start, departure: time.end },
+                    job: Some(single),
+                    commute: None,
+                });
+            });
+        }
         Some(JobInfo(job, single, place, time)) => {
             let is_inserted = added_jobs.insert(job.clone());
             if !is_inserted && matches!(job, Job::Single(_)) {
@@ -149,6 +204,44 @@ fn try_insert_activity(
     Ok(())
 }
 
+/// Returns parts of the split job served by the activity. Consecutive parts are reported as a single
+/// activity with their total demand, so not yet assigned parts are consumed in order till it is reached.
+fn get_split_parts(
+    activity: &FormatActivity,
+    job_index: &JobIndex,
+    added_jobs: &HashSet<Job>,
+) -> Result<Vec<Job>, GenericError> {
+    let mut remaining = activity.demand.clone();
+    let parts = get_all_split_parts(&activity.job_id, job_index)
+        .filter(|part| !added_jobs.contains(part))
+        .take_while(|part| match remaining.as_mut() {
+            Some(remaining) if remaining.iter().any(|value| *value > 0) => {
+                let demand = part.as_single().and_then(|single| get_split_part_demand(single)).unwrap_or_default();
+                remaining.iter_mut().zip(demand).for_each(|(value, part)| *value -= part);
+                true
+            }
+            Some(_) => false,
+            // NOTE without demand, the activity serves exactly one part
+            None => remaining.replace(Vec::default()).is_none(),
+        })
+        .collect::<Vec<_>>();
+
+    let is_matched = remaining.is_some_and(|remaining| remaining.iter().all(|value| *value == 0));
+    if parts.is_empty() || !is_matched {
+        return Err(format!(
+            "cannot match demand of split job '{}' in tour with its unassigned parts, check for double assignment",
+            activity.job_id
+        )
+        .into());
+    }
+
+    Ok(parts)
+}
+
+fn get_all_split_parts<'a>(job_id: &'a str, job_index: &'a JobIndex) -> impl Iterator<Item = Job> + 'a {
+    (0..).map_while(move |part_idx| job_index.get(&get_split_part_id(job_id, part_idx)).cloned())
+}
+
 fn get_actor_key(actor: &Actor) -> ActorKey {
     let dimens = &actor.vehicle.dimens;
 
@@ -185,7 +278,8 @@ fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Rout
 
     set_activity_time(start_stop, start_activity, core_start)?;
 
-    if core_tour.end().is_some() {
+    // NOTE tour of open shift has no end activity, so end() returns start one
+    if actor.detail.end.is_some() {
         let end_stop = format_tour.stops.last().unwrap();
         let end_activity = end_stop.activities().first().ok_or_else(|| "end stop has no activities".to_string())?;
         let core_end = core_tour.all_activities_mut().last().unwrap();
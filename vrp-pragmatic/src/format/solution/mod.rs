@@ -5,6 +5,9 @@ pub(crate) mod activity_matcher;
 mod break_writer;
 use self::break_writer::insert_reserved_times_as_breaks;
 
+mod evaluation;
+pub use self::evaluation::*;
+
 mod extensions;
 
 mod geo_serializer;
@@ -12,12 +15,16 @@ pub use self::geo_serializer::*;
 
 mod initial_reader;
 pub use self::initial_reader::read_init_solution;
+use self::initial_reader::create_init_solution;
 
 mod model;
 pub use self::model::*;
 
 mod solution_writer;
-pub(crate) use self::solution_writer::create_solution;
+pub(crate) use self::solution_writer::{create_solution, get_charge_legs, get_split_part_demand};
+
+mod suggestions;
+pub use self::suggestions::*;
 
 use super::*;
 use crate::{format_time, parse_time};
@@ -107,6 +114,29 @@ fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
         AFFINITY_CONSTRAINT_CODE => ("AFFINITY_CONSTRAINT", "cannot be assigned due to vehicle affinity constraint"),
         SAME_ASSIGNEE_CONSTRAINT_CODE => ("SAME_ASSIGNEE_CONSTRAINT", "cannot be assigned due to same assignee constraint"),
         JOB_SEQUENCE_CONSTRAINT_CODE => ("JOB_SEQUENCE_CONSTRAINT", "cannot be assigned due to job sequence constraint"),
+        HOURS_OF_SERVICE_CONSTRAINT_CODE => {
+            ("HOURS_OF_SERVICE_CONSTRAINT", "cannot be assigned due to driver hours-of-service constraint")
+        }
+        OVERNIGHT_CONSTRAINT_CODE => ("OVERNIGHT_CONSTRAINT", "cannot be assigned due to overnight stop constraint"),
+        CHARGER_RESOURCE_CONSTRAINT_CODE => {
+            ("CHARGER_RESOURCE_CONSTRAINT", "cannot be assigned due to charger resource constraint")
+        }
+        DOCK_RESOURCE_CONSTRAINT_CODE => {
+            ("DOCK_RESOURCE_CONSTRAINT", "cannot be assigned due to loading dock constraint")
+        }
+        COMPARTMENT_CONSTRAINT_CODE => {
+            ("COMPARTMENT_CONSTRAINT", "cannot be assigned due to vehicle compartments constraint")
+        }
+        LOADING_POLICY_CONSTRAINT_CODE => {
+            ("LOADING_POLICY_CONSTRAINT", "cannot be assigned due to vehicle loading policy constraint")
+        }
+        MAX_RIDE_TIME_CONSTRAINT_CODE => {
+            ("MAX_RIDE_TIME_CONSTRAINT", "cannot be assigned due to max ride time constraint")
+        }
+        SPLIT_JOB_CONSTRAINT_CODE => ("SPLIT_JOB_CONSTRAINT", "cannot be assigned due to split job constraint"),
+        TRAILER_CONSTRAINT_CODE => ("TRAILER_CONSTRAINT", "cannot be assigned due to trailer constraint"),
+        TRANSFER_CONSTRAINT_CODE => ("TRANSFER_CONSTRAINT", "cannot be assigned due to transfer constraint"),
+        OUTSOURCING_CONSTRAINT_CODE => ("OUTSOURCING_CONSTRAINT", "cheaper to outsource than to serve by the fleet"),
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -132,6 +162,17 @@ fn map_reason_code(reason: &str) -> ViolationCode {
         "AFFINITY_CONSTRAINT" => AFFINITY_CONSTRAINT_CODE,
         "SAME_ASSIGNEE_CONSTRAINT" => SAME_ASSIGNEE_CONSTRAINT_CODE,
         "JOB_SEQUENCE_CONSTRAINT" => JOB_SEQUENCE_CONSTRAINT_CODE,
+        "HOURS_OF_SERVICE_CONSTRAINT" => HOURS_OF_SERVICE_CONSTRAINT_CODE,
+        "OVERNIGHT_CONSTRAINT" => OVERNIGHT_CONSTRAINT_CODE,
+        "CHARGER_RESOURCE_CONSTRAINT" => CHARGER_RESOURCE_CONSTRAINT_CODE,
+        "DOCK_RESOURCE_CONSTRAINT" => DOCK_RESOURCE_CONSTRAINT_CODE,
+        "COMPARTMENT_CONSTRAINT" => COMPARTMENT_CONSTRAINT_CODE,
+        "LOADING_POLICY_CONSTRAINT" => LOADING_POLICY_CONSTRAINT_CODE,
+        "MAX_RIDE_TIME_CONSTRAINT" => MAX_RIDE_TIME_CONSTRAINT_CODE,
+        "SPLIT_JOB_CONSTRAINT" => SPLIT_JOB_CONSTRAINT_CODE,
+        "TRAILER_CONSTRAINT" => TRAILER_CONSTRAINT_CODE,
+        "TRANSFER_CONSTRAINT" => TRANSFER_CONSTRAINT_CODE,
+        "OUTSOURCING_CONSTRAINT" => OUTSOURCING_CONSTRAINT_CODE,
         _ => ViolationCode::unknown(),
     }
 }
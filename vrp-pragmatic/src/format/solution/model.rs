@@ -43,6 +43,15 @@ pub struct Statistic {
     /// Activity distance.
     #[serde(default, skip_serializing_if = "is_zero_i64")]
     pub activity_distance: i64,
+    /// Job assignment cost, it is included into total cost.
+    #[serde(default, skip_serializing_if = "is_zero_f64")]
+    pub assignment_cost: Float,
+    /// Outsourcing cost of jobs not served by the fleet, it is included into total cost.
+    #[serde(default, skip_serializing_if = "is_zero_f64")]
+    pub outsourcing_cost: Float,
+    /// Estimated CO2 emissions (kg).
+    #[serde(default, skip_serializing_if = "is_zero_f64")]
+    pub emissions: Float,
     /// Timing statistic.
     pub times: Timing,
 }
@@ -51,6 +60,10 @@ fn is_zero_i64(value: &i64) -> bool {
     *value == 0
 }
 
+fn is_zero_f64(value: &Float) -> bool {
+    *value == 0.
+}
+
 /// Represents a schedule.
 #[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
 pub struct Schedule {
@@ -112,6 +125,17 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// Time in seconds the activity is started before its time window. Can be present only
+    /// when job place has soft time windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earliness: Option<i64>,
+    /// Time in seconds the activity is started after its time window. Can be present only
+    /// when job place has soft time windows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<i64>,
+    /// A part of the job demand served by the activity. Can be present only when the job is split.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub demand: Option<Vec<i32>>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -223,10 +247,51 @@ pub struct PointStop {
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
+    /// Battery charge level of an electric vehicle at the stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<StopCharge>,
+    /// A loading dock slot used by the vehicle at the stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dock: Option<DockSlot>,
+    /// Vehicle compartments load after departure from this stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<StopCompartment>>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
 
+/// Specifies battery charge level (kWh) of an electric vehicle at the stop.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct StopCharge {
+    /// Charge level at arrival.
+    pub arrival: Float,
+    /// Charge level at departure.
+    pub departure: Float,
+}
+
+/// Specifies a time when the vehicle occupies a loading dock.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct DockSlot {
+    /// A dock resource id.
+    pub id: String,
+    /// Loading start time in RFC3339 format.
+    pub start: String,
+    /// Loading end time in RFC3339 format.
+    pub end: String,
+}
+
+/// Specifies a product and load of the vehicle compartment.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct StopCompartment {
+    /// A compartment id.
+    pub id: String,
+    /// A product type assigned to the compartment on the current trip.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+    /// A compartment load.
+    pub load: Vec<i32>,
+}
+
 /// A tour is list of stops with their activities performed by specific vehicle.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -276,6 +341,16 @@ pub struct UnassignedJob {
     pub reasons: Vec<UnassignedJobReason>,
 }
 
+/// Outsourced job.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OutsourcedJob {
+    /// Job id.
+    pub job_id: String,
+    /// Outsourcing cost.
+    pub cost: Float,
+}
+
 /// Specifies a type of violation.
 #[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -289,6 +364,14 @@ pub enum Violation {
         /// Index of the shift.
         shift_index: usize,
     },
+    /// An overnight stop assignment violation.
+    #[serde(rename(deserialize = "overnight", serialize = "overnight"))]
+    Overnight {
+        /// An id of a vehicle overnight stop belong to.
+        vehicle_id: String,
+        /// Index of the shift.
+        shift_index: usize,
+    },
 }
 
 /// Encapsulates different measurements regarding algorithm evaluation.
@@ -350,6 +433,21 @@ pub struct Extras {
     /// Represents solution as a collection of geo json features.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub features: Option<FeatureCollection>,
+
+    /// Amount of changes relative to the baseline solution specified by minimize changes objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<BaselineChanges>,
+}
+
+/// Keeps amount of jobs which deviate from the baseline solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct BaselineChanges {
+    /// Amount of jobs served by another vehicle.
+    pub vehicle: usize,
+    /// Amount of jobs moved by more than allowed amount of positions.
+    pub position: usize,
+    /// Amount of jobs with arrival time shifted by more than allowed threshold.
+    pub arrival: usize,
 }
 
 /// A VRP solution.
@@ -366,6 +464,10 @@ pub struct Solution {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unassigned: Option<Vec<UnassignedJob>>,
 
+    /// List of jobs which are cheaper to outsource than to serve by the fleet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outsourced: Option<Vec<OutsourcedJob>>,
+
     /// List of constraint violations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub violations: Option<Vec<Violation>>,
@@ -2,15 +2,23 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
-use crate::format::CoordIndex;
+use crate::format::{BaselinePlanExtraProperty, CoordIndex, LoadingDocksExtraProperty};
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
+use std::collections::HashSet;
 use vrp_core::construction::enablers::{get_route_intervals, ReservedTimesIndex};
-use vrp_core::construction::features::JobDemandDimension;
+use vrp_core::construction::features::{
+    BatteryModel, JobDemandDimension, JobSplitKeyDimension, JobTransferKeyDimension, LoadingDocks, SolutionChanges,
+    VehicleBatteryDimension, VehicleCapacityDimension, VehicleCompartmentsDimension, VehicleEmissionsDimension,
+    get_activity_service_start, get_activity_time_window_deviation, get_compartment_trips, get_outsourced_job_cost,
+};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
+use vrp_core::models::problem::{
+    Actor, JobFixedCostDimension, JobIdDimension, Multi, Single, TimeCostKind, TravelTime,
+    VehicleIdDimension, VehicleOvertimeCostsDimension, get_job_assignment_cost, get_vehicle_time_cost,
+};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::prelude::Float;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
@@ -51,19 +59,24 @@ pub(crate) fn create_solution(
         .map(|r| create_tour(problem, r, &coord_index, reserved_times_index))
         .collect::<Vec<Tour>>();
 
-    let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
+    let outsourced = create_outsourced(solution);
+    let outsourcing_cost = outsourced.iter().flatten().map(|job| job.cost).sum::<Float>();
+    let statistic = tours.iter().fold(
+        Statistic { cost: outsourcing_cost, outsourcing_cost, ..Statistic::default() },
+        |acc, tour| acc + tour.statistic.clone(),
+    );
 
     let unassigned = create_unassigned(solution);
     let violations = create_violations(solution);
 
-    let api_solution = ApiSolution { statistic, tours, unassigned, violations, extras: None };
+    let api_solution = ApiSolution { statistic, tours, unassigned, outsourced, violations, extras: None };
 
-    let extras = create_extras(problem, &api_solution, solution.telemetry.as_ref(), output_type);
+    let extras = create_extras(problem, solution, &api_solution, output_type);
 
     ApiSolution { extras, ..api_solution }
 }
 
-fn create_tour(
+pub(crate) fn create_tour(
     problem: &DomainProblem,
     route: &Route,
     coord_index: &CoordIndex,
@@ -84,6 +97,9 @@ fn create_tour(
         statistic: Statistic::default(),
     };
 
+    let emissions = vehicle.dimens.get_vehicle_emissions();
+    let vehicle_capacity = get_vehicle_capacity(&vehicle.dimens).unwrap_or_default();
+
     let intervals = get_route_intervals(route, |a| get_activity_type(a).is_some_and(|t| t == "reload"));
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
@@ -123,8 +139,14 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    earliness: None,
+                    lateness: None,
+                    demand: None,
                 }],
                 parking: None,
+                charge: None,
+                dock: None,
+                compartments: None,
             }));
             (start_idx + 1, start)
         } else {
@@ -145,20 +167,15 @@ fn create_tour(
                 };
 
                 let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
-                let is_break = activity_type == "break";
+                let is_break = matches!(activity_type.as_str(), "break" | "rest" | "overnight");
 
                 let job_tag = act.job.as_ref().and_then(|single| {
                     get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
                         .cloned()
                 });
-                let job_id = match activity_type.as_str() {
-                    "pickup" | "delivery" | "replacement" | "service" => {
-                        let single = act.job.as_ref().unwrap();
-                        let id = single.dimens.get_job_id().cloned();
-                        id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_job_id().unwrap().clone())
-                    }
-                    _ => activity_type.clone(),
-                };
+                let job_id = get_activity_job_id(act, activity_type.as_str());
+                // NOTE driver rest is reported as a break which is distinguished by its job id
+                let activity_type = if activity_type == "rest" { "break".to_string() } else { activity_type };
 
                 let commute = act.commute.clone().unwrap_or_default();
                 let commuting = commute.duration();
@@ -182,7 +199,7 @@ fn create_tour(
                     };
 
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
-                let service_start = activity_arrival.max(act.place.time.start);
+                let service_start = get_activity_service_start(act, parking + act.schedule.arrival);
                 let waiting = service_start - activity_arrival;
                 let serving = act.place.duration - parking;
                 let service_end = service_start + serving;
@@ -190,12 +207,29 @@ fn create_tour(
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let fixed_cost =
+                    act.job.as_ref().and_then(|single| single.dimens.get_job_fixed_cost()).copied().unwrap_or_default();
+                let assignment_cost =
+                    act.job.as_ref().map_or(Cost::default(), |single| get_job_assignment_cost(single, &route.actor));
+                let waiting_cost = get_vehicle_time_cost(
+                    vehicle,
+                    TimeCostKind::Waiting,
+                    activity_arrival,
+                    waiting,
+                    vehicle.costs.per_waiting_time,
+                );
+                let total_cost = serving_cost + transport_cost + waiting_cost + fixed_cost + assignment_cost;
 
                 let location_distance =
                     transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
                         as i64;
-                let distance = leg.statistic.distance + location_distance - commute.forward.distance as i64;
+                let leg_distance = location_distance - commute.forward.distance as i64;
+                let distance = leg.statistic.distance + leg_distance;
+
+                let leg_emissions = emissions.map_or(Float::default(), |emissions| {
+                    emissions.get_driving_emissions(leg_distance as Float, prev_load.ratio(&vehicle_capacity))
+                        + emissions.get_idle_emissions(waiting)
+                });
 
                 let is_new_stop = match (act.commute.as_ref(), prev_location == act.place.location) {
                     (Some(commute), false) if commute.is_zero_distance() => true,
@@ -217,11 +251,16 @@ fn create_tour(
                         } else {
                             None
                         },
+                        charge: None,
+                        dock: None,
+                        compartments: None,
                         activities: vec![],
                     }));
                 }
 
                 let load = calculate_load(prev_load, act);
+                let (earliness, lateness) = get_activity_time_window_deviation(act, service_start);
+                let get_deviation = |value: Duration| if value > 0. { Some(value.round() as i64) } else { None };
 
                 let last = tour.stops.len() - 1;
                 let last = match tour.stops.get_mut(last).unwrap() {
@@ -231,20 +270,39 @@ fn create_tour(
 
                 last.time.departure = format_time(act.schedule.departure);
                 last.load = load.as_vec();
-                last.activities.push(ApiActivity {
-                    job_id,
-                    activity_type: activity_type.clone(),
-                    location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
-                    time: Some(Interval {
-                        start: format_time(activity_arrival.max(act.place.time.start)),
-                        end: format_time(activity_departure),
-                    }),
-                    job_tag,
-                    commute: act
-                        .commute
-                        .as_ref()
-                        .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                // NOTE consecutive parts of split job are reported as a single visit
+                let split_demand = get_split_demand(act);
+                let split_visit = last.activities.last_mut().filter(|activity| {
+                    split_demand.is_some()
+                        && activity.demand.is_some()
+                        && activity.job_id == job_id
+                        && activity.activity_type == activity_type
                 });
+                if let Some((visit, split_demand)) = split_visit.zip(split_demand.as_ref()) {
+                    if let Some(time) = visit.time.as_mut() {
+                        time.end = format_time(activity_departure);
+                    }
+                    if let Some(demand) = visit.demand.as_mut() {
+                        demand.iter_mut().zip(split_demand.iter()).for_each(|(value, part)| *value += *part);
+                    }
+                } else {
+                    last.activities.push(ApiActivity {
+                        job_id,
+                        activity_type: activity_type.clone(),
+                        location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
+                        time: Some(Interval {
+                            start: format_time(service_start),
+                            end: format_time(activity_departure),
+                        }),
+                        job_tag,
+                        commute: act.commute.as_ref().map(|commute| {
+                            Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)
+                        }),
+                        earliness: get_deviation(earliness),
+                        lateness: get_deviation(lateness),
+                        demand: split_demand,
+                    });
+                }
 
                 // NOTE detect when vehicle returns after activity to stop point
                 let end_location = if commute.backward.is_zero_distance() {
@@ -265,6 +323,9 @@ fn create_tour(
                         duration: leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64,
                         activity_duration: 0,
                         activity_distance: 0,
+                        assignment_cost: leg.statistic.assignment_cost + assignment_cost,
+                        outsourcing_cost: 0.,
+                        emissions: leg.statistic.emissions + leg_emissions,
                         times: Timing {
                             driving: leg.statistic.times.driving + driving as i64,
                             serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
@@ -285,10 +346,25 @@ fn create_tour(
     });
 
     leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += vehicle
+        .dimens
+        .get_vehicle_overtime_costs()
+        .zip(route.tour.start().zip(route.tour.end()))
+        .map_or(0., |(costs, (start, end))| costs.cost(end.schedule.departure - start.schedule.departure));
     tour.statistic = leg.statistic;
 
+    set_stop_compartments(&mut tour, route);
+
     insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
 
+    if let Some(battery) = vehicle.dimens.get_vehicle_battery() {
+        set_stop_charges(&mut tour, battery.as_ref());
+    }
+
+    if let Some(loading_docks) = problem.extras.get_loading_docks() {
+        set_stop_docks(&mut tour, route, loading_docks.as_ref());
+    }
+
     let mut first_job_arrival: Option<Timestamp> = None;
     let mut first_job_distance: Option<i64> = None;
     let mut last_job_departure: Option<Timestamp> = None;
@@ -298,7 +374,7 @@ fn create_tour(
         if let Stop::Point(point_stop) = stop {
             for activity in &point_stop.activities {
                 let is_job = match activity.activity_type.as_str() {
-                    "pickup" | "delivery" | "replacement" | "service" => true,
+                    "pickup" | "delivery" | "replacement" | "service" | "drop" | "collect" => true,
                     _ => false,
                 };
 
@@ -363,51 +439,56 @@ fn calculate_load(current: MultiDimLoad, act: &Activity) -> MultiDimLoad {
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
+fn get_split_demand(act: &Activity) -> Option<Vec<i32>> {
+    act.job.as_ref().and_then(|job| get_split_part_demand(job))
+}
+
+/// Returns demand of the split job part.
+pub(crate) fn get_split_part_demand(single: &Single) -> Option<Vec<i32>> {
+    let demand = get_capacity(&single.dimens).filter(|_| single.dimens.get_job_split_key().is_some())?;
+
+    Some((demand.pickup.0 + demand.delivery.0).as_vec())
+}
+
 fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     let create_simple_reasons = |code: ViolationCode| {
         let (code, reason) = map_code_reason(code);
         vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
     };
 
-    let unassigned = solution
+    let is_coupled_reason = |code: &UnassignmentInfo| {
+        matches!(code, UnassignmentInfo::Simple(code)
+            if *code == SPLIT_JOB_CONSTRAINT_CODE || *code == TRANSFER_CONSTRAINT_CODE)
+    };
+    let mut jobs = solution
         .unassigned
         .iter()
-        .filter(|(job, _)| job.dimens().get_vehicle_id().is_none())
+        .filter(|(job, code)| {
+            // NOTE outsourced jobs are reported separately
+            job.dimens().get_vehicle_id().is_none()
+                && get_outsourced_job_cost(job, code, OUTSOURCING_CONSTRAINT_CODE).is_none()
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE parts of split job and legs of transfer job share the same id, so only one of them is reported
+    // preferring the original reason
+    jobs.sort_by_key(|(_, code)| is_coupled_reason(code));
+    let mut coupled_jobs = HashSet::new();
+    jobs.retain(|(job, _)| {
+        let dimens = job.dimens();
+        let key = dimens.get_job_split_key().or_else(|| dimens.get_job_transfer_key());
+
+        key.is_none_or(|key| coupled_jobs.insert(key.clone()))
+    });
+
+    let unassigned = jobs
+        .into_iter()
         .map(|(job, code)| {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
             let reasons = match code {
                 UnassignmentInfo::Simple(code) => create_simple_reasons(*code),
-                UnassignmentInfo::Detailed(details) if !details.is_empty() => details
-                    .iter()
-                    .collect_group_by_key(|(_, code)| *code)
-                    .into_iter()
-                    .map(|(code, group)| {
-                        let (code, reason) = map_code_reason(code);
-                        let mut vehicle_details = group
-                            .iter()
-                            .map(|(actor, _)| {
-                                let dimens = &actor.vehicle.dimens;
-                                let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
-                                let shift_index = dimens.get_shift_index().copied().unwrap();
-                                (vehicle_id, shift_index)
-                            })
-                            .collect::<Vec<_>>();
-                        // NOTE sort to have consistent order
-                        vehicle_details.sort();
-
-                        UnassignedJobReason {
-                            details: Some(
-                                vehicle_details
-                                    .into_iter()
-                                    .map(|(vehicle_id, shift_index)| UnassignedJobDetail { vehicle_id, shift_index })
-                                    .collect(),
-                            ),
-                            code: code.to_string(),
-                            description: reason.to_string(),
-                        }
-                    })
-                    .collect(),
+                UnassignmentInfo::Detailed(details) if !details.is_empty() => create_detailed_reasons(details),
                 _ => create_simple_reasons(ViolationCode(0)),
             };
 
@@ -418,25 +499,105 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     if unassigned.is_empty() { None } else { Some(unassigned) }
 }
 
+fn create_outsourced(solution: &DomainSolution) -> Option<Vec<OutsourcedJob>> {
+    let mut outsourced = solution
+        .unassigned
+        .iter()
+        .filter_map(|(job, code)| {
+            let cost = get_outsourced_job_cost(job, code, OUTSOURCING_CONSTRAINT_CODE)?;
+            let job_id = job.dimens().get_job_id().expect("job id expected").clone();
+
+            Some(OutsourcedJob { job_id, cost })
+        })
+        .collect::<Vec<_>>();
+    // NOTE sort to have consistent order
+    outsourced.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    if outsourced.is_empty() { None } else { Some(outsourced) }
+}
+
+pub(crate) fn create_detailed_reasons(details: &[(Arc<Actor>, ViolationCode)]) -> Vec<UnassignedJobReason> {
+    details
+        .iter()
+        .collect_group_by_key(|(_, code)| *code)
+        .into_iter()
+        .map(|(code, group)| {
+            let (code, reason) = map_code_reason(code);
+            let mut vehicle_details = group
+                .iter()
+                .map(|(actor, _)| {
+                    let dimens = &actor.vehicle.dimens;
+                    let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
+                    let shift_index = dimens.get_shift_index().copied().unwrap();
+                    (vehicle_id, shift_index)
+                })
+                .collect::<Vec<_>>();
+            // NOTE sort to have consistent order
+            vehicle_details.sort();
+
+            UnassignedJobReason {
+                details: Some(
+                    vehicle_details
+                        .into_iter()
+                        .map(|(vehicle_id, shift_index)| UnassignedJobDetail { vehicle_id, shift_index })
+                        .collect(),
+                ),
+                code: code.to_string(),
+                description: reason.to_string(),
+            }
+        })
+        .collect()
+}
+
 fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
-    // NOTE at the moment only break violation is mapped
+    // NOTE at the moment only break and overnight violations are mapped
     let violations = solution
         .unassigned
         .iter()
-        .filter(|(job, _)| job.dimens().get_job_type().is_some_and(|t| t == "break"))
-        .map(|(job, _)| Violation::Break {
-            vehicle_id: job.dimens().get_vehicle_id().expect("vehicle id").clone(),
-            shift_index: job.dimens().get_shift_index().copied().expect("shift index"),
+        .filter_map(|(job, _)| {
+            let dimens = job.dimens();
+            let vehicle_id = || dimens.get_vehicle_id().expect("vehicle id").clone();
+            let shift_index = || dimens.get_shift_index().copied().expect("shift index");
+
+            match dimens.get_job_type().map(|job_type| job_type.as_str()) {
+                Some("break") => Some(Violation::Break { vehicle_id: vehicle_id(), shift_index: shift_index() }),
+                Some("overnight") => {
+                    Some(Violation::Overnight { vehicle_id: vehicle_id(), shift_index: shift_index() })
+                }
+                _ => None,
+            }
         })
         .collect::<Vec<_>>();
 
     if violations.is_empty() { None } else { Some(violations) }
 }
 
-fn get_activity_type(activity: &Activity) -> Option<&String> {
+/// Returns job id of the activity as it is reported in the solution.
+pub(crate) fn get_activity_job_id(activity: &Activity, activity_type: &str) -> String {
+    match activity_type {
+        "pickup" | "delivery" | "replacement" | "service" | "drop" | "collect" => {
+            let single = activity.job.as_ref().unwrap();
+            let id = single.dimens.get_job_id().cloned();
+            id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_job_id().unwrap().clone())
+        }
+        _ => activity_type.to_string(),
+    }
+}
+
+pub(crate) fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
 
+fn get_vehicle_capacity(dimens: &Dimensions) -> Option<MultiDimLoad> {
+    // NOTE: try to detect whether dimensions stores multidimensional capacity
+    let capacity: Option<MultiDimLoad> = dimens.get_vehicle_capacity().cloned();
+    if capacity.is_some() {
+        return capacity;
+    }
+
+    dimens.get_vehicle_capacity().map(|capacity: &SingleDimLoad| MultiDimLoad::new(vec![capacity.value]))
+}
+
 fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
     // NOTE: try to detect whether dimensions stores multidimensional demand
     let demand: Option<Demand<MultiDimLoad>> = dimens.get_job_demand().cloned();
@@ -459,25 +620,37 @@ fn get_parking_time(extras: &DomainExtras) -> Float {
 
 fn create_extras(
     problem: &DomainProblem,
-    solution: &ApiSolution,
-    metrics: Option<&TelemetryMetrics>,
+    solution: &DomainSolution,
+    api_solution: &ApiSolution,
     output_type: &PragmaticOutputType,
 ) -> Option<Extras> {
+    let metrics = get_api_metrics(solution.telemetry.as_ref());
+    let changes = get_baseline_changes(problem, solution);
+
     match output_type {
         PragmaticOutputType::OnlyPragmatic => {
-            get_api_metrics(metrics).map(|metrics| Extras { metrics: Some(metrics), features: None })
+            (metrics.is_some() || changes.is_some()).then_some(Extras { metrics, features: None, changes })
         }
         PragmaticOutputType::OnlyGeoJson => None,
         PragmaticOutputType::Combined => {
             Some(Extras {
-                metrics: get_api_metrics(metrics),
+                metrics,
                 // TODO do not hide error here, propagate it to the caller
-                features: create_feature_collection(problem, solution).ok(),
+                features: create_feature_collection(problem, api_solution).ok(),
+                changes,
             })
         }
     }
 }
 
+fn get_baseline_changes(problem: &DomainProblem, solution: &DomainSolution) -> Option<BaselineChanges> {
+    problem.extras.get_baseline_plan().map(|baseline| {
+        let SolutionChanges { vehicle, position, arrival } = baseline.get_changes(solution.routes.iter());
+
+        BaselineChanges { vehicle, position, arrival }
+    })
+}
+
 fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
     metrics.as_ref().map(|metrics| ApiMetrics {
         duration: metrics.duration,
@@ -504,3 +677,132 @@ fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
             .collect(),
     })
 }
+
+/// Returns legs used to calculate battery charge levels at the tour stops: energy consumed to reach
+/// the stop and a flag whether the vehicle recharges there.
+pub(crate) fn get_charge_legs<'a>(
+    stops: impl Iterator<Item = &'a PointStop>,
+    battery: &BatteryModel,
+) -> Vec<(Float, bool)> {
+    stops
+        .scan(None, |prev: &mut Option<&PointStop>, stop| {
+            let energy = prev.map_or(Float::default(), |prev| {
+                let distance = (stop.distance - prev.distance) as Float;
+                let payload = prev.load.iter().sum::<i32>() as Float;
+
+                battery.get_consumption(distance, payload)
+            });
+            let is_recharge = stop.activities.iter().any(|activity| activity.activity_type == "recharge");
+            *prev = Some(stop);
+
+            Some((energy, is_recharge))
+        })
+        .collect()
+}
+
+fn set_stop_charges(tour: &mut Tour, battery: &BatteryModel) {
+    let legs = get_charge_legs(tour.stops.iter().filter_map(|stop| stop.as_point()), battery);
+    let levels = battery.get_charge_levels(legs.as_slice());
+
+    tour.stops
+        .iter_mut()
+        .filter_map(|stop| match stop {
+            Stop::Point(point) => Some(point),
+            Stop::Transit(_) => None,
+        })
+        .zip(levels)
+        .for_each(|(stop, (arrival, departure))| stop.charge = Some(StopCharge { arrival, departure }));
+}
+
+fn set_stop_compartments(tour: &mut Tour, route: &Route) {
+    let Some(compartments) = route.actor.vehicle.dimens.get_vehicle_compartments::<MultiDimLoad>() else {
+        return;
+    };
+
+    let trips = get_compartment_trips::<MultiDimLoad>(route, &|single| {
+        single.dimens.get_job_type().is_some_and(|job_type| job_type == "reload")
+    });
+
+    let mut activity_idx = 0;
+    tour.stops.iter_mut().for_each(|stop| {
+        let Stop::Point(point) = stop else { return };
+
+        activity_idx += point.activities.len();
+        let last_idx = activity_idx - 1;
+
+        let Some(trip) = trips.iter().rev().find(|trip| trip.start_idx <= last_idx) else { return };
+        let Some(assignment) = trip.assignment.as_ref() else { return };
+        let mut remaining = trip.loads[last_idx - trip.start_idx].clone();
+
+        point.compartments = Some(
+            compartments
+                .iter()
+                .zip(assignment.iter())
+                .map(|(compartment, product_idx)| {
+                    let load = product_idx.map_or_else(
+                        || vec![0; compartment.capacity.size],
+                        |product_idx| {
+                            let load = (0..compartment.capacity.size)
+                                .map(|dim| remaining[product_idx].get(dim).min(compartment.capacity.get(dim)).max(0))
+                                .collect::<Vec<_>>();
+                            remaining[product_idx] = remaining[product_idx].clone() - MultiDimLoad::new(load.clone());
+
+                            load
+                        },
+                    );
+
+                    StopCompartment {
+                        id: compartment.id.clone(),
+                        product: product_idx.map(|product_idx| trip.products[product_idx].clone()),
+                        load,
+                    }
+                })
+                .collect(),
+        );
+    });
+}
+
+fn set_stop_docks(tour: &mut Tour, route: &Route, loading_docks: &LoadingDocks) {
+    let is_reload = |activity: &Activity| get_activity_type(activity).is_some_and(|t| t == "reload");
+    let reload_indices = route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(|(_, activity)| is_reload(activity))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    let mut stops = tour
+        .stops
+        .iter_mut()
+        .filter_map(|stop| match stop {
+            Stop::Point(point) => Some(point),
+            Stop::Transit(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let reload_stops = stops
+        .iter()
+        .enumerate()
+        .filter(|(_, stop)| stop.activities.iter().any(|activity| activity.activity_type == "reload"))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    loading_docks.get_sessions(route).into_iter().for_each(|session| {
+        let stop_idx = if session.activity_idx == 0 {
+            Some(0)
+        } else {
+            reload_indices
+                .iter()
+                .position(|&idx| idx == session.activity_idx)
+                .and_then(|ordinal| reload_stops.get(ordinal).copied())
+        };
+
+        if let Some(stop) = stop_idx.and_then(|idx| stops.get_mut(idx)) {
+            stop.dock = Some(DockSlot {
+                id: loading_docks.docks()[session.dock_idx].id.clone(),
+                start: format_time(session.time.start),
+                end: format_time(session.time.end),
+            });
+        }
+    });
+}
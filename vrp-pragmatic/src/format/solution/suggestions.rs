@@ -0,0 +1,151 @@
+//! Specifies logic to suggest insertion positions for new jobs in existing solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/suggestions_test.rs"]
+mod suggestions_test;
+
+use super::solution_writer::{create_detailed_reasons, create_tour};
+use super::*;
+use crate::format::problem::{Job as ApiJob, Matrix, PragmaticProblem, Problem as ApiProblem};
+use serde::{Deserialize, Serialize};
+use std::io::{BufWriter, Write};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{InsertionContext, get_insertion_suggestions as get_core_suggestions};
+use vrp_core::prelude::{Environment, Float, GenericError};
+use vrp_core::solver::processing::ReservedTimesExtraProperty;
+
+/// Specifies a job activity within suggested insertion.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedActivity {
+    /// Activity type.
+    #[serde(rename(deserialize = "type", serialize = "type"))]
+    pub activity_type: String,
+    /// Job tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_tag: Option<String>,
+    /// An index of the stop in the tour after insertion.
+    pub stop_index: usize,
+    /// Estimated arrival time in RFC3339 format.
+    pub arrival: String,
+    /// Estimated departure time in RFC3339 format.
+    pub departure: String,
+}
+
+/// Specifies a feasible insertion of the job into a vehicle's tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedInsertion {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Job activities.
+    pub activities: Vec<SuggestedActivity>,
+    /// A change of the total solution cost.
+    pub cost_delta: Float,
+}
+
+/// Specifies insertion suggestions for the job.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobInsertionSuggestions {
+    /// Job id.
+    pub job_id: String,
+    /// Feasible insertions, the cheapest comes first.
+    pub insertions: Vec<SuggestedInsertion>,
+    /// Reasons why specific vehicles cannot serve the job.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reasons: Vec<UnassignedJobReason>,
+}
+
+/// Suggests up to `limit` cheapest insertion positions for each of the new jobs in the existing
+/// solution without re-optimizing it. New jobs are evaluated independently of each other.
+/// NOTE: when routing matrix is used, new jobs have to reuse locations known by the matrix.
+pub fn get_insertion_suggestions(
+    mut problem: ApiProblem,
+    matrices: Option<Vec<Matrix>>,
+    solution: ApiSolution,
+    jobs: Vec<ApiJob>,
+    limit: usize,
+) -> Result<Vec<JobInsertionSuggestions>, GenericError> {
+    let job_ids = jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>();
+    problem.plan.jobs.extend(jobs);
+
+    let problem = Arc::new(
+        (problem, matrices).read_pragmatic().map_err(|errs| format!("cannot read pragmatic problem: '{errs}'"))?,
+    );
+    let environment = Arc::new(Environment::default());
+
+    let solution = create_init_solution(solution, problem.clone(), environment.random.clone())?;
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+
+    let (job_index, coord_index) = get_indices(&problem.extras)?;
+    let empty_reserved_times = Default::default();
+    let reserved_times_index = problem.extras.get_reserved_times();
+    let reserved_times_index = reserved_times_index.as_ref().unwrap_or(&empty_reserved_times);
+
+    job_ids
+        .into_iter()
+        .map(|job_id| {
+            let job = job_index.get(&job_id).ok_or_else(|| format!("cannot find job '{job_id}'"))?;
+            let suggestions = get_core_suggestions(&insertion_ctx, job, limit)?;
+
+            let insertions = suggestions
+                .feasible
+                .iter()
+                .map(|suggestion| {
+                    let tour = create_tour(problem.as_ref(), &suggestion.route, &coord_index, reserved_times_index);
+                    let activities = tour
+                        .stops
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(stop_index, stop)| {
+                            let schedule = stop.schedule();
+                            stop.activities().iter().filter(|activity| activity.job_id == job_id).map(
+                                move |activity| {
+                                    let (arrival, departure) = activity.time.as_ref().map_or(
+                                        (schedule.arrival.clone(), schedule.departure.clone()),
+                                        |time| (time.start.clone(), time.end.clone()),
+                                    );
+
+                                    SuggestedActivity {
+                                        activity_type: activity.activity_type.clone(),
+                                        job_tag: activity.job_tag.clone(),
+                                        stop_index,
+                                        arrival,
+                                        departure,
+                                    }
+                                },
+                            )
+                        })
+                        .collect();
+
+                    SuggestedInsertion {
+                        vehicle_id: tour.vehicle_id,
+                        type_id: tour.type_id,
+                        shift_index: tour.shift_index,
+                        activities,
+                        cost_delta: suggestion.cost_delta,
+                    }
+                })
+                .collect();
+
+            Ok(JobInsertionSuggestions {
+                job_id,
+                insertions,
+                reasons: create_detailed_reasons(suggestions.infeasible.as_slice()),
+            })
+        })
+        .collect()
+}
+
+/// Serializes insertion suggestions into json format.
+pub fn serialize_insertion_suggestions<W: Write>(
+    suggestions: &[JobInsertionSuggestions],
+    writer: &mut BufWriter<W>,
+) -> Result<(), GenericError> {
+    serde_json::to_writer_pretty(writer, suggestions).map_err(|err| err.to_string().into())
+}
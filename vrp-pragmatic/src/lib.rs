@@ -60,3 +60,12 @@ fn parse_time_safe(time: &str) -> Result<Float, GenericError> {
         .map(|time| time.unix_timestamp() as Float)
         .map_err(|err| format!("cannot parse date: {err}").into())
 }
+
+/// Parses time of day in `HH:MM` format and returns amount of seconds since midnight.
+fn parse_time_of_day(time: &str) -> Result<Float, GenericError> {
+    time.split_once(':')
+        .and_then(|(hours, minutes)| Some((hours.parse::<u32>().ok()?, minutes.parse::<u32>().ok()?)))
+        .filter(|&(hours, minutes)| hours < 24 && minutes < 60)
+        .map(|(hours, minutes)| (hours * 3600 + minutes * 60) as Float)
+        .ok_or_else(|| format!("cannot parse time of day: '{time}'").into())
+}
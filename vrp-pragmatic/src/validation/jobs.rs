@@ -3,7 +3,9 @@
 mod jobs_test;
 
 use super::*;
+use crate::format::get_transfer_outbound_id;
 use crate::utils::combine_error_results;
+use std::collections::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 use serde_json;
 
@@ -180,6 +182,210 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job has no negative values in soft time windows.
+fn check_e1108_soft_time_windows_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.soft_times.as_ref())
+                .flat_map(|soft| [soft.max_earliness, soft.max_lateness, soft.earliness_cost, soft.lateness_cost])
+                .flatten()
+                .any(|value| value.is_sign_negative())
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job has invalid soft time windows".to_string(),
+            format!("fix negative values in soft time windows in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that max ride time is specified only for jobs with pickups and deliveries and it is positive.
+fn check_e1112_max_ride_time_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.max_ride_time.as_ref().is_some_and(|max_ride_time| {
+                let value = match max_ride_time {
+                    JobMaxRideTime::Duration(value) | JobMaxRideTime::Factor(value) => *value,
+                };
+                let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().is_some_and(|tasks| !tasks.is_empty());
+
+                value <= 0. || !has_tasks(&job.pickups) || !has_tasks(&job.deliveries)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "job has invalid max ride time".to_string(),
+            format!("use positive max ride time only for jobs with pickups and deliveries, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that split is specified only for jobs with a single pickup or delivery task and its limits are valid.
+fn check_e1113_split_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_jobs =
+        ctx.problem.plan.relations.iter().flatten().flat_map(|relation| relation.jobs.iter()).collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.split.as_ref().is_some_and(|split| {
+                let tasks = job.pickups.iter().chain(job.deliveries.iter()).flatten().collect::<Vec<_>>();
+                let has_other_tasks = job.replacements.as_ref().is_some_and(|tasks| !tasks.is_empty())
+                    || job.services.as_ref().is_some_and(|tasks| !tasks.is_empty());
+                let has_demand = tasks
+                    .first()
+                    .and_then(|task| task.demand.as_ref())
+                    .is_some_and(|demand| demand.iter().any(|value| *value > 0));
+                let has_invalid_limits = split.max_splits.is_some_and(|max_splits| max_splits == 0)
+                    || split.min_size.as_ref().is_some_and(|min_size| min_size.iter().any(|value| *value < 0));
+
+                tasks.len() != 1
+                    || has_other_tasks
+                    || !has_demand
+                    || has_invalid_limits
+                    || relation_jobs.contains(&job.id)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "job has invalid split".to_string(),
+            format!(
+                "use split only for jobs with single pickup or delivery with demand, not used in relations, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that transfer is specified only for jobs with a single pickup and delivery and its timing is valid.
+fn check_e1114_transfer_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_jobs =
+        ctx.problem.plan.relations.iter().flatten().flat_map(|relation| relation.jobs.iter()).collect::<HashSet<_>>();
+    let job_ids = ctx.jobs().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.transfer.as_ref().is_some_and(|transfer| {
+                let get_size = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+                let has_other_tasks = get_size(&job.replacements) > 0 || get_size(&job.services) > 0;
+                let has_other_features = job.split.is_some() || job.max_ride_time.is_some() || job.sync.is_some();
+                let has_invalid_timing = transfer.duration < 0. || transfer.handling_time < 0.;
+
+                get_size(&job.pickups) != 1
+                    || get_size(&job.deliveries) != 1
+                    || has_other_tasks
+                    || has_other_features
+                    || has_invalid_timing
+                    || relation_jobs.contains(&job.id)
+                    || job_ids.contains(get_transfer_outbound_id(&job.id).as_str())
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "job has invalid transfer".to_string(),
+            format!(
+                "use transfer only for jobs with single pickup and delivery, non-negative duration and handling \
+                 time, without split, max ride time, sync and relations, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job assignment costs are correct.
+fn check_e1115_assignment_costs_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx.vehicles().map(|vehicle| vehicle.type_id.as_str()).collect::<HashSet<_>>();
+    let vehicle_ids = ctx.vehicles().flat_map(|vehicle| vehicle.vehicle_ids.iter()).collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.assignment_costs.iter().flatten().any(|cost| {
+                let is_known = match (cost.type_id.as_ref(), cost.vehicle_id.as_ref()) {
+                    (Some(type_id), None) => type_ids.contains(type_id.as_str()),
+                    (None, Some(vehicle_id)) => vehicle_ids.contains(vehicle_id),
+                    _ => false,
+                };
+
+                !is_known || cost.cost < 0.
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1115".to_string(),
+            "job has invalid assignment costs".to_string(),
+            format!(
+                "make sure that each assignment cost has non-negative cost and either known vehicle type id or \
+                 known vehicle id, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job outsourcing costs are correct.
+fn check_e1116_outsourcing_cost_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let plan_cost = ctx.problem.plan.outsourcing_cost;
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            let is_not_supported = job.outsourcing_cost.is_some() && (job.split.is_some() || job.transfer.is_some());
+
+            is_not_supported || job.outsourcing_cost.or(plan_cost).is_some_and(|cost| cost < 0.)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1116".to_string(),
+            "job has invalid outsourcing cost".to_string(),
+            format!(
+                "use non-negative outsourcing cost only for jobs without split and transfer, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -191,6 +397,12 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_soft_time_windows_correctness(ctx),
+        check_e1112_max_ride_time_correctness(ctx),
+        check_e1113_split_correctness(ctx),
+        check_e1114_transfer_correctness(ctx),
+        check_e1115_assignment_costs_correctness(ctx),
+        check_e1116_outsourcing_cost_correctness(ctx),
         check_sync_groups_consistency(ctx),
         check_sequence_groups_consistency(ctx),
     ])
@@ -4,6 +4,7 @@ mod objectives_test;
 
 use super::*;
 use crate::format::problem::Objective::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 use vrp_core::utils::Either;
@@ -166,6 +167,79 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that time window penalty objective is specified when some jobs have soft time windows.
+fn check_e1608_jobs_with_soft_time_windows_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_penalty_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeTimeWindowPenalty));
+    let has_jobs_with_soft_times = ctx
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.all_tasks_iter())
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.times.is_some() && place.soft_times.is_some());
+
+    if has_no_penalty_objective && has_jobs_with_soft_times {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "missing time window penalty objective".to_string(),
+            "specify 'minimize-time-window-penalty' objective, remove objectives property or remove softTimes \
+             property from job places"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that baseline solution of minimize changes objective has valid timestamps.
+fn check_e1609_invalid_minimize_changes_baseline(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let vehicle_ids = get_objectives_flattened(objectives)
+        .filter_map(|objective| match objective {
+            MinimizeChanges { baseline, .. } => Some(baseline),
+            _ => None,
+        })
+        .flat_map(|baseline| baseline.tours.iter())
+        .filter(|tour| {
+            tour.stops.iter().any(|stop| {
+                let schedule = stop.schedule();
+                let activity_times = stop
+                    .activities()
+                    .iter()
+                    .filter_map(|activity| activity.time.as_ref())
+                    .flat_map(|time| [time.start.as_str(), time.end.as_str()]);
+
+                [schedule.arrival.as_str(), schedule.departure.as_str()]
+                    .into_iter()
+                    .chain(activity_times)
+                    .any(|time| parse_time_safe(time).is_err())
+            })
+        })
+        .map(|tour| tour.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "invalid baseline in minimize changes objective".to_string(),
+            format!(
+                "ensure that all stop and activity times of baseline solution are in RFC3339 format, vehicle ids: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
@@ -188,6 +262,8 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_jobs_with_soft_time_windows_but_no_objective(ctx, &objectives),
+            check_e1609_invalid_minimize_changes_baseline(&objectives),
         ])
         .map_err(From::from)
     } else {
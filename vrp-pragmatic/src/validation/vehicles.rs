@@ -5,7 +5,7 @@ mod vehicles_test;
 use super::*;
 use crate::utils::combine_error_results;
 use crate::validation::common::get_time_windows;
-use crate::{parse_time, parse_time_safe};
+use crate::{parse_time, parse_time_of_day, parse_time_safe};
 use std::collections::HashSet;
 use vrp_core::models::common::TimeWindow;
 
@@ -216,8 +216,10 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
         .resources
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, .. } => id.to_string(),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, .. } => Some(id.to_string()),
+            VehicleResource::Charger { .. } => None,
+            VehicleResource::Dock { .. } => None,
         })
         .collect::<Vec<_>>();
 
@@ -258,6 +260,438 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle shift overtime is defined correctly.
+fn check_e1309_vehicle_overtime_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.overtime.as_ref().is_none_or(|overtime| {
+                overtime.regular_duration >= 0.
+                    && match &overtime.cost {
+                        TieredCost::Fixed(cost) => *cost >= 0.,
+                        TieredCost::Tiered(tiers) => tiers.iter().all(|tier| tier.threshold >= 0. && tier.cost >= 0.),
+                    }
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle overtime".to_string(),
+            format!(
+                "ensure that overtime regular duration, tier thresholds and costs are not negative, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle hours of service are defined correctly.
+fn check_e1310_vehicle_hours_of_service_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.hours_of_service.as_ref().is_some_and(|hos| {
+                let has_valid_limits = hos.max_driving_time > 0.
+                    && hos.rest_duration >= 0.
+                    && hos.max_daily_driving_time.is_none_or(|daily| daily >= hos.max_driving_time)
+                    && hos.min_daily_rest.is_none_or(|rest| rest >= 0.);
+
+                let has_valid_daily_rest = hos.min_daily_rest.is_none_or(|min_daily_rest| {
+                    let mut shifts = vehicle
+                        .shifts
+                        .iter()
+                        .map(|shift| {
+                            let start = parse_time(&shift.start.earliest);
+                            let end = shift.end.as_ref().map(|end| parse_time(&end.latest));
+                            (start, end)
+                        })
+                        .collect::<Vec<_>>();
+                    shifts.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+                    shifts.windows(2).all(|shifts| match shifts {
+                        [(_, Some(prev_end)), (next_start, _)] => next_start - prev_end >= min_daily_rest,
+                        _ => false,
+                    })
+                });
+
+                !has_valid_limits || !has_valid_daily_rest
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle hours of service".to_string(),
+            format!(
+                "ensure that max driving time is positive, rest durations are not negative, max daily driving time \
+                 is not less than max driving time and consecutive shifts have end time and are separated by \
+                 min daily rest, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle overnight stops are defined correctly.
+fn check_e1311_vehicle_overnight_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.overnight.as_ref().is_some_and(|overnight| {
+                let has_valid_settings = overnight.cost.is_none_or(|cost| cost >= 0.)
+                    && overnight.locations.as_ref().is_none_or(|locations| !locations.is_empty());
+
+                // TODO support hours of service within multi-day tours: driving time counters and daily rest
+                //      should be reset at the overnight stop which is not modeled yet
+                let has_no_hours_of_service = vehicle.hours_of_service.is_none();
+
+                let has_chained_shifts = vehicle.shifts.len() > 1
+                    && vehicle.shifts.windows(2).all(|shifts| match &shifts[0].end {
+                        Some(end) => parse_time(&shifts[1].start.earliest) >= parse_time(&end.latest),
+                        None => false,
+                    });
+
+                let has_no_shift_extras = vehicle.shifts.iter().skip(1).all(|shift| {
+                    shift.breaks.is_none()
+                        && shift.reloads.is_none()
+                        && shift.recharges.is_none()
+                        && shift.overtime.is_none()
+                });
+
+                !has_valid_settings || !has_no_hours_of_service || !has_chained_shifts || !has_no_shift_extras
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle overnight".to_string(),
+            format!(
+                "ensure that vehicle has at least two non overlapping shifts ordered by time, all shifts except the \
+                 last one have end, only the first shift has breaks, reloads, recharges or overtime, overnight cost \
+                 is not negative, locations are not empty and hours of service are not used as they are not \
+                 supported within multi-day tours yet, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle recharges have either max distance or valid battery model.
+fn check_e1312_vehicle_recharges_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.recharges.as_ref().is_none_or(|recharges| match recharges.battery.as_ref() {
+                Some(battery) => {
+                    let initial_charge = battery.initial_charge.unwrap_or(battery.capacity);
+                    let reserve = battery.reserve.unwrap_or_default();
+
+                    let has_valid_levels = battery.capacity > 0.
+                        && reserve >= 0.
+                        && reserve < initial_charge
+                        && initial_charge <= battery.capacity;
+                    let has_valid_consumption =
+                        battery.consumption >= 0. && battery.load_consumption.is_none_or(|value| value >= 0.);
+                    let has_valid_curve = !battery.charging_curve.is_empty()
+                        && battery.charging_curve.iter().all(|point| point.power > 0.)
+                        && battery.charging_curve.windows(2).all(|points| points[0].level < points[1].level);
+
+                    has_valid_levels && has_valid_consumption && has_valid_curve
+                }
+                None => {
+                    recharges.max_distance.is_some_and(|max_distance| max_distance > 0.)
+                        && recharges.initial_distance.is_none_or(|distance| distance >= 0.)
+                }
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle recharges".to_string(),
+            format!(
+                "ensure that recharges have positive max distance with non-negative initial distance or battery \
+                 with positive capacity, reserve less than initial charge, initial charge not greater than \
+                 capacity, non-negative consumption and charging curve with positive power and increasing levels, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1313_vehicle_charger_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let charger_resources = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Charger { id, capacity } => Some((id.to_string(), *capacity)),
+            VehicleResource::Reload { .. } => None,
+            VehicleResource::Dock { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let unique_resource_ids = charger_resources.iter().map(|(id, _)| id.clone()).collect::<HashSet<_>>();
+
+    if charger_resources.len() != unique_resource_ids.len()
+        || charger_resources.iter().any(|(_, capacity)| *capacity == 0)
+    {
+        return Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle charger resource".to_string(),
+            "make sure that fleet charger resource ids are unique and capacities are positive".to_string(),
+        ));
+    }
+
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(move |_, shift, _| {
+            shift
+                .recharges
+                .as_ref()
+                .iter()
+                .flat_map(|recharges| recharges.stations.iter())
+                .filter_map(|station| station.resource_id.as_ref())
+                .all(|resource_id| unique_resource_ids.contains(resource_id))
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle charger resource".to_string(),
+            format!(
+                "make sure that fleet has all charger resources defined, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1314_vehicle_dock_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let docks = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Dock { id, location, capacity, loading_time, unit_loading_time, times } => {
+                Some((id, location, *capacity, *loading_time, *unit_loading_time, times))
+            }
+            VehicleResource::Reload { .. } => None,
+            VehicleResource::Charger { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let unique_ids = docks.iter().map(|(id, ..)| *id).collect::<HashSet<_>>();
+    let locations =
+        docks.iter().filter_map(|(_, location, ..)| ctx.coord_index.get_by_loc(location)).collect::<Vec<_>>();
+    let unique_locations = locations.iter().collect::<HashSet<_>>();
+
+    let has_invalid_docks = docks.iter().any(|(_, _, capacity, loading_time, unit_loading_time, times)| {
+        *capacity == 0
+            || *loading_time < 0.
+            || unit_loading_time.is_some_and(|time| time < 0.)
+            || times.as_ref().is_some_and(|times| times.is_empty() || !check_raw_time_windows(times, false))
+    });
+
+    if docks.len() != unique_ids.len() || locations.len() != unique_locations.len() || has_invalid_docks {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle dock resource".to_string(),
+            "make sure that fleet dock resource ids and locations are unique, capacities are positive, \
+             loading times are not negative and opening times are valid"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that vehicle compartments are defined correctly.
+fn check_e1315_vehicle_compartments_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.compartments.as_ref().is_some_and(|compartments| {
+                let unique_ids = compartments.iter().map(|compartment| &compartment.id).collect::<HashSet<_>>();
+
+                compartments.is_empty()
+                    || unique_ids.len() != compartments.len()
+                    || compartments.iter().any(|compartment| {
+                        compartment.capacity.len() != vehicle.capacity.len()
+                            || compartment.capacity.iter().any(|value| *value < 0)
+                            || compartment.products.as_ref().is_some_and(|products| products.is_empty())
+                    })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid vehicle compartments".to_string(),
+            format!(
+                "make sure that compartment ids are unique, capacities are not negative and have the same dimensions \
+                 as vehicle capacity, products are not empty, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle trailers are defined correctly.
+fn check_e1316_vehicle_trailers_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let trailers = ctx.problem.fleet.trailers.iter().flatten().collect::<Vec<_>>();
+    let unique_ids = trailers.iter().map(|trailer| &trailer.id).collect::<HashSet<_>>();
+
+    let type_ids = ctx
+        .vehicles()
+        .filter_map(|vehicle| vehicle.trailer_id.as_ref().map(|trailer_id| (vehicle, trailer_id)))
+        .filter(|(vehicle, trailer_id)| {
+            let has_reloads = vehicle.shifts.iter().any(|shift| shift.reloads.is_some());
+
+            unique_ids.len() != trailers.len()
+                || has_reloads
+                || trailers.iter().find(|trailer| trailer.id == **trailer_id).is_none_or(|trailer| {
+                    trailer.parkings.is_empty()
+                        || trailer.capacity.len() != vehicle.capacity.len()
+                        || trailer.capacity.iter().any(|value| *value < 0)
+                })
+        })
+        .map(|(vehicle, _)| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle trailers".to_string(),
+            format!(
+                "make sure that trailer ids are unique and known, trailers have parkings, capacities are not negative \
+                 and have the same dimensions as vehicle capacity, vehicle has no reloads, \
+                 check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle time rates are defined correctly.
+fn check_e1317_vehicle_time_rates_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.costs.time_rates.iter().flatten().any(|rate| {
+                let rates = [rate.driving, rate.service, rate.waiting];
+
+                parse_time_of_day(&rate.start).is_err()
+                    || parse_time_of_day(&rate.end).is_err()
+                    || rate.weekdays.iter().flatten().any(|weekday| !(1..=7).contains(weekday))
+                    || rates.iter().all(|rate| rate.is_none())
+                    || rates.iter().flatten().any(|rate| *rate < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid vehicle time rates".to_string(),
+            format!(
+                "make sure that time rates have start and end in HH:MM format, weekdays from 1 to 7 and at least \
+                 one non-negative rate, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1318_vehicle_price_list_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let prices = vehicle.costs.price_list.iter().flatten().collect::<Vec<_>>();
+            let unique_zones = prices.iter().map(|price| &price.zone).collect::<HashSet<_>>();
+
+            unique_zones.len() != prices.len() || prices.iter().any(|price| price.cost < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1318".to_string(),
+            "invalid vehicle price list".to_string(),
+            format!(
+                "make sure that price list has non-negative costs and unique zones with at most one default price, \
+                 check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1319_vehicle_emissions_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.emissions.as_ref().is_some_and(|emissions| {
+                [Some(emissions.consumption), emissions.full_consumption, emissions.idle_consumption]
+                    .into_iter()
+                    .flatten()
+                    .chain(std::iter::once(emissions.emission_factor))
+                    .any(|value| value < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1319".to_string(),
+            "invalid vehicle emissions".to_string(),
+            format!(
+                "make sure that consumption values and emission factor are non-negative, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -301,6 +735,17 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_overtime_is_correct(ctx),
+        check_e1310_vehicle_hours_of_service_is_correct(ctx),
+        check_e1311_vehicle_overnight_is_correct(ctx),
+        check_e1312_vehicle_recharges_is_correct(ctx),
+        check_e1313_vehicle_charger_resources(ctx),
+        check_e1314_vehicle_dock_resources(ctx),
+        check_e1315_vehicle_compartments_are_correct(ctx),
+        check_e1316_vehicle_trailers_are_correct(ctx),
+        check_e1317_vehicle_time_rates_are_correct(ctx),
+        check_e1318_vehicle_price_list_is_correct(ctx),
+        check_e1319_vehicle_emissions_are_correct(ctx),
     ])
     .map_err(From::from)
 }
@@ -40,7 +40,12 @@ prop_compose! {
         vehicle.vehicle_ids = vec![format!("{}_1", vehicle.type_id)];
 
         vehicle.shifts.first_mut().unwrap().end = None;
-        vehicle.shifts.first_mut().unwrap().recharges = Some(VehicleRecharges { max_distance, stations });
+        vehicle.shifts.first_mut().unwrap().recharges = Some(VehicleRecharges {
+            max_distance: Some(max_distance),
+            initial_distance: None,
+            battery: None,
+            stations,
+        });
 
         vehicle
     }
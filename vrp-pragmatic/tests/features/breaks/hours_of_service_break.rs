@@ -0,0 +1,63 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(hours_of_service: VehicleHoursOfService) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                hours_of_service: Some(hours_of_service),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_schedule_rest_when_driving_time_is_exceeded() {
+    let problem = create_problem(VehicleHoursOfService {
+        max_driving_time: 25.,
+        rest_duration: 5.,
+        max_daily_driving_time: Some(50.),
+        min_daily_rest: None,
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let rests = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break" && activity.job_id == "rest")
+        .count();
+    assert_eq!(rests, 1);
+    assert_eq!(solution.tours[0].statistic.times.break_time, 5);
+}
+
+#[test]
+fn cannot_assign_jobs_exceeding_daily_driving_time() {
+    let problem = create_problem(VehicleHoursOfService {
+        max_driving_time: 25.,
+        rest_duration: 5.,
+        max_daily_driving_time: Some(25.),
+        min_daily_rest: None,
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+}
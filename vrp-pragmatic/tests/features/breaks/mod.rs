@@ -1,5 +1,6 @@
 mod basic_break_test;
 mod break_with_multiple_locations;
+mod hours_of_service_break;
 mod interval_break_test;
 mod multi_break_test;
 mod open_end_by_interval_break;
@@ -1 +1,2 @@
 mod simple_capacity_test;
+mod split_delivery_test;
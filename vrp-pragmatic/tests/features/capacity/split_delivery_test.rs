@@ -0,0 +1,67 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_split_delivery_between_vehicles, (split, capacities, expected), {
+    can_split_delivery_between_vehicles_impl(split, capacities, expected);
+}}
+
+can_split_delivery_between_vehicles! {
+    case01_default_split: (Some(JobSplit { min_size: None, max_splits: None }), vec![10, 10], Some(2)),
+    case02_no_split: (None, vec![10, 10], None),
+    case03_too_big_min_size: (Some(JobSplit { min_size: Some(vec![8]), max_splits: None }), vec![10, 10], None),
+    case04_too_few_splits: (Some(JobSplit { min_size: None, max_splits: Some(1) }), vec![10, 10], None),
+    case05_unequal_parts: (Some(JobSplit { min_size: None, max_splits: Some(2) }), vec![10, 5], Some(2)),
+}
+
+fn can_split_delivery_between_vehicles_impl(split: Option<JobSplit>, capacities: Vec<i32>, expected: Option<usize>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { split, ..create_delivery_job_with_demand("job1", (1., 0.), vec![15]) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: capacities
+                .iter()
+                .enumerate()
+                .map(|(idx, capacity)| VehicleType {
+                    type_id: format!("type{idx}"),
+                    vehicle_ids: vec![format!("v{idx}")],
+                    capacity: vec![*capacity],
+                    ..create_default_vehicle_type()
+                })
+                .collect(),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if let Some(expected) = expected {
+        assert!(solution.unassigned.is_none());
+        assert_eq!(solution.tours.len(), expected);
+        let parts = solution
+            .tours
+            .iter()
+            .map(|tour| {
+                let capacity = capacities[tour.vehicle_id.trim_start_matches('v').parse::<usize>().unwrap()];
+                let parts = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities())
+                    .filter(|activity| activity.job_id == "job1")
+                    .filter_map(|activity| activity.demand.clone())
+                    .collect::<Vec<_>>();
+                assert_eq!(parts.len(), 1);
+                assert!(parts[0][0] <= capacity);
+
+                parts[0][0]
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(parts.iter().sum::<i32>(), 15);
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 1);
+    }
+}
@@ -45,6 +45,9 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            earliness: None,
+            lateness: None,
+            demand: None,
         }
     }
 }
@@ -86,6 +89,9 @@ impl From<StopData> for Stop {
                 None
             },
             load: vec![stop.load],
+            charge: None,
+            dock: None,
+            compartments: None,
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
         })
     }
@@ -100,6 +106,9 @@ fn create_statistic(data: (Float, i64, i64, (i64, i64, i64, i64))) -> Statistic
         // when building the expected solution, matching the calculation in solution_writer.rs
         activity_distance: 0,
         activity_duration: 0,
+        assignment_cost: 0.,
+        outsourcing_cost: 0.,
+        emissions: 0.,
         times: Timing {
             driving: data.3.0,
             serving: data.3.1,
@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+type PriceList = Vec<(Option<&'static str>, f64)>;
+
+parameterized_test! {can_use_job_assignment_costs, (job_costs, price_lists, expected), {
+    can_use_job_assignment_costs_impl(job_costs, price_lists, expected);
+}}
+
+// NOTE the expected cost without assignment cost is fixed + distance + driving + service: 10. + 20. + 20. + 1.
+can_use_job_assignment_costs! {
+    case01_vehicle_id: (vec![(None, Some("v1_1"), 100.)], (vec![], vec![]), ("v2_1", 0.)),
+    case02_type_id: (vec![(Some("v1"), None, 5.)], (vec![], vec![(None, 20.)]), ("v1_1", 5.)),
+    case03_zone: (vec![], (vec![(Some("a"), 30.), (None, 5.)], vec![(None, 10.)]), ("v2_1", 10.)),
+    case04_vehicle_id_precedence: (
+        vec![(None, Some("v1_1"), 1.), (Some("v1"), None, 100.)],
+        (vec![], vec![(None, 10.)]),
+        ("v1_1", 1.)
+    ),
+}
+
+fn can_use_job_assignment_costs_impl(
+    job_costs: Vec<(Option<&str>, Option<&str>, f64)>,
+    price_lists: (PriceList, PriceList),
+    expected: (&str, f64),
+) {
+    let (expected_vehicle_id, expected_assignment_cost) = expected;
+    let assignment_costs = job_costs
+        .into_iter()
+        .map(|(type_id, vehicle_id, cost)| JobAssignmentCost {
+            type_id: type_id.map(str::to_string),
+            vehicle_id: vehicle_id.map(str::to_string),
+            cost,
+        })
+        .collect::<Vec<_>>();
+    let create_vehicle = |type_id: &str, prices: PriceList| {
+        let price_list =
+            prices.into_iter().map(|(zone, cost)| VehiclePrice { zone: zone.map(str::to_string), cost }).collect();
+
+        VehicleType {
+            costs: VehicleCosts { price_list: Some(price_list), ..create_default_vehicle_costs() },
+            ..create_default_vehicle(type_id)
+        }
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                assignment_costs: Some(assignment_costs),
+                zone: Some("a".to_string()),
+                ..create_delivery_job("job1", (10., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle("v1", price_lists.0), create_vehicle("v2", price_lists.1)],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, expected_vehicle_id);
+    assert_eq!(solution.tours[0].statistic.assignment_cost, expected_assignment_cost);
+    assert_eq!(solution.statistic.cost, 51. + expected_assignment_cost);
+}
@@ -0,0 +1,29 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_add_overtime_cost_to_tour_cost() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    overtime: Some(VehicleOvertime { regular_duration: 11., cost: TieredCost::Fixed(2.) }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.duration, 21);
+    // NOTE fixed + distance + duration + overtime (10 units above regular duration)
+    assert_eq!(solution.tours[0].statistic.cost, 10. + 20. + 21. + 10. * 2.);
+    assert_eq!(solution.statistic.cost, 10. + 20. + 21. + 10. * 2.);
+}
@@ -0,0 +1,67 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_product_job(id: &str, location: (f64, f64), product: &str) -> Job {
+    Job { product: Some(product.to_string()), ..create_delivery_job(id, location) }
+}
+
+fn create_compartment(id: &str, product: &str) -> VehicleCompartment {
+    VehicleCompartment { id: id.to_string(), capacity: vec![2], products: Some(vec![product.to_string()]) }
+}
+
+parameterized_test! {can_assign_products_to_compartments, (jobs, expected_unassigned), {
+    can_assign_products_to_compartments_impl(jobs, expected_unassigned);
+}}
+
+can_assign_products_to_compartments! {
+    case01_all_fit: (vec![("job1", "fuel"), ("job2", "fuel"), ("job3", "diesel")], vec![]),
+    case02_unknown_product: (vec![("job1", "fuel"), ("job2", "diesel"), ("job3", "gas")], vec!["job3"]),
+    case03_compartment_is_full: (vec![("job1", "fuel"), ("job2", "fuel"), ("job3", "fuel")], vec!["job3"]),
+}
+
+fn can_assign_products_to_compartments_impl(jobs: Vec<(&str, &str)>, expected_unassigned: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: jobs
+                .iter()
+                .enumerate()
+                .map(|(idx, (id, product))| create_product_job(id, ((idx + 1) as f64, 0.), product))
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![4],
+                compartments: Some(vec![create_compartment("c1", "fuel"), create_compartment("c2", "diesel")]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    let mut unassigned = solution
+        .unassigned
+        .iter()
+        .flatten()
+        .map(|job| {
+            assert_eq!(job.reasons.first().unwrap().code, "COMPARTMENT_CONSTRAINT");
+            job.job_id.as_str()
+        })
+        .collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, expected_unassigned);
+    assert_eq!(solution.tours.len(), 1);
+    let departure = solution.tours[0].stops.first().and_then(|stop| stop.as_point()).unwrap();
+    let compartments = departure.compartments.as_ref().expect("departure should have compartments");
+    assert_eq!(compartments.len(), 2);
+    compartments.iter().filter_map(|compartment| compartment.product.as_ref().map(|p| (compartment, p))).for_each(
+        |(compartment, product)| {
+            let expected_id = if product == "fuel" { "c1" } else { "c2" };
+            assert_eq!(compartment.id, expected_id);
+        },
+    );
+}
@@ -33,6 +33,8 @@ fn can_calculate_exact_tiered_costs() {
                         CostTier { threshold: 1200., cost: 1.5 },
                     ]),
                     calculation_mode: None,
+                    time_rates: None,
+                    price_list: None,
                 },
                 shifts: vec![create_default_vehicle_shift()],
                 capacity: vec![10],
@@ -73,6 +75,8 @@ fn can_handle_mixed_fleet_with_fixed_and_tiered_costs() {
                         distance: TieredCost::Fixed(1.0),
                         time: TieredCost::Fixed(1.0),
                         calculation_mode: None,
+                        time_rates: None,
+                        price_list: None,
                     },
                     shifts: vec![create_default_vehicle_shift()],
                     capacity: vec![10],
@@ -93,6 +97,8 @@ fn can_handle_mixed_fleet_with_fixed_and_tiered_costs() {
                             CostTier { threshold: 50., cost: 1.1 },
                         ]),
                         calculation_mode: None,
+                        time_rates: None,
+                        price_list: None,
                     },
                     shifts: vec![create_default_vehicle_shift()],
                     capacity: vec![10],
@@ -131,6 +137,8 @@ fn can_handle_edge_cases() {
                     distance: TieredCost::Tiered(vec![CostTier { threshold: 0., cost: 2.0 }]),
                     time: TieredCost::Tiered(vec![CostTier { threshold: 0., cost: 1.5 }]),
                     calculation_mode: None,
+                    time_rates: None,
+                    price_list: None,
                 },
                 shifts: vec![create_default_vehicle_shift()],
                 capacity: vec![5],
@@ -177,6 +185,8 @@ fn can_handle_tier_boundaries_correctly() {
                         CostTier { threshold: 15., cost: 0.3 },
                     ]),
                     calculation_mode: None,
+                    time_rates: None,
+                    price_list: None,
                 },
                 shifts: vec![create_default_vehicle_shift()],
                 capacity: vec![10],
@@ -218,6 +228,8 @@ fn can_match_fixed_and_tiered_costs() {
                     distance: TieredCost::Fixed(1.5),
                     time: TieredCost::Fixed(2.0),
                     calculation_mode: None,
+                    time_rates: None,
+                    price_list: None,
                 },
                 shifts: vec![create_default_vehicle_shift()],
                 capacity: vec![10],
@@ -241,6 +253,8 @@ fn can_match_fixed_and_tiered_costs() {
                     distance: TieredCost::Tiered(vec![CostTier { threshold: 0., cost: 1.5 }]),
                     time: TieredCost::Tiered(vec![CostTier { threshold: 0., cost: 2.0 }]),
                     calculation_mode: None,
+                    time_rates: None,
+                    price_list: None,
                 },
                 shifts: vec![create_default_vehicle_shift()],
                 capacity: vec![10],
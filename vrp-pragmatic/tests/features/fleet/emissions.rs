@@ -0,0 +1,85 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_with_emissions(type_id: &str, consumption: f64) -> VehicleType {
+    VehicleType {
+        emissions: Some(VehicleEmissions {
+            consumption,
+            full_consumption: Some(consumption * 2.),
+            idle_consumption: None,
+            emission_factor: 1.,
+        }),
+        ..create_default_vehicle(type_id)
+    }
+}
+
+parameterized_test! {can_minimize_emissions, objectives, {
+    can_minimize_emissions_impl(objectives);
+}}
+
+can_minimize_emissions! {
+    case01_single: vec![MinimizeUnassigned { breaks: None }, MinimizeTours, MinimizeEmissions, MinimizeCost],
+    case02_multi: vec![
+        MinimizeUnassigned { breaks: None },
+        MinimizeTours,
+        MultiObjective { strategy: MultiStrategy::Sum, objectives: vec![MinimizeEmissions, MinimizeCost] },
+    ],
+}
+
+fn can_minimize_emissions_impl(objectives: Vec<Objective>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_emissions("v1", 1000.), create_vehicle_with_emissions("v2", 100.)],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    // NOTE load ratio is 0.1 on the way to the job, the vehicle returns empty
+    let expected = 10. * 0.11 + 10. * 0.1;
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, "v2_1");
+    assert!((solution.tours[0].statistic.emissions - expected).abs() < 1E-6);
+    assert!((solution.statistic.emissions - expected).abs() < 1E-6);
+}
+
+#[test]
+fn can_report_idle_emissions() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (10., 0.), vec![(100, 200)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                emissions: Some(VehicleEmissions {
+                    consumption: 0.,
+                    full_consumption: None,
+                    idle_consumption: Some(3600.),
+                    emission_factor: 2.,
+                }),
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { latest: Some(format_time(0.)), ..create_default_vehicle_shift().start },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle("v1")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.times.waiting, 90);
+    assert_eq!(solution.statistic.emissions, 180.);
+}
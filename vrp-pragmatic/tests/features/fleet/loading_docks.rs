@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+use vrp_core::prelude::Float;
+
+fn create_vehicle_type(vehicle_id: &str) -> VehicleType {
+    VehicleType {
+        type_id: vehicle_id.to_string(),
+        vehicle_ids: vec![vehicle_id.to_string()],
+        capacity: vec![2],
+        ..create_default_vehicle_type()
+    }
+}
+
+parameterized_test! {can_stagger_departures_at_loading_dock, (capacity, expected_departures), {
+    can_stagger_departures_at_loading_dock_impl(capacity, expected_departures);
+}}
+
+can_stagger_departures_at_loading_dock! {
+    case01_single_dock: (1, vec![20., 40.]),
+    case02_two_docks: (2, vec![20., 20.]),
+}
+
+fn can_stagger_departures_at_loading_dock_impl(capacity: usize, expected_departures: Vec<Float>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+                create_delivery_job("job4", (40., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type("v1"), create_vehicle_type("v2")],
+            resources: Some(vec![VehicleResource::Dock {
+                id: "dock".to_string(),
+                location: (0., 0.).to_loc(),
+                capacity,
+                loading_time: 20.,
+                unit_loading_time: None,
+                times: None,
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let mut slots = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let departure = tour.stops.first().and_then(|stop| stop.as_point()).unwrap();
+            let slot = departure.dock.as_ref().expect("departure should have dock slot");
+            assert_eq!(slot.id, "dock");
+            assert_eq!(slot.end, departure.time.departure);
+            (parse_time(&slot.start), parse_time(&slot.end))
+        })
+        .collect::<Vec<_>>();
+    slots.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    assert_eq!(slots.iter().map(|(_, end)| *end).collect::<Vec<_>>(), expected_departures);
+}
@@ -1,7 +1,16 @@
+mod assignment_costs;
 mod basic_multi_shift;
 mod basic_open_end;
+mod basic_overtime;
+mod compartments;
+mod emissions;
 mod comprehensive_tiered_cost_test;
+mod loading_docks;
+mod multi_day_tour;
 mod multi_dimens;
 mod profile_variation;
+mod replanning;
 mod tiered_cost_test;
+mod time_rates;
+mod trailers;
 mod unreachable_jobs;
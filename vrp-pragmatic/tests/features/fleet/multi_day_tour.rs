@@ -0,0 +1,95 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format::solution::*;
+use crate::{format_time, parse_time};
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_shift(start: Float, end: Float, end_location: (f64, f64)) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: end_location.to_loc() }),
+        ..create_default_vehicle_shift()
+    }
+}
+
+fn create_problem(overnight: VehicleOvernight) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (40., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job2", (80., 0.), vec![(200, 400)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_shift(0., 100., (100., 0.)), create_shift(200., 400., (0., 0.))],
+                overnight: Some(overnight),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_overnight_locations(solution: &Solution) -> Vec<Option<Location>> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "overnight")
+        .map(|(stop, activity)| activity.location.clone().or_else(|| stop.location().cloned()))
+        .collect()
+}
+
+#[test]
+fn can_chain_shifts_with_overnight_stop_at_last_activity() {
+    let problem = create_problem(VehicleOvernight { locations: None, cost: None });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].shift_index, 0);
+    assert_eq!(get_overnight_locations(&solution), vec![Some((40., 0.).to_loc())]);
+}
+
+#[test]
+fn can_chain_shifts_with_overnight_stop_at_allowed_location() {
+    let problem = create_problem(VehicleOvernight { locations: Some(vec![(60., 0.).to_loc()]), cost: Some(10.) });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_overnight_locations(&solution), vec![Some((60., 0.).to_loc())]);
+    let statistic = &solution.tours[0].statistic;
+    // NOTE fixed + distance + duration + overnight
+    assert_eq!(statistic.cost, 10. + statistic.distance as Float + statistic.duration as Float + 10.);
+}
+
+#[test]
+fn can_serve_jobs_only_within_shifts() {
+    let mut problem = create_problem(VehicleOvernight { locations: None, cost: None });
+    problem.plan.jobs.extend(vec![
+        create_delivery_job_with_times("job3", (60., 0.), vec![(110, 190)], 1.),
+        create_delivery_job_with_times("job4", (70., 0.), vec![(90, 300)], 30.),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(unassigned, vec!["job3"]);
+    assert_eq!(solution.tours.len(), 1);
+    let job4_arrival = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.job_id == "job4"))
+        .map(|stop| parse_time(&stop.schedule().arrival));
+    assert!(job4_arrival.is_some_and(|arrival| arrival >= 200.));
+}
@@ -89,3 +89,36 @@ fn can_unassign_due_to_dimension_mismatch() {
             .build()
     );
 }
+
+#[test]
+fn can_use_more_than_eight_dimensions() {
+    let create_demand = |last: i32| (0..10).map(|idx| if idx == 9 { last } else { 1 }).collect::<Vec<_>>();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", (1., 0.), create_demand(1)),
+                create_delivery_job_with_demand("job2", (2., 0.), create_demand(1)),
+                create_delivery_job_with_demand("job3", (3., 0.), create_demand(2)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![3, 3, 3, 3, 3, 3, 3, 3, 3, 3],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+    let departure = solution.tours[0].stops.first().unwrap();
+    assert_eq!(departure.load().len(), 10);
+    assert!(departure.load().iter().all(|value| *value <= 3));
+}
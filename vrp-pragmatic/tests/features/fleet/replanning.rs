@@ -0,0 +1,59 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_pickup_delivery_job("job3", (30., 0.), (5., 0.)),
+                create_service_job("job4", (40., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_replan_remaining_part_of_the_plan() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    assert_eq!(solution.tours.len(), 1);
+    let vehicle_id = solution.tours[0].vehicle_id.clone();
+    let first_job = solution.tours[0].stops[1].activities()[0].job_id.clone();
+    let context = ReplanningContext {
+        time: format_time(100.),
+        vehicles: Some(vec![VehicleExecutionState { vehicle_id: vehicle_id.clone(), location: (15., 0.).to_loc() }]),
+        jobs: Some(vec![JobExecutionState { job_id: first_job.clone(), status: JobExecutionStatus::Done }]),
+        new_jobs: Some(vec![create_delivery_job("job5", (25., 0.))]),
+    };
+
+    let problem = create_replanning_problem(&problem, &solution, &context).unwrap();
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.iter().find(|tour| tour.vehicle_id == vehicle_id).expect("cannot find started vehicle");
+    let departure = &tour.stops[0];
+    assert_eq!(departure.location(), Some(&(15., 0.).to_loc()));
+    assert_eq!(departure.schedule().departure, format_time(100.));
+    let job_ids = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert!(!job_ids.contains(&first_job.as_str()));
+    assert!(job_ids.contains(&"job5"));
+}
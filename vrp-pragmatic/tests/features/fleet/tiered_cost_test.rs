@@ -24,6 +24,8 @@ fn can_solve_problem_with_tiered_costs() {
                         CostTier { threshold: 600., cost: 1. },
                     ]),
                     calculation_mode: None,
+                    time_rates: None,
+                    price_list: None,
                 },
                 shifts: vec![create_default_vehicle_shift()],
                 capacity: vec![10],
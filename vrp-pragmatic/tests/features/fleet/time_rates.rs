@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_charge_time_at_time_of_day_rates, (weekdays, expected_cost), {
+    can_charge_time_at_time_of_day_rates_impl(weekdays, expected_cost);
+}}
+
+// NOTE 1970-01-01 is Thursday; the expected cost is fixed + distance + driving + service
+can_charge_time_at_time_of_day_rates! {
+    case01_rate_in_force: (None, 10. + 20. + 20. * 3. + 1.),
+    case02_rate_on_another_day: (Some(vec![5]), 10. + 20. + 20. + 1.),
+}
+
+fn can_charge_time_at_time_of_day_rates_impl(weekdays: Option<Vec<u8>>, expected_cost: f64) {
+    let time_rate = VehicleTimeRate {
+        weekdays,
+        start: "00:00".to_string(),
+        end: "01:00".to_string(),
+        driving: Some(3.),
+        service: None,
+        waiting: None,
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { time_rates: Some(vec![time_rate]), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.duration, 21);
+    assert_eq!(solution.tours[0].statistic.cost, expected_cost);
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
@@ -0,0 +1,63 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_decouple_trailer_to_serve_job, (parking_time, expected), {
+    can_decouple_trailer_to_serve_job_impl(parking_time, expected);
+}}
+
+can_decouple_trailer_to_serve_job! {
+    case01_parking_available: ((0., 1000.), Some(vec!["departure", "decouple", "job2", "couple", "arrival"])),
+    case02_parking_closed: ((2000., 3000.), None),
+}
+
+fn can_decouple_trailer_to_serve_job_impl(parking_time: (f64, f64), expected: Option<Vec<&str>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", (1., 0.), vec![5]),
+                Job { trailer_allowed: Some(false), ..create_delivery_job_with_demand("job2", (3., 0.), vec![2]) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                trailer_id: Some("trailer".to_string()),
+                ..create_vehicle_with_capacity("my_vehicle", vec![4])
+            }],
+            trailers: Some(vec![Trailer {
+                id: "trailer".to_string(),
+                capacity: vec![6],
+                parkings: vec![TrailerParking {
+                    location: (2., 0.).to_loc(),
+                    duration: 1.,
+                    times: Some(vec![vec![format_time(parking_time.0), format_time(parking_time.1)]]),
+                    tag: None,
+                }],
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if let Some(expected) = expected {
+        assert!(solution.unassigned.is_none());
+        assert_eq!(solution.tours.len(), 1);
+        // NOTE job1 can be served before or after trailer is parked
+        let activities = solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities())
+            .map(|activity| activity.job_id.as_str())
+            .filter(|job_id| *job_id != "job1")
+            .collect::<Vec<_>>();
+        assert_eq!(activities, expected);
+    } else {
+        let unassigned = solution.unassigned.expect("unassigned jobs");
+        assert_eq!(unassigned.len(), 1);
+        assert_eq!(unassigned[0].job_id, "job2");
+    }
+}
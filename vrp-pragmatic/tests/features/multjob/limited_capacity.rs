@@ -35,6 +35,9 @@ fn can_handle_limited_capacity() {
             duration: 42,
             activity_distance: 24,
             activity_duration: 30,
+            assignment_cost: 0.,
+            outsourcing_cost: 0.,
+            emissions: 0.,
             times: Timing { driving: 36, serving: 6, ..Timing::default() },
         }
     );
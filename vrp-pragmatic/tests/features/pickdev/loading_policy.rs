@@ -0,0 +1,54 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_follow_loading_policy, policy, {
+    can_follow_loading_policy_impl(policy);
+}}
+
+can_follow_loading_policy! {
+    case01_lifo: VehicleLoadingPolicy::Lifo,
+    case02_fifo: VehicleLoadingPolicy::Fifo,
+}
+
+fn can_follow_loading_policy_impl(policy: VehicleLoadingPolicy) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (4., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (5., 0.)),
+                create_pickup_delivery_job("job3", (3., 0.), (6., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { loading_policy: Some(policy), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .filter(|activity| activity.activity_type == "pickup" || activity.activity_type == "delivery")
+        .collect::<Vec<_>>();
+    let get_job_ids = |activity_type: &str| {
+        activities
+            .iter()
+            .filter(|activity| activity.activity_type == activity_type)
+            .map(|activity| activity.job_id.as_str())
+            .collect::<Vec<_>>()
+    };
+    let pickups = get_job_ids("pickup");
+    let mut deliveries = get_job_ids("delivery");
+    if matches!(policy, VehicleLoadingPolicy::Lifo) {
+        deliveries.reverse();
+    }
+    assert_eq!(pickups, deliveries);
+}
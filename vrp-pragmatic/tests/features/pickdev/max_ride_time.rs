@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_ride_job(id: &str, pickup: (f64, f64), delivery: (f64, f64), max_ride_time: JobMaxRideTime) -> Job {
+    Job { max_ride_time: Some(max_ride_time), ..create_pickup_delivery_job(id, pickup, delivery) }
+}
+
+parameterized_test! {can_avoid_detour_with_max_ride_time, max_ride_time, {
+    can_avoid_detour_with_max_ride_time_impl(max_ride_time);
+}}
+
+can_avoid_detour_with_max_ride_time! {
+    case01_duration: JobMaxRideTime::Duration(9.),
+    case02_factor: JobMaxRideTime::Factor(1.),
+}
+
+fn can_avoid_detour_with_max_ride_time_impl(max_ride_time: JobMaxRideTime) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_ride_job("job1", (1., 0.), (10., 0.), max_ride_time),
+                create_pickup_delivery_job("job2", (5., 0.), (6., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .filter(|activity| activity.activity_type == "pickup" || activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    let first = job_ids.iter().position(|job_id| *job_id == "job1").unwrap();
+    assert_eq!(job_ids[first + 1], "job1");
+}
+
+#[test]
+fn can_unassign_job_with_too_short_max_ride_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_ride_job("job1", (1., 0.), (10., 0.), JobMaxRideTime::Duration(5.)),
+                create_pickup_delivery_job("job2", (5., 0.), (6., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    assert_eq!(unassigned[0].reasons[0].code, "MAX_RIDE_TIME_CONSTRAINT");
+}
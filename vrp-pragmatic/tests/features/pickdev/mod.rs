@@ -1,3 +1,6 @@
 mod basic_pick_dev;
+mod loading_policy;
+mod max_ride_time;
 mod mixed_pick_dev_simple_jobs;
 mod relation_pick_dev;
+mod transfers;
@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_transfer_vehicle(id: &str, location: (f64, f64)) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        limits: Some(VehicleLimits {
+            max_distance: Some(20.),
+            max_duration: None,
+            max_activity_duration: None,
+            tour_size: None,
+        }),
+        ..create_default_vehicle(id)
+    }
+}
+
+parameterized_test! {can_transfer_goods_between_vehicles, (handling_time, expected), {
+    can_transfer_goods_between_vehicles_impl(handling_time, expected);
+}}
+
+can_transfer_goods_between_vehicles! {
+    case01_no_handling_time: (0., Some(12.)),
+    case02_wait_for_handling: (5., Some(17.)),
+    case03_handling_after_shift_end: (2000., None),
+}
+
+fn can_transfer_goods_between_vehicles_impl(handling_time: f64, expected: Option<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                transfer: Some(JobTransfer { location: (10., 0.).to_loc(), duration: 1., handling_time, tag: None }),
+                ..create_pickup_delivery_job("job1", (5., 0.), (15., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_transfer_vehicle("v1", (0., 0.)), create_transfer_vehicle("v2", (20., 0.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if let Some(expected) = expected {
+        assert!(solution.unassigned.is_none());
+        assert_eq!(solution.tours.len(), 2);
+        let get_activities = |vehicle_id: &str| {
+            let tour = solution.tours.iter().find(|tour| tour.vehicle_id == vehicle_id).expect("tour");
+            tour.stops.iter().flat_map(|stop| stop.activities()).cloned().collect::<Vec<_>>()
+        };
+        let inbound = get_activities("v1_1");
+        let outbound = get_activities("v2_1");
+        let get_types = |activities: &[crate::format::solution::Activity]| {
+            activities.iter().map(|activity| activity.activity_type.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(get_types(&inbound), vec!["departure", "pickup", "drop", "arrival"]);
+        assert_eq!(get_types(&outbound), vec!["departure", "collect", "delivery", "arrival"]);
+        let collect_stop = solution.tours.iter().find(|tour| tour.vehicle_id == "v2_1").unwrap().stops[1].schedule();
+        assert_eq!(collect_stop.departure, format_time(expected + 1.));
+    } else {
+        let unassigned = solution.unassigned.expect("unassigned jobs");
+        assert_eq!(unassigned.len(), 1);
+        assert_eq!(unassigned[0].job_id, "job1");
+    }
+}
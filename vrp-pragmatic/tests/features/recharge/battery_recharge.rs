@@ -0,0 +1,83 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+use vrp_core::prelude::Float;
+
+fn create_battery_problem(capacity: Float, reserve: Float, load_consumption: Option<Float>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (30., 0.)), create_delivery_job("job2", (70., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: None,
+                        initial_distance: None,
+                        battery: Some(VehicleBattery {
+                            capacity,
+                            initial_charge: None,
+                            reserve: Some(reserve),
+                            consumption: 1000.,
+                            load_consumption,
+                            charging_curve: vec![ChargingCurvePoint { level: capacity, power: 3600. }],
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (50., 0.).to_loc(),
+                            duration: 10.,
+                            times: None,
+                            tag: None,
+                            resource_id: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
+                }],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_charges(tour: &Tour) -> Vec<(Float, Float)> {
+    tour.stops
+        .iter()
+        .filter_map(|stop| stop.as_point())
+        .map(|stop| stop.charge.as_ref().map_or((-1., -1.), |charge| (charge.arrival, charge.departure)))
+        .collect()
+}
+
+#[test]
+fn can_recharge_only_needed_energy() {
+    let problem = create_battery_problem(60., 5., None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(
+        get_ids_from_tour(tour),
+        vec![vec!["departure"], vec!["job1"], vec!["recharge"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(get_charges(tour), vec![(60., 60.), (30., 30.), (10., 55.), (35., 35.), (5., 5.)]);
+    let recharge = tour.stops[2].schedule();
+    assert_eq!(parse_time(&recharge.departure) - parse_time(&recharge.arrival), 55.);
+}
+
+#[test]
+fn can_consider_payload_in_consumption() {
+    let problem = create_battery_problem(200., 0., Some(1000.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(get_ids_from_tour(tour), vec![vec!["departure"], vec!["job1"], vec!["job2"], vec!["arrival"]]);
+    assert_eq!(get_charges(tour), vec![(200., 200.), (110., 110.), (30., 30.), (0., 0.)]);
+}
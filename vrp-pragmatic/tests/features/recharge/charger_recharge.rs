@@ -0,0 +1,74 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_vehicle_type(vehicle_id: &str, start: Float) -> VehicleType {
+    let shift = create_default_vehicle_shift_with_locations((0., 0.), (100., 0.));
+
+    VehicleType {
+        type_id: vehicle_id.to_string(),
+        vehicle_ids: vec![vehicle_id.to_string()],
+        shifts: vec![VehicleShift {
+            start: ShiftStart { earliest: format_time(start), ..shift.start.clone() },
+            recharges: Some(VehicleRecharges {
+                max_distance: Some(55.),
+                initial_distance: None,
+                battery: None,
+                stations: vec![VehicleRechargeStation {
+                    location: (50., 0.).to_loc(),
+                    duration: 100.,
+                    times: None,
+                    tag: None,
+                    resource_id: Some("charger".to_string()),
+                }],
+            }),
+            ..shift
+        }],
+        capacity: vec![2],
+        ..create_default_vehicle_type()
+    }
+}
+
+parameterized_test! {can_limit_parallel_charging_sessions, (capacity, second_start, expected_tours), {
+    can_limit_parallel_charging_sessions_impl(capacity, second_start, expected_tours);
+}}
+
+can_limit_parallel_charging_sessions! {
+    case01_overlapping_sessions: (1, 0., 1),
+    case02_sequential_sessions: (1, 200., 2),
+    case03_parallel_sessions: (2, 0., 2),
+}
+
+fn can_limit_parallel_charging_sessions_impl(capacity: usize, second_start: Float, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (30., 0.)),
+                create_delivery_job("job2", (70., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+                create_delivery_job("job4", (70., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type("v1", 0.), create_vehicle_type("v2", second_start)],
+            resources: Some(vec![VehicleResource::Charger { id: "charger".to_string(), capacity }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    let recharges = solution
+        .tours
+        .iter()
+        .flat_map(|tour| get_ids_from_tour(tour).into_iter().flatten())
+        .filter(|id| id == "recharge")
+        .count();
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert_eq!(recharges, expected_tours);
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), 4 - 2 * expected_tours);
+}
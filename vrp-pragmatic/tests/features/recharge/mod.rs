@@ -1 +1,3 @@
 mod basic_recharge;
+mod battery_recharge;
+mod charger_recharge;
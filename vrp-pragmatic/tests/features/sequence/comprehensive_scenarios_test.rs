@@ -18,6 +18,7 @@ fn can_handle_weekend_gap_with_same_assignee_no_time_windows() {
                             duration: 300.,
                             times: None, // No time window - timing validation will be skipped
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -39,6 +40,7 @@ fn can_handle_weekend_gap_with_same_assignee_no_time_windows() {
                             duration: 400.,
                             times: None, // No time window
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -60,6 +62,7 @@ fn can_handle_weekend_gap_with_same_assignee_no_time_windows() {
                             duration: 350.,
                             times: None, // No time window
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -177,6 +180,7 @@ fn can_handle_weekly_tutoring_with_calendar_constraints() {
                             duration: 3600., // 1 hour
                             times: None, // No time window - use flexible scheduling
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -199,6 +203,7 @@ fn can_handle_weekly_tutoring_with_calendar_constraints() {
                             duration: 3600.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -221,6 +226,7 @@ fn can_handle_weekly_tutoring_with_calendar_constraints() {
                             duration: 3600.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -243,6 +249,7 @@ fn can_handle_weekly_tutoring_with_calendar_constraints() {
                             duration: 3600.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -372,6 +379,7 @@ fn can_handle_multi_day_equipment_check_different_technicians() {
                             duration: 300.,
                             times: None, // No time window - uses shift start time for validation
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -393,6 +401,7 @@ fn can_handle_multi_day_equipment_check_different_technicians() {
                             duration: 400.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -413,6 +422,7 @@ fn can_handle_multi_day_equipment_check_different_technicians() {
                             duration: 350.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
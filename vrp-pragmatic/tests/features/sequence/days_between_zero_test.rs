@@ -17,6 +17,7 @@ fn can_assign_sequence_jobs_on_same_shift_when_days_between_min_is_zero() {
                             duration: 100.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -37,6 +38,7 @@ fn can_assign_sequence_jobs_on_same_shift_when_days_between_min_is_zero() {
                             duration: 100.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -57,6 +59,7 @@ fn can_assign_sequence_jobs_on_same_shift_when_days_between_min_is_zero() {
                             duration: 100.,
                             times: None,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -145,6 +148,7 @@ fn requires_different_shifts_when_days_between_min_is_one() {
                             // Add time window to guide solver to shift 0
                             times: Some(vec![vec![format_time(0.), format_time(36000.)]]),
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
@@ -166,6 +170,7 @@ fn requires_different_shifts_when_days_between_min_is_one() {
                             // Add time window to guide solver to shift 1
                             times: Some(vec![vec![format_time(86400.), format_time(122400.)]]),
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![1]),
                         order: None,
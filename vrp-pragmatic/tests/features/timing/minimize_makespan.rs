@@ -0,0 +1,49 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_makespan, (objectives, expected_tours), {
+    can_minimize_makespan_impl(objectives, expected_tours);
+}}
+
+// NOTE one tour is cheaper as the second vehicle has a fixed cost, but two tours finish the work earlier
+can_minimize_makespan! {
+    case01_cost: (vec![MinimizeUnassigned { breaks: None }, MinimizeCost], 1),
+    case02_single: (vec![MinimizeUnassigned { breaks: None }, MinimizeMakespan, MinimizeCost], 2),
+    case03_multi: (
+        vec![
+            MinimizeUnassigned { breaks: None },
+            MultiObjective { strategy: MultiStrategy::Sum, objectives: vec![MinimizeMakespan, MinimizeCost] },
+        ],
+        2
+    ),
+}
+
+fn can_minimize_makespan_impl(objectives: Vec<Objective>, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (11., 0.)),
+                create_delivery_job("job3", (-10., 0.)),
+                create_delivery_job("job4", (-11., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert!(solution.unassigned.is_none());
+}
@@ -0,0 +1,64 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_waiting_time, (objectives, expected), {
+    can_minimize_waiting_time_impl(objectives, expected);
+}}
+
+// NOTE serving job1 first is shorter, but the vehicle has to wait for its time window start
+can_minimize_waiting_time! {
+    case01_cost: (vec![MinimizeUnassigned { breaks: None }, MinimizeCost], (vec!["job1", "job2"], 20)),
+    case02_single: (
+        vec![MinimizeUnassigned { breaks: None }, MinimizeWaitingTime, MinimizeCost],
+        (vec!["job2", "job1"], 0)
+    ),
+    case03_multi: (
+        vec![
+            MinimizeUnassigned { breaks: None },
+            MultiObjective { strategy: MultiStrategy::Sum, objectives: vec![MinimizeWaitingTime, MinimizeCost] },
+        ],
+        (vec!["job2", "job1"], 0)
+    ),
+}
+
+fn can_minimize_waiting_time_impl(objectives: Vec<Objective>, expected: (Vec<&str>, i64)) {
+    let (expected_ids, expected_waiting) = expected;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(30, 100)], 0.),
+                create_delivery_job_with_times("job2", (20., 0.), vec![(0, 100)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { latest: Some(format_time(0.)), ..create_default_open_vehicle_shift().start },
+                    ..create_default_open_vehicle_shift()
+                }],
+                costs: VehicleCosts { time: TieredCost::Fixed(0.), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0])
+            .into_iter()
+            .flatten()
+            .filter(|id| id.starts_with("job"))
+            .collect::<Vec<_>>(),
+        expected_ids
+    );
+    assert_eq!(solution.statistic.times.waiting, expected_waiting);
+}
@@ -1,4 +1,7 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod minimize_makespan;
+mod minimize_waiting_time;
+mod soft_time_windows;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
@@ -0,0 +1,94 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_problem_with_soft_job(max_lateness: Float) -> Problem {
+    let mut soft_job = create_delivery_job_with_times("job5", (50., 0.), vec![(0, 10)], 0.);
+    soft_job.deliveries.as_mut().unwrap()[0].places[0].soft_times = Some(JobPlaceSoftTimes {
+        max_earliness: None,
+        max_lateness: Some(max_lateness),
+        earliness_cost: None,
+        lateness_cost: Some(1.),
+    });
+
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 10)], 0.),
+                create_delivery_job_with_times("job2", (20., 0.), vec![(10, 20)], 0.),
+                create_delivery_job_with_times("job3", (30., 0.), vec![(20, 30)], 0.),
+                create_delivery_job_with_times("job4", (40., 0.), vec![(30, 40)], 0.),
+                soft_job,
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn get_activity<'a>(solution: &'a Solution, job_id: &str) -> Option<&'a Activity> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .find(|activity| activity.job_id == job_id)
+}
+
+#[test]
+fn can_serve_job_late_within_soft_time_window() {
+    let problem = create_problem_with_soft_job(100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activity = get_activity(&solution, "job5").expect("cannot find job5 activity");
+    assert_eq!(activity.lateness, Some(40));
+    assert_eq!(activity.earliness, None);
+    assert_eq!(get_activity(&solution, "job4").unwrap().lateness, None);
+}
+
+#[test]
+fn can_have_unassigned_job_when_soft_time_window_is_exceeded() {
+    let problem = create_problem_with_soft_job(30.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job5");
+    assert_eq!(unassigned[0].reasons[0].code, "TIME_WINDOW_CONSTRAINT");
+}
+
+#[test]
+fn can_serve_job_early_within_soft_time_window() {
+    let mut job = create_delivery_job_with_times("job1", (60., 0.), vec![(100, 200)], 10.);
+    job.deliveries.as_mut().unwrap()[0].places[0].soft_times = Some(JobPlaceSoftTimes {
+        max_earliness: Some(50.),
+        max_lateness: None,
+        earliness_cost: Some(60.),
+        lateness_cost: None,
+    });
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let stop = solution.tours[0].stops.get(1).expect("cannot find job1 stop");
+    assert_eq!(stop.schedule().arrival, format_time(60.));
+    assert_eq!(stop.schedule().departure, format_time(70.));
+    let activity = get_activity(&solution, "job1").expect("cannot find job1 activity");
+    assert_eq!(activity.earliness, Some(40));
+    assert_eq!(activity.lateness, None);
+}
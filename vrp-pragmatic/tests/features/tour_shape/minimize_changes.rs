@@ -0,0 +1,93 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::iter::once;
+use vrp_core::prelude::Float;
+
+fn create_baseline_tour(vehicle_id: &str, jobs: &[(&str, Float)]) -> Tour {
+    let stop = |location: Float| {
+        StopBuilder::default().coordinate((location, 0.)).schedule_stamp(location, location + 1.).load(vec![0])
+    };
+
+    TourBuilder::default()
+        .vehicle_id(vehicle_id)
+        .type_id("my_vehicle")
+        .stops(
+            once(stop(0.).build_departure())
+                .chain(jobs.iter().map(|(job_id, location)| stop(*location).build_single(job_id, "delivery")))
+                .collect(),
+        )
+        .build()
+}
+
+fn create_minimize_changes_objective(max_position_shift: Option<usize>) -> Objective {
+    let baseline = SolutionBuilder::default()
+        .tour(create_baseline_tour("my_vehicle_1", &[("job1", 1.), ("job3", 3.)]))
+        .tour(create_baseline_tour("my_vehicle_2", &[("job2", 2.), ("job4", 4.)]))
+        .build();
+
+    MinimizeChanges { baseline: Box::new(baseline), max_position_shift, max_arrival_shift: None, penalties: None }
+}
+
+fn create_test_problem(objectives: Vec<Objective>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_keep_baseline_assignments() {
+    let problem = create_test_problem(vec![
+        MinimizeUnassigned { breaks: None },
+        create_minimize_changes_objective(Some(0)),
+        MinimizeTours,
+        MinimizeCost,
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["job3"]]);
+    assert_eq!(get_ids_from_tour(&solution.tours[1]), vec![vec!["departure"], vec!["job2"], vec!["job4"]]);
+    assert_eq!(
+        solution.extras.and_then(|extras| extras.changes),
+        Some(BaselineChanges { vehicle: 0, position: 0, arrival: 0 })
+    );
+}
+
+#[test]
+fn can_report_changes_when_other_objectives_are_more_important() {
+    let problem = create_test_problem(vec![
+        MinimizeUnassigned { breaks: None },
+        MinimizeTours,
+        create_minimize_changes_objective(None),
+        MinimizeCost,
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let changes = solution.extras.and_then(|extras| extras.changes).expect("no changes reported");
+    assert_eq!(changes.vehicle, 2);
+}
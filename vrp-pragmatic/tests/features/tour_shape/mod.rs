@@ -1 +1,2 @@
 mod basic_tour_compactness;
+mod minimize_changes;
@@ -1,2 +1,3 @@
 mod multi_reasons;
+mod outsourcing;
 mod single_reason;
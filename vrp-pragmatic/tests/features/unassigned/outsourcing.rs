@@ -0,0 +1,110 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+parameterized_test! {can_outsource_expensive_jobs, (job_cost, plan_cost, expected), {
+    can_outsource_expensive_jobs_impl(job_cost, plan_cost, expected);
+}}
+
+can_outsource_expensive_jobs! {
+    case01_job_cost: (Some(50.), None, (Some(vec![("job2", 50.)]), 10. + 2. + 3. + 50.)),
+    case02_plan_cost: (None, Some(1000.), (None, 10. + 200. + 202.)),
+    case03_job_cost_precedence: (Some(40.), Some(1000.), (Some(vec![("job2", 40.)]), 10. + 2. + 3. + 40.)),
+}
+
+fn can_outsource_expensive_jobs_impl(
+    job_cost: Option<f64>,
+    plan_cost: Option<f64>,
+    expected: (Option<Vec<(&str, f64)>>, f64),
+) {
+    let (expected_outsourced, expected_cost) = expected;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job { outsourcing_cost: job_cost, ..create_delivery_job("job2", (100., 0.)) },
+            ],
+            outsourcing_cost: plan_cost,
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        solution.outsourced,
+        expected_outsourced.map(|jobs| jobs
+            .into_iter()
+            .map(|(job_id, cost)| OutsourcedJob { job_id: job_id.to_string(), cost })
+            .collect())
+    );
+    assert_eq!(solution.statistic.cost, expected_cost);
+}
+
+#[test]
+fn can_report_job_which_cannot_be_served_as_unassigned() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job_with_skills("job2", (2., 0.), all_of_skills(vec!["unique_skill".to_string()])),
+            ],
+            outsourcing_cost: Some(1000.),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(unassigned, vec!["job2"]);
+    assert!(solution.outsourced.is_none());
+    assert_eq!(solution.statistic.outsourcing_cost, 0.);
+    assert_eq!(solution.statistic.cost, 10. + 2. + 3.);
+}
+
+#[test]
+fn can_evaluate_solution_with_outsourced_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job { outsourcing_cost: Some(50.), ..create_delivery_job("job2", (100., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let stop = |location: (f64, f64)| StopBuilder::default().coordinate(location).schedule_stamp(0., 0.).load(vec![0]);
+    let solution = Solution {
+        outsourced: Some(vec![OutsourcedJob { job_id: "job2".to_string(), cost: 50. }]),
+        ..SolutionBuilder::default()
+            .tour(
+                TourBuilder::default()
+                    .stops(vec![
+                        stop((0., 0.)).build_departure(),
+                        stop((1., 0.)).build_single("job1", "delivery"),
+                        stop((0., 0.)).build_arrival(),
+                    ])
+                    .build(),
+            )
+            .build()
+    };
+
+    let evaluation = evaluate_solution(problem, Some(vec![matrix]), solution).unwrap();
+
+    let min_unassigned = evaluation.objectives.iter().find(|objective| objective.name == "min_unassigned");
+    assert_eq!(min_unassigned.map(|objective| objective.value), Some(0.));
+    assert!(evaluation.solution.unassigned.is_none());
+    assert_eq!(evaluation.solution.outsourced, Some(vec![OutsourcedJob { job_id: "job2".to_string(), cost: 50. }]));
+    assert_eq!(evaluation.solution.statistic.cost, 10. + 2. + 3. + 50.);
+}
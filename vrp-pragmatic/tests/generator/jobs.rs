@@ -95,6 +95,14 @@ prop_compose! {
             affinity: None,
             same_assignee_key: None,
             sync: None,
+            product: None,
+            max_ride_time: None,
+            split: None,
+            trailer_allowed: None,
+            transfer: None,
+            assignment_costs: None,
+            zone: None,
+            outsourcing_cost: None,
             sequence: None,
         }
     }
@@ -145,6 +153,14 @@ prop_compose! {
             affinity: None,
             same_assignee_key: None,
             sync: None,
+            product: None,
+            max_ride_time: None,
+            split: None,
+            trailer_allowed: None,
+            transfer: None,
+            assignment_costs: None,
+            zone: None,
+            outsourcing_cost: None,
             sequence: None,
         }
     }
@@ -178,7 +194,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, soft_times: None }
     }
 }
 
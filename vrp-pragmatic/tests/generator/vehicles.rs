@@ -34,6 +34,12 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            hours_of_service: None,
+            trailer_id: None,
+            overnight: None,
+            emissions: None,
+            compartments: None,
+            loading_policy: None,
         }
     }
 }
@@ -79,6 +85,7 @@ prop_compose! {
           location,
           duration,
           tag,
+          resource_id: None,
         }
     }
 }
@@ -110,6 +117,7 @@ prop_compose! {
           breaks,
           reloads,
           recharges,
+          overtime: None,
         }
     }
 }
@@ -130,7 +138,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, resources: None, trailers: None }
     }
 }
 
@@ -9,7 +9,7 @@ use vrp_core::models::solution::Route;
 use vrp_core::prelude::Float;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, soft_times: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
@@ -30,6 +30,14 @@ pub fn create_job(id: &str) -> Job {
         affinity: None,
         same_assignee_key: None,
         sync: None,
+        product: None,
+        max_ride_time: None,
+        split: None,
+        trailer_allowed: None,
+        transfer: None,
+        assignment_costs: None,
+        zone: None,
+        outsourcing_cost: None,
         sequence: None,
     }
 }
@@ -169,7 +177,13 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                soft_times: None,
+            }],
             demand: Some(vec![1]),
             order: None,
         }]),
@@ -217,6 +231,7 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
         breaks: None,
         reloads: None,
         recharges: None,
+        overtime: None,
     }
 }
 
@@ -227,6 +242,7 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
         breaks: None,
         reloads: None,
         recharges: None,
+        overtime: None,
     }
 }
 
@@ -236,6 +252,8 @@ pub fn create_default_vehicle_costs() -> VehicleCosts {
         distance: TieredCost::Fixed(1.), 
         time: TieredCost::Fixed(1.),
         calculation_mode: None,
+        time_rates: None,
+        price_list: None,
     }
 }
 
@@ -265,11 +283,22 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        hours_of_service: None,
+        trailer_id: None,
+        overnight: None,
+        emissions: None,
+        compartments: None,
+        loading_policy: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        profiles: create_default_matrix_profiles(),
+        resources: None,
+        trailers: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
@@ -281,13 +310,13 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, outsourcing_cost: None }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, trailers: None },
         objectives: None,
     }
 }
@@ -54,6 +54,34 @@ impl StopBuilder {
         self
     }
 
+    pub fn charge(mut self, arrival: Float, departure: Float) -> Self {
+        let mut stop = self.stop.to_point();
+        stop.charge = Some(StopCharge { arrival, departure });
+        self.stop = Stop::Point(stop);
+
+        self
+    }
+
+    pub fn dock(mut self, id: &str, start: Timestamp, end: Timestamp) -> Self {
+        let mut stop = self.stop.to_point();
+        stop.dock = Some(DockSlot { id: id.to_string(), start: format_time(start), end: format_time(end) });
+        self.stop = Stop::Point(stop);
+
+        self
+    }
+
+    pub fn compartment(mut self, id: &str, product: Option<&str>, load: Vec<i32>) -> Self {
+        let mut stop = self.stop.to_point();
+        stop.compartments.get_or_insert_with(Vec::default).push(StopCompartment {
+            id: id.to_string(),
+            product: product.map(|product| product.to_string()),
+            load,
+        });
+        self.stop = Stop::Point(stop);
+
+        self
+    }
+
     pub fn custom_unknown(mut self) -> Self {
         let mut stop = self.stop.to_point();
         stop.location = Location::Custom { r#type: CustomLocationType::Unknown };
@@ -170,6 +198,9 @@ impl Default for StopBuilder {
                 distance: 0,
                 load: vec![],
                 parking: None,
+                charge: None,
+                dock: None,
+                compartments: None,
                 activities: vec![],
             }),
         }
@@ -239,6 +270,12 @@ impl ActivityBuilder {
         self
     }
 
+    pub fn demand(mut self, demand: Vec<i32>) -> Self {
+        self.activity.demand = Some(demand);
+
+        self
+    }
+
     pub fn build(self) -> Activity {
         if self.activity.activity_type.is_empty() {
             panic!("missing activity type");
@@ -262,6 +299,9 @@ impl Default for ActivityBuilder {
                 time: None,
                 job_tag: None,
                 commute: None,
+                earliness: None,
+                lateness: None,
+                demand: None,
             },
         }
     }
@@ -485,6 +525,7 @@ impl Default for SolutionBuilder {
                 statistic: Default::default(),
                 tours: vec![],
                 unassigned: None,
+                outsourced: None,
                 violations: None,
                 extras: None,
             },
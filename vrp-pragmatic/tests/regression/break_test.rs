@@ -22,6 +22,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                soft_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -38,6 +39,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                soft_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -54,6 +56,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                soft_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -70,6 +73,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                soft_times: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
@@ -86,6 +90,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                soft_times: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
@@ -102,6 +107,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                soft_times: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -116,7 +122,7 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: TieredCost::Fixed(0.002), time: TieredCost::Fixed(0.003), calculation_mode: None },
+                    costs: VehicleCosts { fixed: Some(20.), distance: TieredCost::Fixed(0.002), time: TieredCost::Fixed(0.003), calculation_mode: None, time_rates: None, price_list: None },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
@@ -138,10 +144,17 @@ fn can_handle_properly_invalid_break_removal() {
                         }]),
                         reloads: None,
                         recharges: None,
+                        overtime: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    hours_of_service: None,
+                    trailer_id: None,
+                    overnight: None,
+                    emissions: None,
+                    compartments: None,
+                    loading_policy: None,
                 }],
                 ..create_default_fleet()
             },
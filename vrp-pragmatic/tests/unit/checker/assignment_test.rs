@@ -131,6 +131,7 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
+                    soft_times: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
@@ -309,3 +310,109 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".into()));
 }
+
+parameterized_test! {can_check_split_jobs, (parts, min_size, max_splits, expected), {
+    can_check_split_jobs_impl(parts, min_size, max_splits, expected);
+}}
+
+can_check_split_jobs! {
+    case01_valid: (vec![vec![6], vec![4]], None, None, Ok(())),
+    case02_wrong_sum: (vec![vec![6], vec![3]], None, None, Err("do not sum up to its demand")),
+    case03_too_many_visits: (vec![vec![4], vec![3], vec![3]], None, Some(2), Err("more than allowed")),
+    case04_too_small_part: (vec![vec![8], vec![2]], Some(vec![3]), None, Err("smaller than its min size")),
+}
+
+fn can_check_split_jobs_impl(
+    parts: Vec<Vec<i32>>,
+    min_size: Option<Vec<i32>>,
+    max_splits: Option<usize>,
+    expected: Result<(), &str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                split: Some(JobSplit { min_size, max_splits }),
+                ..create_delivery_job_with_demand("job1", (1., 0.), vec![10])
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let stops = parts
+        .into_iter()
+        .map(|demand| {
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![0])
+                .activity(ActivityBuilder::delivery().job_id("job1").demand(demand).build())
+                .build()
+        })
+        .collect();
+    let solution = SolutionBuilder::default().tour(TourBuilder::default().stops(stops).build()).build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_split_jobs(&ctx);
+
+    match expected {
+        Ok(()) => assert!(result.is_ok()),
+        Err(message) => assert!(result.unwrap_err().to_string().contains(message)),
+    }
+}
+
+parameterized_test! {can_check_transfers, (collect_start, has_delivery, expected), {
+    can_check_transfers_impl(collect_start, has_delivery, expected);
+}}
+
+can_check_transfers! {
+    case01_valid: (5., true, Ok(())),
+    case02_too_early_collect: (4., true, Err("before its goods are handled")),
+    case03_not_complete: (5., false, Err("is not served completely")),
+}
+
+fn can_check_transfers_impl(collect_start: f64, has_delivery: bool, expected: Result<(), &str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                transfer: Some(JobTransfer { location: (2., 0.).to_loc(), duration: 1., handling_time: 1., tag: None }),
+                ..create_pickup_delivery_job("job1", (1., 0.), (3., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_stop = |location: (f64, f64), time: (f64, f64), activity_type: &str| {
+        let stop = StopBuilder::default().coordinate(location).schedule_stamp(time.0, time.1).load(vec![0]);
+        match activity_type {
+            "pickup" => stop.build_single_tag("job1", activity_type, "p1"),
+            "delivery" => stop.build_single_tag("job1", activity_type, "d1"),
+            _ => stop.build_single("job1", activity_type),
+        }
+    };
+    let inbound = vec![create_stop((1., 0.), (1., 2.), "pickup"), create_stop((2., 0.), (3., 4.), "drop")];
+    let outbound = std::iter::once(create_stop((2., 0.), (collect_start, collect_start + 1.), "collect"))
+        .chain(has_delivery.then(|| create_stop((3., 0.), (collect_start + 2., collect_start + 3.), "delivery")))
+        .collect();
+    let solution = SolutionBuilder::default()
+        .tour(TourBuilder::default().vehicle_id("v1").stops(inbound).build())
+        .tour(TourBuilder::default().vehicle_id("v2").stops(outbound).build())
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_transfers(&ctx);
+
+    match expected {
+        Ok(()) => assert!(result.is_ok()),
+        Err(message) => assert!(result.unwrap_err().to_string().contains(message)),
+    }
+}
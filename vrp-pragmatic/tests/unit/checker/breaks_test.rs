@@ -86,6 +86,7 @@ fn can_check_breaks_impl(
                     }]),
                     reloads: None,
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -102,6 +103,9 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        earliness: None,
+        lateness: None,
+        demand: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -111,6 +115,9 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            earliness: None,
+            lateness: None,
+            demand: None,
         });
     }
 
@@ -150,3 +157,76 @@ fn can_check_breaks_impl(
 
     assert_eq!(result, expected_result);
 }
+
+fn get_night_error_msg(message: &str) -> Result<(), Vec<GenericError>> {
+    Err(vec![format!("{message}, night starts at {}", format_time(100.)).into()])
+}
+
+fn get_served_at_night_error_msg() -> Result<(), Vec<GenericError>> {
+    get_night_error_msg("activity 'job1' of tour 'my_vehicle_1' is served between shifts")
+}
+
+fn get_missing_overnight_error_msg() -> Result<(), Vec<GenericError>> {
+    get_night_error_msg("overnight stop is missing in tour 'my_vehicle_1'")
+}
+
+parameterized_test! {can_check_overnights, (job_time, has_violation, expected_result), {
+    can_check_overnights_impl(job_time, has_violation, expected_result);
+}}
+
+can_check_overnights! {
+    case01_before_night_with_violation: (50., true, Ok(())),
+    case02_after_night_with_violation: (250., true, Ok(())),
+    case03_at_night_with_violation: (150., true, get_served_at_night_error_msg()),
+    case04_at_night_without_violation: (150., false, get_served_at_night_error_msg()),
+    case05_before_night_without_violation: (50., false, get_missing_overnight_error_msg()),
+}
+
+fn can_check_overnights_impl(job_time: Float, has_violation: bool, expected_result: Result<(), Vec<GenericError>>) {
+    let create_shift = |start: Float, end: Float| VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_shift(0., 100.), create_shift(200., 400.)],
+                overnight: Some(VehicleOvernight { locations: None, cost: None }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let violations =
+        has_violation.then(|| vec![Violation::Overnight { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]);
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(job_time, job_time + 1.)
+                        .load(vec![0])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(300., 300.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .violations(violations)
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_breaks(&ctx);
+
+    assert_eq!(result, expected_result);
+}
@@ -45,6 +45,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Gen
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -234,3 +235,251 @@ fn can_check_resource_consumption() {
             .into())
     );
 }
+
+type StopCompartmentData = (&'static str, Option<&'static str>, i32);
+
+parameterized_test! {can_check_compartments, (first_stop, second_stop, expected_result), {
+    can_check_compartments_impl(first_stop, second_stop, expected_result);
+}}
+
+can_check_compartments! {
+    case01_valid: (
+        vec![("c1", Some("fuel"), 2), ("c2", Some("diesel"), 1)],
+        vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 1)],
+        Ok(())
+    ),
+    case02_unknown_compartment: (
+        vec![("c1", Some("fuel"), 2), ("c3", Some("diesel"), 1)],
+        vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 1)],
+        Err("unknown compartment 'c3' in tour 'my_vehicle_1'".into())
+    ),
+    case03_not_allowed_product: (
+        vec![("c1", Some("fuel"), 2), ("c2", Some("diesel"), 1)],
+        vec![("c1", Some("diesel"), 0), ("c2", Some("diesel"), 1)],
+        Err("product 'diesel' is not allowed in compartment 'c1' in tour 'my_vehicle_1'".into())
+    ),
+    case04_changed_product: (
+        vec![("c1", Some("fuel"), 2), ("c2", Some("diesel"), 1)],
+        vec![("c1", Some("fuel"), 0), ("c2", None, 0)],
+        Err("product of compartment 'c2' is changed within a trip in tour 'my_vehicle_1'".into())
+    ),
+    case05_exceeded_capacity: (
+        vec![("c1", Some("fuel"), 3), ("c2", Some("diesel"), 1)],
+        vec![("c1", Some("fuel"), 0), ("c2", Some("diesel"), 1)],
+        Err("load exceeds capacity of compartment 'c1' in tour 'my_vehicle_1'".into())
+    ),
+    case06_missing_product: (
+        vec![("c1", Some("fuel"), 2), ("c2", Some("diesel"), 1)],
+        vec![("c2", Some("diesel"), 1)],
+        Err("product 'fuel' has no compartment assigned in tour 'my_vehicle_1'".into())
+    ),
+}
+
+fn can_check_compartments_impl(
+    first_stop: Vec<StopCompartmentData>,
+    second_stop: Vec<StopCompartmentData>,
+    expected_result: GenericResult<()>,
+) {
+    let create_job = |id: &str, location: (f64, f64), product: &str| Job {
+        product: Some(product.to_string()),
+        ..create_delivery_job(id, location)
+    };
+    let create_compartment = |id: &str, capacity: i32, product: &str| VehicleCompartment {
+        id: id.to_string(),
+        capacity: vec![capacity],
+        products: Some(vec![product.to_string()]),
+    };
+    let add_compartments = |builder: StopBuilder, compartments: Vec<StopCompartmentData>| {
+        compartments
+            .into_iter()
+            .fold(builder, |builder, (id, product, load)| builder.compartment(id, product, vec![load]))
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job("job1", (1., 0.), "fuel"),
+                create_job("job2", (2., 0.), "fuel"),
+                create_job("job3", (3., 0.), "diesel"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity: vec![4],
+                compartments: Some(vec![create_compartment("c1", 2, "fuel"), create_compartment("c2", 2, "diesel")]),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    add_compartments(
+                        StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![3]),
+                        first_stop,
+                    )
+                    .build_departure(),
+                    add_compartments(
+                        StopBuilder::default().coordinate((1., 0.)).schedule_stamp(1., 2.).load(vec![1]).distance(1),
+                        second_stop,
+                    )
+                    .activity(ActivityBuilder::delivery().job_id("job1").build())
+                    .activity(ActivityBuilder::delivery().job_id("job2").build())
+                    .build(),
+                    StopBuilder::default()
+                        .coordinate((3., 0.))
+                        .schedule_stamp(4., 5.)
+                        .load(vec![0])
+                        .distance(3)
+                        .compartment("c1", Some("fuel"), vec![0])
+                        .compartment("c2", Some("diesel"), vec![0])
+                        .build_single("job3", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(8., 8.)
+                        .load(vec![0])
+                        .distance(6)
+                        .compartment("c1", Some("fuel"), vec![0])
+                        .compartment("c2", Some("diesel"), vec![0])
+                        .build_arrival(),
+                ])
+                .statistic(StatisticBuilder::default().driving(6).serving(3).build())
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_compartments(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_loading_policy, (policy, sequence, expected_result), {
+    can_check_loading_policy_impl(policy, sequence, expected_result);
+}}
+
+can_check_loading_policy! {
+    case01_lifo_nested: (
+        VehicleLoadingPolicy::Lifo,
+        vec![("job1", "pickup"), ("job2", "pickup"), ("job2", "delivery"), ("job1", "delivery")],
+        Ok(())
+    ),
+    case02_lifo_crossed: (
+        VehicleLoadingPolicy::Lifo,
+        vec![("job1", "pickup"), ("job2", "pickup"), ("job1", "delivery"), ("job2", "delivery")],
+        Err("loading policy is violated for job 'job1' in tour 'my_vehicle_1'".into())
+    ),
+    case03_fifo_crossed: (
+        VehicleLoadingPolicy::Fifo,
+        vec![("job1", "pickup"), ("job2", "pickup"), ("job1", "delivery"), ("job2", "delivery")],
+        Ok(())
+    ),
+    case04_fifo_nested: (
+        VehicleLoadingPolicy::Fifo,
+        vec![("job1", "pickup"), ("job2", "pickup"), ("job2", "delivery"), ("job1", "delivery")],
+        Err("loading policy is violated for job 'job2' in tour 'my_vehicle_1'".into())
+    ),
+}
+
+fn can_check_loading_policy_impl(
+    policy: VehicleLoadingPolicy,
+    sequence: Vec<(&str, &str)>,
+    expected_result: GenericResult<()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (3., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { loading_policy: Some(policy), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let departure = StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure();
+    let stops = sequence.into_iter().enumerate().map(|(idx, (job_id, activity_type))| {
+        let time = (idx + 1) as Float;
+        StopBuilder::default()
+            .coordinate((time, 0.))
+            .schedule_stamp(time, time)
+            .load(vec![0])
+            .distance(idx as i64 + 1)
+            .build_single_tag(job_id, activity_type, &activity_type[..1])
+    });
+    let solution = SolutionBuilder::default()
+        .tour(TourBuilder::default().stops(once(departure).chain(stops).collect()).build())
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_loading_policy(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_trailers, (sequence, expected_result), {
+    can_check_trailers_impl(sequence, expected_result);
+}}
+
+can_check_trailers! {
+    case01_forbidden_without_trailer: (vec!["job1", "decouple", "job2", "couple"], Ok(())),
+    case02_forbidden_with_trailer: (
+        vec!["job1", "job2"],
+        Err("job 'job2' is served with trailer in tour 'my_vehicle_1'".into())
+    ),
+    case03_not_coupled_back: (
+        vec!["decouple", "job2"],
+        Err("trailer is not coupled back in tour 'my_vehicle_1'".into())
+    ),
+    case04_couple_without_decouple: (
+        vec!["couple", "job1"],
+        Err("unexpected trailer operation 'couple' in tour 'my_vehicle_1'".into())
+    ),
+}
+
+fn can_check_trailers_impl(sequence: Vec<&str>, expected_result: GenericResult<()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job { trailer_allowed: Some(false), ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { trailer_id: Some("trailer".to_string()), ..create_default_vehicle_type() }],
+            trailers: Some(vec![Trailer {
+                id: "trailer".to_string(),
+                capacity: vec![5],
+                parkings: vec![TrailerParking { location: (3., 0.).to_loc(), duration: 0., times: None, tag: None }],
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let departure = StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure();
+    let stops = sequence.into_iter().enumerate().map(|(idx, job_id)| {
+        let time = (idx + 1) as Float;
+        let activity_type = if job_id.starts_with("job") { "delivery" } else { job_id };
+        StopBuilder::default()
+            .coordinate((time, 0.))
+            .schedule_stamp(time, time)
+            .load(vec![0])
+            .distance(idx as i64 + 1)
+            .build_single(job_id, activity_type)
+    });
+    let solution = SolutionBuilder::default()
+        .tour(TourBuilder::default().stops(once(departure).chain(stops).collect()).build())
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_trailers(&ctx);
+
+    assert_eq!(result, expected_result);
+}
@@ -0,0 +1,267 @@
+use super::*;
+use crate::helpers::*;
+use std::iter::once;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_test_problem(jobs: Vec<Job>, skills: Option<Vec<&str>>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                skills: skills.map(to_strings),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_tour(vehicle_id: &str, shift_index: usize, jobs: &[(&str, Timestamp)]) -> Tour {
+    let stop = |time: Timestamp| StopBuilder::default().coordinate((0., 0.)).schedule_stamp(time, time).load(vec![0]);
+
+    TourBuilder::default()
+        .vehicle_id(vehicle_id)
+        .type_id("my_vehicle")
+        .shift_index(shift_index)
+        .stops(
+            once(stop(0.).build_departure())
+                .chain(jobs.iter().map(|(job_id, time)| stop(*time).build_single(job_id, "delivery")))
+                .collect(),
+        )
+        .build()
+}
+
+fn create_test_context(problem: Problem, tours: Vec<Tour>) -> CheckerContext {
+    let solution = tours.into_iter().fold(SolutionBuilder::default(), |builder, tour| builder.tour(tour)).build();
+
+    CheckerContext::new(create_example_problem(), problem, None, solution).unwrap()
+}
+
+fn run_check(jobs: &[Job], tours: Vec<Tour>, check_fn: fn(&CheckerContext) -> GenericResult<()>) -> GenericResult<()> {
+    check_fn(&create_test_context(create_test_problem(jobs.to_vec(), None), tours))
+}
+
+fn create_job_with(id: &str, modify: impl FnOnce(&mut Job)) -> Job {
+    let mut job = create_delivery_job(id, (1., 0.));
+    modify(&mut job);
+
+    job
+}
+
+parameterized_test! {can_check_skills, (job_skills, vehicle_skills, is_ok), {
+    can_check_skills_impl(job_skills, vehicle_skills, is_ok);
+}}
+
+can_check_skills! {
+    case01_all_of_ok: ((Some(vec!["a"]), None, None), Some(vec!["a", "b"]), true),
+    case02_all_of_missing: ((Some(vec!["a", "c"]), None, None), Some(vec!["a", "b"]), false),
+    case03_all_of_no_vehicle_skills: ((Some(vec!["a"]), None, None), None, false),
+    case04_one_of_ok: ((None, Some(vec!["c", "b"]), None), Some(vec!["a", "b"]), true),
+    case05_one_of_missing: ((None, Some(vec!["c"]), None), Some(vec!["a", "b"]), false),
+    case06_none_of_ok: ((None, None, Some(vec!["c"])), Some(vec!["a", "b"]), true),
+    case07_none_of_present: ((None, None, Some(vec!["a"])), Some(vec!["a", "b"]), false),
+}
+
+type SkillsData = (Option<Vec<&'static str>>, Option<Vec<&'static str>>, Option<Vec<&'static str>>);
+
+fn can_check_skills_impl(job_skills: SkillsData, vehicle_skills: Option<Vec<&str>>, is_ok: bool) {
+    let (all_of, one_of, none_of) = job_skills;
+    let skills =
+        JobSkills { all_of: all_of.map(to_strings), one_of: one_of.map(to_strings), none_of: none_of.map(to_strings) };
+    let problem = create_test_problem(vec![create_delivery_job_with_skills("job1", (1., 0.), skills)], vehicle_skills);
+    let ctx = create_test_context(problem, vec![create_tour("v1", 0, &[("job1", 1.)])]);
+
+    let result = check_skills(&ctx);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+#[test]
+fn can_check_compatibility() {
+    let jobs = vec![
+        create_delivery_job_with_compatibility("job1", (1., 0.), "food"),
+        create_delivery_job_with_compatibility("job2", (1., 0.), "junk"),
+        create_delivery_job_with_compatibility("job3", (1., 0.), "food"),
+    ];
+    let check = |tours: Vec<Tour>| run_check(&jobs, tours, check_compatibility);
+
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.), ("job3", 2.)])]).is_ok());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)]), create_tour("v2", 0, &[("job2", 1.)])]).is_ok());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.), ("job2", 2.)])]).is_err());
+}
+
+#[test]
+fn can_check_same_assignee() {
+    let jobs = vec![
+        create_job_with("job1", |job| job.same_assignee_key = Some("alice".to_string())),
+        create_job_with("job2", |job| job.same_assignee_key = Some("alice".to_string())),
+    ];
+    let check = |tours: Vec<Tour>| run_check(&jobs, tours, check_same_assignee);
+
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)]), create_tour("v1", 1, &[("job2", 1.)])]).is_ok());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)]), create_tour("v2", 0, &[("job2", 1.)])]).is_err());
+}
+
+#[test]
+fn can_check_affinity() {
+    let day = 24. * 3600.;
+    let create_affinity_job = |id: &str, sequence: u32| {
+        create_job_with(id, |job| {
+            job.affinity = Some(AffinityInfo { key: "project".to_string(), sequence, duration_days: 2 })
+        })
+    };
+    let jobs = vec![create_affinity_job("job1", 0), create_affinity_job("job2", 1), create_affinity_job("job3", 1)];
+    let check = |tours: Vec<Tour>| run_check(&jobs, tours, check_affinity);
+
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)]), create_tour("v1", 1, &[("job2", day)])]).is_ok());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)]), create_tour("v2", 1, &[("job2", day)])]).is_err());
+    assert!(check(vec![create_tour("v1", 0, &[("job2", 1.)]), create_tour("v1", 1, &[("job3", day)])]).is_err());
+}
+
+#[test]
+fn can_check_affinity_consecutive_days() {
+    let day = 24. * 3600.;
+    let create_affinity_job = |id: &str, sequence: u32, start: Timestamp| {
+        let mut job = create_delivery_job_with_times(id, (1., 0.), vec![(start as i32, (start + 3600.) as i32)], 1.);
+        job.affinity = Some(AffinityInfo { key: "project".to_string(), sequence, duration_days: 2 });
+        job
+    };
+    let check = |second_start: Timestamp| {
+        let jobs = vec![create_affinity_job("job1", 0, 0.), create_affinity_job("job2", 1, second_start)];
+        let tours = vec![create_tour("v1", 0, &[("job1", 0.)]), create_tour("v1", 1, &[("job2", second_start)])];
+        check_affinity(&create_test_context(create_test_problem(jobs, None), tours))
+    };
+
+    assert!(check(3600.).is_ok());
+    assert!(check(day).is_err());
+}
+
+#[test]
+fn can_check_sync() {
+    let create_sync_job = |id: &str, index: u32| {
+        create_job_with(id, |job| {
+            job.sync = Some(JobSync { key: "team".to_string(), index, vehicles_required: 2, tolerance: None })
+        })
+    };
+    let jobs = vec![create_sync_job("job1", 0), create_sync_job("job2", 1)];
+    let check = |tours: Vec<Tour>| run_check(&jobs, tours, check_sync);
+
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 100.)]), create_tour("v2", 0, &[("job2", 900.)])]).is_ok());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 100.)]), create_tour("v2", 0, &[("job2", 1100.)])]).is_err());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 100.), ("job2", 100.)])]).is_err());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 100.)])]).is_err());
+    assert!(check(vec![]).is_ok());
+}
+
+parameterized_test! {can_check_sync_tolerance, (tolerances, start, is_ok), {
+    can_check_sync_tolerance_impl(tolerances, start, is_ok);
+}}
+
+can_check_sync_tolerance! {
+    case01_custom_above_default: ((Some(1800.), Some(1800.)), 1500., true),
+    case02_custom_above_default_exceeded: ((Some(1800.), Some(1800.)), 2000., false),
+    case03_custom_below_default: ((Some(300.), Some(300.)), 500., false),
+    case04_min_of_custom_and_default: ((Some(1800.), None), 1500., false),
+}
+
+fn can_check_sync_tolerance_impl(tolerances: (Option<Float>, Option<Float>), start: Timestamp, is_ok: bool) {
+    let create_sync_job = |id: &str, index: u32, tolerance: Option<Float>| {
+        create_job_with(id, |job| {
+            job.sync = Some(JobSync { key: "team".to_string(), index, vehicles_required: 2, tolerance })
+        })
+    };
+    let jobs = vec![create_sync_job("job1", 0, tolerances.0), create_sync_job("job2", 1, tolerances.1)];
+    let tours = vec![create_tour("v1", 0, &[("job1", 0.)]), create_tour("v2", 0, &[("job2", start)])];
+
+    let result = run_check(&jobs, tours, check_sync);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+#[test]
+fn can_check_sequence() {
+    let create_sequence_job = |id: &str, order: u32| {
+        create_job_with(id, |job| {
+            job.sequence =
+                Some(JobSequence { key: "phases".to_string(), order, days_between_min: None, days_between_max: None })
+        })
+    };
+    let jobs = vec![create_sequence_job("job1", 0), create_sequence_job("job2", 1)];
+    let check = |tours: Vec<Tour>| run_check(&jobs, tours, check_sequence);
+
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)]), create_tour("v1", 1, &[("job2", 1.)])]).is_ok());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)])]).is_ok());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.)]), create_tour("v1", 2, &[("job2", 1.)])]).is_err());
+    assert!(check(vec![create_tour("v1", 0, &[("job1", 1.), ("job2", 2.)])]).is_err());
+    assert!(check(vec![create_tour("v1", 1, &[("job2", 1.)])]).is_err());
+}
+
+parameterized_test! {can_check_max_ride_time, (pickup_time, delivery_time, is_ok), {
+    can_check_max_ride_time_impl(pickup_time, delivery_time, is_ok);
+}}
+
+can_check_max_ride_time! {
+    case01_within_limit: (10., 90., true),
+    case02_at_limit: (10., 110., true),
+    case03_rounding_tolerance: (10., 111., true),
+    case04_exceeds_limit: (10., 120., false),
+}
+
+fn can_check_max_ride_time_impl(pickup_time: Timestamp, delivery_time: Timestamp, is_ok: bool) {
+    let mut job = create_pickup_delivery_job("job1", (0., 0.), (0., 0.));
+    job.max_ride_time = Some(JobMaxRideTime::Duration(100.));
+    let stop = |time: Timestamp| StopBuilder::default().coordinate((0., 0.)).schedule_stamp(time, time).load(vec![0]);
+    let tour = TourBuilder::default()
+        .vehicle_id("v1")
+        .type_id("my_vehicle")
+        .stops(vec![
+            stop(0.).build_departure(),
+            stop(pickup_time).build_single("job1", "pickup"),
+            stop(delivery_time).build_single("job1", "delivery"),
+        ])
+        .build();
+
+    let result = run_check(&[job], vec![tour], check_max_ride_time);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+parameterized_test! {can_check_soft_time_windows, (time, earliness, lateness, is_ok), {
+    can_check_soft_time_windows_impl(time, earliness, lateness, is_ok);
+}}
+
+can_check_soft_time_windows! {
+    case01_within_time_window: (150., None, None, true),
+    case02_early_within_limit: (60., Some(40), None, true),
+    case03_late_within_limit: (220., None, Some(20), true),
+    case04_early_beyond_limit: (40., Some(60), None, false),
+    case05_late_beyond_limit: (240., None, Some(40), false),
+    case06_unreported_lateness: (220., None, None, false),
+}
+
+fn can_check_soft_time_windows_impl(time: Timestamp, earliness: Option<i64>, lateness: Option<i64>, is_ok: bool) {
+    let mut job = create_delivery_job_with_times("job1", (0., 0.), vec![(100, 200)], 0.);
+    job.deliveries.as_mut().unwrap()[0].places[0].soft_times = Some(JobPlaceSoftTimes {
+        max_earliness: Some(50.),
+        max_lateness: Some(30.),
+        earliness_cost: Some(1.),
+        lateness_cost: Some(1.),
+    });
+    let activity = Activity {
+        earliness,
+        lateness,
+        ..ActivityBuilder::default().activity_type("delivery").job_id("job1").build()
+    };
+    let stop = |time: Timestamp| StopBuilder::default().coordinate((0., 0.)).schedule_stamp(time, time).load(vec![0]);
+    let tour = TourBuilder::default()
+        .vehicle_id("v1")
+        .type_id("my_vehicle")
+        .stops(vec![stop(0.).build_departure(), stop(time).activity(activity).build()])
+        .build();
+
+    let result = run_check(&[job], vec![tour], check_soft_time_windows);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
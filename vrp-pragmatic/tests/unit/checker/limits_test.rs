@@ -165,8 +165,21 @@ fn can_check_shift_time() {
     assert_eq!(result, Err("tour time is outside shift time, vehicle id 'my_vehicle_1', shift index: 0".into()));
 }
 
-#[test]
-fn can_check_recharge_distance() {
+parameterized_test! {can_check_recharge_distance, (max_distance, initial_distance, expected), {
+    can_check_recharge_distance_impl(max_distance, initial_distance, expected);
+}}
+
+can_check_recharge_distance! {
+    case01_exceeded_limit: (8., None, Err(
+        "recharge distance violation: expected limit is 8, got 10, vehicle id 'my_vehicle_1', shift index: 0".into()
+    )),
+    case02_within_limit: (12., None, Ok(())),
+    case03_exceeded_limit_with_initial_distance: (12., Some(3.), Err(
+        "recharge distance violation: expected limit is 12, got 13, vehicle id 'my_vehicle_1', shift index: 0".into()
+    )),
+}
+
+fn can_check_recharge_distance_impl(max_distance: Float, initial_distance: Option<Float>, expected: GenericResult<()>) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (10., 0.))],
@@ -178,12 +191,15 @@ fn can_check_recharge_distance() {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
                     end: None,
                     recharges: Some(VehicleRecharges {
-                        max_distance: 8.,
+                        max_distance: Some(max_distance),
+                        initial_distance,
+                        battery: None,
                         stations: vec![VehicleRechargeStation {
                             location: (8., 0.).to_loc(),
                             duration: 0.,
                             times: None,
                             tag: None,
+                            resource_id: None,
                         }],
                     }),
                     ..create_default_vehicle_shift()
@@ -222,9 +238,228 @@ fn can_check_recharge_distance() {
 
     let result = check_recharge_limits(&ctx);
 
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_battery_charge, (reserve, charges, expected), {
+    can_check_battery_charge_impl(reserve, charges, expected);
+}}
+
+can_check_battery_charge! {
+    case01_valid: (5., vec![(20., 20.), (19., 19.), (10., 10.)], None),
+    case02_wrong_arrival: (5., vec![(20., 20.), (19., 19.), (12., 12.)],
+        Some("expected arrival level is 10, got 12 at stop 2")
+    ),
+    case03_below_reserve: (11., vec![(20., 20.), (19., 19.), (10., 10.)],
+        Some("level 10 is below reserve 11 at stop 2")
+    ),
+    case04_recharge_without_station: (5., vec![(20., 20.), (19., 20.), (11., 11.)],
+        Some("unexpected departure level 20, arrival level is 19 at stop 1")
+    ),
+    case05_no_charge: (5., vec![(20., 20.)], Some("no charge level at stop 1")),
+}
+
+fn can_check_battery_charge_impl(reserve: Float, charges: Vec<(Float, Float)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: None,
+                    recharges: Some(VehicleRecharges {
+                        max_distance: None,
+                        initial_distance: None,
+                        battery: Some(VehicleBattery {
+                            capacity: 20.,
+                            initial_charge: None,
+                            reserve: Some(reserve),
+                            consumption: 1000.,
+                            load_consumption: None,
+                            charging_curve: vec![ChargingCurvePoint { level: 20., power: 3600. }],
+                        }),
+                        stations: vec![],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let with_charge = |builder: StopBuilder, idx: usize| match charges.get(idx) {
+        Some(&(arrival, departure)) => builder.charge(arrival, departure),
+        None => builder,
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    with_charge(StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]), 0)
+                        .build_departure(),
+                    with_charge(
+                        StopBuilder::default().coordinate((1., 0.)).schedule_stamp(1., 2.).load(vec![1]).distance(1),
+                        1,
+                    )
+                    .build_single("job1", "delivery"),
+                    with_charge(
+                        StopBuilder::default().coordinate((10., 0.)).schedule_stamp(11., 12.).load(vec![0]),
+                        2,
+                    )
+                    .distance(10)
+                    .build_single("job2", "delivery"),
+                ])
+                .statistic(StatisticBuilder::default().driving(10).serving(2).waiting(0).build())
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_recharge_limits(&ctx);
+
     assert_eq!(
         result,
-        Err("recharge distance violation: expected limit is 8, got 10, vehicle id 'my_vehicle_1', shift index: 0"
-            .into())
+        expected.map_or(Ok(()), |message| Err(format!(
+            "battery charge violation: {message}, vehicle id 'my_vehicle_1', shift index: 0"
+        )
+        .into()))
     );
 }
+
+parameterized_test! {can_check_charger_resources, (capacity, second_start, expected), {
+    can_check_charger_resources_impl(capacity, second_start, expected);
+}}
+
+can_check_charger_resources! {
+    case01_sequential_sessions: (1, 15., Ok(())),
+    case02_overlapping_sessions: (1, 10., Err("charger resource 'c1' is used by 2 vehicles, capacity is 1".into())),
+    case03_overlapping_sessions_within_capacity: (2, 10., Ok(())),
+}
+
+fn can_check_charger_resources_impl(capacity: usize, second_start: Float, expected: GenericResult<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: None,
+                    recharges: Some(VehicleRecharges {
+                        max_distance: Some(100.),
+                        initial_distance: None,
+                        battery: None,
+                        stations: vec![VehicleRechargeStation {
+                            location: (5., 0.).to_loc(),
+                            duration: 10.,
+                            times: None,
+                            tag: None,
+                            resource_id: Some("c1".to_string()),
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Charger { id: "c1".to_string(), capacity }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle_id: &str, start: Float| {
+        TourBuilder::default()
+            .vehicle_id(vehicle_id)
+            .stops(vec![
+                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure(),
+                StopBuilder::default()
+                    .coordinate((5., 0.))
+                    .schedule_stamp(start, start + 10.)
+                    .load(vec![0])
+                    .distance(5)
+                    .build_single("recharge", "recharge"),
+            ])
+            .build()
+    };
+    let solution = SolutionBuilder::default().tour(create_tour("v1", 5.)).tour(create_tour("v2", second_start)).build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_charger_resources(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_dock_resources, (capacity, times, second_slot, expected), {
+    can_check_dock_resources_impl(capacity, times, second_slot, expected);
+}}
+
+can_check_dock_resources! {
+    case01_sequential_slots: (1, None, (10., 20.), Ok(())),
+    case02_overlapping_slots: (1, None, (5., 15.), Err(
+        "dock resource 'd1' is used by 2 vehicles, capacity is 1".into()
+    )),
+    case03_overlapping_slots_within_capacity: (2, None, (5., 15.), Ok(())),
+    case04_short_slot: (2, None, (5., 10.), Err(
+        "dock 'd1' slot is too short, expected: not less than 10, got: 5, vehicle id 'v2'".into()
+    )),
+    case05_outside_opening_hours: (2, Some((0., 12.)), (5., 15.), Err(
+        "dock 'd1' is used outside its opening hours, vehicle id 'v2'".into()
+    )),
+}
+
+fn can_check_dock_resources_impl(
+    capacity: usize,
+    times: Option<(Float, Float)>,
+    second_slot: (Float, Float),
+    expected: GenericResult<()>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Dock {
+                id: "d1".to_string(),
+                location: (0., 0.).to_loc(),
+                capacity,
+                loading_time: 10.,
+                unit_loading_time: None,
+                times: times.map(|(start, end)| vec![vec![format_time(start), format_time(end)]]),
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let create_tour = |vehicle_id: &str, slot: (Float, Float)| {
+        TourBuilder::default()
+            .vehicle_id(vehicle_id)
+            .stops(vec![
+                StopBuilder::default()
+                    .coordinate((0., 0.))
+                    .schedule_stamp(slot.1, slot.1)
+                    .load(vec![1])
+                    .dock("d1", slot.0, slot.1)
+                    .build_departure(),
+                StopBuilder::default()
+                    .coordinate((5., 0.))
+                    .schedule_stamp(slot.1 + 5., slot.1 + 6.)
+                    .load(vec![0])
+                    .distance(5)
+                    .build_single("job1", "delivery"),
+            ])
+            .build()
+    };
+    let solution =
+        SolutionBuilder::default().tour(create_tour("v1", (0., 10.))).tour(create_tour("v2", second_slot)).build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_dock_resources(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -99,10 +99,17 @@ mod single {
                             ..create_default_reload()
                         }]),
                         recharges: None,
+                        overtime: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    hours_of_service: None,
+                    trailer_id: None,
+                    overnight: None,
+                    emissions: None,
+                    compartments: None,
+                    loading_policy: None,
                 }],
                 ..create_default_fleet()
             },
@@ -153,6 +153,8 @@ mod tiered_costs {
             ]),
             time: TieredCost::Fixed(1.5),
             calculation_mode: None,
+            time_rates: None,
+            price_list: None,
         };
 
         let json_value = serde_json::to_value(&original_costs).expect("Should serialize");
@@ -77,6 +77,7 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            soft_times: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
@@ -94,6 +95,7 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -107,6 +109,7 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -123,6 +126,7 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            soft_times: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
@@ -138,7 +142,7 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: TieredCost::Fixed(1.), time: TieredCost::Fixed(2.), calculation_mode: None },
+                costs: VehicleCosts { fixed: Some(100.), distance: TieredCost::Fixed(1.), time: TieredCost::Fixed(2.), calculation_mode: None, time_rates: None, price_list: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -164,10 +168,17 @@ fn can_read_complex_problem() {
                     }]),
                     reloads: None,
                     recharges: None,
+                    overtime: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
                 limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), max_activity_duration: None, tour_size: Some(3) }),
+                hours_of_service: None,
+                trailer_id: None,
+                overnight: None,
+                emissions: None,
+                compartments: None,
+                loading_policy: None,
             }],
             ..create_default_fleet()
         },
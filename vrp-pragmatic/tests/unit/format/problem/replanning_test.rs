@@ -0,0 +1,351 @@
+use super::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_pickup_job_with_demand("job3", (30., 0.), vec![2]),
+                create_pickup_delivery_job("job4", (40., 0.), (50., 0.)),
+                create_delivery_job("job5", (60., 0.)),
+                create_service_job("job6", (70., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                limits: Some(VehicleLimits {
+                    max_distance: Some(200.),
+                    max_duration: None,
+                    max_activity_duration: None,
+                    tour_size: Some(10),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> Solution {
+    let stop = |location: (f64, f64), time: (Float, Float), distance: i64| {
+        StopBuilder::default().coordinate(location).schedule_stamp(time.0, time.1).load(vec![0]).distance(distance)
+    };
+
+    SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    stop((0., 0.), (0., 0.), 0).build_departure(),
+                    stop((10., 0.), (10., 11.), 10).build_single("job1", "delivery"),
+                    stop((30., 0.), (31., 32.), 30).build_single("job3", "pickup"),
+                    stop((40., 0.), (42., 43.), 40).build_single("job4", "pickup"),
+                    stop((20., 0.), (63., 64.), 60).build_single("job2", "delivery"),
+                    stop((50., 0.), (94., 95.), 90).build_single("job4", "delivery"),
+                    stop((60., 0.), (105., 106.), 100).build_single("job5", "delivery"),
+                    stop((70., 0.), (116., 117.), 110).build_single("job6", "service"),
+                    stop((0., 0.), (187., 187.), 180).build_arrival(),
+                ])
+                .build(),
+        )
+        .build()
+}
+
+fn create_context(jobs: Vec<(&str, JobExecutionStatus)>, vehicles: Vec<(&str, (f64, f64))>) -> ReplanningContext {
+    ReplanningContext {
+        time: format_time(45.),
+        vehicles: Some(
+            vehicles
+                .into_iter()
+                .map(|(vehicle_id, location)| VehicleExecutionState {
+                    vehicle_id: vehicle_id.to_string(),
+                    location: location.to_loc(),
+                })
+                .collect(),
+        ),
+        jobs: Some(
+            jobs.into_iter()
+                .map(|(job_id, status)| JobExecutionState { job_id: job_id.to_string(), status })
+                .collect(),
+        ),
+        new_jobs: None,
+    }
+}
+
+fn get_vehicle<'a>(problem: &'a Problem, type_id: &str) -> &'a VehicleType {
+    problem.fleet.vehicles.iter().find(|vehicle| vehicle.type_id == type_id).expect("cannot find vehicle type")
+}
+
+fn get_job_ids(problem: &Problem) -> Vec<String> {
+    problem.plan.jobs.iter().map(|job| job.id.clone()).collect()
+}
+
+#[test]
+fn can_create_replanning_problem_for_started_vehicle() {
+    let context = create_context(
+        vec![
+            ("job1", JobExecutionStatus::Done),
+            ("job3", JobExecutionStatus::Done),
+            ("job4", JobExecutionStatus::InProgress),
+            ("job6", JobExecutionStatus::Cancelled),
+        ],
+        vec![("my_vehicle_1", (45., 0.))],
+    );
+
+    let problem = create_replanning_problem(&create_test_problem(), &create_test_solution(), &context).unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["job2", "job4", "job5"]);
+    let job4 = problem.plan.jobs.iter().find(|job| job.id == "job4").unwrap();
+    assert!(job4.pickups.is_none());
+    assert!(job4.deliveries.is_some());
+
+    assert_eq!(problem.fleet.vehicles.len(), 2);
+    let idle = get_vehicle(&problem, "my_vehicle");
+    assert_eq!(idle.vehicle_ids, vec!["my_vehicle_2".to_string()]);
+    assert_eq!(idle.shifts[0].start.earliest, format_time(45.));
+    assert_eq!(idle.capacity, vec![10]);
+
+    let started = get_vehicle(&problem, "my_vehicle_my_vehicle_1");
+    assert_eq!(started.vehicle_ids, vec!["my_vehicle_1".to_string()]);
+    assert_eq!(started.shifts.len(), 1);
+    assert_eq!(started.shifts[0].start.earliest, format_time(45.));
+    assert_eq!(started.shifts[0].start.location, (45., 0.).to_loc());
+    assert_eq!(started.capacity, vec![8]);
+    let limits = started.limits.as_ref().unwrap();
+    assert_eq!(limits.max_distance, Some(160.));
+    assert_eq!(limits.tour_size, Some(7));
+
+    let relations = problem.plan.relations.as_ref().unwrap();
+    assert_eq!(relations.len(), 1);
+    assert!(matches!(relations[0].type_field, RelationType::Any));
+    assert_eq!(relations[0].jobs, vec!["job2", "job4", "job5"]);
+    assert_eq!(relations[0].vehicle_id, "my_vehicle_1");
+    assert_eq!(relations[0].shift_index, Some(0));
+}
+
+#[test]
+fn can_use_last_executed_stop_when_vehicle_location_is_unknown() {
+    let context = create_context(vec![("job1", JobExecutionStatus::Done)], vec![]);
+
+    let problem = create_replanning_problem(&create_test_problem(), &create_test_solution(), &context).unwrap();
+
+    let started = get_vehicle(&problem, "my_vehicle_my_vehicle_1");
+    assert_eq!(started.shifts[0].start.location, (10., 0.).to_loc());
+    assert_eq!(started.capacity, vec![10]);
+}
+
+#[test]
+fn can_start_vehicle_after_in_progress_job() {
+    let mut solution = create_test_solution();
+    solution.tours[0].stops[2] = StopBuilder::default()
+        .coordinate((30., 0.))
+        .schedule_stamp(31., 50.)
+        .load(vec![0])
+        .distance(30)
+        .build_single("job3", "pickup");
+    let context = create_context(
+        vec![("job1", JobExecutionStatus::Done), ("job3", JobExecutionStatus::InProgress)],
+        vec![("my_vehicle_1", (30., 0.))],
+    );
+
+    let problem = create_replanning_problem(&create_test_problem(), &solution, &context).unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["job2", "job4", "job5", "job6"]);
+    let started = get_vehicle(&problem, "my_vehicle_my_vehicle_1");
+    assert_eq!(started.shifts[0].start.earliest, format_time(50.));
+    assert_eq!(started.capacity, vec![8]);
+}
+
+#[test]
+fn can_keep_not_started_vehicles_and_remove_finished_shifts() {
+    let create_shift = |start: Float, end: Float| VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    };
+    let mut problem = create_test_problem();
+    problem.fleet.vehicles[0].shifts = vec![create_shift(0., 40.), create_shift(30., 100.), create_shift(200., 300.)];
+    problem.plan.relations = Some(vec![
+        Relation {
+            type_field: RelationType::Sequence,
+            jobs: to_strings(vec!["departure", "job1", "job2"]),
+            vehicle_id: "my_vehicle_2".to_string(),
+            shift_index: Some(2),
+        },
+        Relation {
+            type_field: RelationType::Any,
+            jobs: to_strings(vec!["job5"]),
+            vehicle_id: "my_vehicle_2".to_string(),
+            shift_index: Some(0),
+        },
+    ]);
+    let context = create_context(vec![("job1", JobExecutionStatus::Done)], vec![]);
+
+    let problem = create_replanning_problem(&problem, &SolutionBuilder::default().build(), &context).unwrap();
+
+    assert_eq!(problem.fleet.vehicles.len(), 1);
+    let shifts = &problem.fleet.vehicles[0].shifts;
+    assert_eq!(shifts.len(), 2);
+    assert_eq!(shifts[0].start.earliest, format_time(45.));
+    assert_eq!(shifts[1].start.earliest, format_time(200.));
+    let relations = problem.plan.relations.as_ref().unwrap();
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].jobs, vec!["departure", "job2"]);
+    assert_eq!(relations[0].shift_index, Some(1));
+}
+
+#[test]
+fn can_add_new_jobs() {
+    let mut context = create_context(vec![("job6", JobExecutionStatus::Cancelled)], vec![]);
+    context.new_jobs = Some(vec![create_delivery_job("job7", (80., 0.))]);
+
+    let problem = create_replanning_problem(&create_test_problem(), &create_test_solution(), &context).unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["job1", "job2", "job3", "job4", "job5", "job7"]);
+    assert_eq!(problem.fleet.vehicles.len(), 1);
+}
+
+parameterized_test! {can_detect_invalid_context, (context, expected), {
+    can_detect_invalid_context_impl(context, expected);
+}}
+
+can_detect_invalid_context! {
+    case01_unknown_job: (create_context(vec![("job8", JobExecutionStatus::Done)], vec![]), "unknown job id"),
+    case02_unknown_vehicle: (create_context(vec![], vec![("vehicle", (0., 0.))]), "unknown vehicle id"),
+    case03_duplicated_job: (
+        create_context(vec![("job1", JobExecutionStatus::Done), ("job1", JobExecutionStatus::Done)], vec![]),
+        "duplicated job id",
+    ),
+    case04_invalid_time: (
+        ReplanningContext { time: "now".to_string(), ..create_context(vec![], vec![]) },
+        "cannot parse date",
+    ),
+}
+
+fn can_detect_invalid_context_impl(context: ReplanningContext, expected: &str) {
+    let result = create_replanning_problem(&create_test_problem(), &create_test_solution(), &context);
+
+    assert!(result.unwrap_err().to_string().contains(expected));
+}
+
+#[test]
+fn can_detect_in_progress_job_which_is_not_on_board() {
+    let context = create_context(vec![("job4", JobExecutionStatus::InProgress)], vec![]);
+
+    let result = create_replanning_problem(&create_test_problem(), &SolutionBuilder::default().build(), &context);
+
+    assert!(result.unwrap_err().to_string().contains("is in progress, but not assigned"));
+}
+
+#[test]
+fn can_deserialize_replanning_context() {
+    let json = r#"{
+        "time": "1970-01-01T00:00:45Z",
+        "vehicles": [{ "vehicleId": "my_vehicle_1", "location": { "lat": 1.0, "lng": 2.0 } }],
+        "jobs": [{ "jobId": "job1", "status": "done" }, { "jobId": "job4", "status": "inProgress" }]
+    }"#;
+
+    let context = deserialize_replanning_context(std::io::BufReader::new(json.as_bytes())).unwrap();
+
+    assert_eq!(context.time, format_time(45.));
+    assert_eq!(context.vehicles.as_ref().unwrap()[0].vehicle_id, "my_vehicle_1");
+    let jobs = context.jobs.as_ref().unwrap();
+    assert_eq!(jobs[1].status, JobExecutionStatus::InProgress);
+    assert!(context.new_jobs.is_none());
+}
+
+#[test]
+fn can_adjust_breaks_overtime_and_recharges_of_started_vehicle() {
+    let mut problem = create_test_problem();
+    problem.fleet.vehicles[0].shifts[0] = VehicleShift {
+        breaks: Some(vec![VehicleBreak::Required {
+            time: VehicleRequiredBreakTime::OffsetTime { earliest: 100., latest: 120. },
+            duration: 10.,
+        }]),
+        overtime: Some(VehicleOvertime { regular_duration: 100., cost: TieredCost::Fixed(1.) }),
+        recharges: Some(VehicleRecharges {
+            max_distance: Some(100.),
+            initial_distance: Some(5.),
+            battery: None,
+            stations: vec![],
+        }),
+        ..create_default_vehicle_shift()
+    };
+    let context = create_context(
+        vec![("job1", JobExecutionStatus::Done), ("job3", JobExecutionStatus::Done)],
+        vec![("my_vehicle_1", (35., 0.))],
+    );
+
+    let problem = create_replanning_problem(&problem, &create_test_solution(), &context).unwrap();
+
+    let shift = &get_vehicle(&problem, "my_vehicle_my_vehicle_1").shifts[0];
+    assert!(matches!(
+        shift.breaks.as_ref().unwrap()[0],
+        VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime { earliest: 55., latest: 75. }, .. }
+    ));
+    assert_eq!(shift.overtime.as_ref().unwrap().regular_duration, 55.);
+    assert_eq!(shift.recharges.as_ref().unwrap().initial_distance, Some(45.));
+}
+
+#[test]
+fn can_merge_replanning_solution() {
+    let problem = create_test_problem();
+    let solution = create_test_solution();
+    let context = create_context(
+        vec![
+            ("job1", JobExecutionStatus::Done),
+            ("job3", JobExecutionStatus::Done),
+            ("job4", JobExecutionStatus::InProgress),
+            ("job6", JobExecutionStatus::Cancelled),
+        ],
+        vec![("my_vehicle_1", (45., 0.))],
+    );
+    let stop = |location: (f64, f64), time: (Float, Float), load: i32, distance: i64| {
+        StopBuilder::default().coordinate(location).schedule_stamp(time.0, time.1).load(vec![load]).distance(distance)
+    };
+    let new_solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .type_id("my_vehicle_my_vehicle_1")
+                .vehicle_id("my_vehicle_1")
+                .stops(vec![
+                    stop((45., 0.), (45., 45.), 1, 0).build_departure(),
+                    stop((50., 0.), (50., 51.), 1, 5).build_single("job4", "delivery"),
+                    stop((0., 0.), (101., 101.), 0, 55).build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+
+    let merged = merge_replanning_solution(&problem, &solution, &context, &new_solution).unwrap();
+
+    assert_eq!(merged.tours.len(), 1);
+    let tour = &merged.tours[0];
+    assert_eq!(tour.type_id, "my_vehicle");
+    assert_eq!(tour.shift_index, 0);
+    let stops = tour
+        .stops
+        .iter()
+        .map(|stop| {
+            let activity = stop.activities().first().unwrap();
+            (activity.job_id.as_str(), stop.load()[0], stop.as_point().unwrap().distance)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        stops,
+        vec![
+            ("departure", 0, 0),
+            ("job1", 0, 10),
+            ("job3", 0, 30),
+            ("job4", 0, 40),
+            ("job4", 3, 45),
+            ("arrival", 2, 95),
+        ]
+    );
+}
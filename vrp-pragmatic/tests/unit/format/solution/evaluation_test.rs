@@ -0,0 +1,141 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem(capacity: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (5., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_vehicle_with_capacity("vehicle", vec![capacity])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> ApiSolution {
+    // NOTE schedules are not valid intentionally: they have to be recomputed
+    let stop = |location: (f64, f64)| StopBuilder::default().coordinate(location).schedule_stamp(0., 0.).load(vec![0]);
+
+    SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("vehicle_1")
+                .type_id("vehicle")
+                .stops(vec![
+                    stop((0., 0.)).build_departure(),
+                    stop((5., 0.)).build_single("job2", "delivery"),
+                    stop((1., 0.)).build_single("job1", "delivery"),
+                ])
+                .build(),
+        )
+        .build()
+}
+
+fn evaluate(capacity: i32) -> SolutionEvaluation {
+    let problem = create_test_problem(capacity);
+    let matrix = create_matrix_from_problem(&problem);
+
+    evaluate_solution(problem, Some(vec![matrix]), create_test_solution()).unwrap()
+}
+
+#[test]
+fn can_recompute_schedules_and_costs() {
+    let evaluation = evaluate(2);
+
+    assert!(evaluation.violations.is_empty());
+    let tour = &evaluation.solution.tours[0];
+    assert_eq!(tour.stops.len(), 3);
+    assert_eq!(tour.stops[1].schedule().arrival, format_time(5.));
+    assert_eq!(tour.stops[2].schedule().departure, format_time(11.));
+    assert_eq!(tour.stops[0].load(), &vec![2]);
+    assert_eq!(tour.statistic.distance, 9);
+    assert_eq!(tour.statistic.cost, evaluation.solution.statistic.cost);
+    assert_eq!(evaluation.solution.statistic.cost, 10. + 9. + 11.);
+}
+
+#[test]
+fn can_report_objective_values() {
+    let evaluation = evaluate(2);
+
+    let get_value = |name: &str| {
+        evaluation.objectives.iter().find(|objective| objective.name == name).map(|objective| objective.value)
+    };
+    assert_eq!(get_value("min_unassigned"), Some(0.));
+    assert_eq!(get_value("min_tours"), Some(1.));
+    assert_eq!(get_value("min_cost"), Some(evaluation.solution.statistic.cost));
+}
+
+#[test]
+fn can_report_hard_constraint_violations() {
+    let evaluation = evaluate(1);
+
+    assert_eq!(
+        evaluation.violations,
+        vec![StopViolation {
+            code: "CAPACITY_CONSTRAINT".to_string(),
+            description: "does not fit into any vehicle due to capacity".to_string(),
+            job_id: "job1".to_string(),
+            vehicle_id: "vehicle_1".to_string(),
+            shift_index: 0,
+            stop_index: Some(2),
+        }]
+    );
+    assert_eq!(evaluation.solution.tours[0].stops.len(), 3);
+    assert!(evaluation.solution.unassigned.is_none());
+}
+
+#[test]
+fn can_report_overbooked_dock_without_removing_jobs() {
+    let vehicle = |vehicle_id: &str| VehicleType {
+        type_id: vehicle_id.to_string(),
+        vehicle_ids: vec![vehicle_id.to_string()],
+        shifts: vec![create_default_open_vehicle_shift()],
+        ..create_vehicle_with_capacity(vehicle_id, vec![2])
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (5., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![vehicle("v1"), vehicle("v2")],
+            resources: Some(vec![VehicleResource::Dock {
+                id: "dock".to_string(),
+                location: (0., 0.).to_loc(),
+                capacity: 1,
+                loading_time: 20.,
+                unit_loading_time: None,
+                times: None,
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let stop = |location: (f64, f64)| StopBuilder::default().coordinate(location).schedule_stamp(0., 0.).load(vec![0]);
+    let tour = |vehicle_id: &str, job_id: &str, location: (f64, f64)| {
+        TourBuilder::default()
+            .vehicle_id(vehicle_id)
+            .type_id(vehicle_id)
+            .stops(vec![stop((0., 0.)).build_departure(), stop(location).build_single(job_id, "delivery")])
+            .build()
+    };
+    let solution =
+        SolutionBuilder::default().tour(tour("v1", "job1", (1., 0.))).tour(tour("v2", "job2", (5., 0.))).build();
+
+    let evaluation = evaluate_solution(problem, Some(vec![matrix]), solution).unwrap();
+
+    assert_eq!(evaluation.solution.tours.len(), 2);
+    assert!(evaluation.solution.unassigned.is_none());
+    assert_eq!(evaluation.violations.len(), 1);
+    assert_eq!(evaluation.violations[0].code, "NO_REASON_FOUND");
+    assert_eq!(evaluation.violations[0].stop_index, Some(1));
+}
@@ -140,6 +140,35 @@ fn can_read_basic_init_solution() {
     assert_eq!(result_solution, solution);
 }
 
+#[test]
+fn can_read_init_solution_with_open_tour() {
+    let mut problem = create_basic_problem(None);
+    problem.fleet.vehicles[0].shifts = vec![create_default_open_vehicle_shift()];
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![0])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                ])
+                .build(),
+        )
+        .build();
+
+    let result_solution =
+        get_init_solution(problem, &solution).unwrap_or_else(|err| panic!("cannot get solution: {err}"));
+
+    let stops = &result_solution.tours[0].stops;
+    assert_eq!(stops.len(), 2);
+    assert_eq!(stops[0].schedule().departure, format_time(0.));
+    assert_eq!(stops[1].schedule().arrival, format_time(1.));
+}
+
 #[test]
 fn can_handle_empty_tour_error_in_init_solution() {
     let problem = create_basic_problem(create_default_breaks());
@@ -189,3 +218,58 @@ fn can_handle_commute_error_in_init_solution() {
 
     assert_eq!(result_solution, Err("commute property in initial solution is not supported".into()));
 }
+
+parameterized_test! {can_read_solution_with_split_job, (capacities, expected_tours), {
+    can_read_solution_with_split_job_impl(capacities, expected_tours);
+}}
+
+can_read_solution_with_split_job! {
+    case01_served_by_two_vehicles: (vec![2, 2], 2),
+    case02_served_in_single_visit: (vec![3], 1),
+}
+
+fn can_read_solution_with_split_job_impl(capacities: Vec<i32>, expected_tours: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                split: Some(JobSplit { min_size: None, max_splits: None }),
+                ..create_delivery_job_with_demand("job1", (1., 0.), vec![3])
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: capacities
+                .iter()
+                .enumerate()
+                .map(|(idx, capacity)| VehicleType {
+                    type_id: format!("type{idx}"),
+                    vehicle_ids: vec![format!("v{idx}")],
+                    capacity: vec![*capacity],
+                    ..create_default_vehicle_type()
+                })
+                .collect(),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert!(solution.unassigned.is_none());
+
+    let result_solution =
+        get_init_solution(problem, &solution).unwrap_or_else(|err| panic!("cannot get solution: {err}"));
+
+    assert!(result_solution.unassigned.is_none());
+    let get_demands = |solution: &Solution| {
+        let mut demands = solution
+            .tours
+            .iter()
+            .flat_map(|tour| tour.stops.iter().flat_map(|stop| stop.activities().iter()))
+            .filter_map(|activity| activity.demand.clone())
+            .collect::<Vec<_>>();
+        demands.sort();
+        demands
+    };
+    assert_eq!(get_demands(&result_solution), get_demands(&solution));
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (5., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType { shifts: vec![create_default_open_vehicle_shift()], ..create_default_vehicle("vehicle") },
+                VehicleType {
+                    shifts: vec![create_default_open_vehicle_shift()],
+                    ..create_vehicle_with_capacity("small", vec![0])
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> ApiSolution {
+    let stop = |location: (f64, f64), time: (Float, Float), distance: i64| {
+        StopBuilder::default().coordinate(location).schedule_stamp(time.0, time.1).load(vec![0]).distance(distance)
+    };
+
+    SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("vehicle_1")
+                .type_id("vehicle")
+                .stops(vec![
+                    stop((0., 0.), (0., 0.), 0).load(vec![2]).build_departure(),
+                    stop((1., 0.), (1., 2.), 1).load(vec![1]).build_single("job1", "delivery"),
+                    stop((5., 0.), (6., 7.), 5).build_single("job2", "delivery"),
+                ])
+                .build(),
+        )
+        .build()
+}
+
+fn get_suggestions(jobs: Vec<Job>, limit: usize) -> Result<Vec<JobInsertionSuggestions>, GenericError> {
+    let mut problem = create_test_problem();
+    problem.plan.jobs.extend(jobs.iter().cloned());
+    let matrix = create_matrix_from_problem(&problem);
+
+    get_insertion_suggestions(create_test_problem(), Some(vec![matrix]), create_test_solution(), jobs, limit)
+}
+
+#[test]
+fn can_suggest_cheapest_insertions() {
+    let suggestions = get_suggestions(vec![create_delivery_job("job3", (4., 0.))], 2).unwrap();
+
+    assert_eq!(suggestions.len(), 1);
+    let suggestions = &suggestions[0];
+    assert_eq!(suggestions.job_id, "job3");
+    assert_eq!(suggestions.insertions.len(), 2);
+
+    let best = &suggestions.insertions[0];
+    assert_eq!(best.vehicle_id, "vehicle_1");
+    assert_eq!(best.type_id, "vehicle");
+    assert_eq!(best.shift_index, 0);
+    assert_eq!(
+        best.activities,
+        vec![SuggestedActivity {
+            activity_type: "delivery".to_string(),
+            job_tag: None,
+            stop_index: 2,
+            arrival: format_time(5.),
+            departure: format_time(6.),
+        }]
+    );
+    let next = &suggestions.insertions[1];
+    assert_eq!(next.activities[0].stop_index, 3);
+    assert!(best.cost_delta < next.cost_delta);
+
+    assert_eq!(suggestions.reasons.len(), 1);
+    assert_eq!(suggestions.reasons[0].code, "CAPACITY_CONSTRAINT");
+    assert_eq!(
+        suggestions.reasons[0].details,
+        Some(vec![UnassignedJobDetail { vehicle_id: "small_1".to_string(), shift_index: 0 }])
+    );
+}
+
+#[test]
+fn can_suggest_insertions_for_multiple_jobs() {
+    let jobs = vec![create_delivery_job("job3", (4., 0.)), create_delivery_job("job4", (2., 0.))];
+
+    let suggestions = get_suggestions(jobs, 1).unwrap();
+
+    assert_eq!(suggestions.len(), 2);
+    assert_eq!(suggestions[0].job_id, "job3");
+    assert_eq!(suggestions[0].insertions.len(), 1);
+    assert_eq!(suggestions[1].job_id, "job4");
+    assert_eq!(suggestions[1].insertions.len(), 1);
+    assert_eq!(suggestions[1].insertions[0].activities[0].stop_index, 2);
+}
+
+#[test]
+fn can_detect_job_with_duplicated_id() {
+    let result = get_suggestions(vec![create_delivery_job("job1", (4., 0.))], 1);
+
+    assert!(result.is_err());
+}
@@ -74,6 +74,189 @@ fn can_detect_negative_demand() {
     assert_result("E1107", "job1", result);
 }
 
+#[test]
+fn can_detect_invalid_soft_time_windows() {
+    let mut job = create_delivery_job_with_times("job1", (1., 0.), vec![(0, 10)], 0.);
+    job.deliveries.as_mut().unwrap()[0].places[0].soft_times = Some(JobPlaceSoftTimes {
+        max_earliness: None,
+        max_lateness: Some(-600.),
+        earliness_cost: None,
+        lateness_cost: Some(1.),
+    });
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1108_soft_time_windows_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    assert_result("E1108", "job1", result);
+}
+
+parameterized_test! {can_detect_invalid_max_ride_time, (job, max_ride_time, expected), {
+    can_detect_invalid_max_ride_time_impl(job, max_ride_time, expected);
+}}
+
+can_detect_invalid_max_ride_time! {
+    case01_duration: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), JobMaxRideTime::Duration(600.), None),
+    case02_factor: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), JobMaxRideTime::Factor(1.5), None),
+    case03_zero_duration: (
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), JobMaxRideTime::Duration(0.), Some("job1")
+    ),
+    case04_negative_factor: (
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), JobMaxRideTime::Factor(-1.), Some("job1")
+    ),
+    case05_delivery_job: (create_delivery_job("job1", (1., 0.)), JobMaxRideTime::Duration(600.), Some("job1")),
+}
+
+fn can_detect_invalid_max_ride_time_impl(job: Job, max_ride_time: JobMaxRideTime, expected: Option<&str>) {
+    let job = Job { max_ride_time: Some(max_ride_time), ..job };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1112_max_ride_time_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(action) = expected {
+        assert_result("E1112", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_split, (job, min_size, max_splits, expected), {
+    can_detect_invalid_split_impl(job, min_size, max_splits, expected);
+}}
+
+can_detect_invalid_split! {
+    case01_delivery: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), Some(vec![2]), Some(3), None),
+    case02_pickup_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), None, None, Some("job1")),
+    case03_no_demand: (create_service_job("job1", (1., 0.)), None, None, Some("job1")),
+    case04_zero_splits: (create_delivery_job_with_demand("job1", (1., 0.), vec![10]), None, Some(0), Some("job1")),
+    case05_negative_size: (
+        create_delivery_job_with_demand("job1", (1., 0.), vec![10]), Some(vec![-1]), None, Some("job1")
+    ),
+}
+
+fn can_detect_invalid_split_impl(
+    job: Job,
+    min_size: Option<Vec<i32>>,
+    max_splits: Option<usize>,
+    expected: Option<&str>,
+) {
+    let job = Job { split: Some(JobSplit { min_size, max_splits }), ..job };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1113_split_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1113", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_transfer, (job, handling_time, expected), {
+    can_detect_invalid_transfer_impl(job, handling_time, expected);
+}}
+
+can_detect_invalid_transfer! {
+    case01_pickup_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), 5., None),
+    case02_delivery: (create_delivery_job("job1", (1., 0.)), 5., Some("job1")),
+    case03_negative_handling: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), -1., Some("job1")),
+}
+
+fn can_detect_invalid_transfer_impl(job: Job, handling_time: f64, expected: Option<&str>) {
+    let transfer = JobTransfer { location: (5., 0.).to_loc(), duration: 1., handling_time, tag: None };
+    let job = Job { transfer: Some(transfer), ..job };
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1114_transfer_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1114", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_assignment_costs, (type_id, vehicle_id, cost, expected), {
+    can_detect_invalid_assignment_costs_impl(type_id, vehicle_id, cost, expected);
+}}
+
+can_detect_invalid_assignment_costs! {
+    case01_type_id: (Some("my_vehicle"), None, 10., None),
+    case02_vehicle_id: (None, Some("my_vehicle_1"), 10., None),
+    case03_both_ids: (Some("my_vehicle"), Some("my_vehicle_1"), 10., Some("job1")),
+    case04_no_ids: (None, None, 10., Some("job1")),
+    case05_unknown_id: (None, Some("unknown"), 10., Some("job1")),
+    case06_negative_cost: (Some("my_vehicle"), None, -1., Some("job1")),
+}
+
+fn can_detect_invalid_assignment_costs_impl(
+    type_id: Option<&str>,
+    vehicle_id: Option<&str>,
+    cost: f64,
+    expected: Option<&str>,
+) {
+    let assignment_cost =
+        JobAssignmentCost { type_id: type_id.map(str::to_string), vehicle_id: vehicle_id.map(str::to_string), cost };
+    let job = Job { assignment_costs: Some(vec![assignment_cost]), ..create_delivery_job("job1", (1., 0.)) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1115_assignment_costs_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(action) = expected {
+        assert_result("E1115", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_outsourcing_cost, (job_cost, plan_cost, has_split, expected), {
+    can_detect_invalid_outsourcing_cost_impl(job_cost, plan_cost, has_split, expected);
+}}
+
+can_detect_invalid_outsourcing_cost! {
+    case01_job_cost: (Some(10.), None, false, None),
+    case02_plan_cost: (None, Some(10.), false, None),
+    case03_negative_job_cost: (Some(-1.), Some(10.), false, Some("job1")),
+    case04_negative_plan_cost: (None, Some(-1.), false, Some("job1")),
+    case05_split_with_plan_cost: (None, Some(10.), true, None),
+    case06_split_with_job_cost: (Some(10.), None, true, Some("job1")),
+}
+
+fn can_detect_invalid_outsourcing_cost_impl(
+    job_cost: Option<f64>,
+    plan_cost: Option<f64>,
+    has_split: bool,
+    expected: Option<&str>,
+) {
+    let split = if has_split { Some(JobSplit { max_splits: None, min_size: None }) } else { None };
+    let job = Job { outsourcing_cost: job_cost, split, ..create_delivery_job("job1", (1., 0.)) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], outsourcing_cost: plan_cost, ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1116_outsourcing_cost_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(action) = expected {
+        assert_result("E1116", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
 // --- Sync groups validation tests (E1110) ---
 
 #[test]
@@ -1,5 +1,6 @@
 use super::*;
 use crate::format::problem::Objective::*;
+use crate::format_time;
 use crate::helpers::create_empty_problem;
 use crate::helpers::*;
 use vrp_core::prelude::Float;
@@ -207,3 +208,98 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Objective>>, e
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_missing_time_window_penalty_objective, (objectives, expected), {
+    can_detect_missing_time_window_penalty_objective_impl(objectives, expected);
+}}
+
+can_detect_missing_time_window_penalty_objective! {
+    case01: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MinimizeCost,
+            ]), Some("E1608".to_string())),
+    case02: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MinimizeTimeWindowPenalty,
+                MinimizeCost,
+            ]), None),
+    case03: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MultiObjective {
+                    strategy: MultiStrategy::Sum,
+                    objectives: vec![MinimizeTimeWindowPenalty, MinimizeCost],
+                },
+            ]), None),
+    case04: (None, None),
+}
+
+fn can_detect_missing_time_window_penalty_objective_impl(objectives: Option<Vec<Objective>>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: Some(vec![vec![format_time(10.), format_time(20.)]]),
+                        soft_times: Some(JobPlaceSoftTimes {
+                            max_earliness: Some(10.),
+                            max_lateness: None,
+                            earliness_cost: Some(1.),
+                            lateness_cost: None,
+                        }),
+                        ..create_job_place((1., 0.), None)
+                    }],
+                    ..create_task((1., 0.), None)
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1608_jobs_with_soft_time_windows_but_no_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_minimize_changes_baseline, (arrival, activity_time, expected), {
+    can_detect_invalid_minimize_changes_baseline_impl(arrival, activity_time, expected);
+}}
+
+can_detect_invalid_minimize_changes_baseline! {
+    case01_valid: (format_time(1.), None, None),
+    case02_invalid_arrival: ("not a time".to_string(), None, Some("E1609".to_string())),
+    case03_invalid_activity_time: (format_time(1.), Some("2020-13-01".to_string()), Some("E1609".to_string())),
+}
+
+fn can_detect_invalid_minimize_changes_baseline_impl(
+    arrival: String,
+    activity_time: Option<String>,
+    expected: Option<String>,
+) {
+    let stop = StopBuilder::default().coordinate((1., 0.)).schedule_stamp(1., 2.).load(vec![0]);
+    let mut tour = TourBuilder::default()
+        .vehicle_id("my_vehicle_1")
+        .stops(vec![stop.build_single_time("job1", "delivery", (1., 2.))])
+        .build();
+    tour.stops[0].schedule_mut().arrival = arrival;
+    if let Some(start) = activity_time {
+        tour.stops[0].activities_mut()[0].time.as_mut().unwrap().start = start;
+    }
+    let baseline = SolutionBuilder::default().tour(tour).build();
+    let objectives = [MinimizeChanges {
+        baseline: Box::new(baseline),
+        max_position_shift: None,
+        max_arrival_shift: None,
+        penalties: None,
+    }];
+    let objectives = objectives.iter().collect::<Vec<_>>();
+
+    let result = check_e1609_invalid_minimize_changes_baseline(objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
@@ -45,7 +45,7 @@ fn can_detect_zero_costs_impl(costs: (Float, Float), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance: TieredCost::Fixed(distance), time: TieredCost::Fixed(time), calculation_mode: None },
+                costs: VehicleCosts { fixed: None, distance: TieredCost::Fixed(distance), time: TieredCost::Fixed(time), calculation_mode: None, time_rates: None, price_list: None },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -137,3 +137,541 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_overtime, (regular_duration, cost, expected), {
+    can_detect_invalid_overtime_impl(regular_duration, cost, expected);
+}}
+
+can_detect_invalid_overtime! {
+    case01: (3600., TieredCost::Fixed(1.), None),
+    case02: (-1., TieredCost::Fixed(1.), Some("E1309".to_string())),
+    case03: (3600., TieredCost::Fixed(-1.), Some("E1309".to_string())),
+    case04: (3600., TieredCost::Tiered(vec![CostTier { threshold: 0., cost: -1. }]), Some("E1309".to_string())),
+}
+
+fn can_detect_invalid_overtime_impl(regular_duration: Float, cost: TieredCost, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    overtime: Some(VehicleOvertime { regular_duration, cost }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1309_vehicle_overtime_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_hours_of_service, (limits, min_daily_rest, next_shift_start, expected), {
+    can_detect_invalid_hours_of_service_impl(limits, min_daily_rest, next_shift_start, expected);
+}}
+
+can_detect_invalid_hours_of_service! {
+    case01_valid: ((100., 10., Some(200.)), Some(500.), Some(2000.), None),
+    case02_zero_driving: ((0., 10., None), None, None, Some("E1310".to_string())),
+    case03_negative_rest: ((100., -1., None), None, None, Some("E1310".to_string())),
+    case04_small_daily_driving: ((100., 10., Some(50.)), None, None, Some("E1310".to_string())),
+    case05_short_daily_rest: ((100., 10., None), Some(500.), Some(1200.), Some("E1310".to_string())),
+    case06_no_daily_rest_check: ((100., 10., None), None, Some(1200.), None),
+}
+
+fn can_detect_invalid_hours_of_service_impl(
+    limits: (Float, Float, Option<Float>),
+    min_daily_rest: Option<Float>,
+    next_shift_start: Option<Float>,
+    expected: Option<String>,
+) {
+    let (max_driving_time, rest_duration, max_daily_driving_time) = limits;
+    let next_shifts = next_shift_start.into_iter().map(|start| VehicleShift {
+        start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(start + 1000.), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    });
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: std::iter::once(create_default_vehicle_shift()).chain(next_shifts).collect(),
+                hours_of_service: Some(VehicleHoursOfService {
+                    max_driving_time,
+                    rest_duration,
+                    max_daily_driving_time,
+                    min_daily_rest,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_hours_of_service_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_overnight, (shift_times, cost, has_next_shift_breaks, expected), {
+    can_detect_invalid_overnight_impl(shift_times, cost, has_next_shift_breaks, expected);
+}}
+
+can_detect_invalid_overnight! {
+    case01_valid: (vec![(0., 100.), (200., 300.)], Some(10.), false, None),
+    case02_single_shift: (vec![(0., 100.)], None, false, Some("E1311".to_string())),
+    case03_overlapping_shifts: (vec![(0., 100.), (50., 300.)], None, false, Some("E1311".to_string())),
+    case04_negative_cost: (vec![(0., 100.), (200., 300.)], Some(-1.), false, Some("E1311".to_string())),
+    case05_next_shift_breaks: (vec![(0., 100.), (200., 300.)], None, true, Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_overnight_impl(
+    shift_times: Vec<(Float, Float)>,
+    cost: Option<Float>,
+    has_next_shift_breaks: bool,
+    expected: Option<String>,
+) {
+    let shifts = shift_times
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (start, end))| VehicleShift {
+            start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+            end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+            breaks: if has_next_shift_breaks && idx > 0 {
+                Some(vec![VehicleBreak::Optional {
+                    time: VehicleOptionalBreakTime::TimeOffset(vec![0., 10.]),
+                    places: vec![VehicleOptionalBreakPlace { duration: 1., location: None, tag: None }],
+                    policy: None,
+                }])
+            } else {
+                None
+            },
+            ..create_default_vehicle_shift()
+        })
+        .collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts,
+                overnight: Some(VehicleOvernight { locations: None, cost }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_overnight_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+#[test]
+fn can_detect_overnight_with_hours_of_service() {
+    let shifts = [(0., 100.), (200., 300.)]
+        .into_iter()
+        .map(|(start, end)| VehicleShift {
+            start: ShiftStart { earliest: format_time(start), latest: None, location: (0., 0.).to_loc() },
+            end: Some(ShiftEnd { earliest: None, latest: format_time(end), location: (0., 0.).to_loc() }),
+            ..create_default_vehicle_shift()
+        })
+        .collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts,
+                overnight: Some(VehicleOvernight { locations: None, cost: None }),
+                hours_of_service: Some(VehicleHoursOfService {
+                    max_driving_time: 50.,
+                    rest_duration: 10.,
+                    max_daily_driving_time: None,
+                    min_daily_rest: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_overnight_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    let err = result.expect_err("overnight with hours of service should be rejected");
+    assert_eq!(err.code, "E1311");
+    assert!(err.action.contains("not supported within multi-day tours"));
+}
+
+parameterized_test! {can_detect_invalid_recharges, (max_distance, battery, expected), {
+    can_detect_invalid_recharges_impl(max_distance, battery, expected);
+}}
+
+can_detect_invalid_recharges! {
+    case01_valid_distance: (Some(100.), None, None),
+    case02_no_limits: (None, None, Some("E1312".to_string())),
+    case03_valid_battery: (None, Some((60., Some(50.), Some(5.), vec![(60., 50.)])), None),
+    case04_zero_capacity: (None, Some((0., None, None, vec![(60., 50.)])), Some("E1312".to_string())),
+    case05_reserve_above_initial: (None, Some((60., Some(5.), Some(10.), vec![(60., 50.)])), Some("E1312".to_string())),
+    case06_initial_above_capacity: (None, Some((60., Some(70.), None, vec![(60., 50.)])), Some("E1312".to_string())),
+    case07_empty_curve: (None, Some((60., None, None, vec![])), Some("E1312".to_string())),
+    case08_unordered_curve: (None, Some((60., None, None, vec![(40., 50.), (20., 20.)])), Some("E1312".to_string())),
+    case09_zero_power: (None, Some((60., None, None, vec![(60., 0.)])), Some("E1312".to_string())),
+}
+
+type BatteryData = (Float, Option<Float>, Option<Float>, Vec<(Float, Float)>);
+
+fn can_detect_invalid_recharges_impl(
+    max_distance: Option<Float>,
+    battery: Option<BatteryData>,
+    expected: Option<String>,
+) {
+    let battery = battery.map(|(capacity, initial_charge, reserve, curve)| VehicleBattery {
+        capacity,
+        initial_charge,
+        reserve,
+        consumption: 0.2,
+        load_consumption: None,
+        charging_curve: curve.into_iter().map(|(level, power)| ChargingCurvePoint { level, power }).collect(),
+    });
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance,
+                        initial_distance: None,
+                        battery,
+                        stations: vec![],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_recharges_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_charger_resources, (resources, expected), {
+    can_detect_invalid_charger_resources_impl(resources, expected);
+}}
+
+can_detect_invalid_charger_resources! {
+    case01: (None, Some("E1313".to_string())),
+    case02: (Some(vec![("c1", 2)]), None),
+    case03: (Some(vec![("c1", 2), ("c1", 1)]), Some("E1313".to_string())),
+    case04: (Some(vec![("c1", 0)]), Some("E1313".to_string())),
+    case05: (Some(vec![("c2", 1)]), Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_charger_resources_impl(resources: Option<Vec<(&str, usize)>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: Some(100.),
+                        initial_distance: None,
+                        battery: None,
+                        stations: vec![VehicleRechargeStation {
+                            location: (0., 0.).to_loc(),
+                            duration: 10.,
+                            times: None,
+                            tag: None,
+                            resource_id: Some("c1".to_string()),
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: resources.map(|resources| {
+                resources
+                    .into_iter()
+                    .map(|(id, capacity)| VehicleResource::Charger { id: id.to_string(), capacity })
+                    .collect()
+            }),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_charger_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_dock_resources, (docks, expected), {
+    can_detect_invalid_dock_resources_impl(docks, expected);
+}}
+
+can_detect_invalid_dock_resources! {
+    case01: (vec![("d1", (0., 0.), 1, 10., None)], None),
+    case02: (vec![("d1", (0., 0.), 1, 10., None), ("d1", (1., 0.), 1, 10., None)], Some("E1314".to_string())),
+    case03: (vec![("d1", (0., 0.), 1, 10., None), ("d2", (0., 0.), 1, 10., None)], Some("E1314".to_string())),
+    case04: (vec![("d1", (0., 0.), 0, 10., None)], Some("E1314".to_string())),
+    case05: (vec![("d1", (0., 0.), 1, -1., None)], Some("E1314".to_string())),
+    case06: (vec![("d1", (0., 0.), 1, 10., Some(vec![]))], Some("E1314".to_string())),
+    case07: (
+        vec![("d1", (0., 0.), 1, 10., Some(vec![vec![format_time(10.), format_time(0.)]]))],
+        Some("E1314".to_string())
+    ),
+}
+
+type DockData = (&'static str, (f64, f64), usize, Float, Option<Vec<Vec<String>>>);
+
+fn can_detect_invalid_dock_resources_impl(docks: Vec<DockData>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            resources: Some(
+                docks
+                    .into_iter()
+                    .map(|(id, location, capacity, loading_time, times)| VehicleResource::Dock {
+                        id: id.to_string(),
+                        location: location.to_loc(),
+                        capacity,
+                        loading_time,
+                        unit_loading_time: None,
+                        times,
+                    })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1314_vehicle_dock_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_compartments, (compartments, expected), {
+    can_detect_invalid_compartments_impl(compartments, expected);
+}}
+
+can_detect_invalid_compartments! {
+    case01: (Some(vec![("c1", vec![10], None)]), None),
+    case02: (Some(vec![("c1", vec![10], Some(vec!["fuel"])), ("c2", vec![5], None)]), None),
+    case03: (None, None),
+    case04: (Some(vec![]), Some("E1315".to_string())),
+    case05: (Some(vec![("c1", vec![10], None), ("c1", vec![5], None)]), Some("E1315".to_string())),
+    case06: (Some(vec![("c1", vec![10, 1], None)]), Some("E1315".to_string())),
+    case07: (Some(vec![("c1", vec![-1], None)]), Some("E1315".to_string())),
+    case08: (Some(vec![("c1", vec![10], Some(vec![]))]), Some("E1315".to_string())),
+}
+
+type CompartmentData = (&'static str, Vec<i32>, Option<Vec<&'static str>>);
+
+fn can_detect_invalid_compartments_impl(compartments: Option<Vec<CompartmentData>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                compartments: compartments.map(|compartments| {
+                    compartments
+                        .into_iter()
+                        .map(|(id, capacity, products)| VehicleCompartment {
+                            id: id.to_string(),
+                            capacity,
+                            products: products.map(|products| products.iter().map(|p| p.to_string()).collect()),
+                        })
+                        .collect()
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1315_vehicle_compartments_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_trailers, (trailer_id, trailers, expected), {
+    can_detect_invalid_trailers_impl(trailer_id, trailers, expected);
+}}
+
+can_detect_invalid_trailers! {
+    case01: (Some("t1"), vec![("t1", vec![5], 1)], None),
+    case02: (None, vec![], None),
+    case03: (Some("t2"), vec![("t1", vec![5], 1)], Some("E1316".to_string())),
+    case04: (Some("t1"), vec![("t1", vec![5], 1), ("t1", vec![5], 1)], Some("E1316".to_string())),
+    case05: (Some("t1"), vec![("t1", vec![5], 0)], Some("E1316".to_string())),
+    case06: (Some("t1"), vec![("t1", vec![5, 1], 1)], Some("E1316".to_string())),
+    case07: (Some("t1"), vec![("t1", vec![-1], 1)], Some("E1316".to_string())),
+}
+
+fn can_detect_invalid_trailers_impl(
+    trailer_id: Option<&str>,
+    trailers: Vec<(&str, Vec<i32>, usize)>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                trailer_id: trailer_id.map(|id| id.to_string()),
+                ..create_default_vehicle_type()
+            }],
+            trailers: Some(
+                trailers
+                    .into_iter()
+                    .map(|(id, capacity, parkings)| Trailer {
+                        id: id.to_string(),
+                        capacity,
+                        parkings: (0..parkings)
+                            .map(|_| TrailerParking {
+                                location: (1., 0.).to_loc(),
+                                duration: 1.,
+                                times: None,
+                                tag: None,
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1316_vehicle_trailers_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_time_rates, (weekdays, start, driving, expected), {
+    can_detect_invalid_time_rates_impl(weekdays, start, driving, expected);
+}}
+
+can_detect_invalid_time_rates! {
+    case01: (Some(vec![6, 7]), "22:00", Some(2.), None),
+    case02: (Some(vec![0]), "22:00", Some(2.), Some("E1317".to_string())),
+    case03: (None, "25:00", Some(2.), Some("E1317".to_string())),
+    case04: (None, "22:00", Some(-1.), Some("E1317".to_string())),
+    case05: (None, "22:00", None, Some("E1317".to_string())),
+}
+
+fn can_detect_invalid_time_rates_impl(
+    weekdays: Option<Vec<u8>>,
+    start: &str,
+    driving: Option<Float>,
+    expected: Option<String>,
+) {
+    let time_rate = VehicleTimeRate {
+        weekdays,
+        start: start.to_string(),
+        end: "06:00".to_string(),
+        driving,
+        service: None,
+        waiting: None,
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { time_rates: Some(vec![time_rate]), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1317_vehicle_time_rates_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_price_list, (prices, expected), {
+    can_detect_invalid_price_list_impl(prices, expected);
+}}
+
+can_detect_invalid_price_list! {
+    case01: (vec![(None, 10.), (Some("a"), 5.)], None),
+    case02: (vec![(Some("a"), 10.), (Some("a"), 5.)], Some("E1318".to_string())),
+    case03: (vec![(None, 10.), (None, 5.)], Some("E1318".to_string())),
+    case04: (vec![(None, -1.)], Some("E1318".to_string())),
+}
+
+fn can_detect_invalid_price_list_impl(prices: Vec<(Option<&str>, Float)>, expected: Option<String>) {
+    let price_list =
+        prices.into_iter().map(|(zone, cost)| VehiclePrice { zone: zone.map(str::to_string), cost }).collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { price_list: Some(price_list), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1318_vehicle_price_list_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_emissions, (consumption, full_consumption, emission_factor, expected), {
+    can_detect_invalid_emissions_impl(consumption, full_consumption, emission_factor, expected);
+}}
+
+can_detect_invalid_emissions! {
+    case01: (0.3, Some(0.4), 2.6, None),
+    case02: (0.3, None, 2.6, None),
+    case03: (-0.3, None, 2.6, Some("E1319".to_string())),
+    case04: (0.3, Some(-0.4), 2.6, Some("E1319".to_string())),
+    case05: (0.3, None, -2.6, Some("E1319".to_string())),
+}
+
+fn can_detect_invalid_emissions_impl(
+    consumption: Float,
+    full_consumption: Option<Float>,
+    emission_factor: Float,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                emissions: Some(VehicleEmissions {
+                    consumption,
+                    full_consumption,
+                    idle_consumption: None,
+                    emission_factor,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1319_vehicle_emissions_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}